@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+// === ACCOUNT NAMING ===
+// Staker positions were originally published as `LenderStake` and later renamed
+// to `BackerDeposit`. The PDA seed never changed, so both names resolve to the
+// same address. These constants are exported in the IDL so clients can map
+// either name onto the canonical account.
+
+/// PDA seed for staker positions: ["lender_stake", staker]
+#[constant]
+pub const BACKER_DEPOSIT_SEED: &[u8] = b"lender_stake";
+
+/// Canonical account name for staker positions
+#[constant]
+pub const BACKER_DEPOSIT_ACCOUNT_NAME: &str = "BackerDeposit";
+
+/// Legacy account name for staker positions (same PDA, same layout)
+#[constant]
+pub const LENDER_STAKE_LEGACY_ACCOUNT_NAME: &str = "LenderStake";
+
+/// Metaplex Token Metadata program (boost NFT collection checks)
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+  pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bS518x1s");
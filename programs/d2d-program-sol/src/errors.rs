@@ -142,4 +142,434 @@ pub enum ErrorCode {
   InvalidDistributionPercentage,
   #[msg("No pending rewards to distribute")]
   NoPendingRewards,
+
+  // Keeper incentive errors
+  #[msg("Only the head of the withdrawal queue can be processed")]
+  NotQueueHead,
+  #[msg("Keeper tip exceeds maximum allowed")]
+  InvalidKeeperTip,
+
+  // Donation accounting errors
+  #[msg("No surplus lamports to absorb")]
+  NoSurplusLamports,
+  #[msg("Invalid donation policy")]
+  InvalidDonationPolicy,
+
+  // Payout safety errors
+  #[msg("Payout would leave the source PDA below rent exemption")]
+  PayoutBelowRentExemption,
+
+  // Emergency exit policy errors
+  #[msg("Liquidity is reserved for queued withdrawals")]
+  QueueLiquidityReserved,
+  #[msg("Invalid emergency exit policy")]
+  InvalidEmergencyExitPolicy,
+  #[msg("Queue jump fee exceeds maximum allowed")]
+  InvalidQueueJumpFee,
+
+  // Queue expiry errors
+  #[msg("Queue entry has not reached max queue age")]
+  QueueEntryNotExpired,
+  #[msg("Invalid max queue age")]
+  InvalidMaxQueueAge,
+  #[msg("No finished queue entries to compact")]
+  QueueNotCompactable,
+
+  // Off-boarding errors
+  #[msg("Returning upgrade authority is disabled by the off-boarding policy")]
+  OffboardingDisabled,
+  #[msg("Invalid off-boarding policy")]
+  InvalidOffboardingPolicy,
+
+  // Buffer rent loan errors
+  #[msg("Buffer rent loans are disabled")]
+  BufferRentLoansDisabled,
+  #[msg("Buffer rent loan exceeds maximum allowed")]
+  BufferRentLoanTooLarge,
+  #[msg("Buffer rent loan is outstanding - spill account must be the loan PDA")]
+  BufferRentLoanOutstanding,
+  #[msg("Invalid buffer rent loan limit")]
+  InvalidBufferRentLoanLimit,
+
+  // Auto compound errors
+  #[msg("Too many stake accounts for one compound batch")]
+  CompoundBatchTooLarge,
+
+  // Subscription revenue split errors
+  #[msg("Invalid subscription revenue split")]
+  InvalidSubscriptionSplit,
+  #[msg("No pending subscription revenue split change")]
+  NoPendingSubscriptionSplit,
+
+  // Stake limit errors
+  #[msg("Stake amount exceeds the per-transaction maximum")]
+  StakeExceedsPerTxLimit,
+  #[msg("Daily stake limit exceeded for this wallet")]
+  DailyStakeLimitExceeded,
+
+  // Service fee refund errors
+  #[msg("Invalid service fee refund policy")]
+  InvalidServiceFeeRefundPolicy,
+
+  // Bonus vesting errors
+  #[msg("No vested bonus to claim")]
+  NoVestedBonus,
+  #[msg("Invalid bonus vesting period")]
+  InvalidBonusVestingPeriod,
+
+  // Terms of service errors
+  #[msg("Terms hash does not match the current terms")]
+  TermsHashMismatch,
+
+  // Insurance errors
+  #[msg("Invalid insurance policy")]
+  InvalidInsurancePolicy,
+  #[msg("Coverage exceeds the effective stake")]
+  CoverageExceedsStake,
+  #[msg("No stake is covered by the insurance fund")]
+  NoInsuredStake,
+  #[msg("Deployment recovery is above the insurance loss threshold")]
+  LossThresholdNotMet,
+  #[msg("No insurance compensation to claim")]
+  NoInsuranceCompensation,
+
+  // Request parameter bounds errors
+  #[msg("Invalid request parameter bounds")]
+  InvalidRequestBounds,
+  #[msg("Service fee is outside the allowed bounds")]
+  ServiceFeeOutOfBounds,
+  #[msg("Monthly fee is outside the allowed bounds")]
+  MonthlyFeeOutOfBounds,
+  #[msg("Initial months is outside the allowed bounds")]
+  InitialMonthsOutOfBounds,
+
+  // Emergency haircut errors
+  #[msg("Invalid emergency haircut")]
+  InvalidEmergencyHaircut,
+
+  // Funding reconciliation errors
+  #[msg("Recovered and spent funds do not reconcile with the funded amount")]
+  FundingReconciliationFailed,
+  #[msg("Invalid funding variance tolerance")]
+  InvalidFundingVarianceTolerance,
+
+  // Stake snapshot errors
+  #[msg("Snapshot epoch must be the current epoch")]
+  InvalidSnapshotEpoch,
+
+  // LST staking errors
+  #[msg("Invalid LST config")]
+  InvalidLstConfig,
+  #[msg("LST mint is not accepted for staking")]
+  LstNotAccepted,
+  #[msg("LST oracle rate is stale")]
+  StaleLstRate,
+  #[msg("Insufficient LST vault balance")]
+  InsufficientLstVaultBalance,
+
+  // Reward backing errors
+  #[msg("Reward pool debit would leave protected rewards unbacked")]
+  ProtectedRewardsUnbacked,
+
+  // Stake position errors
+  #[msg("Only another indexed position without a queued withdrawal can be merged")]
+  InvalidPositionMerge,
+
+  // Collateral pledge errors
+  #[msg("Stake position is pledged as collateral")]
+  StakePledged,
+  #[msg("Stake position is not pledged to this partner")]
+  StakeNotPledged,
+  #[msg("Collateral partner is disabled")]
+  CollateralPartnerDisabled,
+
+  // Auto unstake errors
+  #[msg("Auto-unstake time must be in the future")]
+  InvalidAutoUnstakeTime,
+  #[msg("Auto-unstake time has not been reached")]
+  AutoUnstakeNotDue,
+
+  // Watchtower errors
+  #[msg("Invalid watchtower bounty or cooldown")]
+  InvalidWatchtowerBounty,
+  #[msg("Alert condition does not hold on-chain")]
+  AlertNotValidated,
+  #[msg("An alert of this kind was paid recently")]
+  AlertCooldownActive,
+
+  // Dust sweep errors
+  #[msg("Position is not an inactive dust position")]
+  PositionNotSweepable,
+
+  // Pool share cap errors
+  #[msg("Invalid max pool share")]
+  InvalidMaxPoolShare,
+  #[msg("Stake would exceed the max share of the pool per staker")]
+  StakeExceedsPoolShare,
+
+  // Voting power errors
+  #[msg("Invalid voting power proof")]
+  InvalidVotingPowerProof,
+
+  // Payout address errors
+  #[msg("Destination is not the registered payout address")]
+  InvalidPayoutDestination,
+
+  // Unstake commitment errors
+  #[msg("Unstakes of this size must be committed first")]
+  UnstakeCommitmentRequired,
+  #[msg("Position has a pending unstake commitment")]
+  UnstakeCommitmentPending,
+  #[msg("Position has no unstake commitment")]
+  NoUnstakeCommitment,
+  #[msg("Unstake commitment delay has not passed")]
+  UnstakeCommitmentNotReady,
+  #[msg("Unstake amount does not match the commitment")]
+  UnstakeCommitmentMismatch,
+  #[msg("Invalid unstake commitment policy")]
+  InvalidUnstakeCommitmentPolicy,
+
+  // Payment sizing errors
+  #[msg("Subscription payment exceeds the maximum allowed")]
+  MaxPaymentExceeded,
+
+  // Developer identity errors
+  #[msg("Profile hash cannot be empty")]
+  InvalidProfileHash,
+  #[msg("Profile hash does not match the linked identity")]
+  IdentityProfileMismatch,
+
+  // Boost campaign errors
+  #[msg("Invalid campaign window")]
+  InvalidCampaignWindow,
+  #[msg("Invalid campaign boost")]
+  InvalidCampaignBoost,
+  #[msg("Invalid campaign budget")]
+  InvalidCampaignBudget,
+  #[msg("Campaign is not active")]
+  CampaignNotActive,
+  #[msg("Position is not eligible for this campaign")]
+  NotEligibleForCampaign,
+  #[msg("No campaign boost to claim")]
+  NoCampaignBoost,
+  #[msg("Campaign claim window is still open")]
+  CampaignClaimWindowOpen,
+
+  // Reward pool solvency errors
+  #[msg("Reward pool lamports do not cover its recorded obligations")]
+  RewardPoolInsolvent,
+
+  // Batch claim errors
+  #[msg("Invalid number of positions for one claim batch")]
+  InvalidClaimBatch,
+  #[msg("Position appears more than once in the claim batch")]
+  DuplicateClaimPosition,
+
+  // Boost NFT errors
+  #[msg("Boost NFT collection is not configured")]
+  BoostNftDisabled,
+  #[msg("Invalid boost NFT: not a verified collection NFT held by the staker")]
+  InvalidBoostNft,
+  #[msg("Boost NFT bps exceeds maximum")]
+  InvalidNftBoost,
+
+  // Drawdown simulation errors
+  #[msg("Invalid drawdown scenario")]
+  InvalidDrawdownScenario,
+
+  // Stablecoin subscription errors
+  #[msg("Stablecoin subscription payments are not configured")]
+  StablecoinNotConfigured,
+  #[msg("No stablecoin monthly fee set for this request")]
+  StablecoinFeeNotSet,
+
+  // Decentralization errors
+  #[msg("Protocol is already decentralized")]
+  AlreadyDecentralized,
+  #[msg("Admin-only path retired by decentralization")]
+  AdminPathRetired,
+
+  // Escrow token errors
+  #[msg("Token mint is not accepted for escrow deposits")]
+  UnsupportedEscrowMint,
+  #[msg("Invalid escrow token mint configuration")]
+  InvalidEscrowMints,
+  #[msg("Escrow token vault, stablecoin vault and token program are required")]
+  EscrowTokenAccountsRequired,
+
+  // Escrow renewal errors
+  #[msg("Subscription is not within the permissionless renewal window")]
+  RenewalWindowClosed,
+  #[msg("Permissionless renewal window exceeds maximum")]
+  InvalidRenewalWindow,
+
+  // Escrow top-up errors
+  #[msg("Invalid escrow top-up configuration")]
+  InvalidTopUpConfig,
+  #[msg("Escrow balance is not below its alert threshold")]
+  TopUpNotDue,
+
+  // Escrow spending limit errors
+  #[msg("Renewal price per month exceeds the escrow's spending cap")]
+  EscrowSpendingCapExceeded,
+  #[msg("Renewal would exceed the escrow's monthly auto-deduct limit")]
+  AutoDeductCapExceeded,
+  #[msg("Invalid escrow configuration")]
+  InvalidEscrowConfig,
+
+  // Escrow closing errors
+  #[msg("Escrow still holds token balances")]
+  EscrowNotEmpty,
+  #[msg("Escrow is still used by auto-renewal or top-ups")]
+  EscrowInUse,
+
+  // Per-program auto-renewal errors
+  #[msg("Renewal months exceed the program's per-renewal limit")]
+  RenewalMonthsExceedLimit,
+
+  // Pending deployment cancellation errors
+  #[msg("Pending deployment has not reached its expiry")]
+  PendingDeploymentNotStale,
+
+  // Subscription plan errors
+  #[msg("Invalid plan tier: non-zero id, size and fees required, max 8 tiers")]
+  InvalidPlanTier,
+  #[msg("Plan not found or disabled")]
+  PlanNotFound,
+  #[msg("Program size exceeds the plan's limit")]
+  ProgramSizeExceedsPlan,
+  #[msg("Upgrades included in the plan are used up")]
+  PlanUpgradeLimitReached,
+  #[msg("Request is already on this plan")]
+  InvalidPlanChange,
+
+  // Authority buy-out errors
+  #[msg("Authority exit fee exceeds the maximum")]
+  InvalidAuthorityExitFee,
+
+  // Authority transfer errors
+  #[msg("New authority is not on the approved allowlist")]
+  AuthorityNotApproved,
+  #[msg("Authority transfer is still timelocked")]
+  AuthorityTransferTimelocked,
+
+  // Scheduled upgrade errors
+  #[msg("An upgrade is already scheduled for this program")]
+  ScheduledUpgradePending,
+  #[msg("No upgrade is scheduled for this program")]
+  NoScheduledUpgrade,
+  #[msg("Scheduled upgrade must give at least the minimum notice")]
+  InvalidUpgradeSchedule,
+
+  // Co-developer errors
+  #[msg("Upgrade signer is the owner, already listed, or not listed")]
+  InvalidUpgradeSigner,
+  #[msg("Maximum number of upgrade signers reached")]
+  UpgradeSignerLimitReached,
+
+  // Buffer verification errors
+  #[msg("Buffer bytecode does not match the expected hash")]
+  BufferHashMismatch,
+
+  // Upgrade frequency errors
+  #[msg("Monthly upgrade limit reached and the plan has no overage fee")]
+  MonthlyUpgradeLimitReached,
+  #[msg("Developer escrow is required to pay the upgrade overage fee")]
+  UpgradeOverageEscrowRequired,
+
+  // Rollback errors
+  #[msg("Rollback target must be an earlier release with a recorded bytecode hash")]
+  InvalidRollbackTarget,
+
+  // Coupon errors
+  #[msg("Coupon discount must be between 1 and 10000 bps")]
+  InvalidCoupon,
+  #[msg("Coupon is inactive, expired or fully redeemed")]
+  CouponNotRedeemable,
+
+  // Prepayment discount errors
+  #[msg("Prepayment discount steps must increase in months and discount")]
+  InvalidPrepayDiscount,
+
+  // Deployment quota errors
+  #[msg("Developer has reached the maximum number of concurrent deployments")]
+  DeploymentQuotaExceeded,
+
+  // Expiry warning errors
+  #[msg("Subscription is not due an expiry warning")]
+  ExpiryWarningNotDue,
+  #[msg("Expiry warning window or interval out of range")]
+  InvalidExpiryWarningPolicy,
+
+  // Escrow budget errors
+  #[msg("Renewal would exceed the program's escrow budget")]
+  EscrowBudgetExceeded,
+  #[msg("All escrow budget slots are in use")]
+  EscrowBudgetsFull,
+
+  // Role errors
+  #[msg("Role cannot be assigned to the admin")]
+  InvalidRoleHolder,
+
+  // Admin council errors
+  #[msg("Operation requires an approved admin council proposal")]
+  CouncilApprovalRequired,
+  #[msg("Invalid council members or threshold")]
+  InvalidCouncilConfig,
+  #[msg("Signer is not an admin council member")]
+  NotCouncilMember,
+  #[msg("Member already approved this proposal")]
+  AlreadyApproved,
+  #[msg("Proposal does not have enough approvals")]
+  CouncilThresholdNotMet,
+  #[msg("Proposal has expired")]
+  CouncilProposalExpired,
+  #[msg("Proposal was made by a previous council")]
+  CouncilProposalStale,
+
+  // Scheduled unpause errors
+  #[msg("Program is not paused")]
+  ProgramNotPaused,
+  #[msg("Unpausing requires schedule_unpause and execute_unpause")]
+  UnpauseRequiresSchedule,
+  #[msg("An unpause is already scheduled")]
+  UnpauseAlreadyScheduled,
+  #[msg("No unpause is scheduled")]
+  UnpauseNotScheduled,
+
+  // LST redemption errors
+  #[msg("Redeemed SOL is below the tokens' value less the allowed slippage")]
+  RedemptionBelowValue,
+
+  // Coupon errors
+  #[msg("Coupon code does not match the coupon account")]
+  CouponCodeMismatch,
+
+  // Insurance coverage errors
+  #[msg("Insurance fund account is required to shrink the position's coverage")]
+  InsuranceFundRequired,
+
+  // Boost campaign checkpoint errors
+  #[msg("Position is already enrolled in a running campaign")]
+  AlreadyInCampaign,
+  #[msg("Position's running campaign and enrollment must be passed")]
+  CampaignAccountsRequired,
+
+  // Boost NFT usage errors
+  #[msg("Boost NFT already boosts another position")]
+  BoostNftInUse,
+  #[msg("Position already holds a different boost NFT; revoke it first")]
+  BoostNftAlreadyApplied,
+  #[msg("Boost NFT is still held by the staker")]
+  BoostNftStillHeld,
+
+  // Hibernation errors
+  #[msg("Storage fee must be paid up before the program is redeployed")]
+  StorageFeeOverdue,
+
+  // Decentralized deployment funding errors
+  #[msg("Deployment funding exceeds the rent limit for the request's program size")]
+  DeploymentFundingExceedsLimit,
+  #[msg("Decentralization requires the admin council")]
+  AdminCouncilRequired,
 }
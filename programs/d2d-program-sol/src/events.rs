@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
 
+use crate::states::{
+  CampaignEligibility, CouncilAction, FeeSource, PrepayDiscount, Role, WatchtowerAlert,
+};
+
 #[event]
 pub struct TreasuryInitialized {
   pub admin: Pubkey,
@@ -7,6 +11,15 @@ pub struct TreasuryInitialized {
   pub initial_apy: u64,
 }
 
+#[event]
+pub struct TreasuryVaultMigrated {
+  pub admin: Pubkey,
+  pub treasury_vault: Pubkey,
+  pub lamports_moved: u64,
+  pub vault_top_up: u64,
+  pub migrated_at: i64,
+}
+
 #[event]
 pub struct SolStaked {
   pub lender: Pubkey,
@@ -22,6 +35,44 @@ pub struct SolUnstaked {
   pub remaining_staked: u64,
 }
 
+/// Compact pool state captured at the time of a staker action
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PoolSnapshot {
+  pub reward_per_share: u128,
+  pub total_deposited: u64,
+  pub liquid_balance: u64,
+  pub utilization_bps: u64,
+  pub queued_withdrawal_amount: u64,
+}
+
+/// SolStaked with a pool snapshot (emitted alongside SolStaked)
+#[event]
+pub struct SolStakedV2 {
+  pub lender: Pubkey,
+  pub amount: u64,
+  pub total_staked: u64,
+  pub pool: PoolSnapshot,
+  pub staked_at: i64,
+}
+
+/// SolUnstaked with a pool snapshot (emitted alongside SolUnstaked)
+#[event]
+pub struct SolUnstakedV2 {
+  pub lender: Pubkey,
+  pub amount: u64,
+  pub remaining_staked: u64,
+  pub pool: PoolSnapshot,
+  pub unstaked_at: i64,
+}
+
+#[event]
+pub struct FeeCollected {
+  pub source: FeeSource,
+  pub fee_reward: u64,
+  pub fee_platform: u64,
+  pub reward_per_share: u128,
+}
+
 #[event]
 pub struct RewardsClaimed {
   pub lender: Pubkey,
@@ -29,16 +80,86 @@ pub struct RewardsClaimed {
   pub total_claimed: u64,
 }
 
+#[event]
+pub struct ClaimDelegateChanged {
+  pub staker: Pubkey,
+  pub old_delegate: Option<Pubkey>,
+  pub new_delegate: Option<Pubkey>,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct PayoutAddressChanged {
+  pub staker: Pubkey,
+  pub old_payout_address: Option<Pubkey>,
+  pub new_payout_address: Option<Pubkey>,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct DelegatedClaimExecuted {
+  pub staker: Pubkey,
+  pub delegate: Pubkey,
+  pub amount: u64,
+  pub claimed_at: i64,
+}
+
+#[event]
+pub struct AutoCompoundToggled {
+  pub staker: Pubkey,
+  pub enabled: bool,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct RewardsCompounded {
+  pub staker: Pubkey,
+  pub amount: u64,
+  pub new_deposited_amount: u64,
+  pub compounded_at: i64,
+}
+
+#[event]
+pub struct CompoundBatchProcessed {
+  pub cranker: Pubkey,
+  pub stakers_compounded: u32,
+  pub total_compounded: u64,
+  pub total_deposited: u64,
+  pub processed_at: i64,
+}
+
+#[event]
+pub struct ClaimableRewardsQueried {
+  pub staker: Pubkey,
+  pub base_claimable: u64,
+  pub duration_bonus: u64,
+  pub total_claimable: u64,
+  pub queried_at: i64,
+}
+
+#[event]
+pub struct WaitTimeEstimated {
+  pub staker: Pubkey,
+  pub queue_position: u32,
+  pub remaining_amount: u64,
+  pub recovery_rate_per_day: u64,
+  pub estimated_wait_seconds: i64,
+  pub queried_at: i64,
+}
+
 #[event]
 pub struct DeploymentFundsRequested {
   pub request_id: [u8; 32],
   pub developer: Pubkey,
   pub program_hash: [u8; 32],
+  pub plan_id: u8,
   pub service_fee: u64,
   pub monthly_fee: u64,
   pub initial_months: u32,
   pub deployment_cost: u64,
   pub total_payment: u64,
+  /// 0.1% of deployment_cost credited to the platform pool
+  pub platform_fee: u64,
   pub requested_at: i64,
 }
 
@@ -60,6 +181,25 @@ pub struct DeploymentConfirmed {
   pub confirmed_at: i64,
 }
 
+#[event]
+pub struct FundingVarianceDetected {
+  pub request_id: [u8; 32],
+  pub funded_amount: u64,
+  pub recovered_funds: u64,
+  pub spent_funds: u64,
+  /// funded - (recovered + spent); positive = unexplained shortfall
+  pub variance: i64,
+  pub detected_at: i64,
+}
+
+#[event]
+pub struct FundingVarianceToleranceChanged {
+  pub admin: Pubkey,
+  pub old_tolerance: u64,
+  pub new_tolerance: u64,
+  pub changed_at: i64,
+}
+
 #[event]
 pub struct DeploymentFailed {
   pub request_id: [u8; 32],
@@ -70,12 +210,58 @@ pub struct DeploymentFailed {
   pub failed_at: i64,
 }
 
+#[event]
+pub struct TermsAccepted {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub terms_hash: [u8; 32],
+  pub accepted_at: i64,
+}
+
+#[event]
+pub struct TermsUpdated {
+  pub admin: Pubkey,
+  pub old_terms_hash: [u8; 32],
+  pub new_terms_hash: [u8; 32],
+  pub updated_at: i64,
+}
+
+#[event]
+pub struct ServiceFeeRefunded {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub service_fee: u64,
+  pub refund_amount: u64,
+  pub refunded_at: i64,
+}
+
+#[event]
+pub struct DeployRequestCancelled {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub service_fee_refund: u64,
+  pub subscription_refund: u64,
+  pub cancelled_at: i64,
+}
+
+#[event]
+pub struct ServiceFeeRefundPolicyChanged {
+  pub admin: Pubkey,
+  pub old_refund_bps: u64,
+  pub new_refund_bps: u64,
+  pub old_refund_window: i64,
+  pub new_refund_window: i64,
+  pub changed_at: i64,
+}
+
 #[event]
 pub struct SubscriptionPaid {
   pub request_id: [u8; 32],
   pub developer: Pubkey,
   pub months: u32,
   pub payment_amount: u64,
+  /// Prepayment discount taken off the payment
+  pub discount: u64,
   pub subscription_valid_until: i64,
 }
 
@@ -115,6 +301,15 @@ pub struct DepositMade {
   pub deposited_at: i64,
 }
 
+#[event]
+pub struct DepositFeeModeChanged {
+  pub admin: Pubkey,
+  pub deposit_fees_enabled: bool,
+  pub reward_fee_bps: u64,
+  pub platform_fee_bps: u64,
+  pub changed_at: i64,
+}
+
 #[event]
 pub struct RewardCredited {
   pub fee_reward: u64,
@@ -200,6 +395,36 @@ pub struct DailyLimitChanged {
   pub changed_at: i64,
 }
 
+#[event]
+pub struct StakeLimitsChanged {
+  pub admin: Pubkey,
+  pub old_max_stake_per_tx: u64,
+  pub new_max_stake_per_tx: u64,
+  pub old_daily_stake_limit: u64,
+  pub new_daily_stake_limit: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct MaxPoolShareChanged {
+  pub admin: Pubkey,
+  pub old_max_pool_share_bps: u64,
+  pub new_max_pool_share_bps: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct RequestBoundsChanged {
+  pub admin: Pubkey,
+  pub min_service_fee: u64,
+  pub max_service_fee: u64,
+  pub min_monthly_fee: u64,
+  pub max_monthly_fee: u64,
+  pub min_initial_months: u32,
+  pub max_initial_months: u32,
+  pub changed_at: i64,
+}
+
 #[event]
 pub struct EmergencyUnstake {
   pub lender: Pubkey,
@@ -208,6 +433,48 @@ pub struct EmergencyUnstake {
   pub unstaked_at: i64,
 }
 
+#[event]
+pub struct EmergencyHaircutCharged {
+  pub lender: Pubkey,
+  pub amount: u64,
+  pub haircut_amount: u64,
+  pub forfeited_rewards: u64,
+  pub charged_at: i64,
+}
+
+#[event]
+pub struct EmergencyHaircutChanged {
+  pub admin: Pubkey,
+  pub old_haircut_bps: u64,
+  pub new_haircut_bps: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct QueueJumpFeeCharged {
+  pub lender: Pubkey,
+  pub amount: u64,
+  pub reserved_portion: u64,
+  pub fee_amount: u64,
+  pub charged_at: i64,
+}
+
+#[event]
+pub struct EmergencyExitPolicyChanged {
+  pub admin: Pubkey,
+  pub old_policy: u8,
+  pub new_policy: u8,
+  pub queue_jump_fee_bps: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct EmergencyExitPausePolicyChanged {
+  pub admin: Pubkey,
+  pub allow_emergency_exit_during_pause: bool,
+  pub changed_at: i64,
+}
+
 // Authority Proxy events
 #[event]
 pub struct AuthorityTransferred {
@@ -221,18 +488,129 @@ pub struct AuthorityTransferred {
 pub struct ProgramUpgraded {
   pub program_id: Pubkey,
   pub developer: Pubkey,
+  /// Owner or co-developer who signed the upgrade
+  pub upgraded_by: Pubkey,
   pub buffer_address: Pubkey,
+  /// Bytecode hash checked before upgrading, when one was supplied
+  pub buffer_hash: Option<[u8; 32]>,
+  /// Charged from escrow for an upgrade beyond the monthly limit (0 if none)
+  pub overage_fee: u64,
   pub upgraded_at: i64,
 }
 
+#[event]
+pub struct ProgramRolledBack {
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  /// Owner or co-developer who signed the rollback
+  pub rolled_back_by: Pubkey,
+  pub buffer_address: Pubkey,
+  /// Bytecode hash of the restored release
+  pub buffer_hash: [u8; 32],
+  /// UpgradeRecord index of the restored release
+  pub restored_index: u32,
+  pub rolled_back_at: i64,
+}
+
 #[event]
 pub struct ProgramRentReclaimed {
   pub program_id: Pubkey,
   pub developer: Pubkey,
   pub lamports_recovered: u64,
+  /// Part of lamports_recovered returned to the developer (prepaid extension rent)
+  pub extension_rent_refunded: u64,
   pub reclaimed_at: i64,
 }
 
+#[event]
+pub struct BufferRentLoanIssued {
+  pub developer: Pubkey,
+  pub program_id: Pubkey,
+  pub buffer_rent_loan: Pubkey,
+  pub amount: u64,
+  pub due_at: i64,
+  pub borrowed_at: i64,
+}
+
+#[event]
+pub struct BufferRentLoanRepaid {
+  pub developer: Pubkey,
+  pub program_id: Pubkey,
+  pub amount_repaid: u64,
+  pub outstanding: u64,
+  pub closed: bool,
+  pub repaid_at: i64,
+}
+
+#[event]
+pub struct MaxBufferRentLoanChanged {
+  pub admin: Pubkey,
+  pub old_max: u64,
+  pub new_max: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct SubscriptionSplitProposed {
+  pub admin: Pubkey,
+  pub current_platform_bps: u64,
+  pub proposed_platform_bps: u64,
+  pub effective_at: i64,
+  pub proposed_at: i64,
+}
+
+#[event]
+pub struct SubscriptionSplitChanged {
+  pub admin: Pubkey,
+  pub old_platform_bps: u64,
+  pub new_platform_bps: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct SubscriptionSplitCancelled {
+  pub cancelled_by: Pubkey,
+  pub cancelled_platform_bps: u64,
+  pub cancelled_at: i64,
+}
+
+#[event]
+pub struct ProgramOffboarded {
+  pub request_id: [u8; 32],
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub debt_settled: u64,
+  pub escrow_remaining: u64,
+  pub offboarded_at: i64,
+}
+
+#[event]
+pub struct OffboardingPolicyChanged {
+  pub admin: Pubkey,
+  pub old_policy: u8,
+  pub new_policy: u8,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct UpgradeAuthorityMismatch {
+  pub program_id: Pubkey,
+  pub managed_program: Pubkey,
+  pub developer: Pubkey,
+  pub expected_authority: Pubkey,
+  pub actual_authority: Option<Pubkey>,
+  pub detected_at: i64,
+}
+
+#[event]
+pub struct UpgradeAuthorityInventoryChecked {
+  pub caller: Pubkey,
+  pub programs_checked: u32,
+  pub inactive_skipped: u32,
+  pub mismatches: u32,
+  pub checked_at: i64,
+}
+
 // Escrow & Auto-Renewal events
 
 #[event]
@@ -267,6 +645,8 @@ pub struct AutoRenewalExecuted {
   pub developer: Pubkey,
   pub token_type: u8,
   pub amount_deducted: u64,
+  /// Prepayment discount taken off the renewal
+  pub discount: u64,
   pub months_renewed: u32,
   pub new_expiry: i64,
   pub escrow_remaining: u64,
@@ -366,6 +746,35 @@ pub struct StakerWithdrawalCancelled {
   pub cancelled_at: i64,
 }
 
+#[event]
+pub struct QueueEntryExpired {
+  pub staker: Pubkey,
+  pub queue_position: u32,
+  pub amount_requested: u64,
+  pub amount_withdrawn: u64,
+  pub amount_restaked: u64,
+  pub expired_by: Pubkey,
+  pub expired_at: i64,
+}
+
+#[event]
+pub struct WithdrawalQueueCompacted {
+  pub cranker: Pubkey,
+  pub entries_closed: u32,
+  pub rent_refunded: u64,
+  pub old_head: u32,
+  pub new_head: u32,
+  pub compacted_at: i64,
+}
+
+#[event]
+pub struct MaxQueueAgeChanged {
+  pub admin: Pubkey,
+  pub old_max_age: i64,
+  pub new_max_age: i64,
+  pub changed_at: i64,
+}
+
 #[event]
 pub struct QueuedWithdrawalFulfilled {
   pub staker: Pubkey,
@@ -374,8 +783,36 @@ pub struct QueuedWithdrawalFulfilled {
   pub fulfilled_at: i64,
 }
 
+#[event]
+pub struct KeeperTipPaid {
+  pub cranker: Pubkey,
+  pub staker: Pubkey,
+  pub queue_position: u32,
+  pub processed_amount: u64,
+  pub tip_amount: u64,
+  pub paid_at: i64,
+}
+
+#[event]
+pub struct KeeperTipChanged {
+  pub admin: Pubkey,
+  pub old_tip_bps: u64,
+  pub new_tip_bps: u64,
+  pub changed_at: i64,
+}
+
 // === FAIR REWARD DISTRIBUTION EVENTS ===
 
+#[event]
+pub struct StakerBonusCredited {
+  pub admin: Pubkey,
+  pub staker: Pubkey,
+  pub amount: u64,
+  pub pending_rewards: u64,
+  pub total_bonus_credited: u64,
+  pub credited_at: i64,
+}
+
 #[event]
 pub struct PendingRewardsDistributed {
   pub amount_distributed: u64,
@@ -393,21 +830,1088 @@ pub struct DurationBonusClaimed {
 }
 
 #[event]
-pub struct RewardsMovedToPending {
+pub struct BonusVestingScheduled {
+  pub staker: Pubkey,
   pub amount: u64,
-  pub reason: String,
-  pub moved_at: i64,
+  pub vesting_total: u64,
+  pub vesting_end_at: i64,
+  pub scheduled_at: i64,
 }
 
-// === PROTOCOL HEALTH EVENTS ===
+#[event]
+pub struct VestedBonusClaimed {
+  pub staker: Pubkey,
+  pub amount: u64,
+  pub still_vesting: u64,
+  pub claimed_at: i64,
+}
 
 #[event]
-pub struct ProtocolHealthUpdated {
-  pub utilization_bps: u64,
-  pub current_apy_bps: u64,
-  pub total_borrowed: u64,
+pub struct BonusVestingPeriodChanged {
+  pub admin: Pubkey,
+  pub old_period: i64,
+  pub new_period: i64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct RewardsMovedToPending {
+  pub amount: u64,
+  pub reason: String,
+  pub moved_at: i64,
+}
+
+// === PROTOCOL HEALTH EVENTS ===
+
+#[event]
+pub struct ProtocolHealthUpdated {
+  pub utilization_bps: u64,
+  pub current_apy_bps: u64,
+  pub total_borrowed: u64,
   pub total_deposited: u64,
   pub queued_withdrawals: u64,
   pub recovery_ratio_bps: u64,
   pub updated_at: i64,
 }
+
+// === ACCOUNT MIGRATION EVENTS ===
+
+#[event]
+pub struct LenderStakeMigrated {
+  pub staker: Pubkey,
+  pub lender_stake: Pubkey,
+  pub from_legacy_name: bool,
+  pub old_size: u32,
+  pub new_size: u32,
+  pub migrated_at: i64,
+}
+
+// === DONATION EVENTS ===
+
+#[event]
+pub struct DonationsAbsorbed {
+  pub caller: Pubkey,
+  pub treasury_surplus: u64,
+  pub reward_pool_surplus: u64,
+  pub platform_pool_surplus: u64,
+  pub credited_to_rewards: u64,
+  pub credited_to_platform: u64,
+  pub donation_policy: u8,
+  pub absorbed_at: i64,
+}
+
+#[event]
+pub struct DonationPolicyChanged {
+  pub admin: Pubkey,
+  pub old_policy: u8,
+  pub new_policy: u8,
+  pub changed_at: i64,
+}
+
+// === INSURANCE EVENTS ===
+
+#[event]
+pub struct InsuranceFundInitialized {
+  pub admin: Pubkey,
+  pub premium_bps: u64,
+  pub loss_threshold_bps: u64,
+  pub initialized_at: i64,
+}
+
+#[event]
+pub struct InsurancePolicyChanged {
+  pub admin: Pubkey,
+  pub old_premium_bps: u64,
+  pub new_premium_bps: u64,
+  pub old_loss_threshold_bps: u64,
+  pub new_loss_threshold_bps: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct InsuranceCoveragePurchased {
+  pub staker: Pubkey,
+  pub coverage_amount: u64,
+  pub premium: u64,
+  pub insured_amount: u64,
+  pub purchased_at: i64,
+}
+
+#[event]
+pub struct InsuranceLossRecorded {
+  pub request_id: [u8; 32],
+  pub loss_amount: u64,
+  pub compensation_committed: u64,
+  pub recovery_ratio_bps: u64,
+  pub total_covered: u64,
+  pub recorded_at: i64,
+}
+
+#[event]
+pub struct InsuranceClaimed {
+  pub staker: Pubkey,
+  pub amount: u64,
+  pub insured_amount: u64,
+  pub claimed_at: i64,
+}
+
+#[event]
+pub struct InsuranceCoverageReduced {
+  pub staker: Pubkey,
+  /// Coverage no longer backed by stake
+  pub released_coverage: u64,
+  pub insured_amount: u64,
+  pub total_covered: u64,
+  pub reduced_at: i64,
+}
+
+// === GOVERNANCE EVENTS ===
+
+#[event]
+pub struct StakeSnapshotRecorded {
+  pub epoch: u64,
+  pub slot: u64,
+  pub total_deposited: u64,
+  pub reward_per_share: u128,
+  pub merkle_root: [u8; 32],
+  pub staker_count: u32,
+  pub recorded_at: i64,
+}
+
+#[event]
+pub struct VotingPowerVerified {
+  pub epoch: u64,
+  pub staker: Pubkey,
+  pub weight: u64,
+  pub total_deposited: u64,
+}
+
+// === LST STAKING EVENTS ===
+
+#[event]
+pub struct LstConfigChanged {
+  pub admin: Pubkey,
+  pub mint: Pubkey,
+  pub rate: u64,
+  pub max_rate_age: i64,
+  pub enabled: bool,
+  pub normalized_value: u64,
+  pub normalized_token_value: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct StakedFromLst {
+  pub lender: Pubkey,
+  pub mint: Pubkey,
+  pub token_amount: u64,
+  pub rate: u64,
+  pub sol_value: u64,
+  pub total_staked: u64,
+  pub staked_at: i64,
+}
+
+#[event]
+pub struct LstVaultRedeemed {
+  pub admin: Pubkey,
+  pub mint: Pubkey,
+  pub token_amount: u64,
+  pub sol_amount: u64,
+  pub lst_backed_deposits: u64,
+  pub redeemed_at: i64,
+}
+
+// === STAKE POSITION EVENTS ===
+
+#[event]
+pub struct StakePositionSplit {
+  pub staker: Pubkey,
+  pub position: Pubkey,
+  pub position_index: u32,
+  pub amount: u64,
+  pub remaining_deposit: u64,
+  pub split_at: i64,
+}
+
+#[event]
+pub struct StakePositionsMerged {
+  pub staker: Pubkey,
+  pub target: Pubkey,
+  pub source: Pubkey,
+  pub target_index: u32,
+  pub source_index: u32,
+  pub amount: u64,
+  pub total_deposit: u64,
+  pub merged_at: i64,
+}
+
+// === COLLATERAL EVENTS ===
+
+#[event]
+pub struct CollateralPartnerRegistered {
+  pub admin: Pubkey,
+  pub partner_program: Pubkey,
+  pub authority: Pubkey,
+  pub enabled: bool,
+  pub registered_at: i64,
+}
+
+#[event]
+pub struct StakePledged {
+  pub staker: Pubkey,
+  pub position: Pubkey,
+  pub partner_program: Pubkey,
+  pub deposited_amount: u64,
+  pub pledged_at: i64,
+}
+
+#[event]
+pub struct StakePledgeReleased {
+  pub staker: Pubkey,
+  pub position: Pubkey,
+  pub partner_program: Pubkey,
+  pub released_at: i64,
+}
+
+// === AUTO UNSTAKE EVENTS ===
+
+#[event]
+pub struct AutoUnstakeScheduled {
+  pub staker: Pubkey,
+  pub auto_unstake_at: i64,
+  pub scheduled_at: i64,
+}
+
+#[event]
+pub struct AutoUnstakeTriggered {
+  pub staker: Pubkey,
+  pub cranker: Pubkey,
+  pub amount: u64,
+  pub queue_position: u32,
+  pub triggered_at: i64,
+}
+
+// === WATCHTOWER EVENTS ===
+
+#[event]
+pub struct WatchtowerRegistered {
+  pub operator: Pubkey,
+  pub registered_at: i64,
+}
+
+#[event]
+pub struct WatchtowerAlertValidated {
+  pub operator: Pubkey,
+  pub alert: WatchtowerAlert,
+  /// Treasury pool, LST mint or managed program id, depending on the alert
+  pub subject: Pubkey,
+  pub bounty: u64,
+  pub validated_at: i64,
+}
+
+#[event]
+pub struct WatchtowerBountyChanged {
+  pub admin: Pubkey,
+  pub old_bounty: u64,
+  pub new_bounty: u64,
+  pub old_cooldown: i64,
+  pub new_cooldown: i64,
+  pub changed_at: i64,
+}
+
+// === DUST SWEEP EVENTS ===
+
+#[event]
+pub struct DustPositionSwept {
+  pub staker: Pubkey,
+  pub cranker: Pubkey,
+  pub amount: u64,
+  pub queue_position: u32,
+  pub last_action_at: i64,
+  pub swept_at: i64,
+}
+
+#[event]
+pub struct DustPositionClosed {
+  pub staker: Pubkey,
+  pub rewards_paid: u64,
+  pub closed_at: i64,
+}
+
+// === UNSTAKE COMMITMENT EVENTS ===
+
+#[event]
+pub struct UnstakeCommitted {
+  pub staker: Pubkey,
+  pub amount: u64,
+  pub executable_at: i64,
+  pub committed_unstake_total: u64,
+  pub committed_at: i64,
+}
+
+#[event]
+pub struct UnstakeCommitmentCancelled {
+  pub staker: Pubkey,
+  pub amount: u64,
+  pub cancelled_at: i64,
+}
+
+#[event]
+pub struct UnstakeCommitmentPolicyChanged {
+  pub admin: Pubkey,
+  pub old_threshold_bps: u64,
+  pub new_threshold_bps: u64,
+  pub old_commit_delay: i64,
+  pub new_commit_delay: i64,
+  pub changed_at: i64,
+}
+
+// === DEVELOPER IDENTITY EVENTS ===
+
+#[event]
+pub struct DeveloperIdentityLinked {
+  pub developer: Pubkey,
+  pub profile_hash: [u8; 32],
+  /// Whether an existing attestation was kept (same profile re-linked)
+  pub attested: bool,
+  pub linked_at: i64,
+}
+
+#[event]
+pub struct DeveloperIdentityAttested {
+  pub registrar: Pubkey,
+  pub developer: Pubkey,
+  pub profile_hash: [u8; 32],
+  pub attested: bool,
+  pub attested_at: i64,
+}
+
+#[event]
+pub struct DeveloperIdentityUnlinked {
+  pub developer: Pubkey,
+  pub unlinked_at: i64,
+}
+
+#[event]
+pub struct IdentityPolicyChanged {
+  pub admin: Pubkey,
+  pub old_registrar: Pubkey,
+  pub new_registrar: Pubkey,
+  pub old_verified_max_buffer_rent_loan: u64,
+  pub new_verified_max_buffer_rent_loan: u64,
+  pub changed_at: i64,
+}
+
+// === BOOST CAMPAIGN EVENTS ===
+
+#[event]
+pub struct BoostCampaignCreated {
+  pub admin: Pubkey,
+  pub campaign: Pubkey,
+  pub campaign_id: u64,
+  pub start_at: i64,
+  pub end_at: i64,
+  pub boost_bps: u64,
+  pub eligibility: CampaignEligibility,
+  pub budget: u64,
+  pub created_at: i64,
+}
+
+#[event]
+pub struct CampaignEnrolled {
+  pub campaign: Pubkey,
+  pub staker: Pubkey,
+  pub boosted_amount: u64,
+  pub enrolled_at: i64,
+}
+
+#[event]
+pub struct CampaignBoostClaimed {
+  pub campaign: Pubkey,
+  pub staker: Pubkey,
+  pub amount: u64,
+  pub remaining_budget: u64,
+  pub claimed_at: i64,
+}
+
+#[event]
+pub struct BoostCampaignClosed {
+  pub admin: Pubkey,
+  pub campaign: Pubkey,
+  pub paid_out: u64,
+  pub returned_to_platform_pool: u64,
+  pub closed_at: i64,
+}
+
+// === REWARD POOL SOLVENCY EVENTS ===
+
+#[event]
+pub struct RewardPoolReconciled {
+  pub admin: Pubkey,
+  pub payable_lamports: u64,
+  pub obligations: u64,
+  pub shortfall: u64,
+  pub surplus: u64,
+  pub written_off: u64,
+  pub reward_pool_balance: u64,
+  pub pending_undistributed_rewards: u64,
+  pub reconciled_at: i64,
+}
+
+// === BATCH CLAIM EVENTS ===
+
+#[event]
+pub struct RewardsBatchClaimed {
+  pub staker: Pubkey,
+  pub positions_claimed: u32,
+  pub total_amount: u64,
+  pub claimed_at: i64,
+}
+
+// === AUTO RENEW DRY RUN EVENTS ===
+
+#[event]
+pub struct AutoRenewChecked {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub renewable: bool,
+  pub skip_reason: u8,
+  pub payment_amount: u64,
+  pub escrow_balance: u64,
+  pub checked_at: i64,
+}
+
+// === BOOST NFT EVENTS ===
+
+#[event]
+pub struct BoostNftPolicyChanged {
+  pub admin: Pubkey,
+  pub old_collection: Pubkey,
+  pub new_collection: Pubkey,
+  pub old_boost_bps: u64,
+  pub new_boost_bps: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct BoostNftApplied {
+  pub staker: Pubkey,
+  pub mint: Pubkey,
+  pub boost_bps: u64,
+  pub applied_at: i64,
+}
+
+#[event]
+pub struct BoostNftRevoked {
+  /// Position that lost the boost (default when it was already closed)
+  pub staker: Pubkey,
+  pub mint: Pubkey,
+  pub revoked_by: Pubkey,
+  pub revoked_at: i64,
+}
+
+// === DRAWDOWN SIMULATION EVENTS ===
+
+#[event]
+pub struct DrawdownSimulated {
+  pub guardian: Pubkey,
+  pub exit_bps: u64,
+  pub defaulted_deployments: u32,
+  pub defaulted_debt: u64,
+  pub liquidity_shortfall: u64,
+  pub exit_coverage_bps: u64,
+  pub default_loss: u64,
+  pub principal_loss_bps: u64,
+  pub protected_rewards_shortfall: u64,
+  pub simulated_at: i64,
+}
+
+// === STABLECOIN SUBSCRIPTION EVENTS ===
+
+#[event]
+pub struct SubscriptionStablecoinSet {
+  pub admin: Pubkey,
+  pub mint: Pubkey,
+  pub vault: Pubkey,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct StablecoinMonthlyFeeSet {
+  pub request_id: [u8; 32],
+  pub admin: Pubkey,
+  pub old_fee: u64,
+  pub new_fee: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct SubscriptionPaidSpl {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub mint: Pubkey,
+  pub months: u32,
+  pub payment_amount: u64,
+  pub subscription_valid_until: i64,
+}
+
+// === DECENTRALIZATION EVENTS ===
+
+#[event]
+pub struct DecentralizationMilestone {
+  pub admin: Pubkey,
+  pub guardian: Pubkey,
+  pub timelock_duration: i64,
+  pub decentralized_at: i64,
+}
+
+// === ESCROW TOKEN EVENTS ===
+
+#[event]
+pub struct EscrowTokenMintsChanged {
+  pub admin: Pubkey,
+  pub old_usdc_mint: Pubkey,
+  pub new_usdc_mint: Pubkey,
+  pub old_usdt_mint: Pubkey,
+  pub new_usdt_mint: Pubkey,
+  pub changed_at: i64,
+}
+
+// === ESCROW RENEWAL EVENTS ===
+
+#[event]
+pub struct PermissionlessRenewalWindowChanged {
+  pub admin: Pubkey,
+  pub old_window: i64,
+  pub new_window: i64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct SubscriptionRenewedFromEscrow {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  /// Developer, or any keeper inside the permissionless window
+  pub renewed_by: Pubkey,
+  pub permissionless: bool,
+  pub token_type: u8,
+  pub amount_deducted: u64,
+  /// Prepayment discount taken off the renewal
+  pub discount: u64,
+  pub months_renewed: u32,
+  pub new_expiry: i64,
+  pub escrow_remaining: u64,
+  pub renewed_at: i64,
+}
+
+// === ESCROW TOP-UP EVENTS ===
+
+#[event]
+pub struct EscrowTopUpEnabled {
+  pub developer: Pubkey,
+  pub token_type: u8,
+  pub source_token_account: Pubkey,
+  pub top_up_amount: u64,
+  pub allowance: u64,
+  pub min_balance_alert: u64,
+  pub enabled_at: i64,
+}
+
+#[event]
+pub struct EscrowTopUpDisabled {
+  pub developer: Pubkey,
+  pub total_topped_up: u64,
+  pub disabled_at: i64,
+}
+
+#[event]
+pub struct EscrowToppedUp {
+  pub developer: Pubkey,
+  pub cranker: Pubkey,
+  pub token_type: u8,
+  pub amount: u64,
+  pub new_balance: u64,
+  pub topped_up_at: i64,
+}
+
+// === ESCROW CONFIGURATION EVENTS ===
+
+#[event]
+pub struct EscrowConfigured {
+  pub developer: Pubkey,
+  pub min_balance_alert: u64,
+  pub max_monthly_spend: u64,
+  pub max_auto_deduct_per_month: u64,
+  pub configured_at: i64,
+}
+
+#[event]
+pub struct EscrowBudgetSet {
+  pub developer: Pubkey,
+  pub request_id: [u8; 32],
+  /// 0 = budget removed
+  pub limit: u64,
+  pub spent: u64,
+  pub set_at: i64,
+}
+
+#[event]
+pub struct EscrowClosed {
+  pub developer: Pubkey,
+  /// Recorded SOL balance swept back with the rent
+  pub sol_returned: u64,
+  pub token_vaults_closed: u32,
+  pub closed_at: i64,
+}
+
+// === PROGRAM AUTO-RENEWAL EVENTS ===
+
+#[event]
+pub struct ProgramAutoRenewChanged {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub enabled: bool,
+  pub max_months_per_renewal: u32,
+  pub changed_at: i64,
+}
+
+// === PENDING DEPLOYMENT EXPIRY EVENTS ===
+
+#[event]
+pub struct PendingDeploymentCancelled {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub refund_amount: u64,
+  /// Lamports the temporary wallet returned to the treasury vault
+  pub returned_lamports: u64,
+  /// Borrowed amount not returned (temporary wallet was drained)
+  pub outstanding_loan: u64,
+  pub ephemeral_key: Option<Pubkey>,
+  pub pending_since: i64,
+  pub expiry_at: i64,
+  pub cancelled_at: i64,
+}
+
+#[event]
+pub struct DeployRequestExpired {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub refund_amount: u64,
+  /// Lamports the temporary wallet returned to the treasury vault
+  pub returned_lamports: u64,
+  /// Borrowed amount not returned (temporary wallet was drained)
+  pub outstanding_loan: u64,
+  pub expiry_at: i64,
+  pub expired_by: Pubkey,
+  pub expired_at: i64,
+}
+
+// === SUBSCRIPTION PLAN EVENTS ===
+
+#[event]
+pub struct PlanTierChanged {
+  pub admin: Pubkey,
+  pub plan_id: u8,
+  pub max_program_size: u64,
+  pub service_fee: u64,
+  pub monthly_fee: u64,
+  pub included_upgrades: u32,
+  pub enabled: bool,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct PlanUpgradePricingChanged {
+  pub admin: Pubkey,
+  pub plan_id: u8,
+  pub max_upgrades_per_month: u32,
+  pub upgrade_overage_fee: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct SubscriptionPlanChanged {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub old_plan_id: u8,
+  pub new_plan_id: u8,
+  pub old_monthly_fee: u64,
+  pub new_monthly_fee: u64,
+  /// Prorated difference paid for an upgrade (0 for a downgrade)
+  pub charged_amount: u64,
+  pub paid_from_escrow: bool,
+  /// Subscription time added by a downgrade (0 for an upgrade)
+  pub extension_seconds: i64,
+  pub subscription_paid_until: i64,
+  pub changed_at: i64,
+}
+
+// === HIBERNATION EVENTS ===
+
+#[event]
+pub struct ProgramHibernated {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub program_id: Pubkey,
+  /// Kept on the request for redeployment
+  pub program_hash: [u8; 32],
+  pub lamports_recovered: u64,
+  /// Part of lamports_recovered returned to the developer (prepaid extension rent)
+  pub extension_rent_refunded: u64,
+  pub remaining_debt: u64,
+  pub storage_monthly_fee: u64,
+  pub hibernated_at: i64,
+}
+
+// === SUBSCRIPTION REFUND EVENTS ===
+
+#[event]
+pub struct UnusedSubscriptionRefunded {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  /// Prepaid time left at closure, prorated at the monthly fee
+  pub unused_value: u64,
+  /// Amount paid out (unused_value capped by the reward pool's excess)
+  pub refund_amount: u64,
+  pub refunded_at: i64,
+}
+
+// === AUTHORITY BUY-OUT EVENTS ===
+
+#[event]
+pub struct AuthorityExitFeeChanged {
+  pub admin: Pubkey,
+  pub old_fee_bps: u64,
+  pub new_fee_bps: u64,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct PrepayDiscountsChanged {
+  pub admin: Pubkey,
+  pub steps: Vec<PrepayDiscount>,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct AuthorityReclaimed {
+  pub request_id: [u8; 32],
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub debt_repaid: u64,
+  pub exit_fee: u64,
+  pub reclaimed_at: i64,
+}
+
+// === AUTHORITY TRANSFER EVENTS ===
+
+#[event]
+pub struct ApprovedAuthoritySet {
+  pub admin: Pubkey,
+  pub authority: Pubkey,
+  pub enabled: bool,
+  pub set_at: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+  pub request_id: [u8; 32],
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub new_authority: Pubkey,
+  pub execute_after: i64,
+  pub proposed_at: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+  pub request_id: [u8; 32],
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub new_authority: Pubkey,
+  pub cancelled_at: i64,
+}
+
+#[event]
+pub struct AuthorityTransferExecuted {
+  pub request_id: [u8; 32],
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub old_authority: Pubkey,
+  pub new_authority: Pubkey,
+  /// Deployment debt paid off before the hand-over
+  pub debt_repaid: u64,
+  /// Exit fee paid to the reward pool
+  pub exit_fee: u64,
+  pub executed_at: i64,
+}
+
+// === PROGRAM EXTENSION EVENTS ===
+
+#[event]
+pub struct ProgramExtended {
+  pub request_id: [u8; 32],
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub additional_bytes: u32,
+  /// Extra ProgramData rent paid by the developer
+  pub rent_paid: u64,
+  pub paid_from_escrow: bool,
+  pub program_size: u64,
+  /// Total extension rent the developer has prepaid on this deployment
+  pub developer_extension_rent: u64,
+  pub extended_at: i64,
+}
+
+// === SCHEDULED UPGRADE EVENTS ===
+
+#[event]
+pub struct UpgradeScheduled {
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub buffer_address: Pubkey,
+  pub execute_after: i64,
+  pub scheduled_at: i64,
+}
+
+#[event]
+pub struct ScheduledUpgradeCancelled {
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub buffer_address: Pubkey,
+  pub cancelled_at: i64,
+}
+
+#[event]
+pub struct ScheduledUpgradeExecuted {
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub buffer_address: Pubkey,
+  pub scheduled_at: i64,
+  pub executed_at: i64,
+}
+
+#[event]
+pub struct ManagedProgramMigrated {
+  pub managed_program: Pubkey,
+  pub program_id: Pubkey,
+  pub old_size: u32,
+  pub new_size: u32,
+  pub migrated_at: i64,
+}
+
+#[event]
+pub struct DeveloperEscrowMigrated {
+  pub developer_escrow: Pubkey,
+  pub developer: Pubkey,
+  pub old_size: u32,
+  pub new_size: u32,
+  pub migrated_at: i64,
+}
+
+// === CO-DEVELOPER EVENTS ===
+
+#[event]
+pub struct UpgradeSignerAdded {
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub signer: Pubkey,
+  pub signer_count: u8,
+  pub added_at: i64,
+}
+
+#[event]
+pub struct UpgradeSignerRemoved {
+  pub program_id: Pubkey,
+  pub developer: Pubkey,
+  pub signer: Pubkey,
+  pub signer_count: u8,
+  pub removed_at: i64,
+}
+
+// === DEVELOPER COLLATERAL EVENTS ===
+
+#[event]
+pub struct DeveloperCollateralLocked {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub amount: u64,
+  pub total_collateral: u64,
+  pub borrowed_amount: u64,
+  pub locked_at: i64,
+}
+
+#[event]
+pub struct DeveloperCollateralReleased {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  /// Collateral applied to debt the rent recovery left unpaid
+  pub debt_settled: u64,
+  pub returned: u64,
+  pub released_at: i64,
+}
+
+// === COUPON EVENTS ===
+
+#[event]
+pub struct CouponChanged {
+  pub admin: Pubkey,
+  pub code_hash: [u8; 32],
+  pub discount_bps: u64,
+  pub expires_at: i64,
+  pub max_uses: u32,
+  pub is_active: bool,
+  pub uses: u32,
+  pub changed_at: i64,
+}
+
+#[event]
+pub struct CouponRedeemed {
+  pub code_hash: [u8; 32],
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  /// Service fee waived
+  pub discount: u64,
+  pub uses: u32,
+  pub redeemed_at: i64,
+}
+
+// === DEPLOYMENT QUOTA EVENTS ===
+
+#[event]
+pub struct DeveloperQuotaChanged {
+  pub admin: Pubkey,
+  pub old_max_active_deployments: u32,
+  pub new_max_active_deployments: u32,
+  pub changed_at: i64,
+}
+
+// === SUBSCRIPTION STATUS EVENTS ===
+
+#[event]
+pub struct SubscriptionStatusQueried {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub days_remaining: u64,
+  pub in_grace_period: bool,
+  pub outstanding_borrow_fees: u64,
+  pub next_renewal_amount: u64,
+  pub queried_at: i64,
+}
+
+// === EXPIRY WARNING EVENTS ===
+
+#[event]
+pub struct SubscriptionExpiring {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub subscription_paid_until: i64,
+  pub seconds_remaining: i64,
+  pub auto_renewal_enabled: bool,
+  pub warned_at: i64,
+}
+
+#[event]
+pub struct ExpiryWarningPolicyChanged {
+  pub admin: Pubkey,
+  pub old_window: i64,
+  pub new_window: i64,
+  pub old_interval: i64,
+  pub new_interval: i64,
+  pub changed_at: i64,
+}
+
+// === BORROW FEE EVENTS ===
+
+#[event]
+pub struct BorrowFeeCollected {
+  pub request_id: [u8; 32],
+  pub developer: Pubkey,
+  pub amount: u64,
+  /// Borrow fees collected on the request so far
+  pub fees_paid: u64,
+  /// Paid from the developer's SOL escrow (collect_borrow_fee) rather than
+  /// the developer's wallet (pay_subscription)
+  pub from_escrow: bool,
+  pub collected_at: i64,
+}
+
+// === ROLE EVENTS ===
+
+#[event]
+pub struct RoleAssigned {
+  pub admin: Pubkey,
+  pub role: Role,
+  pub old_holder: Pubkey,
+  /// Default pubkey when the role was revoked
+  pub new_holder: Pubkey,
+  pub assigned_at: i64,
+}
+
+// === ADMIN COUNCIL EVENTS ===
+
+#[event]
+pub struct AdminCouncilInitialized {
+  pub admin: Pubkey,
+  pub members: Vec<Pubkey>,
+  pub threshold: u8,
+  pub initialized_at: i64,
+}
+
+#[event]
+pub struct CouncilProposalCreated {
+  pub proposal_id: u64,
+  pub proposer: Pubkey,
+  pub action: CouncilAction,
+  pub expires_at: i64,
+  pub proposed_at: i64,
+}
+
+#[event]
+pub struct CouncilProposalApproved {
+  pub proposal_id: u64,
+  pub member: Pubkey,
+  pub approval_count: u8,
+  pub threshold: u8,
+  pub approved_at: i64,
+}
+
+#[event]
+pub struct CouncilProposalExecuted {
+  pub proposal_id: u64,
+  pub executor: Pubkey,
+  pub action: CouncilAction,
+  pub approval_count: u8,
+  pub executed_at: i64,
+}
+
+#[event]
+pub struct CouncilProposalCancelled {
+  pub proposal_id: u64,
+  pub proposer: Pubkey,
+  pub cancelled_at: i64,
+}
+
+// === SCHEDULED UNPAUSE EVENTS ===
+
+#[event]
+pub struct UnpauseScheduled {
+  pub admin: Pubkey,
+  pub executable_at: i64,
+  pub scheduled_at: i64,
+}
+
+#[event]
+pub struct UnpauseExecuted {
+  pub executor: Pubkey,
+  pub scheduled_at: i64,
+  pub executed_at: i64,
+}
+
+#[event]
+pub struct UnpauseVetoed {
+  /// Admin or guardian vetoing, or the key that paused again
+  pub vetoed_by: Pubkey,
+  pub scheduled_at: i64,
+  pub vetoed_at: i64,
+}
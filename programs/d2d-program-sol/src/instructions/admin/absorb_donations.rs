@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::DonationsAbsorbed,
+  states::{FeeSource, TreasuryPool},
+  utils::{vault_payable_lamports, vault_transfer},
+};
+
+/// Absorb SOL sent directly to the protocol PDAs into internal accounting
+///
+/// Permissionless - anyone may call it, since it only credits lamports that
+/// already sit in the PDAs above their tracked balances.
+#[derive(Accounts)]
+pub struct AbsorbDonations<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Reward Pool PDA
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform Pool PDA
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Treasury vault PDA
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  pub caller: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Detect and route surplus lamports
+///
+/// Flow:
+/// 1. Surplus = PDA lamports - rent exemption - tracked balance (per PDA; the
+///    treasury surplus is measured on the treasury vault)
+/// 2. Reward pool surplus is credited to stakers (updates reward_per_share)
+/// 3. Platform pool surplus is credited to platform_pool_balance
+/// 4. Treasury surplus is moved to the reward or platform pool per donation_policy
+pub fn absorb_donations(ctx: Context<AbsorbDonations>) -> Result<()> {
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let reward_pool_info = ctx.accounts.reward_pool.to_account_info();
+  let platform_pool_info = ctx.accounts.platform_pool.to_account_info();
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+
+  let rent = Rent::get()?;
+
+  let treasury_surplus =
+    vault_payable_lamports(&treasury_vault_info)?.saturating_sub(treasury_pool.liquid_balance);
+  let reward_pool_surplus = reward_pool_info
+    .lamports()
+    .saturating_sub(rent.minimum_balance(reward_pool_info.data_len()))
+    .saturating_sub(treasury_pool.reward_pool_balance)
+    .saturating_sub(treasury_pool.total_escrowed_payments);
+  let platform_pool_surplus = platform_pool_info
+    .lamports()
+    .saturating_sub(rent.minimum_balance(platform_pool_info.data_len()))
+    .saturating_sub(treasury_pool.platform_pool_balance);
+
+  require!(
+    treasury_surplus > 0 || reward_pool_surplus > 0 || platform_pool_surplus > 0,
+    ErrorCode::NoSurplusLamports
+  );
+
+  let mut credited_to_rewards = reward_pool_surplus;
+  let mut credited_to_platform = platform_pool_surplus;
+
+  // Route treasury surplus by moving the lamports into the destination pool
+  if treasury_surplus > 0 {
+    let destination_info =
+      if treasury_pool.donation_policy == TreasuryPool::DONATION_POLICY_PLATFORM {
+        credited_to_platform = credited_to_platform
+          .checked_add(treasury_surplus)
+          .ok_or(ErrorCode::CalculationOverflow)?;
+        &platform_pool_info
+      } else {
+        credited_to_rewards = credited_to_rewards
+          .checked_add(treasury_surplus)
+          .ok_or(ErrorCode::CalculationOverflow)?;
+        &reward_pool_info
+      };
+
+    vault_transfer(
+      &ctx.accounts.system_program.to_account_info(),
+      &treasury_vault_info,
+      destination_info,
+      treasury_surplus,
+      treasury_pool.vault_bump,
+    )?;
+  }
+
+  // Credit both pools and update reward_per_share
+  treasury_pool.collect_fee(
+    FeeSource::Donation,
+    credited_to_rewards,
+    credited_to_platform,
+  )?;
+
+  emit!(DonationsAbsorbed {
+    caller: ctx.accounts.caller.key(),
+    treasury_surplus,
+    reward_pool_surplus,
+    platform_pool_surplus,
+    credited_to_rewards,
+    credited_to_platform,
+    donation_policy: treasury_pool.donation_policy,
+    absorbed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
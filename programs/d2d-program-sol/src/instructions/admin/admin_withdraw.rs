@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::ErrorCode, events::AdminWithdrew, states::TreasuryPool};
+use crate::{
+  errors::ErrorCode, events::AdminWithdrew, states::TreasuryPool, utils::pool_debit_credit,
+};
 
 /// Admin withdraw funds from Platform Pool
 ///
-/// Safety: Only admin can withdraw, with event logging for audit
+/// Safety: Only admin can withdraw, with event logging for audit. Once the
+/// admin council is enabled this goes through execute_council_proposal.
 #[derive(Accounts)]
 pub struct AdminWithdraw<'info> {
   #[account(
@@ -47,7 +50,38 @@ pub fn admin_withdraw(ctx: Context<AdminWithdraw>, amount: u64, reason: String)
   let platform_pool_info = ctx.accounts.platform_pool.to_account_info();
   let destination_info = ctx.accounts.destination.to_account_info();
 
+  treasury_pool.check_not_council_governed()?;
+  withdraw_platform_funds(
+    treasury_pool,
+    &platform_pool_info,
+    &destination_info,
+    amount,
+  )?;
+
+  emit!(AdminWithdrew {
+    admin: ctx.accounts.admin.key(),
+    amount,
+    destination: destination_info.key(),
+    reason,
+    withdrawn_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+/// Checks and transfer shared by admin_withdraw and the council's AdminWithdraw
+pub(crate) fn withdraw_platform_funds<'info>(
+  treasury_pool: &mut TreasuryPool,
+  platform_pool_info: &AccountInfo<'info>,
+  destination_info: &AccountInfo<'info>,
+  amount: u64,
+) -> Result<()> {
   require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  // After decentralization only the timelocked initiate/execute_withdrawal path remains
+  require!(
+    !treasury_pool.is_decentralized(),
+    ErrorCode::AdminPathRetired
+  );
   require!(amount > 0, ErrorCode::InvalidAmount);
   require!(
     treasury_pool.platform_pool_balance >= amount,
@@ -60,33 +94,11 @@ pub fn admin_withdraw(ctx: Context<AdminWithdraw>, amount: u64, reason: String)
     ErrorCode::InsufficientTreasuryFunds
   );
 
-  // Transfer from Platform Pool PDA -> destination
-  // Use lamport mutation for program-owned account
-  {
-    let mut platform_pool_lamports = platform_pool_info.try_borrow_mut_lamports()?;
-    let mut destination_lamports = destination_info.try_borrow_mut_lamports()?;
-
-    **platform_pool_lamports = (**platform_pool_lamports)
-      .checked_sub(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-    **destination_lamports = (**destination_lamports)
-      .checked_add(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-  }
-
-  // Update Platform Pool balance in state
-  treasury_pool.platform_pool_balance = treasury_pool
-    .platform_pool_balance
-    .checked_sub(amount)
-    .ok_or(ErrorCode::CalculationOverflow)?;
-
-  emit!(AdminWithdrew {
-    admin: ctx.accounts.admin.key(),
+  // Transfer from Platform Pool PDA -> destination and update platform_pool_balance
+  pool_debit_credit(
+    platform_pool_info,
+    destination_info,
     amount,
-    destination: destination_info.key(),
-    reason,
-    withdrawn_at: Clock::get()?.unix_timestamp,
-  });
-
-  Ok(())
+    &mut treasury_pool.platform_pool_balance,
+  )
 }
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::ErrorCode, events::AdminWithdrew, states::TreasuryPool};
+use crate::{
+  errors::ErrorCode, events::AdminWithdrew, states::TreasuryPool, utils::pool_debit_credit,
+};
 
 #[derive(Accounts)]
 pub struct AdminWithdrawRewardPool<'info> {
@@ -40,7 +42,34 @@ pub fn admin_withdraw_reward_pool(
   let reward_pool_info = ctx.accounts.reward_pool.to_account_info();
   let destination_info = ctx.accounts.destination.to_account_info();
 
+  treasury_pool.check_not_council_governed()?;
+  withdraw_excess_rewards(treasury_pool, &reward_pool_info, &destination_info, amount)?;
+
+  emit!(AdminWithdrew {
+    admin: ctx.accounts.admin.key(),
+    amount,
+    destination: destination_info.key(),
+    reason,
+    withdrawn_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+/// Checks and transfer shared by admin_withdraw_reward_pool and the council's
+/// AdminWithdrawRewardPool
+pub(crate) fn withdraw_excess_rewards<'info>(
+  treasury_pool: &mut TreasuryPool,
+  reward_pool_info: &AccountInfo<'info>,
+  destination_info: &AccountInfo<'info>,
+  amount: u64,
+) -> Result<()> {
   require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  // After decentralization only the timelocked initiate/execute_withdrawal path remains
+  require!(
+    !treasury_pool.is_decentralized(),
+    ErrorCode::AdminPathRetired
+  );
   require!(amount > 0, ErrorCode::InvalidAmount);
 
   require!(
@@ -59,30 +88,10 @@ pub fn admin_withdraw_reward_pool(
     ErrorCode::InsufficientTreasuryFunds
   );
 
-  {
-    let mut reward_pool_lamports = reward_pool_info.try_borrow_mut_lamports()?;
-    let mut destination_lamports = destination_info.try_borrow_mut_lamports()?;
-
-    **reward_pool_lamports = (**reward_pool_lamports)
-      .checked_sub(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-    **destination_lamports = (**destination_lamports)
-      .checked_add(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-  }
-
-  treasury_pool.reward_pool_balance = treasury_pool
-    .reward_pool_balance
-    .checked_sub(amount)
-    .ok_or(ErrorCode::CalculationOverflow)?;
-
-  emit!(AdminWithdrew {
-    admin: ctx.accounts.admin.key(),
+  pool_debit_credit(
+    reward_pool_info,
+    destination_info,
     amount,
-    destination: destination_info.key(),
-    reason,
-    withdrawn_at: Clock::get()?.unix_timestamp,
-  });
-
-  Ok(())
+    &mut treasury_pool.reward_pool_balance,
+  )
 }
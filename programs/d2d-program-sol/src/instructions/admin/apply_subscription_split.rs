@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::SubscriptionSplitChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct ApplySubscriptionSplit<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Apply the proposed subscription revenue split after its timelock
+pub fn apply_subscription_split(ctx: Context<ApplySubscriptionSplit>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    treasury_pool.has_pending_subscription_split(),
+    ErrorCode::NoPendingSubscriptionSplit
+  );
+  require!(
+    current_time >= treasury_pool.subscription_split_effective_at,
+    ErrorCode::TimelockNotExpired
+  );
+
+  let old_platform_bps = treasury_pool.subscription_platform_bps;
+  treasury_pool.subscription_platform_bps = treasury_pool.pending_subscription_platform_bps;
+  treasury_pool.pending_subscription_platform_bps = 0;
+  treasury_pool.subscription_split_effective_at = 0;
+
+  emit!(SubscriptionSplitChanged {
+    admin: ctx.accounts.admin.key(),
+    old_platform_bps,
+    new_platform_bps: treasury_pool.subscription_platform_bps,
+    changed_at: current_time,
+  });
+
+  Ok(())
+}
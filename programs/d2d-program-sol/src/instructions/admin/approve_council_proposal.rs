@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::CouncilProposalApproved,
+  states::{AdminCouncil, CouncilProposal},
+};
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ApproveCouncilProposal<'info> {
+  #[account(
+        seeds = [AdminCouncil::PREFIX_SEED],
+        bump = admin_council.bump
+    )]
+  pub admin_council: Account<'info, AdminCouncil>,
+
+  #[account(
+        mut,
+        seeds = [CouncilProposal::PREFIX_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = council_proposal.bump
+    )]
+  pub council_proposal: Account<'info, CouncilProposal>,
+
+  pub member: Signer<'info>,
+}
+
+/// A council member approves an open proposal
+pub fn approve_council_proposal(
+  ctx: Context<ApproveCouncilProposal>,
+  proposal_id: u64,
+) -> Result<()> {
+  let admin_council = &ctx.accounts.admin_council;
+  let council_proposal = &mut ctx.accounts.council_proposal;
+  let member = ctx.accounts.member.key();
+  let current_time = Clock::get()?.unix_timestamp;
+
+  let member_index = admin_council
+    .member_index(&member)
+    .ok_or(ErrorCode::NotCouncilMember)?;
+  council_proposal.check_open(admin_council, current_time)?;
+  council_proposal.approve(member_index)?;
+
+  emit!(CouncilProposalApproved {
+    proposal_id,
+    member,
+    approval_count: council_proposal.approval_count,
+    threshold: admin_council.threshold,
+    approved_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::DeveloperIdentityAttested,
+  states::{DeveloperIdentity, TreasuryPool},
+};
+
+/// Identity registrar attests (or revokes) a developer's linked profile
+///
+/// `profile_hash` must match the linked hash, so an attestation can never
+/// apply to a profile the registrar did not verify.
+#[derive(Accounts)]
+pub struct AttestDeveloperIdentity<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeveloperIdentity::PREFIX_SEED, developer_identity.developer.as_ref()],
+        bump = developer_identity.bump
+    )]
+  pub developer_identity: Account<'info, DeveloperIdentity>,
+
+  #[account(
+        constraint = treasury_pool.is_identity_registrar(&registrar.key()) @ ErrorCode::Unauthorized
+    )]
+  pub registrar: Signer<'info>,
+}
+
+pub fn attest_developer_identity(
+  ctx: Context<AttestDeveloperIdentity>,
+  profile_hash: [u8; 32],
+  attested: bool,
+) -> Result<()> {
+  let developer_identity = &mut ctx.accounts.developer_identity;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    developer_identity.profile_hash == profile_hash,
+    ErrorCode::IdentityProfileMismatch
+  );
+
+  developer_identity.attested = attested;
+  developer_identity.attested_by = ctx.accounts.registrar.key();
+  developer_identity.attested_at = current_time;
+
+  emit!(DeveloperIdentityAttested {
+    registrar: ctx.accounts.registrar.key(),
+    developer: developer_identity.developer,
+    profile_hash,
+    attested,
+    attested_at: current_time,
+  });
+
+  Ok(())
+}
@@ -1,11 +1,21 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::{
   errors::ErrorCode,
   events::{AutoRenewalExecuted, AutoRenewalFailed},
-  states::{DeployRequest, DeployRequestStatus, DeveloperEscrow, TokenType, TreasuryPool},
+  states::{
+    DeployRequest, DeployRequestStatus, DeveloperEscrow, FeeSource, Role, TokenType, TreasuryPool,
+  },
+  utils::payout_from_pda,
 };
 
+/// Renew a subscription from the developer's escrow
+///
+/// SOL escrows pay `monthly_fee` into the reward/platform pools. USDC/USDT
+/// escrows pay the request's `stablecoin_monthly_fee` from the escrow token
+/// vault into the protocol stablecoin vault for that mint (created by
+/// set_subscription_stablecoin); the three token accounts are then required.
 #[derive(Accounts)]
 #[instruction(request_id: [u8; 32])]
 pub struct AutoRenewSubscription<'info> {
@@ -18,7 +28,7 @@ pub struct AutoRenewSubscription<'info> {
 
   #[account(
         mut,
-        seeds = [DeployRequest::PREFIX_SEED, deploy_request.program_hash.as_ref()],
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
         bump = deploy_request.bump,
         constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
     )]
@@ -32,18 +42,37 @@ pub struct AutoRenewSubscription<'info> {
     )]
   pub developer_escrow: Account<'info, DeveloperEscrow>,
 
-  /// CHECK: Dev wallet address - validated against treasury_pool
+  /// CHECK: Reward pool PDA - receives renewal payments for staker rewards
   #[account(
         mut,
-        constraint = dev_wallet.key() == treasury_pool.dev_wallet @ ErrorCode::InvalidTreasuryWallet
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
     )]
-  pub dev_wallet: UncheckedAccount<'info>,
+  pub reward_pool: UncheckedAccount<'info>,
 
+  /// CHECK: Platform pool PDA - receives the platform share of renewal payments
   #[account(
-        constraint = treasury_pool.is_admin(&admin.key()) @ ErrorCode::Unauthorized
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  #[account(
+        constraint = treasury_pool.has_role(Role::DeployerOperator, &admin.key()) @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
 
+  /// Escrow token vault for the preferred token's mint (USDC/USDT only)
+  #[account(mut)]
+  pub escrow_token_vault: Option<Account<'info, TokenAccount>>,
+
+  /// Protocol stablecoin vault for the same mint (USDC/USDT only)
+  #[account(mut)]
+  pub stablecoin_vault: Option<Account<'info, TokenAccount>>,
+
+  pub token_program: Option<Program<'info, Token>>,
+
   pub system_program: Program<'info, System>,
 }
 
@@ -72,13 +101,17 @@ pub fn auto_renew_subscription(
     developer_escrow.auto_renew_enabled && deploy_request.auto_renewal_enabled,
     ErrorCode::AutoRenewalDisabled
   );
-
-  // Calculate payment amount
-  let payment_amount = deploy_request.monthly_fee * months as u64;
+  require!(
+    deploy_request.allows_renewal_months(months),
+    ErrorCode::RenewalMonthsExceedLimit
+  );
 
   // Get preferred token type from escrow
   let token_type = developer_escrow.preferred_token;
 
+  let (payment_amount, discount) =
+    escrow_renewal_payment(treasury_pool, deploy_request, token_type, months)?;
+
   // Check if escrow has sufficient balance
   if !developer_escrow.can_auto_deduct(payment_amount, token_type) {
     // Auto-renewal failed due to insufficient funds
@@ -96,26 +129,180 @@ pub fn auto_renew_subscription(
     return Err(ErrorCode::InsufficientEscrowBalance.into());
   }
 
+  // Developer-configured spending cap and monthly auto-deduct limit
+  developer_escrow.apply_auto_deduction(
+    &request_id,
+    payment_amount,
+    months,
+    Clock::get()?.unix_timestamp,
+  )?;
+
+  settle_escrow_renewal(
+    treasury_pool,
+    deploy_request,
+    developer_escrow,
+    RenewalPayees {
+      reward_pool: &ctx.accounts.reward_pool.to_account_info(),
+      platform_pool: &ctx.accounts.platform_pool.to_account_info(),
+      escrow_token_vault: ctx.accounts.escrow_token_vault.as_ref(),
+      stablecoin_vault: ctx.accounts.stablecoin_vault.as_ref(),
+      token_program: ctx.accounts.token_program.as_ref(),
+    },
+    ctx.program_id,
+    payment_amount,
+    months,
+  )?;
+
+  let current_time = Clock::get()?.unix_timestamp;
+
+  emit!(AutoRenewalExecuted {
+    request_id,
+    developer: deploy_request.developer,
+    token_type: token_type as u8,
+    amount_deducted: payment_amount,
+    discount,
+    months_renewed: months,
+    new_expiry: deploy_request.subscription_paid_until,
+    escrow_remaining: developer_escrow.get_balance(token_type),
+    renewed_at: current_time,
+  });
+
+  Ok(())
+}
+
+/// Size an escrow renewal in the escrow's token
+///
+/// SOL uses the plan-bounded `monthly_fee`; stablecoins use the request's
+/// fixed `stablecoin_monthly_fee`. Both get the prepayment discount for
+/// `months`. Returns (amount due, discount).
+pub(crate) fn escrow_renewal_payment(
+  treasury_pool: &TreasuryPool,
+  deploy_request: &DeployRequest,
+  token_type: TokenType,
+  months: u32,
+) -> Result<(u64, u64)> {
+  let payment = match token_type {
+    TokenType::SOL => {
+      treasury_pool.calculate_subscription_payment(deploy_request.monthly_fee, months)?
+    }
+    TokenType::USDC | TokenType::USDT => deploy_request.calculate_stablecoin_payment(months)?,
+  };
+  treasury_pool.apply_prepay_discount(payment, months)
+}
+
+/// Accounts an escrow renewal pays into
+///
+/// The token accounts are only needed for USDC/USDT escrows.
+pub(crate) struct RenewalPayees<'a, 'info> {
+  pub reward_pool: &'a AccountInfo<'info>,
+  pub platform_pool: &'a AccountInfo<'info>,
+  pub escrow_token_vault: Option<&'a Account<'info, TokenAccount>>,
+  pub stablecoin_vault: Option<&'a Account<'info, TokenAccount>>,
+  pub token_program: Option<&'a Program<'info, Token>>,
+}
+
+/// Charge `payment_amount` from the escrow and extend the subscription
+///
+/// Shared by auto_renew_subscription and renew_from_escrow; callers check
+/// authorization, status and balance first.
+pub(crate) fn settle_escrow_renewal<'info>(
+  treasury_pool: &mut TreasuryPool,
+  deploy_request: &mut DeployRequest,
+  developer_escrow: &mut Account<'info, DeveloperEscrow>,
+  payees: RenewalPayees<'_, 'info>,
+  program_id: &Pubkey,
+  payment_amount: u64,
+  months: u32,
+) -> Result<()> {
+  let token_type = developer_escrow.preferred_token;
+
   // Deduct from escrow
   developer_escrow.deduct_balance(payment_amount, token_type)?;
 
-  // For SOL payments, transfer from escrow PDA to dev_wallet
+  // For SOL payments, split between the reward and platform pools (same
+  // split as pay_subscription) and credit through the canonical fee path
   if token_type == TokenType::SOL {
-    let escrow_account_info = developer_escrow.to_account_info();
-    let dev_wallet_account_info = ctx.accounts.dev_wallet.to_account_info();
+    let (reward_share, platform_share) =
+      treasury_pool.split_subscription_payment(payment_amount)?;
+    payout_from_pda(
+      &developer_escrow.to_account_info(),
+      payees.reward_pool,
+      reward_share,
+    )?;
+    payout_from_pda(
+      &developer_escrow.to_account_info(),
+      payees.platform_pool,
+      platform_share,
+    )?;
+    treasury_pool.collect_fee(FeeSource::AutoRenewal, reward_share, platform_share)?;
+    deploy_request.record_developer_payment(payment_amount)?;
+  } else {
+    // Stablecoins settle from the escrow token vault into the protocol vault
+    let mint = treasury_pool
+      .escrow_token_mint(token_type)
+      .ok_or(ErrorCode::UnsupportedEscrowMint)?;
+    let (escrow_token_vault, stablecoin_vault, token_program) = match (
+      payees.escrow_token_vault,
+      payees.stablecoin_vault,
+      payees.token_program,
+    ) {
+      (Some(escrow_token_vault), Some(stablecoin_vault), Some(token_program)) => {
+        (escrow_token_vault, stablecoin_vault, token_program)
+      }
+      _ => return err!(ErrorCode::EscrowTokenAccountsRequired),
+    };
 
-    **escrow_account_info.try_borrow_mut_lamports()? = escrow_account_info
-      .lamports()
-      .checked_sub(payment_amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
+    let escrow_key = developer_escrow.key();
+    let (expected_escrow_vault, _) = Pubkey::find_program_address(
+      &[
+        DeveloperEscrow::TOKEN_VAULT_SEED,
+        escrow_key.as_ref(),
+        mint.as_ref(),
+      ],
+      program_id,
+    );
+    let (expected_stablecoin_vault, _) = Pubkey::find_program_address(
+      &[TreasuryPool::STABLECOIN_VAULT_SEED, mint.as_ref()],
+      program_id,
+    );
+    require!(
+      escrow_token_vault.key() == expected_escrow_vault
+        && stablecoin_vault.key() == expected_stablecoin_vault,
+      ErrorCode::InvalidAccountData
+    );
 
-    **dev_wallet_account_info.try_borrow_mut_lamports()? = dev_wallet_account_info
-      .lamports()
+    let escrow_seeds: &[&[u8]] = &[
+      DeveloperEscrow::PREFIX_SEED,
+      developer_escrow.developer.as_ref(),
+      &[developer_escrow.bump],
+    ];
+    token::transfer(
+      CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        Transfer {
+          from: escrow_token_vault.to_account_info(),
+          to: stablecoin_vault.to_account_info(),
+          authority: developer_escrow.to_account_info(),
+        },
+        &[escrow_seeds],
+      ),
+      payment_amount,
+    )?;
+
+    treasury_pool.total_stablecoin_subscription_revenue = treasury_pool
+      .total_stablecoin_subscription_revenue
       .checked_add(payment_amount)
       .ok_or(ErrorCode::CalculationOverflow)?;
   }
-  // Note: SPL token transfers would require additional accounts and logic
-  // For USDC/USDT, the transfer would use token program CPI
+
+  // Refunds are paid in SOL, so stablecoin renewals are valued at what the
+  // same months would have cost in SOL
+  let paid_value = if token_type == TokenType::SOL {
+    payment_amount
+  } else {
+    escrow_renewal_payment(treasury_pool, deploy_request, TokenType::SOL, months)?.0
+  };
+  deploy_request.record_subscription_payment(paid_value, Clock::get()?.unix_timestamp)?;
 
   // Extend subscription (with overflow protection)
   deploy_request.extend_subscription(months)?;
@@ -123,21 +310,5 @@ pub fn auto_renew_subscription(
   // Update status to active
   deploy_request.status = DeployRequestStatus::Active;
 
-  // Credit payment to treasury reward pool
-  treasury_pool.credit_reward_pool(payment_amount as u128)?;
-
-  let current_time = Clock::get()?.unix_timestamp;
-
-  emit!(AutoRenewalExecuted {
-    request_id,
-    developer: deploy_request.developer,
-    token_type: token_type as u8,
-    amount_deducted: payment_amount,
-    months_renewed: months,
-    new_expiry: deploy_request.subscription_paid_until,
-    escrow_remaining: developer_escrow.get_balance(token_type),
-    renewed_at: current_time,
-  });
-
   Ok(())
 }
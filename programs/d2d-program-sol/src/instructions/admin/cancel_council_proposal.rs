@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::CouncilProposalCancelled,
+  states::{AdminCouncil, CouncilProposal},
+};
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CancelCouncilProposal<'info> {
+  #[account(
+        seeds = [AdminCouncil::PREFIX_SEED],
+        bump = admin_council.bump
+    )]
+  pub admin_council: Account<'info, AdminCouncil>,
+
+  #[account(
+        mut,
+        seeds = [CouncilProposal::PREFIX_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = council_proposal.bump,
+        close = proposer
+    )]
+  pub council_proposal: Account<'info, CouncilProposal>,
+
+  /// CHECK: Receives the proposal rent back
+  #[account(mut, address = council_proposal.proposer @ ErrorCode::Unauthorized)]
+  pub proposer: UncheckedAccount<'info>,
+
+  /// The proposer, or any current member once the proposal has expired or
+  /// was made by a previous council
+  pub caller: Signer<'info>,
+}
+
+pub fn cancel_council_proposal(
+  ctx: Context<CancelCouncilProposal>,
+  proposal_id: u64,
+) -> Result<()> {
+  let admin_council = &ctx.accounts.admin_council;
+  let council_proposal = &ctx.accounts.council_proposal;
+  let caller = ctx.accounts.caller.key();
+  let current_time = Clock::get()?.unix_timestamp;
+
+  let is_proposer = caller == council_proposal.proposer;
+  let is_closed = council_proposal
+    .check_open(admin_council, current_time)
+    .is_err();
+  require!(
+    is_proposer || (is_closed && admin_council.is_member(&caller)),
+    ErrorCode::Unauthorized
+  );
+
+  emit!(CouncilProposalCancelled {
+    proposal_id,
+    proposer: council_proposal.proposer,
+    cancelled_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::SubscriptionSplitCancelled, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct CancelSubscriptionSplit<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = treasury_pool.is_admin_or_guardian(&caller.key()) @ ErrorCode::Unauthorized
+    )]
+  pub caller: Signer<'info>,
+}
+
+/// Admin withdraws, or guardian vetoes, a pending subscription split change
+pub fn cancel_subscription_split(ctx: Context<CancelSubscriptionSplit>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    treasury_pool.has_pending_subscription_split(),
+    ErrorCode::NoPendingSubscriptionSplit
+  );
+
+  let cancelled_platform_bps = treasury_pool.pending_subscription_platform_bps;
+  treasury_pool.pending_subscription_platform_bps = 0;
+  treasury_pool.subscription_split_effective_at = 0;
+
+  emit!(SubscriptionSplitCancelled {
+    cancelled_by: ctx.accounts.caller.key(),
+    cancelled_platform_bps,
+    cancelled_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::{
   errors::ErrorCode,
   events::WithdrawalCancelled,
-  states::{PendingWithdrawal, TreasuryPool, WithdrawalType},
+  states::{PendingWithdrawal, TreasuryPool},
 };
 
 #[derive(Accounts)]
@@ -40,10 +40,7 @@ pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
   require!(!pending_withdrawal.vetoed, ErrorCode::NoPendingWithdrawal);
 
   let amount = pending_withdrawal.amount;
-  let withdrawal_type_str = match pending_withdrawal.withdrawal_type {
-    WithdrawalType::PlatformPool => "PlatformPool",
-    WithdrawalType::RewardPool => "RewardPool",
-  };
+  let withdrawal_type_str = pending_withdrawal.withdrawal_type.label();
 
   treasury_pool.pending_withdrawal_count = 0;
 
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::AutoRenewChecked,
+  instructions::escrow_renewal_payment,
+  states::{DeployRequest, DeployRequestStatus, DeveloperEscrow, TokenType, TreasuryPool},
+  utils::pda_payable_lamports,
+};
+
+/// Read-only dry run of `auto_renew_subscription`
+///
+/// Runs the same checks in the same order and reports the first one that
+/// would fail instead of erroring, so the backend can batch only viable
+/// renewals. Intended to be simulated: the result is returned via return
+/// data and also emitted as an event. No account is modified.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct CheckAutoRenew<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  #[account(
+        seeds = [DeveloperEscrow::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == deploy_request.developer @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Account<'info, DeveloperEscrow>,
+}
+
+/// Why a renewal would be skipped (`None` = it would succeed)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AutoRenewSkipReason {
+  None,
+  ProgramPaused,
+  InvalidMonths,
+  InvalidStatus,
+  AutoRenewalDisabled,
+  /// Months above the extension cap, payment above the plan bound, or no
+  /// stablecoin fee set for a USDC/USDT escrow
+  PaymentOutOfBounds,
+  InsufficientEscrowBalance,
+  /// SOL balance is recorded but the escrow PDA cannot pay it above rent
+  EscrowBelowRentExemption,
+  /// Over the escrow's spending cap, monthly auto-deduct limit or the
+  /// program's budget
+  SpendingLimitExceeded,
+  /// Months above the program's max_months_per_renewal
+  MonthsOverProgramLimit,
+}
+
+/// Dry-run result returned by `check_auto_renew`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AutoRenewCheck {
+  pub renewable: bool,
+  pub skip_reason: AutoRenewSkipReason,
+  pub token_type: TokenType,
+  /// 0 when the payment could not be sized
+  pub payment_amount: u64,
+  pub escrow_balance: u64,
+  /// Subscription expiry after the renewal (unchanged when not renewable)
+  pub new_expiry: i64,
+}
+
+pub fn check_auto_renew(
+  ctx: Context<CheckAutoRenew>,
+  request_id: [u8; 32],
+  months: u32,
+) -> Result<AutoRenewCheck> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  let deploy_request = &ctx.accounts.deploy_request;
+  let developer_escrow = &ctx.accounts.developer_escrow;
+
+  let current_time = Clock::get()?.unix_timestamp;
+  let token_type = developer_escrow.preferred_token;
+  let escrow_balance = developer_escrow.get_balance(token_type);
+  let payment = escrow_renewal_payment(treasury_pool, deploy_request, token_type, months)
+    .ok()
+    .map(|(amount, _discount)| amount);
+  let payment_amount = payment.unwrap_or(0);
+
+  let skip_reason = if treasury_pool.emergency_pause {
+    AutoRenewSkipReason::ProgramPaused
+  } else if months == 0 {
+    AutoRenewSkipReason::InvalidMonths
+  } else if !matches!(
+    deploy_request.status,
+    DeployRequestStatus::Active
+      | DeployRequestStatus::SubscriptionExpired
+      | DeployRequestStatus::InGracePeriod
+  ) {
+    AutoRenewSkipReason::InvalidStatus
+  } else if !(developer_escrow.auto_renew_enabled && deploy_request.auto_renewal_enabled) {
+    AutoRenewSkipReason::AutoRenewalDisabled
+  } else if !deploy_request.allows_renewal_months(months) {
+    AutoRenewSkipReason::MonthsOverProgramLimit
+  } else if payment.is_none() {
+    AutoRenewSkipReason::PaymentOutOfBounds
+  } else if !developer_escrow.can_auto_deduct(payment_amount, token_type) {
+    AutoRenewSkipReason::InsufficientEscrowBalance
+  } else if token_type == TokenType::SOL
+    && payment_amount > pda_payable_lamports(&developer_escrow.to_account_info())?
+  {
+    AutoRenewSkipReason::EscrowBelowRentExemption
+  } else if developer_escrow
+    .check_spending_limits(&request_id, payment_amount, months, current_time)
+    .is_err()
+  {
+    AutoRenewSkipReason::SpendingLimitExceeded
+  } else {
+    AutoRenewSkipReason::None
+  };
+
+  let renewable = skip_reason == AutoRenewSkipReason::None;
+  let new_expiry = if renewable {
+    deploy_request
+      .subscription_paid_until
+      .saturating_add((months as i64).saturating_mul(DeployRequest::SECONDS_PER_MONTH))
+  } else {
+    deploy_request.subscription_paid_until
+  };
+
+  emit!(AutoRenewChecked {
+    request_id,
+    developer: deploy_request.developer,
+    renewable,
+    skip_reason: skip_reason as u8,
+    payment_amount,
+    escrow_balance,
+    checked_at: current_time,
+  });
+
+  Ok(AutoRenewCheck {
+    renewable,
+    skip_reason,
+    token_type,
+    payment_amount,
+    escrow_balance,
+    new_expiry,
+  })
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::BoostCampaignClosed,
+  states::{BoostCampaign, TreasuryPool},
+  utils::payout_from_pda,
+};
+
+/// Admin closes a campaign once its claim grace period is over
+///
+/// The unspent budget returns to the platform pool and the account rent to
+/// the admin. Unclaimed boosts are forfeited.
+#[derive(Accounts)]
+pub struct CloseBoostCampaign<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [BoostCampaign::PREFIX_SEED, &boost_campaign.campaign_id.to_le_bytes()],
+        bump = boost_campaign.bump,
+        close = admin,
+    )]
+  pub boost_campaign: Account<'info, BoostCampaign>,
+
+  /// CHECK: Platform Pool PDA (receives the unspent budget)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn close_boost_campaign(ctx: Context<CloseBoostCampaign>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let boost_campaign = &ctx.accounts.boost_campaign;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    current_time
+      >= boost_campaign
+        .end_at
+        .saturating_add(BoostCampaign::CLAIM_GRACE_PERIOD),
+    ErrorCode::CampaignClaimWindowOpen
+  );
+
+  let unspent = boost_campaign.get_remaining_budget();
+  payout_from_pda(
+    &boost_campaign.to_account_info(),
+    &ctx.accounts.platform_pool.to_account_info(),
+    unspent,
+  )?;
+  treasury_pool.platform_pool_balance = treasury_pool
+    .platform_pool_balance
+    .checked_add(unspent)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  emit!(BoostCampaignClosed {
+    admin: ctx.accounts.admin.key(),
+    campaign: boost_campaign.key(),
+    paid_out: boost_campaign.paid_out,
+    returned_to_platform_pool: unspent,
+    closed_at: current_time,
+  });
+
+  Ok(())
+}
@@ -3,7 +3,9 @@ use anchor_lang::prelude::*;
 use crate::{
   errors::ErrorCode,
   events::{GracePeriodEnded, ProgramClosedAfterGrace},
-  states::{DeployRequest, DeployRequestStatus, ManagedProgram, TreasuryPool},
+  states::{
+    DeployRequest, DeployRequestStatus, ManagedProgram, Role, TreasuryPool, UserDeployStats,
+  },
 };
 
 #[derive(Accounts)]
@@ -18,7 +20,7 @@ pub struct CloseExpiredProgram<'info> {
 
   #[account(
         mut,
-        seeds = [DeployRequest::PREFIX_SEED, deploy_request.program_hash.as_ref()],
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
         bump = deploy_request.bump,
         constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
     )]
@@ -33,9 +35,17 @@ pub struct CloseExpiredProgram<'info> {
   pub managed_program: Account<'info, ManagedProgram>,
 
   #[account(
-        constraint = treasury_pool.is_admin(&admin.key()) @ ErrorCode::Unauthorized
+        constraint = treasury_pool.has_role(Role::DeployerOperator, &admin.key()) @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
+
+  /// Developer's deployment stats (frees the request's quota slot)
+  #[account(
+        mut,
+        seeds = [UserDeployStats::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = user_stats.bump
+    )]
+  pub user_stats: Account<'info, UserDeployStats>,
 }
 
 pub fn close_expired_program(
@@ -64,6 +74,7 @@ pub fn close_expired_program(
   let program_id = managed_program.program_id;
 
   // Update deploy request status
+  ctx.accounts.user_stats.release_slot(&deploy_request.status);
   deploy_request.status = DeployRequestStatus::Closed;
 
   // Mark managed program as inactive
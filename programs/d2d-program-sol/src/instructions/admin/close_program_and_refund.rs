@@ -2,12 +2,18 @@ use anchor_lang::{prelude::*, system_program};
 
 use crate::{
   errors::ErrorCode,
-  events::ProgramClosed,
-  states::{DeployRequest, DeployRequestStatus, TreasuryPool},
+  events::{ProgramClosed, ServiceFeeRefunded, UnusedSubscriptionRefunded},
+  instructions::claw_back_rewards,
+  states::{DeployRequest, DeployRequestStatus, Role, TreasuryPool, UserDeployStats},
 };
 
 /// Close a deployed program and refund recovered lamports to pool
 /// This is called after a program is closed on-chain
+///
+/// Closing an active program is a voluntary closure: the developer gets the
+/// service fee refund from the schedule stored on the deploy request, and the
+/// unused prepaid subscription time pro rata, paid from the reward pool's
+/// excess only (never from protected rewards or the platform pool)
 #[derive(Accounts)]
 #[instruction(request_id: [u8; 32])]
 pub struct CloseProgramAndRefund<'info> {
@@ -18,6 +24,14 @@ pub struct CloseProgramAndRefund<'info> {
     )]
   pub treasury_pool: Account<'info, TreasuryPool>,
 
+  /// CHECK: Treasury vault PDA (receives recovered lamports)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
   #[account(
         mut,
         seeds = [DeployRequest::PREFIX_SEED, request_id.as_ref()],
@@ -28,7 +42,7 @@ pub struct CloseProgramAndRefund<'info> {
 
   #[account(
         mut,
-        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+        constraint = treasury_pool.has_role(Role::DeployerOperator, &admin.key()) @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
 
@@ -36,7 +50,38 @@ pub struct CloseProgramAndRefund<'info> {
   #[account(mut)]
   pub refund_source: UncheckedAccount<'info>,
 
+  /// CHECK: Reward Pool PDA (service fee refunds)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform Pool PDA (covers refunds that would touch protected rewards)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Developer wallet - receives the service fee and subscription refunds
+  #[account(
+        mut,
+        constraint = developer_wallet.key() == deploy_request.developer @ ErrorCode::Unauthorized
+    )]
+  pub developer_wallet: UncheckedAccount<'info>,
+
   pub system_program: Program<'info, System>,
+
+  /// Developer's deployment stats (frees the request's quota slot)
+  #[account(
+        mut,
+        seeds = [UserDeployStats::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = user_stats.bump
+    )]
+  pub user_stats: Account<'info, UserDeployStats>,
 }
 
 pub fn close_program_and_refund(
@@ -44,9 +89,6 @@ pub fn close_program_and_refund(
   _request_id: [u8; 32],
   recovered_lamports: u64,
 ) -> Result<()> {
-  // Get account info before mutable borrow
-  let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
-
   let treasury_pool = &mut ctx.accounts.treasury_pool;
   let deploy_request = &mut ctx.accounts.deploy_request;
   let current_time = Clock::get()?.unix_timestamp;
@@ -54,12 +96,12 @@ pub fn close_program_and_refund(
   require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
   require!(recovered_lamports > 0, ErrorCode::InvalidAmount);
 
-  // Transfer recovered lamports directly to Treasury Pool PDA
+  // Transfer recovered lamports directly to the treasury vault
   let cpi_context = CpiContext::new(
     ctx.accounts.system_program.to_account_info(),
     system_program::Transfer {
       from: ctx.accounts.refund_source.to_account_info(),
-      to: treasury_pool_info.clone(),
+      to: ctx.accounts.treasury_vault.to_account_info(),
     },
   );
   system_program::transfer(cpi_context, recovered_lamports)?;
@@ -71,8 +113,54 @@ pub fn close_program_and_refund(
     .ok_or(ErrorCode::CalculationOverflow)?;
 
   // Mark deploy request as closed
+  ctx.accounts.user_stats.release_slot(&deploy_request.status);
   deploy_request.status = DeployRequestStatus::Closed;
 
+  // Refund the service fee per the schedule stored on the request
+  let service_fee_refund = deploy_request.calculate_service_fee_refund(current_time)?;
+  if service_fee_refund > 0 {
+    claw_back_rewards(
+      treasury_pool,
+      &ctx.accounts.reward_pool.to_account_info(),
+      &ctx.accounts.platform_pool.to_account_info(),
+      &ctx.accounts.developer_wallet.to_account_info(),
+      service_fee_refund,
+    )?;
+    deploy_request.record_service_fee_refund(service_fee_refund);
+
+    emit!(ServiceFeeRefunded {
+      request_id: deploy_request.request_id,
+      developer: deploy_request.developer,
+      service_fee: deploy_request.service_fee,
+      refund_amount: service_fee_refund,
+      refunded_at: current_time,
+    });
+  }
+
+  // Refund the unused prepaid time, capped by what stakers are not owed
+  let unused_subscription = deploy_request.calculate_unused_subscription(current_time)?;
+  let subscription_refund = unused_subscription.min(treasury_pool.get_excess_rewards());
+  if subscription_refund > 0 {
+    claw_back_rewards(
+      treasury_pool,
+      &ctx.accounts.reward_pool.to_account_info(),
+      &ctx.accounts.platform_pool.to_account_info(),
+      &ctx.accounts.developer_wallet.to_account_info(),
+      subscription_refund,
+    )?;
+  }
+  if unused_subscription > 0 {
+    deploy_request.end_subscription(current_time);
+
+    emit!(UnusedSubscriptionRefunded {
+      request_id: deploy_request.request_id,
+      developer: deploy_request.developer,
+      unused_value: unused_subscription,
+      refund_amount: subscription_refund,
+      refunded_at: current_time,
+    });
+  }
+
   emit!(ProgramClosed {
     request_id: deploy_request.request_id,
     program_id: deploy_request.deployed_program_id.unwrap_or_default(),
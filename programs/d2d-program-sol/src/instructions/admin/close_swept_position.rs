@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::DustPositionClosed,
+  states::{BackerDeposit, TreasuryPool},
+  utils::payout_from_pda,
+};
+
+/// Permissionless crank: close a swept dust position once the withdrawal
+/// queue has paid it out
+///
+/// Settled base rewards and the account rent go to the staker. Duration
+/// weight is forfeited, as it is reset on every claim.
+#[derive(Accounts)]
+pub struct CloseSweptPosition<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Reward Pool PDA (pays remaining rewards)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        close = staker,
+        seeds = [BackerDeposit::PREFIX_SEED, staker.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  /// CHECK: Staker wallet - receives rewards and rent, bound by the PDA seeds
+  #[account(mut)]
+  pub staker: UncheckedAccount<'info>,
+
+  pub cranker: Signer<'info>,
+}
+
+pub fn close_swept_position(ctx: Context<CloseSweptPosition>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    lender_stake.dust_swept_at != 0
+      && lender_stake.deposited_amount == 0
+      && lender_stake.has_no_attachments(),
+    ErrorCode::PositionNotSweepable
+  );
+
+  let rewards = lender_stake.calculate_claimable_rewards(treasury_pool.reward_per_share)?;
+  if rewards > 0 {
+    treasury_pool.record_claimed_rewards(rewards)?;
+    treasury_pool.debit_reward_pool(rewards)?;
+    payout_from_pda(
+      &ctx.accounts.reward_pool.to_account_info(),
+      &ctx.accounts.staker.to_account_info(),
+      rewards,
+    )?;
+  }
+
+  emit!(DustPositionClosed {
+    staker: lender_stake.backer,
+    rewards_paid: rewards,
+    closed_at: current_time,
+  });
+
+  Ok(())
+}
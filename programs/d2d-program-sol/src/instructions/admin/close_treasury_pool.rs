@@ -1,13 +1,18 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::ErrorCode, states::TreasuryPool};
+use crate::{
+  errors::ErrorCode,
+  states::TreasuryPool,
+  utils::{pda_transfer, vault_payable_lamports, vault_transfer},
+};
 
 /// Close Treasury Pool Account (Admin only)
 ///
 /// SECURITY: This instruction REQUIRES admin verification via deserialization.
 /// If the account has an old layout, call migrate_treasury_pool first.
 ///
-/// WARNING: This will transfer all funds to admin and make the account rent-exempt!
+/// WARNING: This will transfer all funds (state account and treasury vault) to
+/// admin and leave both accounts at their rent-exempt minimum!
 /// After closing, you can call initialize() again to create a new account.
 #[derive(Accounts)]
 pub struct CloseTreasuryPool<'info> {
@@ -22,6 +27,14 @@ pub struct CloseTreasuryPool<'info> {
     )]
   pub treasury_pool: Account<'info, TreasuryPool>,
 
+  /// CHECK: Treasury vault PDA - drained alongside the state account
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
   /// Admin who will receive the lamports - MUST match treasury_pool.admin
   #[account(mut)]
   pub admin: Signer<'info>,
@@ -32,6 +45,12 @@ pub struct CloseTreasuryPool<'info> {
 /// Close treasury pool account by transferring all lamports to admin
 /// SECURITY: Admin verification is enforced via account constraint
 pub fn close_treasury_pool(ctx: Context<CloseTreasuryPool>) -> Result<()> {
+  require!(
+    !ctx.accounts.treasury_pool.is_decentralized(),
+    ErrorCode::AdminPathRetired
+  );
+  ctx.accounts.treasury_pool.check_not_council_governed()?;
+
   msg!("[CLOSE] Closing Treasury Pool account");
   msg!("[CLOSE] Admin: {}", ctx.accounts.admin.key());
   msg!(
@@ -74,22 +93,34 @@ pub fn close_treasury_pool(ctx: Context<CloseTreasuryPool>) -> Result<()> {
     );
 
     // Use direct lamport mutation for program-owned accounts
-    **treasury_account.try_borrow_mut_lamports()? = balance_before
-      .checked_sub(transfer_amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-
-    **ctx.accounts.admin.try_borrow_mut_lamports()? = ctx
-      .accounts
-      .admin
-      .lamports()
-      .checked_add(transfer_amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
+    pda_transfer(
+      &treasury_account,
+      &ctx.accounts.admin.to_account_info(),
+      transfer_amount,
+    )?;
 
     msg!("[CLOSE] Transfer complete");
   } else {
     msg!("[CLOSE] No lamports to transfer (account already rent-exempt)");
   }
 
+  // Drain the treasury vault as well (liquidity lives there after the vault split)
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let vault_amount = vault_payable_lamports(&treasury_vault_info)?;
+  if vault_amount > 0 {
+    msg!(
+      "[CLOSE] Transferring {} vault lamports to verified admin",
+      vault_amount
+    );
+    vault_transfer(
+      &ctx.accounts.system_program.to_account_info(),
+      &treasury_vault_info,
+      &ctx.accounts.admin.to_account_info(),
+      vault_amount,
+      ctx.accounts.treasury_pool.vault_bump,
+    )?;
+  }
+
   msg!("[CLOSE] Treasury Pool account closed successfully");
   msg!(
     "[CLOSE] Remaining balance: {} lamports (rent-exempt minimum)",
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::BorrowFeeCollected,
+  states::{
+    DeployRequest, DeployRequestStatus, DeveloperEscrow, FeeSource, TokenType, TreasuryPool,
+  },
+  utils::{payout_from_pda, pda_payable_lamports},
+};
+
+/// Permissionless crank: collect accrued borrow fees from the developer's
+/// SOL escrow
+///
+/// Covers developers who only renew from escrow and never call
+/// pay_subscription (which settles the fees itself). The full outstanding
+/// amount is charged to the reward pool; the escrow's spending limits do not
+/// apply as the fee is owed regardless of renewals.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct CollectBorrowFee<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == deploy_request.developer @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Account<'info, DeveloperEscrow>,
+
+  /// CHECK: Reward pool PDA - receives the borrow fees for staker rewards
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+}
+
+pub fn collect_borrow_fee(ctx: Context<CollectBorrowFee>, request_id: [u8; 32]) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let developer_escrow = &mut ctx.accounts.developer_escrow;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    matches!(
+      deploy_request.status,
+      DeployRequestStatus::Active
+        | DeployRequestStatus::SubscriptionExpired
+        | DeployRequestStatus::InGracePeriod
+    ),
+    ErrorCode::InvalidRequestStatus
+  );
+
+  let borrow_fee = deploy_request.outstanding_borrow_fees()?;
+  require!(borrow_fee > 0, ErrorCode::InvalidAmount);
+  require!(
+    developer_escrow.get_balance(TokenType::SOL) >= borrow_fee
+      && pda_payable_lamports(&developer_escrow.to_account_info())? >= borrow_fee,
+    ErrorCode::InsufficientEscrowBalance
+  );
+
+  developer_escrow.deduct_balance(borrow_fee, TokenType::SOL)?;
+  payout_from_pda(
+    &developer_escrow.to_account_info(),
+    &ctx.accounts.reward_pool.to_account_info(),
+    borrow_fee,
+  )?;
+  treasury_pool.collect_fee(FeeSource::BorrowFee, borrow_fee, 0)?;
+  deploy_request.record_borrow_fee_payment(borrow_fee)?;
+
+  emit!(BorrowFeeCollected {
+    request_id,
+    developer: deploy_request.developer,
+    amount: borrow_fee,
+    fees_paid: deploy_request.fees_paid,
+    from_escrow: true,
+    collected_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
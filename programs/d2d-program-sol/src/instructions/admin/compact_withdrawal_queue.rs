@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::WithdrawalQueueCompacted,
+  states::{TreasuryPool, WithdrawalQueueEntry},
+  utils::close_pda,
+};
+
+/// Close finished queue entries and advance the head past cancelled ones
+/// Permissionless crank - each entry's rent is refunded to its original staker
+///
+/// Remaining accounts: `[queue_entry, staker]` pairs. An entry is closed if it
+/// is no longer pending (cancelled, expired, fully or force-processed) and sits
+/// at or behind the queue head; closing the head entry advances the head.
+/// Slots already closed on cancel/processing only advance the head (their
+/// staker account is not checked). Processing stops at the first pending entry.
+#[derive(Accounts)]
+pub struct CompactWithdrawalQueue<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  pub cranker: Signer<'info>,
+}
+
+pub fn compact_withdrawal_queue(ctx: Context<CompactWithdrawalQueue>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let old_head = treasury_pool.withdrawal_queue_head;
+  let mut entries_closed: u32 = 0;
+  let mut rent_refunded: u64 = 0;
+
+  for accounts in ctx.remaining_accounts.chunks_exact(2) {
+    let (entry_info, staker_info) = (&accounts[0], &accounts[1]);
+
+    let head = treasury_pool.withdrawal_queue_head;
+    if treasury_pool.has_pending_withdrawals()
+      && WithdrawalQueueEntry::is_closed_slot(entry_info, head, ctx.program_id)
+    {
+      treasury_pool.withdrawal_queue_head =
+        head.checked_add(1).ok_or(ErrorCode::CalculationOverflow)?;
+      continue;
+    }
+
+    require!(
+      entry_info.owner == ctx.program_id,
+      ErrorCode::InvalidAccountOwner
+    );
+
+    let entry = WithdrawalQueueEntry::try_deserialize(&mut &entry_info.data.borrow()[..])
+      .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
+    let expected_key = Pubkey::create_program_address(
+      &[
+        WithdrawalQueueEntry::PREFIX_SEED,
+        &entry.position.to_le_bytes(),
+        &[entry.bump],
+      ],
+      ctx.program_id,
+    )
+    .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
+    require!(
+      entry_info.key() == expected_key,
+      ErrorCode::InvalidAccountData
+    );
+    require!(
+      staker_info.key() == entry.staker && staker_info.is_writable,
+      ErrorCode::Unauthorized
+    );
+
+    if !entry.processed {
+      break;
+    }
+
+    // Entries ahead of a pending head stay open - the head must still find
+    // them when it gets there
+    if entry.position == head {
+      treasury_pool.withdrawal_queue_head =
+        head.checked_add(1).ok_or(ErrorCode::CalculationOverflow)?;
+    } else {
+      require!(entry.position < head, ErrorCode::InvalidQueuePosition);
+    }
+
+    rent_refunded = rent_refunded
+      .checked_add(entry_info.lamports())
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    close_pda(entry_info, staker_info)?;
+
+    entries_closed = entries_closed
+      .checked_add(1)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+  }
+
+  require!(
+    entries_closed > 0 || treasury_pool.withdrawal_queue_head != old_head,
+    ErrorCode::QueueNotCompactable
+  );
+
+  emit!(WithdrawalQueueCompacted {
+    cranker: ctx.accounts.cranker.key(),
+    entries_closed,
+    rent_refunded,
+    old_head,
+    new_head: treasury_pool.withdrawal_queue_head,
+    compacted_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -2,8 +2,10 @@ use anchor_lang::{prelude::*, system_program};
 
 use crate::{
   errors::ErrorCode,
-  events::{DeploymentConfirmed, DeploymentFailed},
-  states::{DeployRequest, DeployRequestStatus, TreasuryPool},
+  events::{DeploymentConfirmed, DeploymentFailed, FundingVarianceDetected},
+  instructions::auto_fulfill_withdrawal_queue,
+  states::{DeployRequest, DeployRequestStatus, FeeSource, Role, TreasuryPool, UserDeployStats},
+  utils::{payout_from_pda, pool_debit_credit},
 };
 
 #[derive(Accounts)]
@@ -17,14 +19,22 @@ pub struct ConfirmDeployment<'info> {
 
   #[account(
         mut,
-        seeds = [DeployRequest::PREFIX_SEED, deploy_request.program_hash.as_ref()],
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
         bump = deploy_request.bump
     )]
   pub deploy_request: Account<'info, DeployRequest>,
 
+  /// Developer's deployment stats (moves the request's quota slot)
   #[account(
         mut,
-        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+        seeds = [UserDeployStats::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = user_stats.bump
+    )]
+  pub user_stats: Account<'info, UserDeployStats>,
+
+  #[account(
+        mut,
+        constraint = treasury_pool.has_role(Role::DeployerOperator, &admin.key()) @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
 
@@ -36,15 +46,15 @@ pub struct ConfirmDeployment<'info> {
   #[account(mut)]
   pub developer_wallet: UncheckedAccount<'info>,
 
-  /// CHECK: Treasury Pool PDA (for recovered funds transfer)
-  /// Note: Recovered funds go back to TreasuryPool, not PlatformPool
+  /// CHECK: Treasury vault PDA (for recovered funds transfer)
+  /// Note: Recovered funds go back to the treasury vault, not PlatformPool
   /// PlatformPool only receives 0.1% developer fees
   #[account(
         mut,
-        seeds = [TreasuryPool::PREFIX_SEED],
-        bump = treasury_pool.bump
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
     )]
-  pub treasury_pda: UncheckedAccount<'info>,
+  pub treasury_vault: UncheckedAccount<'info>,
 
   /// CHECK: Reward Pool PDA (for refunds on failure)
   #[account(
@@ -54,17 +64,51 @@ pub struct ConfirmDeployment<'info> {
     )]
   pub reward_pool: UncheckedAccount<'info>,
 
+  /// CHECK: Platform Pool PDA (covers refunds the reward pool cannot without touching protected rewards)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
   pub system_program: Program<'info, System>,
 }
 
-pub fn confirm_deployment_success(
-  ctx: Context<ConfirmDeployment>,
+/// Claw back already-credited fees without dipping into protected rewards:
+/// the reward pool's excess pays first, the platform pool covers the rest
+pub(crate) fn claw_back_rewards<'info>(
+  treasury_pool: &mut TreasuryPool,
+  reward_pool: &AccountInfo<'info>,
+  platform_pool: &AccountInfo<'info>,
+  to: &AccountInfo<'info>,
+  amount: u64,
+) -> Result<()> {
+  let (from_rewards, from_platform) = treasury_pool.split_reward_clawback(amount)?;
+  pool_debit_credit(
+    reward_pool,
+    to,
+    from_rewards,
+    &mut treasury_pool.reward_pool_balance,
+  )?;
+  pool_debit_credit(
+    platform_pool,
+    to,
+    from_platform,
+    &mut treasury_pool.platform_pool_balance,
+  )?;
+  treasury_pool.check_protected_rewards_backed()
+}
+
+pub fn confirm_deployment_success<'info>(
+  ctx: Context<'_, '_, '_, 'info, ConfirmDeployment<'info>>,
   request_id: [u8; 32],
   deployed_program_id: Pubkey,
   recovered_funds: u64,
+  spent_funds: u64,
 ) -> Result<()> {
   // Get account infos before mutable borrows
-  let treasury_pda_info = ctx.accounts.treasury_pda.to_account_info();
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
   let ephemeral_key_info = ctx.accounts.ephemeral_key.to_account_info();
 
   let treasury_pool = &mut ctx.accounts.treasury_pool;
@@ -86,6 +130,13 @@ pub fn confirm_deployment_success(
     ErrorCode::InvalidRecoveredFunds
   );
 
+  // Reported recovered + spent must account for everything that was funded
+  let reported_variance = deploy_request.calculate_funding_variance(recovered_funds, spent_funds);
+  require!(
+    reported_variance.unsigned_abs() <= treasury_pool.funding_variance_tolerance,
+    ErrorCode::FundingReconciliationFailed
+  );
+
   // SECURITY FIX C-02: Always verify ephemeral_key - MUST be set and match
   // Reject if ephemeral_key was never set (deployment was never properly funded)
   require!(
@@ -102,6 +153,14 @@ pub fn confirm_deployment_success(
   deploy_request.deployed_program_id = Some(deployed_program_id);
   // borrowed_amount is already set in fund_temporary_wallet
 
+  // Deployment is live - the escrowed developer payment now earns for stakers
+  let escrowed_payment = deploy_request.take_escrowed_payment();
+  treasury_pool.release_escrowed_payment(FeeSource::DeploymentRequest, escrowed_payment)?;
+  ctx
+    .accounts
+    .user_stats
+    .record_deployment_success(deploy_request.deployment_cost)?;
+
   // If there are recovered funds, transfer them back to Platform Pool
   // Note: Recovered funds go to Platform Pool (not Reward Pool) as they're operational funds
   // Note: Only recover what's actually available in ephemeral key (may have been partially drained)
@@ -115,19 +174,15 @@ pub fn confirm_deployment_success(
   };
 
   if actual_recovered > 0 {
-    // Transfer recovered funds back to Treasury Pool PDA via CPI System Program transfer
+    // Transfer recovered funds back to the treasury vault via CPI System Program transfer
     // CRITICAL: Recovered funds go to TreasuryPool (liquid_balance), NOT PlatformPool
     // PlatformPool only receives 0.1% developer fees, not operational funds
     // Note: ephemeral_key must be a signer for this transfer
-    let treasury_seeds = &[TreasuryPool::PREFIX_SEED, &[treasury_pool.bump]];
-    let _signer_seeds = &[&treasury_seeds[..]];
-
-    // Use CPI System Program transfer from ephemeral_key to treasury_pda
     let cpi_context = CpiContext::new(
       ctx.accounts.system_program.to_account_info(),
       system_program::Transfer {
         from: ephemeral_key_info,
-        to: treasury_pda_info,
+        to: treasury_vault_info.clone(),
       },
     );
     system_program::transfer(cpi_context, actual_recovered)?;
@@ -143,6 +198,25 @@ pub fn confirm_deployment_success(
     // PlatformPool only receives 0.1% developer fees, not recovered deployment funds
   }
 
+  // Reconcile against what was actually recovered (ephemeral key may have been drained)
+  let funding_variance = deploy_request.calculate_funding_variance(actual_recovered, spent_funds);
+  deploy_request.reported_spent = spent_funds;
+  deploy_request.funding_variance = funding_variance;
+
+  // Recovered funds restored liquid_balance - fulfill queued withdrawals
+  // Remaining accounts: `[queue_entry, lender_stake, staker]` triples from the queue head
+  if actual_recovered > 0 {
+    auto_fulfill_withdrawal_queue(
+      treasury_pool,
+      &treasury_vault_info,
+      &ctx.accounts.system_program.to_account_info(),
+      ctx.remaining_accounts,
+      &[],
+      ctx.program_id,
+      Clock::get()?.unix_timestamp,
+    )?;
+  }
+
   emit!(DeploymentConfirmed {
     request_id: deploy_request.request_id,
     developer: deploy_request.developer,
@@ -152,16 +226,27 @@ pub fn confirm_deployment_success(
     confirmed_at: Clock::get()?.unix_timestamp,
   });
 
+  if funding_variance != 0 {
+    emit!(FundingVarianceDetected {
+      request_id: deploy_request.request_id,
+      funded_amount: deploy_request.get_funded_amount(),
+      recovered_funds: actual_recovered,
+      spent_funds,
+      variance: funding_variance,
+      detected_at: Clock::get()?.unix_timestamp,
+    });
+  }
+
   Ok(())
 }
 
-pub fn confirm_deployment_failure(
-  ctx: Context<ConfirmDeployment>,
+pub fn confirm_deployment_failure<'info>(
+  ctx: Context<'_, '_, '_, 'info, ConfirmDeployment<'info>>,
   request_id: [u8; 32],
   failure_reason: String,
 ) -> Result<()> {
   let reward_pool_info = ctx.accounts.reward_pool.to_account_info();
-  let treasury_pda_info = ctx.accounts.treasury_pda.to_account_info();
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
   let ephemeral_key_info = ctx.accounts.ephemeral_key.to_account_info();
 
   let treasury_pool = &mut ctx.accounts.treasury_pool;
@@ -187,24 +272,8 @@ pub fn confirm_deployment_failure(
     ErrorCode::InvalidEphemeralKey
   );
 
-  // Calculate refund amount
-  // Developer paid: service_fee + (monthly_fee * initial_months)
-  // Calculate initial_months from subscription_paid_until and created_at
-  let seconds_per_month: i64 = 30 * 24 * 60 * 60;
-  let subscription_duration = deploy_request
-    .subscription_paid_until
-    .saturating_sub(deploy_request.created_at);
-  let initial_months = (subscription_duration / seconds_per_month).max(1) as u64;
-
-  // Calculate total payment that was made (full refund for failed deployment)
-  let monthly_payment = deploy_request
-    .monthly_fee
-    .checked_mul(initial_months)
-    .ok_or(ErrorCode::CalculationOverflow)?;
-  let refund_amount = deploy_request
-    .service_fee
-    .checked_add(monthly_payment)
-    .ok_or(ErrorCode::CalculationOverflow)?;
+  // Full refund of what the developer actually paid for this request
+  let refund_amount = deploy_request.calculate_failure_refund()?;
 
   // Validate refund amount is reasonable
   require!(
@@ -214,26 +283,38 @@ pub fn confirm_deployment_failure(
 
   // Update deploy request
   deploy_request.status = DeployRequestStatus::Failed;
+  ctx
+    .accounts
+    .user_stats
+    .record_deployment_failure(refund_amount);
+
+  // Refund developer payment from escrow; only requests created before
+  // escrowing claw back already-credited rewards (decrease reward_pool_balance)
+  let escrowed_payment = deploy_request.take_escrowed_payment();
+  let (from_escrow, from_rewards) = treasury_pool.split_escrowed_refund(
+    FeeSource::DeploymentRequest,
+    escrowed_payment,
+    refund_amount,
+  )?;
 
-  // Check Reward Pool has enough lamports for refund
-  let reward_pool_lamports = reward_pool_info.lamports();
+  // Check Reward Pool has enough lamports for the escrowed refund
   require!(
-    reward_pool_lamports >= refund_amount,
+    reward_pool_info.lamports() >= from_escrow,
     ErrorCode::InsufficientTreasuryFunds
   );
-
-  // Refund developer payment from Reward Pool PDA via direct lamport manipulation
-  {
-    let developer_wallet_info = ctx.accounts.developer_wallet.to_account_info();
-    let mut reward_pool_lamports_mut = reward_pool_info.try_borrow_mut_lamports()?;
-    let mut developer_lamports = developer_wallet_info.try_borrow_mut_lamports()?;
-
-    **reward_pool_lamports_mut = (**reward_pool_lamports_mut)
-      .checked_sub(refund_amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-    **developer_lamports = (**developer_lamports)
-      .checked_add(refund_amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
+  payout_from_pda(
+    &reward_pool_info,
+    &ctx.accounts.developer_wallet.to_account_info(),
+    from_escrow,
+  )?;
+  if from_rewards > 0 {
+    claw_back_rewards(
+      treasury_pool,
+      &reward_pool_info,
+      &ctx.accounts.platform_pool.to_account_info(),
+      &ctx.accounts.developer_wallet.to_account_info(),
+      from_rewards,
+    )?;
   }
 
   // Return deployment cost to liquid_balance (where it came from)
@@ -241,13 +322,13 @@ pub fn confirm_deployment_failure(
   // CRITICAL: Recovered funds go to TreasuryPool, NOT PlatformPool
   let remaining_funds = ephemeral_key_info.lamports();
   if remaining_funds > 0 {
-    // Use CPI System Program transfer from ephemeral_key to treasury_pda
+    // Use CPI System Program transfer from ephemeral_key to the treasury vault
     // Note: ephemeral_key must be a signer for this transfer
     let cpi_context = CpiContext::new(
       ctx.accounts.system_program.to_account_info(),
       system_program::Transfer {
         from: ephemeral_key_info,
-        to: treasury_pda_info,
+        to: treasury_vault_info,
       },
     );
     system_program::transfer(cpi_context, remaining_funds)?;
@@ -260,9 +341,6 @@ pub fn confirm_deployment_failure(
       .ok_or(ErrorCode::CalculationOverflow)?;
   }
 
-  // IMPORTANT: Refund fees collected (decrease reward_pool_balance)
-  treasury_pool.debit_reward_pool(refund_amount)?;
-
   emit!(DeploymentFailed {
     request_id: deploy_request.request_id,
     developer: deploy_request.developer,
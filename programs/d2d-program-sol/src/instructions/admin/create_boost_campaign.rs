@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::BoostCampaignCreated,
+  states::{BoostCampaign, CampaignEligibility, TreasuryPool},
+  utils::pool_debit_credit,
+};
+
+/// Admin creates a reward boost campaign, funding its budget from the platform pool
+#[derive(Accounts)]
+#[instruction(campaign_id: u64)]
+pub struct CreateBoostCampaign<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        init,
+        payer = admin,
+        space = 8 + BoostCampaign::INIT_SPACE,
+        seeds = [BoostCampaign::PREFIX_SEED, &campaign_id.to_le_bytes()],
+        bump
+    )]
+  pub boost_campaign: Account<'info, BoostCampaign>,
+
+  /// CHECK: Platform Pool PDA (funds the campaign budget)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn create_boost_campaign(
+  ctx: Context<CreateBoostCampaign>,
+  campaign_id: u64,
+  start_at: i64,
+  end_at: i64,
+  boost_bps: u64,
+  eligibility: CampaignEligibility,
+  budget: u64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let boost_campaign = &mut ctx.accounts.boost_campaign;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    end_at > start_at
+      && end_at > current_time
+      && end_at.saturating_sub(start_at) <= BoostCampaign::MAX_CAMPAIGN_DURATION,
+    ErrorCode::InvalidCampaignWindow
+  );
+  require!(
+    boost_bps > 0 && boost_bps <= BoostCampaign::MAX_BOOST_BPS,
+    ErrorCode::InvalidCampaignBoost
+  );
+  require!(
+    budget > 0 && budget <= treasury_pool.platform_pool_balance,
+    ErrorCode::InvalidCampaignBudget
+  );
+
+  boost_campaign.campaign_id = campaign_id;
+  boost_campaign.start_at = start_at;
+  boost_campaign.end_at = end_at;
+  boost_campaign.boost_bps = boost_bps;
+  boost_campaign.eligibility = eligibility;
+  boost_campaign.budget = budget;
+  boost_campaign.end_reward_per_share = treasury_pool.reward_per_share;
+  boost_campaign.created_at = current_time;
+  boost_campaign.bump = ctx.bumps.boost_campaign;
+
+  pool_debit_credit(
+    &ctx.accounts.platform_pool.to_account_info(),
+    &boost_campaign.to_account_info(),
+    budget,
+    &mut treasury_pool.platform_pool_balance,
+  )?;
+
+  emit!(BoostCampaignCreated {
+    admin: ctx.accounts.admin.key(),
+    campaign: boost_campaign.key(),
+    campaign_id,
+    start_at,
+    end_at,
+    boost_bps,
+    eligibility,
+    budget,
+    created_at: current_time,
+  });
+
+  Ok(())
+}
@@ -4,16 +4,25 @@ use anchor_lang::{prelude::*, solana_program::rent::Rent, system_program};
 
 use crate::{
   errors::ErrorCode,
-  events::DeploymentFundsRequested,
-  states::{DeployRequest, DeployRequestStatus, TreasuryPool, UserDeployStats},
+  events::{DeploymentFundsRequested, TermsAccepted},
+  states::{
+    Coupon, DeployRequest, DeployRequestStatus, FeeSource, PlanConfig, Role, TreasuryPool,
+    UserDeployStats,
+  },
+  utils::apply_coupon,
 };
 
 /// Create deploy request after payment verification
-/// Only backend admin can call this instruction
+/// Only the admin or the deployer operator can call this instruction
 /// Payment has already been verified and transferred to Reward Pool
 /// This instruction creates the deploy_request and credits Reward Pool
+///
+/// `nonce` selects the deployment of `program_hash` as in
+/// request_deployment_funds; an existing request is only replaced once it
+/// failed or was cancelled unfunded (or to redeploy a hibernated program),
+/// otherwise the new deployment needs a new nonce
 #[derive(Accounts)]
-#[instruction(program_hash: [u8; 32])]
+#[instruction(program_hash: [u8; 32], nonce: u32)]
 pub struct CreateDeployRequest<'info> {
   #[account(
         mut,
@@ -22,6 +31,12 @@ pub struct CreateDeployRequest<'info> {
     )]
   pub treasury_pool: Account<'info, TreasuryPool>,
 
+  #[account(
+        seeds = [PlanConfig::PREFIX_SEED],
+        bump = plan_config.bump
+    )]
+  pub plan_config: Account<'info, PlanConfig>,
+
   /// CHECK: Reward Pool PDA (program-owned, receives monthly fee: 1% of borrowed amount)
   #[account(
         mut,
@@ -42,7 +57,10 @@ pub struct CreateDeployRequest<'info> {
   /// We use UncheckedAccount to handle old layouts, then manually deserialize/resize
   #[account(
         mut,
-        seeds = [DeployRequest::PREFIX_SEED, program_hash.as_ref()],
+        seeds = [
+          DeployRequest::PREFIX_SEED,
+          DeployRequest::derive_request_id(&developer.key(), &program_hash, nonce).as_ref(),
+        ],
         bump
     )]
   pub deploy_request: UncheckedAccount<'info>,
@@ -62,25 +80,43 @@ pub struct CreateDeployRequest<'info> {
 
   #[account(
         mut,
-        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+        constraint = treasury_pool.has_role(Role::DeployerOperator, &admin.key()) @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
 
+  /// Optional promo code discounting the service fee
+  #[account(
+        mut,
+        seeds = [Coupon::PREFIX_SEED, coupon.code_hash.as_ref()],
+        bump = coupon.bump
+    )]
+  pub coupon: Option<Account<'info, Coupon>>,
+
   pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_deploy_request(
   ctx: Context<CreateDeployRequest>,
   program_hash: [u8; 32],
-  service_fee: u64,
-  monthly_fee: u64,
+  nonce: u32,
+  plan_id: u8,
+  program_size: u64,
   initial_months: u32,
   deployment_cost: u64,
+  terms_hash: [u8; 32],
+  coupon_code: Option<String>,
 ) -> Result<()> {
   let treasury_pool = &mut ctx.accounts.treasury_pool;
   let deploy_request_info = ctx.accounts.deploy_request.to_account_info();
   let user_stats = &mut ctx.accounts.user_stats;
   let current_time = Clock::get()?.unix_timestamp;
+  let request_id =
+    DeployRequest::derive_request_id(&ctx.accounts.developer.key(), &program_hash, nonce);
+
+  // Fees come from the referenced plan tier, not the caller
+  let tier = *ctx.accounts.plan_config.get_tier(plan_id)?;
+  let (service_fee, monthly_fee) = (tier.service_fee, tier.monthly_fee);
 
   // Handle deploy_request account (may have old layout)
   let program_id = ctx.program_id;
@@ -107,7 +143,7 @@ pub fn create_deploy_request(
     // This creates the account with correct size and assigns ownership in one step
     let deploy_request_seeds = &[
       DeployRequest::PREFIX_SEED,
-      program_hash.as_ref(),
+      request_id.as_ref(),
       &[ctx.bumps.deploy_request],
     ];
     let signer_seeds = &[&deploy_request_seeds[..]];
@@ -206,6 +242,35 @@ pub fn create_deploy_request(
           actual_rent_recovered: 0,
           recovery_ratio_bps: 0,
           debt_repaid_at: 0,
+          // Service fee refund fields (set below)
+          service_fee_refund_bps: 0,
+          service_fee_refund_window: 0,
+          service_fee_refunded: 0,
+          // Terms acceptance fields (set below)
+          accepted_terms_hash: [0u8; 32],
+          terms_accepted_at: 0,
+          total_funded: 0,
+          reported_spent: 0,
+          funding_variance: 0,
+          total_paid_by_developer: 0,
+          escrowed_payment: 0,
+          stablecoin_monthly_fee: 0,
+          max_months_per_renewal: 0,
+          expiry_at: 0,
+          plan_id: 0,
+          program_size: 0,
+          included_upgrades: 0,
+          hibernated_at: 0,
+          max_upgrades_per_month: 0,
+          upgrade_overage_fee: 0,
+          collateral_amount: 0,
+          last_expiry_warning_at: 0,
+          fees_paid: 0,
+          accrued_borrow_fees: 0,
+          borrow_fee_months_checkpointed: 0,
+          developer_extension_rent: 0,
+          subscription_paid_value: 0,
+          subscription_paid_from: 0,
         }
       }
     };
@@ -222,6 +287,23 @@ pub fn create_deploy_request(
   require!(monthly_fee > 0, ErrorCode::InvalidAmount);
   require!(initial_months > 0, ErrorCode::InvalidAmount);
   require!(deployment_cost > 0, ErrorCode::InvalidAmount);
+  treasury_pool.check_request_params(service_fee, monthly_fee, initial_months)?;
+
+  // An optional coupon waives part of the plan's service fee
+  let service_fee = apply_coupon(
+    ctx.accounts.coupon.as_mut(),
+    coupon_code.as_deref(),
+    request_id,
+    ctx.accounts.developer.key(),
+    service_fee,
+    current_time,
+  )?;
+
+  // Developer must have accepted the terms currently in force
+  require!(
+    terms_hash == treasury_pool.terms_hash,
+    ErrorCode::TermsHashMismatch
+  );
 
   // Note: Deployment cost funding will be handled by fund_temporary_wallet
   // We don't check pool balances here as funding comes from Admin/Reward Pool
@@ -246,22 +328,19 @@ pub fn create_deploy_request(
   // Payment structure:
   // - monthlyFee (1% monthly) + serviceFee → RewardPool
   // - deploymentPlatformFee (0.1% platform) → PlatformPool
-  let monthly_fee_total = monthly_fee
-    .checked_mul(initial_months as u64)
-    .ok_or(ErrorCode::CalculationOverflow)?;
+  let monthly_fee_total =
+    treasury_pool.calculate_subscription_payment(monthly_fee, initial_months)?;
   let reward_fee_amount = monthly_fee_total
     .checked_add(service_fee)
     .ok_or(ErrorCode::CalculationOverflow)?; // Monthly fee + service fee → RewardPool
-  let platform_fee_amount = deployment_cost
-    .checked_div(1000)
-    .ok_or(ErrorCode::CalculationOverflow)?; // 0.1% of deployment_cost → PlatformPool
+  let platform_fee_amount = TreasuryPool::calculate_deployment_platform_fee(deployment_cost); // 0.1% of deployment_cost → PlatformPool
   let total_payment = reward_fee_amount
     .checked_add(platform_fee_amount)
     .ok_or(ErrorCode::CalculationOverflow)?;
 
   // Initialize deploy request with PendingDeployment status
   if is_new_deploy_request {
-    deploy_request.request_id = program_hash;
+    deploy_request.request_id = request_id;
     deploy_request.developer = ctx.accounts.developer.key();
     deploy_request.program_hash = program_hash;
     deploy_request.created_at = current_time;
@@ -271,22 +350,15 @@ pub fn create_deploy_request(
     let developer_matches = deploy_request.developer == ctx.accounts.developer.key();
 
     if hash_matches && !developer_matches {
-      // Conflict handling (same as before)
-      let can_reset = matches!(
-        deploy_request.status,
-        DeployRequestStatus::Failed
-          | DeployRequestStatus::Cancelled
-          | DeployRequestStatus::Closed
-          | DeployRequestStatus::SubscriptionExpired
-          | DeployRequestStatus::Suspended
-      ) || (deploy_request.status == DeployRequestStatus::PendingDeployment
-        && deploy_request.ephemeral_key.is_none())
-        || (deploy_request.status == DeployRequestStatus::Active);
-
-      require!(can_reset, ErrorCode::InvalidRequestId);
+      // Nonce-0 ids are the bare hash, so another developer's request may sit
+      // here - it is only taken over once it ended without a deployment
+      require!(
+        deploy_request.check_retryable().is_ok(),
+        ErrorCode::InvalidRequestId
+      );
 
       // Reset the deploy_request for new developer
-      deploy_request.request_id = program_hash;
+      deploy_request.request_id = request_id;
       deploy_request.developer = ctx.accounts.developer.key();
       deploy_request.program_hash = program_hash;
       deploy_request.created_at = current_time;
@@ -295,42 +367,55 @@ pub fn create_deploy_request(
     } else if !hash_matches {
       require!(hash_matches, ErrorCode::InvalidRequestId);
     } else {
-      // Hash and developer match - allow update if status permits
-      // Allow retry for:
-      // 1. Failed/Cancelled/Closed deployments (obvious retry cases)
-      // 2. PendingDeployment without ephemeral_key (initial request, can retry)
-      // 3. PendingDeployment with ephemeral_key (deployment in progress but can retry if needed)
-      // 4. Active status (same developer/hash, can update subscription or retry deployment)
-      let can_retry = matches!(
-        deploy_request.status,
-        DeployRequestStatus::Failed
-          | DeployRequestStatus::Cancelled
-          | DeployRequestStatus::Closed
-          | DeployRequestStatus::PendingDeployment
-          | DeployRequestStatus::Active
-      ) || matches!(
-        deploy_request.status,
-        DeployRequestStatus::SubscriptionExpired | DeployRequestStatus::Suspended
-      );
-
-      require!(can_retry, ErrorCode::InvalidDeploymentStatus);
+      // Hash and developer match - a live or funded deployment is never
+      // overwritten (deploy again under a new nonce); only an unfunded
+      // failed/cancelled request is retried, or a hibernated one redeployed
+      if deploy_request.status != DeployRequestStatus::Hibernated {
+        deploy_request.check_retryable()?;
+      }
+      // The retried request gives back the quota slot it held
+      user_stats.release_slot(&deploy_request.status);
     }
   }
 
+  // A previous request on this PDA never reached confirmation - its held
+  // payment is released to stakers before the new payment is escrowed
+  let previous_escrow = deploy_request.take_escrowed_payment();
+  treasury_pool.release_escrowed_payment(FeeSource::DeployRequest, previous_escrow)?;
+
+  // Storage time left on a hibernated request carries into the redeployment
+  let hibernation_credit = if deploy_request.status == DeployRequestStatus::Hibernated {
+    deploy_request.take_hibernation_credit(current_time)?
+  } else {
+    0
+  };
+
+  deploy_request.apply_plan(&tier, program_size)?;
   deploy_request.service_fee = service_fee;
-  deploy_request.monthly_fee = monthly_fee;
   deploy_request.deployment_cost = deployment_cost;
   deploy_request.borrowed_amount = 0; // Will be set when temporary wallet is funded (equals deployment_cost)
-  deploy_request.subscription_paid_until =
-    current_time + (initial_months as i64 * 30 * 24 * 60 * 60);
+  deploy_request.start_subscription(initial_months, monthly_fee_total, current_time);
+  deploy_request.credit_subscription_value(hibernation_credit, monthly_fee)?;
   deploy_request.ephemeral_key = None; // Will be set when backend funds temporary wallet
   deploy_request.deployed_program_id = None; // Will be set after backend deploys
   deploy_request.status = DeployRequestStatus::PendingDeployment;
-
-  // Update user stats
-  user_stats.active_sessions += 1;
-  user_stats.daily_deploys += 1;
-  user_stats.total_deploys += 1;
+  deploy_request.set_pending_expiry(current_time);
+  deploy_request.set_service_fee_refund_schedule(
+    treasury_pool.service_fee_refund_bps,
+    treasury_pool.service_fee_refund_window,
+  );
+  deploy_request.accepted_terms_hash = terms_hash;
+  deploy_request.terms_accepted_at = current_time;
+  deploy_request.total_funded = 0;
+  deploy_request.reported_spent = 0;
+  deploy_request.funding_variance = 0;
+  // Payment tracking restarts with this request's fees (paid to the reward pool)
+  deploy_request.total_paid_by_developer = 0;
+  deploy_request.record_developer_payment(reward_fee_amount)?;
+
+  // Update user stats, enforcing the per-developer deployment quota
+  user_stats.check_quota(treasury_pool.max_active_deployments_per_developer)?;
+  user_stats.record_request(total_payment)?;
 
   // IMPORTANT: Credit fees to pools
   // Note: Payment has already been transferred to pools by developer (off-chain):
@@ -338,29 +423,21 @@ pub fn create_deploy_request(
   // - deploymentPlatformFee (0.1% platform) → PlatformPool
   // We just need to update the state to track the balances
 
-  // Credit fees to respective pools
-  treasury_pool.credit_reward_pool(reward_fee_amount as u128)?;
-  treasury_pool.credit_platform_pool(platform_fee_amount as u128)?;
-
-  // Update reward_per_share if there are deposits
-  if treasury_pool.total_deposited > 0 {
-    // Only update reward_per_share for reward fees (not platform fees)
-    let reward_per_share_increment = (reward_fee_amount as u128)
-      .checked_mul(TreasuryPool::PRECISION)
-      .and_then(|x| x.checked_div(treasury_pool.total_deposited as u128))
-      .ok_or(ErrorCode::CalculationOverflow)?;
-    treasury_pool.reward_per_share = treasury_pool
-      .reward_per_share
-      .checked_add(reward_per_share_increment)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-  }
+  // Platform fee is credited now; the reward part (service fee + subscription)
+  // is held in escrow until confirm_deployment_success credits it to stakers
+  treasury_pool.collect_fee(FeeSource::DeployRequest, 0, platform_fee_amount)?;
+  treasury_pool.hold_developer_payment(reward_fee_amount)?;
+  deploy_request.escrowed_payment = reward_fee_amount;
 
   // Verify pools have received the payments
   // This is a safety check - the actual transfers happened off-chain
   let reward_pool_lamports = ctx.accounts.reward_pool.lamports();
   let platform_pool_lamports = ctx.accounts.platform_pool.lamports();
   require!(
-    reward_pool_lamports >= treasury_pool.reward_pool_balance,
+    reward_pool_lamports
+      >= treasury_pool
+        .reward_pool_balance
+        .saturating_add(treasury_pool.total_escrowed_payments),
     ErrorCode::InsufficientTreasuryFunds
   );
   require!(
@@ -375,13 +452,22 @@ pub fn create_deploy_request(
     request_id: deploy_request.request_id,
     developer: deploy_request.developer,
     program_hash: deploy_request.program_hash,
+    plan_id,
     service_fee,
     monthly_fee,
     initial_months,
     deployment_cost,
     total_payment,
+    platform_fee: platform_fee_amount,
     requested_at: current_time,
   });
 
+  emit!(TermsAccepted {
+    request_id: deploy_request.request_id,
+    developer: deploy_request.developer,
+    terms_hash,
+    accepted_at: current_time,
+  });
+
   Ok(())
 }
@@ -1,6 +1,10 @@
 use anchor_lang::{prelude::*, system_program};
 
-use crate::{errors::ErrorCode, events::RewardCredited, states::TreasuryPool};
+use crate::{
+  errors::ErrorCode,
+  events::RewardCredited,
+  states::{FeeSource, Role, TreasuryPool},
+};
 
 /// Credit fees to pools (developer pays fees)
 ///
@@ -35,7 +39,7 @@ pub struct CreditFeeToPool<'info> {
 
   /// Admin signer to authorize the fee credit operation
   #[account(
-        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+        constraint = treasury_pool.has_role(Role::Treasurer, &admin.key()) @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
 
@@ -51,7 +55,7 @@ pub struct CreditFeeToPool<'info> {
 /// SECURITY FIX Flow:
 /// 1. Developer (fee_payer) transfers fees to RewardPool and PlatformPool PDAs
 /// 2. Admin authorizes the fee credit operation
-/// 3. Call treasury_pool.credit_fee_to_pool() which updates reward_per_share
+/// 3. Call treasury_pool.collect_fee() which updates reward_per_share
 ///
 /// IMPORTANT: Developer (fee_payer) pays the fees, NOT admin
 pub fn credit_fee_to_pool(
@@ -101,7 +105,7 @@ pub fn credit_fee_to_pool(
 
   // Credit fees to pools and update reward_per_share
   // This is the key function that updates the accumulator
-  treasury_pool.credit_fee_to_pool(fee_reward, fee_platform)?;
+  treasury_pool.collect_fee(FeeSource::AdminCredit, fee_reward, fee_platform)?;
 
   emit!(RewardCredited {
     fee_reward,
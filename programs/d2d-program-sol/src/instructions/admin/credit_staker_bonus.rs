@@ -0,0 +1,109 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::StakerBonusCredited,
+  states::{BackerDeposit, TreasuryPool},
+};
+
+/// Credit a bonus to a single staker (promotions, manual make-goods)
+///
+/// The admin funds the reward pool and the amount is added to the staker's
+/// pending_rewards, so it is paid through the normal claim path. Unlike
+/// credit_fee_to_pool, reward_per_share is not touched.
+#[derive(Accounts)]
+#[instruction(staker: Pubkey)]
+pub struct CreditStakerBonus<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Reward Pool PDA (receives the bonus)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, staker.as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn credit_staker_bonus(
+  ctx: Context<CreditStakerBonus>,
+  staker: Pubkey,
+  amount: u64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+
+  treasury_pool.check_not_council_governed()?;
+  fund_staker_bonus(
+    treasury_pool,
+    lender_stake,
+    &ctx.accounts.admin.to_account_info(),
+    &ctx.accounts.reward_pool.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    amount,
+  )?;
+
+  emit!(StakerBonusCredited {
+    admin: ctx.accounts.admin.key(),
+    staker,
+    amount,
+    pending_rewards: lender_stake.pending_rewards,
+    total_bonus_credited: lender_stake.total_bonus_credited,
+    credited_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+/// Move `amount` from `funder` to the reward pool and owe it to one staker
+/// (shared with the council's CreditStakerBonus)
+pub(crate) fn fund_staker_bonus<'info>(
+  treasury_pool: &mut TreasuryPool,
+  lender_stake: &mut BackerDeposit,
+  funder: &AccountInfo<'info>,
+  reward_pool: &AccountInfo<'info>,
+  system_program_info: &AccountInfo<'info>,
+  amount: u64,
+) -> Result<()> {
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(amount > 0, ErrorCode::InvalidAmount);
+  require!(
+    amount <= TreasuryPool::MAX_AMOUNT as u64,
+    ErrorCode::FeeAmountTooLarge
+  );
+
+  system_program::transfer(
+    CpiContext::new(
+      system_program_info.clone(),
+      system_program::Transfer {
+        from: funder.clone(),
+        to: reward_pool.clone(),
+      },
+    ),
+    amount,
+  )?;
+
+  // Protected as credited rewards, but owed to this staker only
+  treasury_pool.credit_targeted_rewards(amount)?;
+  lender_stake.credit_bonus(amount)
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::DecentralizationMilestone, states::TreasuryPool};
+
+/// One-way switch retiring the admin-only paths
+///
+/// Requires the admin council, so the remaining admin setters and
+/// initiate/execute_withdrawal already run only through council proposals.
+/// After this call reinitialize_treasury_pool, close_treasury_pool,
+/// force_reset_deployment, force_rebalance, sync_liquid_balance,
+/// redeem_lst_vault and the instant admin_withdraw* instructions fail for
+/// good. fund_temporary_wallet stays open to the deployer operator role only,
+/// once per request and within the request's rent limit. Pool funds can then
+/// only leave through initiate/execute_withdrawal, which the guardian can
+/// veto during the timelock; the timelock can no longer be shortened and the
+/// guardian can no longer be removed. Lenders can check `decentralized_at`
+/// before depositing.
+#[derive(Accounts)]
+pub struct Decentralize<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn decentralize(ctx: Context<Decentralize>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    !treasury_pool.is_decentralized(),
+    ErrorCode::AlreadyDecentralized
+  );
+  // Without a guardian nobody could veto a timelocked withdrawal
+  require!(treasury_pool.has_guardian(), ErrorCode::GuardianNotSet);
+  // Without the council the admin setters would stay single-key
+  require!(
+    treasury_pool.admin_council_enabled,
+    ErrorCode::AdminCouncilRequired
+  );
+
+  let decentralized_at = Clock::get()?.unix_timestamp;
+  treasury_pool.decentralized_at = decentralized_at;
+
+  emit!(DecentralizationMilestone {
+    admin: ctx.accounts.admin.key(),
+    guardian: treasury_pool.guardian,
+    timelock_duration: treasury_pool.timelock_duration,
+    decentralized_at,
+  });
+
+  Ok(())
+}
@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::ErrorCode, events::PendingRewardsDistributed, states::TreasuryPool};
+use crate::{
+  errors::ErrorCode,
+  events::PendingRewardsDistributed,
+  states::{Role, TreasuryPool},
+};
 
 /// Distribute accumulated pending rewards to stakers
 /// This instruction gradually releases rewards that were accumulated
 /// (e.g., from first-depositor protection or excess rent recovery)
 ///
-/// Called periodically by admin/cron to ensure fair reward distribution
+/// Called periodically by the admin, guardian or treasurer to ensure fair
+/// reward distribution
 #[derive(Accounts)]
 pub struct DistributePendingRewards<'info> {
   #[account(
@@ -17,7 +22,8 @@ pub struct DistributePendingRewards<'info> {
   pub treasury_pool: Account<'info, TreasuryPool>,
 
   #[account(
-        constraint = treasury_pool.is_admin_or_guardian(&caller.key()) @ ErrorCode::Unauthorized
+        constraint = treasury_pool.is_admin_or_guardian(&caller.key())
+            || treasury_pool.has_role(Role::Treasurer, &caller.key()) @ ErrorCode::Unauthorized
     )]
   pub caller: Signer<'info>,
 }
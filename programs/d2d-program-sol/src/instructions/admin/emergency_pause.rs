@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::ErrorCode, events::EmergencyPauseToggled, states::TreasuryPool};
+use crate::{
+  errors::ErrorCode,
+  events::{EmergencyPauseToggled, UnpauseVetoed},
+  states::{Role, TreasuryPool},
+};
 
 #[derive(Accounts)]
 pub struct EmergencyPause<'info> {
@@ -14,19 +18,40 @@ pub struct EmergencyPause<'info> {
   pub admin: Signer<'info>,
 }
 
+/// Pause, or confirm the program is unpaused
+///
+/// Resuming a paused program goes through schedule_unpause/execute_unpause.
+/// Pausing again vetoes a scheduled unpause.
 pub fn emergency_pause(ctx: Context<EmergencyPause>, pause: bool) -> Result<()> {
   let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let current_time = Clock::get()?.unix_timestamp;
 
+  // The pauser may only pause
+  let caller = ctx.accounts.admin.key();
   require!(
-    ctx.accounts.admin.key() == treasury_pool.admin,
+    treasury_pool.is_admin(&caller) || (pause && treasury_pool.has_role(Role::Pauser, &caller)),
     ErrorCode::Unauthorized
   );
 
+  require!(
+    pause || !treasury_pool.emergency_pause,
+    ErrorCode::UnpauseRequiresSchedule
+  );
+
+  let scheduled_at = treasury_pool.unpause_scheduled_at;
+  if pause && treasury_pool.cancel_scheduled_unpause() {
+    emit!(UnpauseVetoed {
+      vetoed_by: caller,
+      scheduled_at,
+      vetoed_at: current_time,
+    });
+  }
+
   treasury_pool.emergency_pause = pause;
 
   emit!(EmergencyPauseToggled {
     paused: pause,
-    toggled_at: Clock::get()?.unix_timestamp,
+    toggled_at: current_time,
   });
 
   Ok(())
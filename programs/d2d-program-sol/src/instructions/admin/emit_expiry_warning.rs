@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::SubscriptionExpiring,
+  states::{DeployRequest, DeployRequestStatus, TreasuryPool},
+};
+
+/// Permissionless crank: warn that a subscription is about to expire
+///
+/// Fires once per `expiry_warning_interval` while an active subscription is
+/// within the pool's `expiry_warning_window` of expiry, giving indexers and
+/// notification services an on-chain trigger. Only the warning timestamp on
+/// the request is modified.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct EmitExpiryWarning<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+}
+
+pub fn emit_expiry_warning(ctx: Context<EmitExpiryWarning>, request_id: [u8; 32]) -> Result<()> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    deploy_request.status == DeployRequestStatus::Active,
+    ErrorCode::InvalidRequestStatus
+  );
+  require!(
+    treasury_pool.is_expiry_warning_due(
+      deploy_request.subscription_paid_until,
+      deploy_request.last_expiry_warning_at,
+      current_time,
+    ),
+    ErrorCode::ExpiryWarningNotDue
+  );
+
+  deploy_request.last_expiry_warning_at = current_time;
+
+  emit!(SubscriptionExpiring {
+    request_id,
+    developer: deploy_request.developer,
+    subscription_paid_until: deploy_request.subscription_paid_until,
+    seconds_remaining: deploy_request
+      .subscription_paid_until
+      .saturating_sub(current_time),
+    auto_renewal_enabled: deploy_request.auto_renewal_enabled,
+    warned_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,787 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+  errors::ErrorCode,
+  events::{
+    AdminWithdrew, AuthorityExitFeeChanged, CouncilProposalExecuted, CouponChanged,
+    DailyLimitChanged, DepositFeeModeChanged, EmergencyExitPolicyChanged, EmergencyHaircutChanged,
+    GuardianSet, InsurancePolicyChanged, KeeperTipChanged, LstConfigChanged,
+    MaxBufferRentLoanChanged, PlanTierChanged, PlanUpgradePricingChanged, PrepayDiscountsChanged,
+    RoleAssigned, ServiceFeeRefundPolicyChanged, StablecoinMonthlyFeeSet, StakerBonusCredited,
+    SubscriptionSplitProposed, SubscriptionStablecoinSet, TimelockDurationChanged,
+    WatchtowerBountyChanged, WithdrawalExecuted, WithdrawalInitiated,
+  },
+  instructions::{
+    fund_staker_bonus, open_pending_withdrawal, pay_pending_withdrawal, reset_deployment,
+    schedule_subscription_split, withdraw_excess_rewards, withdraw_platform_funds, write_coupon,
+    write_emergency_exit_policy, write_guardian, write_insurance_policy, write_lst_config,
+    write_max_buffer_rent_loan, write_plan_tier, write_service_fee_refund_policy,
+    write_timelock_duration, write_watchtower_bounty,
+  },
+  states::{
+    AdminCouncil, BackerDeposit, CouncilAction, CouncilProposal, Coupon, DeployRequest,
+    InsuranceFund, LstConfig, PendingWithdrawal, PlanConfig, TreasuryPool, UserDeployStats,
+  },
+};
+
+/// Execute an approved council proposal
+///
+/// Action-specific accounts:
+/// - AdminWithdraw: platform_pool and destination
+/// - AdminWithdrawRewardPool: reward_pool and destination
+/// - ForceResetDeployment: deploy_request and the developer's user_stats
+/// - SetPlanTier / SetPlanUpgradePricing: plan_config, SetCoupon: coupon
+///   (created if needed, the executor pays the rent)
+/// - InitiateWithdrawal: pending_withdrawal (created, the executor pays the
+///   rent); ExecuteWithdrawal: pending_withdrawal, platform_pool,
+///   reward_pool and destination (closed to the executor)
+/// - SetStablecoinMonthlyFee: deploy_request, SetInsurancePolicy:
+///   insurance_fund, CreditStakerBonus: reward_pool and the staker's primary
+///   lender_stake (the executor funds the bonus)
+/// - SetLstConfig: token_mint, lst_config and lst_vault;
+///   SetSubscriptionStablecoin: token_mint and stablecoin_vault (created if
+///   needed, with token_program)
+///
+/// Any current member may execute; the proposal is closed to its proposer.
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteCouncilProposal<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [AdminCouncil::PREFIX_SEED],
+        bump = admin_council.bump
+    )]
+  pub admin_council: Account<'info, AdminCouncil>,
+
+  #[account(
+        mut,
+        seeds = [CouncilProposal::PREFIX_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = council_proposal.bump,
+        close = proposer
+    )]
+  pub council_proposal: Account<'info, CouncilProposal>,
+
+  /// CHECK: Receives the proposal rent back
+  #[account(mut, address = council_proposal.proposer @ ErrorCode::Unauthorized)]
+  pub proposer: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        constraint = admin_council.is_member(&executor.key()) @ ErrorCode::NotCouncilMember
+    )]
+  pub executor: Signer<'info>,
+
+  /// CHECK: Platform Pool PDA (AdminWithdraw only)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: Option<UncheckedAccount<'info>>,
+
+  /// CHECK: Withdrawal destination, must match the proposal (AdminWithdraw only)
+  #[account(mut)]
+  pub destination: Option<UncheckedAccount<'info>>,
+
+  /// Request to reset (ForceResetDeployment only)
+  #[account(mut)]
+  pub deploy_request: Option<Account<'info, DeployRequest>>,
+
+  /// Developer's deployment stats (ForceResetDeployment only)
+  #[account(mut)]
+  pub user_stats: Option<Account<'info, UserDeployStats>>,
+
+  /// CHECK: Reward Pool PDA (AdminWithdrawRewardPool only)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: Option<UncheckedAccount<'info>>,
+
+  /// Plan catalogue (SetPlanTier only)
+  #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + PlanConfig::INIT_SPACE,
+        seeds = [PlanConfig::PREFIX_SEED],
+        bump
+    )]
+  pub plan_config: Option<Account<'info, PlanConfig>>,
+
+  /// Coupon of the proposal's code hash (SetCoupon only)
+  #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + Coupon::INIT_SPACE,
+        seeds = [Coupon::PREFIX_SEED, council_proposal.action.coupon_code_hash().as_ref()],
+        bump
+    )]
+  pub coupon: Option<Account<'info, Coupon>>,
+
+  /// Timelocked withdrawal (InitiateWithdrawal / ExecuteWithdrawal only)
+  #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + PendingWithdrawal::INIT_SPACE,
+        seeds = [PendingWithdrawal::PREFIX_SEED, treasury_pool.key().as_ref()],
+        bump
+    )]
+  pub pending_withdrawal: Option<Account<'info, PendingWithdrawal>>,
+
+  /// Insurance fund (SetInsurancePolicy only)
+  #[account(
+        mut,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump = insurance_fund.bump
+    )]
+  pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+  /// Staker's primary position (CreditStakerBonus only)
+  #[account(mut)]
+  pub lender_stake: Option<Account<'info, BackerDeposit>>,
+
+  /// Mint the proposal configures (SetLstConfig / SetSubscriptionStablecoin only)
+  #[account(address = council_proposal.action.token_mint() @ ErrorCode::InvalidAccountData)]
+  pub token_mint: Option<Account<'info, Mint>>,
+
+  /// LST config of token_mint (SetLstConfig only)
+  #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + LstConfig::INIT_SPACE,
+        seeds = [LstConfig::PREFIX_SEED, council_proposal.action.token_mint().as_ref()],
+        bump
+    )]
+  pub lst_config: Option<Account<'info, LstConfig>>,
+
+  /// LST vault of token_mint (SetLstConfig only)
+  #[account(
+        init_if_needed,
+        payer = executor,
+        seeds = [LstConfig::VAULT_SEED, council_proposal.action.token_mint().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = lst_config
+    )]
+  pub lst_vault: Option<Account<'info, TokenAccount>>,
+
+  /// Stablecoin vault of token_mint (SetSubscriptionStablecoin only)
+  #[account(
+        init_if_needed,
+        payer = executor,
+        seeds = [TreasuryPool::STABLECOIN_VAULT_SEED, council_proposal.action.token_mint().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = treasury_pool
+    )]
+  pub stablecoin_vault: Option<Account<'info, TokenAccount>>,
+
+  /// Token program (SetLstConfig / SetSubscriptionStablecoin only)
+  pub token_program: Option<Program<'info, Token>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn execute_council_proposal(
+  ctx: Context<ExecuteCouncilProposal>,
+  proposal_id: u64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let admin_council = &mut ctx.accounts.admin_council;
+  let council_proposal = &ctx.accounts.council_proposal;
+  let council = admin_council.key();
+  let current_time = Clock::get()?.unix_timestamp;
+
+  council_proposal.check_open(admin_council, current_time)?;
+  require!(
+    council_proposal.is_approved(admin_council),
+    ErrorCode::CouncilThresholdNotMet
+  );
+
+  let action = council_proposal.action.clone();
+  match &action {
+    CouncilAction::AdminWithdraw {
+      amount,
+      destination,
+    } => {
+      let platform_pool = ctx
+        .accounts
+        .platform_pool
+        .as_ref()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      let destination_info = ctx
+        .accounts
+        .destination
+        .as_ref()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      require!(
+        destination_info.key() == *destination,
+        ErrorCode::InvalidAccountData
+      );
+      withdraw_platform_funds(
+        treasury_pool,
+        &platform_pool.to_account_info(),
+        &destination_info.to_account_info(),
+        *amount,
+      )?;
+      emit!(AdminWithdrew {
+        admin: council,
+        amount: *amount,
+        destination: *destination,
+        reason: format!("council proposal {}", proposal_id),
+        withdrawn_at: current_time,
+      });
+    }
+    CouncilAction::SetDailyLimit { new_limit } => {
+      let old_limit = treasury_pool.daily_withdrawal_limit;
+      treasury_pool.daily_withdrawal_limit = *new_limit;
+      emit!(DailyLimitChanged {
+        admin: council,
+        old_limit,
+        new_limit: *new_limit,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::ForceResetDeployment { request_id } => {
+      require!(
+        !treasury_pool.is_decentralized(),
+        ErrorCode::AdminPathRetired
+      );
+      let deploy_request = ctx
+        .accounts
+        .deploy_request
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      require!(
+        deploy_request.request_id == *request_id,
+        ErrorCode::InvalidRequestId
+      );
+      let user_stats = ctx
+        .accounts
+        .user_stats
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      require!(
+        user_stats.user == deploy_request.developer,
+        ErrorCode::Unauthorized
+      );
+      reset_deployment(deploy_request, user_stats, "Force reset by admin council")?;
+    }
+    CouncilAction::SetKeeperTip { new_tip_bps } => {
+      require!(
+        *new_tip_bps <= TreasuryPool::MAX_KEEPER_TIP_BPS,
+        ErrorCode::InvalidKeeperTip
+      );
+      let old_tip_bps = treasury_pool.keeper_tip_bps;
+      treasury_pool.keeper_tip_bps = *new_tip_bps;
+      emit!(KeeperTipChanged {
+        admin: council,
+        old_tip_bps,
+        new_tip_bps: *new_tip_bps,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetAuthorityExitFee { new_fee_bps } => {
+      require!(
+        *new_fee_bps <= TreasuryPool::MAX_AUTHORITY_EXIT_FEE_BPS,
+        ErrorCode::InvalidAuthorityExitFee
+      );
+      let old_fee_bps = treasury_pool.authority_exit_fee_bps;
+      treasury_pool.authority_exit_fee_bps = *new_fee_bps;
+      emit!(AuthorityExitFeeChanged {
+        admin: council,
+        old_fee_bps,
+        new_fee_bps: *new_fee_bps,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetEmergencyHaircut { haircut_bps } => {
+      require!(
+        *haircut_bps <= TreasuryPool::MAX_EMERGENCY_HAIRCUT_BPS,
+        ErrorCode::InvalidEmergencyHaircut
+      );
+      let old_haircut_bps = treasury_pool.emergency_haircut_bps;
+      treasury_pool.emergency_haircut_bps = *haircut_bps;
+      emit!(EmergencyHaircutChanged {
+        admin: council,
+        old_haircut_bps,
+        new_haircut_bps: *haircut_bps,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetCouncil {
+      members,
+      member_count,
+      threshold,
+    } => {
+      require!(
+        *member_count as usize <= AdminCouncil::MAX_MEMBERS,
+        ErrorCode::InvalidCouncilConfig
+      );
+      admin_council.set_members(&members[..*member_count as usize], *threshold)?;
+      // Open proposals were approved by the old member set
+      admin_council.epoch = admin_council.epoch.saturating_add(1);
+    }
+    CouncilAction::AdminWithdrawRewardPool {
+      amount,
+      destination,
+    } => {
+      let reward_pool = ctx
+        .accounts
+        .reward_pool
+        .as_ref()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      let destination_info = ctx
+        .accounts
+        .destination
+        .as_ref()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      require!(
+        destination_info.key() == *destination,
+        ErrorCode::InvalidAccountData
+      );
+      withdraw_excess_rewards(
+        treasury_pool,
+        &reward_pool.to_account_info(),
+        &destination_info.to_account_info(),
+        *amount,
+      )?;
+      emit!(AdminWithdrew {
+        admin: council,
+        amount: *amount,
+        destination: *destination,
+        reason: format!("council proposal {}", proposal_id),
+        withdrawn_at: current_time,
+      });
+    }
+    CouncilAction::SetRole { role, new_holder } => {
+      require!(
+        *new_holder != treasury_pool.admin,
+        ErrorCode::InvalidRoleHolder
+      );
+      let old_holder = treasury_pool.role_holder(*role);
+      treasury_pool.set_role_holder(*role, *new_holder);
+      emit!(RoleAssigned {
+        admin: council,
+        role: *role,
+        old_holder,
+        new_holder: *new_holder,
+        assigned_at: current_time,
+      });
+    }
+    CouncilAction::SetPrepayDiscounts { steps } => {
+      let steps = CouncilAction::active_prepay_steps(steps);
+      treasury_pool.set_prepay_discounts(steps)?;
+      emit!(PrepayDiscountsChanged {
+        admin: council,
+        steps: steps.to_vec(),
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetPlanTier {
+      plan_id,
+      max_program_size,
+      service_fee,
+      monthly_fee,
+      included_upgrades,
+      enabled,
+    } => {
+      let plan_config = ctx
+        .accounts
+        .plan_config
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      write_plan_tier(
+        plan_config,
+        *plan_id,
+        *max_program_size,
+        *service_fee,
+        *monthly_fee,
+        *included_upgrades,
+        *enabled,
+      )?;
+      plan_config.updated_at = current_time;
+      plan_config.bump = ctx.bumps.plan_config.ok_or(ErrorCode::InvalidAccountData)?;
+      emit!(PlanTierChanged {
+        admin: council,
+        plan_id: *plan_id,
+        max_program_size: *max_program_size,
+        service_fee: *service_fee,
+        monthly_fee: *monthly_fee,
+        included_upgrades: *included_upgrades,
+        enabled: *enabled,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetCoupon {
+      code_hash,
+      discount_bps,
+      expires_at,
+      max_uses,
+      is_active,
+    } => {
+      let coupon = ctx
+        .accounts
+        .coupon
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      write_coupon(
+        coupon,
+        *code_hash,
+        *discount_bps,
+        *expires_at,
+        *max_uses,
+        *is_active,
+        current_time,
+      )?;
+      coupon.bump = ctx.bumps.coupon.ok_or(ErrorCode::InvalidAccountData)?;
+      emit!(CouponChanged {
+        admin: council,
+        code_hash: *code_hash,
+        discount_bps: *discount_bps,
+        expires_at: *expires_at,
+        max_uses: *max_uses,
+        is_active: *is_active,
+        uses: coupon.uses,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::ProposeSubscriptionSplit { platform_bps } => {
+      let effective_at = schedule_subscription_split(treasury_pool, *platform_bps, current_time)?;
+      emit!(SubscriptionSplitProposed {
+        admin: council,
+        current_platform_bps: treasury_pool.subscription_platform_bps,
+        proposed_platform_bps: *platform_bps,
+        effective_at,
+        proposed_at: current_time,
+      });
+    }
+    CouncilAction::InitiateWithdrawal {
+      withdrawal_type,
+      amount,
+      destination,
+    } => {
+      let pending_withdrawal = ctx
+        .accounts
+        .pending_withdrawal
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      let reason = format!("council proposal {}", proposal_id);
+      open_pending_withdrawal(
+        treasury_pool,
+        pending_withdrawal,
+        withdrawal_type.clone(),
+        *amount,
+        *destination,
+        council,
+        reason.clone(),
+        current_time,
+      )?;
+      pending_withdrawal.bump = ctx
+        .bumps
+        .pending_withdrawal
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      emit!(WithdrawalInitiated {
+        initiator: council,
+        withdrawal_type: withdrawal_type.label().to_string(),
+        amount: *amount,
+        destination: *destination,
+        execute_after: pending_withdrawal.execute_after,
+        expires_at: pending_withdrawal.expires_at,
+        reason,
+        initiated_at: current_time,
+      });
+    }
+    CouncilAction::ExecuteWithdrawal => {
+      let pending_withdrawal = ctx
+        .accounts
+        .pending_withdrawal
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      let platform_pool = ctx
+        .accounts
+        .platform_pool
+        .as_ref()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      let reward_pool = ctx
+        .accounts
+        .reward_pool
+        .as_ref()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      let destination_info = ctx
+        .accounts
+        .destination
+        .as_ref()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      pay_pending_withdrawal(
+        treasury_pool,
+        pending_withdrawal,
+        &platform_pool.to_account_info(),
+        &reward_pool.to_account_info(),
+        &destination_info.to_account_info(),
+        current_time,
+      )?;
+      emit!(WithdrawalExecuted {
+        executor: ctx.accounts.executor.key(),
+        withdrawal_type: pending_withdrawal.withdrawal_type.label().to_string(),
+        amount: pending_withdrawal.amount,
+        destination: pending_withdrawal.destination,
+        executed_at: current_time,
+      });
+      pending_withdrawal.close(ctx.accounts.executor.to_account_info())?;
+    }
+    CouncilAction::SetTimelockDuration { new_duration } => {
+      let old_duration = write_timelock_duration(treasury_pool, *new_duration)?;
+      emit!(TimelockDurationChanged {
+        admin: council,
+        old_duration,
+        new_duration: *new_duration,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetGuardian { new_guardian } => {
+      let old_guardian = write_guardian(treasury_pool, *new_guardian)?;
+      emit!(GuardianSet {
+        admin: council,
+        old_guardian,
+        new_guardian: *new_guardian,
+        set_at: current_time,
+      });
+    }
+    CouncilAction::SetDepositFeeMode { enabled } => {
+      treasury_pool.deposit_fees_enabled = *enabled;
+      emit!(DepositFeeModeChanged {
+        admin: council,
+        deposit_fees_enabled: *enabled,
+        reward_fee_bps: TreasuryPool::REWARD_FEE_BPS,
+        platform_fee_bps: TreasuryPool::PLATFORM_FEE_BPS,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetStablecoinMonthlyFee {
+      request_id,
+      new_fee,
+    } => {
+      let deploy_request = ctx
+        .accounts
+        .deploy_request
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      require!(
+        deploy_request.request_id == *request_id,
+        ErrorCode::InvalidRequestId
+      );
+      let old_fee = deploy_request.stablecoin_monthly_fee;
+      deploy_request.stablecoin_monthly_fee = *new_fee;
+      emit!(StablecoinMonthlyFeeSet {
+        request_id: *request_id,
+        admin: council,
+        old_fee,
+        new_fee: *new_fee,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetPlanUpgradePricing {
+      plan_id,
+      max_upgrades_per_month,
+      upgrade_overage_fee,
+    } => {
+      let plan_config = ctx
+        .accounts
+        .plan_config
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      plan_config.set_upgrade_pricing(*plan_id, *max_upgrades_per_month, *upgrade_overage_fee)?;
+      plan_config.updated_at = current_time;
+      emit!(PlanUpgradePricingChanged {
+        admin: council,
+        plan_id: *plan_id,
+        max_upgrades_per_month: *max_upgrades_per_month,
+        upgrade_overage_fee: *upgrade_overage_fee,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetServiceFeeRefundPolicy {
+      refund_bps,
+      refund_window,
+    } => {
+      let (old_refund_bps, old_refund_window) =
+        write_service_fee_refund_policy(treasury_pool, *refund_bps, *refund_window)?;
+      emit!(ServiceFeeRefundPolicyChanged {
+        admin: council,
+        old_refund_bps,
+        new_refund_bps: *refund_bps,
+        old_refund_window,
+        new_refund_window: *refund_window,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetInsurancePolicy {
+      premium_bps,
+      loss_threshold_bps,
+    } => {
+      let insurance_fund = ctx
+        .accounts
+        .insurance_fund
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      let (old_premium_bps, old_loss_threshold_bps) =
+        write_insurance_policy(insurance_fund, *premium_bps, *loss_threshold_bps)?;
+      emit!(InsurancePolicyChanged {
+        admin: council,
+        old_premium_bps,
+        new_premium_bps: *premium_bps,
+        old_loss_threshold_bps,
+        new_loss_threshold_bps: *loss_threshold_bps,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetWatchtowerBounty {
+      new_bounty,
+      new_cooldown,
+    } => {
+      let (old_bounty, old_cooldown) =
+        write_watchtower_bounty(treasury_pool, *new_bounty, *new_cooldown)?;
+      emit!(WatchtowerBountyChanged {
+        admin: council,
+        old_bounty,
+        new_bounty: *new_bounty,
+        old_cooldown,
+        new_cooldown: *new_cooldown,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetEmergencyExitPolicy {
+      new_policy,
+      queue_jump_fee_bps,
+    } => {
+      let old_policy =
+        write_emergency_exit_policy(treasury_pool, *new_policy, *queue_jump_fee_bps)?;
+      emit!(EmergencyExitPolicyChanged {
+        admin: council,
+        old_policy,
+        new_policy: *new_policy,
+        queue_jump_fee_bps: *queue_jump_fee_bps,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetLstConfig {
+      mint,
+      rate,
+      max_rate_age,
+      enabled,
+    } => {
+      let lst_vault = ctx
+        .accounts
+        .lst_vault
+        .as_ref()
+        .ok_or(ErrorCode::InvalidAccountData)?
+        .key();
+      let lst_config = ctx
+        .accounts
+        .lst_config
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      write_lst_config(
+        treasury_pool,
+        lst_config,
+        *mint,
+        lst_vault,
+        *rate,
+        *max_rate_age,
+        *enabled,
+        current_time,
+      )?;
+      lst_config.bump = ctx.bumps.lst_config.ok_or(ErrorCode::InvalidAccountData)?;
+      emit!(LstConfigChanged {
+        admin: council,
+        mint: *mint,
+        rate: *rate,
+        max_rate_age: *max_rate_age,
+        enabled: *enabled,
+        normalized_value: lst_config.normalized_value,
+        normalized_token_value: treasury_pool.normalized_token_value,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::SetSubscriptionStablecoin { mint } => {
+      let stablecoin_vault = ctx
+        .accounts
+        .stablecoin_vault
+        .as_ref()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      treasury_pool.subscription_stablecoin_mint = *mint;
+      treasury_pool.subscription_stablecoin_vault = stablecoin_vault.key();
+      emit!(SubscriptionStablecoinSet {
+        admin: council,
+        mint: *mint,
+        vault: treasury_pool.subscription_stablecoin_vault,
+        changed_at: current_time,
+      });
+    }
+    CouncilAction::CreditStakerBonus { staker, amount } => {
+      let reward_pool = ctx
+        .accounts
+        .reward_pool
+        .as_ref()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      let lender_stake = ctx
+        .accounts
+        .lender_stake
+        .as_mut()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      require!(
+        lender_stake.backer == *staker
+          && lender_stake.position_index == 0
+          && lender_stake.is_position_address(&lender_stake.key(), ctx.program_id),
+        ErrorCode::InvalidAccountData
+      );
+      fund_staker_bonus(
+        treasury_pool,
+        lender_stake,
+        &ctx.accounts.executor.to_account_info(),
+        &reward_pool.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        *amount,
+      )?;
+      emit!(StakerBonusCredited {
+        admin: council,
+        staker: *staker,
+        amount: *amount,
+        pending_rewards: lender_stake.pending_rewards,
+        total_bonus_credited: lender_stake.total_bonus_credited,
+        credited_at: current_time,
+      });
+    }
+    CouncilAction::SetMaxBufferRentLoan {
+      max_buffer_rent_loan,
+    } => {
+      let old_max = write_max_buffer_rent_loan(treasury_pool, *max_buffer_rent_loan)?;
+      emit!(MaxBufferRentLoanChanged {
+        admin: council,
+        old_max,
+        new_max: *max_buffer_rent_loan,
+        changed_at: current_time,
+      });
+    }
+  }
+
+  emit!(CouncilProposalExecuted {
+    proposal_id,
+    executor: ctx.accounts.executor.key(),
+    action,
+    approval_count: council_proposal.approval_count,
+    executed_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  events::{EmergencyPauseToggled, UnpauseExecuted},
+  states::TreasuryPool,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteUnpause<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// Anyone may execute once the delay has passed without a veto
+  pub executor: Signer<'info>,
+}
+
+/// Lift the pause once a scheduled unpause has outlived its veto window
+pub fn execute_unpause(ctx: Context<ExecuteUnpause>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  let scheduled_at = treasury_pool.unpause_scheduled_at;
+  treasury_pool.execute_unpause(current_time)?;
+
+  emit!(UnpauseExecuted {
+    executor: ctx.accounts.executor.key(),
+    scheduled_at,
+    executed_at: current_time,
+  });
+  emit!(EmergencyPauseToggled {
+    paused: false,
+    toggled_at: current_time,
+  });
+
+  Ok(())
+}
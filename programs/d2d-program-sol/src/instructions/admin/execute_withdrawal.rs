@@ -4,6 +4,7 @@ use crate::{
   errors::ErrorCode,
   events::WithdrawalExecuted,
   states::{PendingWithdrawal, TreasuryPool, WithdrawalType},
+  utils::pool_debit_credit,
 };
 
 #[derive(Accounts)]
@@ -58,11 +59,40 @@ pub struct ExecuteWithdrawal<'info> {
 pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
   let treasury_pool = &mut ctx.accounts.treasury_pool;
   let pending_withdrawal = &ctx.accounts.pending_withdrawal;
+  let current_time = Clock::get()?.unix_timestamp;
 
-  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  treasury_pool.check_not_council_governed()?;
+  pay_pending_withdrawal(
+    treasury_pool,
+    pending_withdrawal,
+    &ctx.accounts.platform_pool.to_account_info(),
+    &ctx.accounts.reward_pool.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    current_time,
+  )?;
 
-  let current_time = Clock::get()?.unix_timestamp;
+  emit!(WithdrawalExecuted {
+    executor: ctx.accounts.admin.key(),
+    withdrawal_type: pending_withdrawal.withdrawal_type.label().to_string(),
+    amount: pending_withdrawal.amount,
+    destination: pending_withdrawal.destination,
+    executed_at: current_time,
+  });
+
+  Ok(())
+}
 
+/// Pay out a pending withdrawal whose timelock has passed (shared with the
+/// council's ExecuteWithdrawal; the caller closes the pending withdrawal)
+pub(crate) fn pay_pending_withdrawal<'info>(
+  treasury_pool: &mut TreasuryPool,
+  pending_withdrawal: &PendingWithdrawal,
+  platform_pool: &AccountInfo<'info>,
+  reward_pool: &AccountInfo<'info>,
+  destination: &AccountInfo<'info>,
+  current_time: i64,
+) -> Result<()> {
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
   require!(!pending_withdrawal.executed, ErrorCode::NoPendingWithdrawal);
   require!(!pending_withdrawal.vetoed, ErrorCode::NoPendingWithdrawal);
   require!(
@@ -73,18 +103,19 @@ pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
     !pending_withdrawal.is_expired(current_time),
     ErrorCode::PendingWithdrawalExpired
   );
+  require!(
+    destination.key() == pending_withdrawal.destination,
+    ErrorCode::InvalidTreasuryWallet
+  );
 
   let amount = pending_withdrawal.amount;
 
   treasury_pool.check_and_update_daily_limit(amount, current_time)?;
 
-  let withdrawal_type_str = match pending_withdrawal.withdrawal_type {
+  match pending_withdrawal.withdrawal_type {
     WithdrawalType::PlatformPool => {
-      let platform_pool_info = ctx.accounts.platform_pool.to_account_info();
-      let destination_info = ctx.accounts.destination.to_account_info();
-
       require!(
-        platform_pool_info.lamports() >= amount,
+        platform_pool.lamports() >= amount,
         ErrorCode::InsufficientTreasuryFunds
       );
       require!(
@@ -92,29 +123,14 @@ pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
         ErrorCode::InsufficientTreasuryFunds
       );
 
-      {
-        let mut platform_pool_lamports = platform_pool_info.try_borrow_mut_lamports()?;
-        let mut destination_lamports = destination_info.try_borrow_mut_lamports()?;
-
-        **platform_pool_lamports = (**platform_pool_lamports)
-          .checked_sub(amount)
-          .ok_or(ErrorCode::CalculationOverflow)?;
-        **destination_lamports = (**destination_lamports)
-          .checked_add(amount)
-          .ok_or(ErrorCode::CalculationOverflow)?;
-      }
-
-      treasury_pool.platform_pool_balance = treasury_pool
-        .platform_pool_balance
-        .checked_sub(amount)
-        .ok_or(ErrorCode::CalculationOverflow)?;
-
-      "PlatformPool"
+      pool_debit_credit(
+        platform_pool,
+        destination,
+        amount,
+        &mut treasury_pool.platform_pool_balance,
+      )?;
     }
     WithdrawalType::RewardPool => {
-      let reward_pool_info = ctx.accounts.reward_pool.to_account_info();
-      let destination_info = ctx.accounts.destination.to_account_info();
-
       let excess_rewards = treasury_pool.get_excess_rewards();
       require!(
         amount <= excess_rewards,
@@ -122,7 +138,7 @@ pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
       );
 
       require!(
-        reward_pool_info.lamports() >= amount,
+        reward_pool.lamports() >= amount,
         ErrorCode::InsufficientTreasuryFunds
       );
       require!(
@@ -130,36 +146,16 @@ pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
         ErrorCode::InsufficientTreasuryFunds
       );
 
-      {
-        let mut reward_pool_lamports = reward_pool_info.try_borrow_mut_lamports()?;
-        let mut destination_lamports = destination_info.try_borrow_mut_lamports()?;
-
-        **reward_pool_lamports = (**reward_pool_lamports)
-          .checked_sub(amount)
-          .ok_or(ErrorCode::CalculationOverflow)?;
-        **destination_lamports = (**destination_lamports)
-          .checked_add(amount)
-          .ok_or(ErrorCode::CalculationOverflow)?;
-      }
-
-      treasury_pool.reward_pool_balance = treasury_pool
-        .reward_pool_balance
-        .checked_sub(amount)
-        .ok_or(ErrorCode::CalculationOverflow)?;
-
-      "RewardPool"
+      pool_debit_credit(
+        reward_pool,
+        destination,
+        amount,
+        &mut treasury_pool.reward_pool_balance,
+      )?;
     }
-  };
+  }
 
   treasury_pool.pending_withdrawal_count = 0;
 
-  emit!(WithdrawalExecuted {
-    executor: ctx.accounts.admin.key(),
-    withdrawal_type: withdrawal_type_str.to_string(),
-    amount,
-    destination: pending_withdrawal.destination,
-    executed_at: current_time,
-  });
-
   Ok(())
 }
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::DeployRequestExpired,
+  instructions::{claw_back_rewards, return_deployment_loan},
+  states::{DeployRequest, DeployRequestStatus, FeeSource, TreasuryPool, UserDeployStats},
+  utils::payout_from_pda,
+};
+
+/// Permissionless expiry of a request the backend never confirmed
+///
+/// Once `expiry_at` passes, anyone can mark the request Failed and refund the
+/// developer in full, like confirm_deployment_failure. If the temporary
+/// wallet was funded it must co-sign: everything it holds returns to the
+/// treasury vault and closes the deployment loan.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ExpireDeployRequest<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  /// Funded temporary wallet (required once funded; returns its lamports)
+  #[account(mut)]
+  pub ephemeral_key: Option<Signer<'info>>,
+
+  /// CHECK: Developer wallet receiving the refund
+  #[account(
+        mut,
+        constraint = developer_wallet.key() == deploy_request.developer @ ErrorCode::Unauthorized
+    )]
+  pub developer_wallet: UncheckedAccount<'info>,
+
+  /// CHECK: Treasury vault PDA (receives the returned deployment funds)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  /// CHECK: Reward Pool PDA (holds the developer payment)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform Pool PDA (covers refunds that would touch protected rewards)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  pub cranker: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+
+  /// Developer's deployment stats (frees the request's quota slot)
+  #[account(
+        mut,
+        seeds = [UserDeployStats::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = user_stats.bump
+    )]
+  pub user_stats: Account<'info, UserDeployStats>,
+}
+
+pub fn expire_deploy_request(
+  ctx: Context<ExpireDeployRequest>,
+  request_id: [u8; 32],
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    deploy_request.status == DeployRequestStatus::PendingDeployment,
+    ErrorCode::InvalidRequestStatus
+  );
+  require!(
+    deploy_request.is_pending_deployment_stale(current_time),
+    ErrorCode::PendingDeploymentNotStale
+  );
+
+  let returned_lamports = return_deployment_loan(
+    treasury_pool,
+    deploy_request,
+    ctx.accounts.ephemeral_key.as_ref(),
+    &ctx.accounts.treasury_vault.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    current_time,
+  )?;
+
+  let refund_amount = deploy_request.calculate_failure_refund()?;
+  ctx.accounts.user_stats.release_slot(&deploy_request.status);
+  deploy_request.status = DeployRequestStatus::Failed;
+
+  let escrowed_payment = deploy_request.take_escrowed_payment();
+  let (from_escrow, from_rewards) = treasury_pool.split_escrowed_refund(
+    FeeSource::DeploymentRequest,
+    escrowed_payment,
+    refund_amount,
+  )?;
+  payout_from_pda(
+    &ctx.accounts.reward_pool.to_account_info(),
+    &ctx.accounts.developer_wallet.to_account_info(),
+    from_escrow,
+  )?;
+  if from_rewards > 0 {
+    claw_back_rewards(
+      treasury_pool,
+      &ctx.accounts.reward_pool.to_account_info(),
+      &ctx.accounts.platform_pool.to_account_info(),
+      &ctx.accounts.developer_wallet.to_account_info(),
+      from_rewards,
+    )?;
+  }
+
+  emit!(DeployRequestExpired {
+    request_id,
+    developer: deploy_request.developer,
+    refund_amount,
+    returned_lamports,
+    outstanding_loan: deploy_request.get_remaining_debt(),
+    expiry_at: deploy_request.pending_expiry_at(),
+    expired_by: ctx.accounts.cranker.key(),
+    expired_at: current_time,
+  });
+
+  Ok(())
+}
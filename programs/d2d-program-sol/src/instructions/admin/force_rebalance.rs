@@ -10,9 +10,12 @@ pub struct ForceRebalance<'info> {
   #[account(mut)]
   pub treasury_pool: UncheckedAccount<'info>,
 
-  /// CHECK: Treasury Pool PDA
-  #[account(mut)]
-  pub treasury_pda: UncheckedAccount<'info>,
+  /// CHECK: Treasury vault PDA (to get actual account balance)
+  #[account(
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
 
   /// Admin signer required for security
   pub admin: Signer<'info>,
@@ -28,31 +31,31 @@ pub fn force_rebalance(ctx: Context<ForceRebalance>) -> Result<()> {
     ctx.accounts.treasury_pool.key() == expected_treasury_pool,
     ErrorCode::InvalidAccountOwner
   );
-  require!(
-    ctx.accounts.treasury_pda.key() == expected_treasury_pool,
-    ErrorCode::InvalidAccountOwner
-  );
 
   // Deserialize treasury pool
   let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
   let mut treasury_pool = TreasuryPool::try_deserialize(&mut &treasury_pool_info.data.borrow()[..])
     .map_err(|_| ErrorCode::InvalidAccountData)?;
 
-  let treasury_pda_info = ctx.accounts.treasury_pda.to_account_info();
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
 
   // SECURITY: Verify admin authorization
   require!(
     ctx.accounts.admin.key() == treasury_pool.admin,
     ErrorCode::Unauthorized
   );
+  require!(
+    !treasury_pool.is_decentralized(),
+    ErrorCode::AdminPathRetired
+  );
 
   require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
 
   // Get actual account balance
-  let actual_account_balance = treasury_pda_info.lamports();
+  let actual_account_balance = treasury_vault_info.lamports();
 
   // Calculate rent exemption
-  let account_data_size = treasury_pda_info.data_len();
+  let account_data_size = treasury_vault_info.data_len();
   let rent_exemption = Rent::get()?.minimum_balance(account_data_size);
 
   // Calculate available balance after rent
@@ -61,8 +64,8 @@ pub fn force_rebalance(ctx: Context<ForceRebalance>) -> Result<()> {
     .ok_or(ErrorCode::CalculationOverflow)?;
 
   // SECURITY FIX H-01: reward_pool and platform_pool are SEPARATE PDAs
-  // They are NOT part of treasury_pda lamports, so do NOT subtract them
-  // treasury_pda only holds staker deposits (liquid_balance)
+  // They are NOT part of treasury_vault lamports, so do NOT subtract them
+  // treasury_vault only holds staker deposits (liquid_balance)
   //
   // The old logic incorrectly subtracted reward_pool_balance + platform_pool_balance
   // which are state values for different accounts, not lamports in this PDA
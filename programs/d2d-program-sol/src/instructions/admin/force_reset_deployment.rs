@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::{
   errors::ErrorCode,
   events::DeploymentFailed,
-  states::{DeployRequest, DeployRequestStatus, TreasuryPool},
+  states::{DeployRequest, DeployRequestStatus, TreasuryPool, UserDeployStats},
 };
 
 #[derive(Accounts)]
@@ -17,7 +17,7 @@ pub struct ForceResetDeployment<'info> {
 
   #[account(
         mut,
-        seeds = [DeployRequest::PREFIX_SEED, deploy_request.program_hash.as_ref()],
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
         bump = deploy_request.bump
     )]
   pub deploy_request: Account<'info, DeployRequest>,
@@ -27,15 +27,42 @@ pub struct ForceResetDeployment<'info> {
         constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
+
+  /// Developer's deployment stats (frees the request's quota slot)
+  #[account(
+        mut,
+        seeds = [UserDeployStats::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = user_stats.bump
+    )]
+  pub user_stats: Account<'info, UserDeployStats>,
 }
 
 pub fn force_reset_deployment(ctx: Context<ForceResetDeployment>) -> Result<()> {
-  let deploy_request = &mut ctx.accounts.deploy_request;
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  require!(
+    !treasury_pool.is_decentralized(),
+    ErrorCode::AdminPathRetired
+  );
+  treasury_pool.check_not_council_governed()?;
+
+  reset_deployment(
+    &mut ctx.accounts.deploy_request,
+    &mut ctx.accounts.user_stats,
+    "Force reset by admin",
+  )
+}
 
+/// Mark a stuck deployment failed; shared with the council's ForceResetDeployment
+pub(crate) fn reset_deployment(
+  deploy_request: &mut DeployRequest,
+  user_stats: &mut UserDeployStats,
+  failure_reason: &str,
+) -> Result<()> {
   // Status must be resettable or hung
   // We allow force reset for any status if admin deems it necessary
 
   let previous_status = deploy_request.status.clone();
+  user_stats.release_slot(&previous_status);
   deploy_request.status = DeployRequestStatus::Failed;
   deploy_request.ephemeral_key = None; // Critical: clear the key that was blocking reset
 
@@ -48,7 +75,7 @@ pub fn force_reset_deployment(ctx: Context<ForceResetDeployment>) -> Result<()>
   emit!(DeploymentFailed {
     request_id: deploy_request.request_id,
     developer: deploy_request.developer,
-    failure_reason: "Force reset by admin".to_string(),
+    failure_reason: failure_reason.to_string(),
     refund_amount: 0,            // No automatic refund in force reset
     deployment_cost_returned: 0, // Admin must manually recover SOL from ephemeral if known
     failed_at: Clock::get()?.unix_timestamp,
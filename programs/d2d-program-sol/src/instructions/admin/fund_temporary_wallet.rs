@@ -3,11 +3,14 @@ use anchor_lang::prelude::*;
 use crate::{
   errors::ErrorCode,
   events::{DeploymentBorrowed, TemporaryWalletFunded},
-  states::{DeployRequest, DeployRequestStatus, TreasuryPool},
+  states::{DeployRequest, DeployRequestStatus, Role, TreasuryPool},
+  utils::{vault_debit_credit, vault_payable_lamports},
 };
 
 /// Fund a temporary wallet for deployment
-/// Only backend admin can call this instruction
+/// Only the admin or the deployer operator can call this instruction; once
+/// the pool is decentralized only the deployer operator, and each request is
+/// funded at most once and within its program size's rent limit
 ///
 /// Funds are taken from TreasuryPool.liquid_balance (not from reward/platform pools)
 /// This ensures proper tracking of deployed funds and protects backer deposits.
@@ -31,21 +34,23 @@ pub struct FundTemporaryWallet<'info> {
 
   #[account(
         mut,
-        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+        constraint = treasury_pool.has_role(Role::DeployerOperator, &admin.key()) @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
 
-  /// CHECK: Treasury Pool PDA (holds deposits, source of liquid_balance)
+  /// CHECK: Treasury vault PDA (holds deposits, source of liquid_balance)
   #[account(
         mut,
-        seeds = [TreasuryPool::PREFIX_SEED],
-        bump = treasury_pool.bump
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
     )]
-  pub treasury_pda: UncheckedAccount<'info>,
+  pub treasury_vault: UncheckedAccount<'info>,
 
   /// CHECK: Temporary wallet generated by backend
   #[account(mut)]
   pub temporary_wallet: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
 }
 
 /// Fund temporary wallet for deployment
@@ -53,7 +58,7 @@ pub struct FundTemporaryWallet<'info> {
 /// Flow:
 /// 1. Check TreasuryPool.liquid_balance >= deployment_cost
 /// 2. Verify 80% pool utilization limit is not exceeded
-/// 3. Transfer from Treasury Pool PDA -> temporary wallet (via lamport mutation)
+/// 3. Transfer from treasury vault -> temporary wallet (signed System Program transfer)
 /// 4. Update liquid_balance in TreasuryPool state
 ///
 /// NOTE: Funds sourced from TreasuryPool.liquid_balance (NOT RewardPool or PlatformPool)
@@ -69,6 +74,23 @@ pub fn fund_temporary_wallet(
 
   require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
   require!(amount > 0, ErrorCode::InvalidAmount);
+  // A request's temporary wallet is funded once
+  require!(
+    deploy_request.ephemeral_key.is_none(),
+    ErrorCode::InvalidDeploymentStatus
+  );
+  // Once decentralized the admin key no longer moves liquidity: only the
+  // deployer operator, and only what the request's program size needs
+  if treasury_pool.is_decentralized() {
+    require!(
+      treasury_pool.role_holder(Role::DeployerOperator) == ctx.accounts.admin.key(),
+      ErrorCode::AdminPathRetired
+    );
+    require!(
+      amount <= deploy_request.max_deployment_funding(&Rent::get()?)?,
+      ErrorCode::DeploymentFundingExceedsLimit
+    );
+  }
 
   // Verify that the requested amount matches the deployment cost in deploy_request
   require!(
@@ -78,8 +100,9 @@ pub fn fund_temporary_wallet(
 
   // IMPORTANT: Use liquid_balance from Treasury PDA (not from pools)
   // This ensures withdrawals work correctly when funds are used for deployments
+  // Liquidity declared by committed unstakes is not available to new borrows
   require!(
-    treasury_pool.liquid_balance >= amount,
+    treasury_pool.get_uncommitted_liquidity(treasury_pool.liquid_balance) >= amount,
     ErrorCode::InsufficientLiquidBalance
   );
 
@@ -90,42 +113,30 @@ pub fn fund_temporary_wallet(
     ErrorCode::PoolUtilizationTooHigh
   );
 
-  let treasury_pda_info = ctx.accounts.treasury_pda.to_account_info();
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
   let temporary_wallet_info = ctx.accounts.temporary_wallet.to_account_info();
 
-  // Verify Treasury PDA has enough lamports
+  // Verify the treasury vault has enough lamports
   require!(
-    treasury_pda_info.lamports() >= amount,
+    vault_payable_lamports(&treasury_vault_info)? >= amount,
     ErrorCode::InsufficientTreasuryFunds
   );
 
-  // Transfer SOL from Treasury PDA -> temporary wallet via lamport mutation
-  // CRITICAL: Use lamport mutation for program-owned accounts (not CPI System transfer)
-  {
-    let mut treasury_lamports = treasury_pda_info.try_borrow_mut_lamports()?;
-    let mut temporary_lamports = temporary_wallet_info.try_borrow_mut_lamports()?;
-
-    let new_treasury_balance = (**treasury_lamports)
-      .checked_sub(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-    let new_temporary_balance = (**temporary_lamports)
-      .checked_add(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-
-    **treasury_lamports = new_treasury_balance;
-    **temporary_lamports = new_temporary_balance;
-  }
-
-  // Update treasury pool state
+  // Transfer SOL from the treasury vault -> temporary wallet
   // IMPORTANT: Deduct from liquid_balance (shared between deployments and withdrawals)
-  treasury_pool.liquid_balance = treasury_pool
-    .liquid_balance
-    .checked_sub(amount)
-    .ok_or(ErrorCode::CalculationOverflow)?;
+  vault_debit_credit(
+    &ctx.accounts.system_program.to_account_info(),
+    &treasury_vault_info,
+    &temporary_wallet_info,
+    amount,
+    treasury_pool.vault_bump,
+    &mut treasury_pool.liquid_balance,
+  )?;
 
   // Store temporary wallet address and borrowed amount in deploy_request
   deploy_request.ephemeral_key = Some(temporary_wallet_info.key());
   deploy_request.borrowed_amount = amount; // Track borrowed amount for fee calculation (1% monthly)
+  deploy_request.record_funding(amount)?; // Cumulative, reconciled at confirmation
 
   // Set expected rent recovery estimate (typically ~80% of deployment cost)
   deploy_request.set_expected_rent_recovery(amount);
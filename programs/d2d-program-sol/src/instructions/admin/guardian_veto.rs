@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::{
   errors::ErrorCode,
   events::WithdrawalVetoed,
-  states::{PendingWithdrawal, TreasuryPool, WithdrawalType},
+  states::{PendingWithdrawal, TreasuryPool},
 };
 
 #[derive(Accounts)]
@@ -49,10 +49,7 @@ pub fn guardian_veto(ctx: Context<GuardianVeto>) -> Result<()> {
   );
 
   let amount = pending_withdrawal.amount;
-  let withdrawal_type_str = match pending_withdrawal.withdrawal_type {
-    WithdrawalType::PlatformPool => "PlatformPool",
-    WithdrawalType::RewardPool => "RewardPool",
-  };
+  let withdrawal_type_str = pending_withdrawal.withdrawal_type.label();
 
   treasury_pool.pending_withdrawal_count = 0;
 
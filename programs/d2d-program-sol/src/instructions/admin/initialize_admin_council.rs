@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::AdminCouncilInitialized,
+  states::{AdminCouncil, TreasuryPool},
+};
+
+/// Hand the high-risk admin operations to an M-of-N council
+///
+/// One-way: once the council exists, admin_withdraw(_reward_pool),
+/// set_daily_limit, force_reset_deployment, set_role, the keeper tip / exit
+/// fee / haircut setters, set_plan_tier, set_coupon, set_prepay_discounts,
+/// propose_subscription_split, initiate_withdrawal / execute_withdrawal,
+/// set_timelock_duration, set_guardian, the deposit fee / stablecoin fee /
+/// upgrade pricing / refund / insurance / watchtower / emergency exit /
+/// buffer loan setters, set_lst_config, set_subscription_stablecoin and
+/// credit_staker_bonus reject the admin key and only run through
+/// execute_council_proposal. redeem_lst_vault is retired.
+#[derive(Accounts)]
+pub struct InitializeAdminCouncil<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        init,
+        payer = admin,
+        space = 8 + AdminCouncil::INIT_SPACE,
+        seeds = [AdminCouncil::PREFIX_SEED],
+        bump
+    )]
+  pub admin_council: Account<'info, AdminCouncil>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_admin_council(
+  ctx: Context<InitializeAdminCouncil>,
+  members: Vec<Pubkey>,
+  threshold: u8,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let admin_council = &mut ctx.accounts.admin_council;
+
+  admin_council.set_members(&members, threshold)?;
+  admin_council.proposal_count = 0;
+  admin_council.epoch = 0;
+  admin_council.bump = ctx.bumps.admin_council;
+  treasury_pool.admin_council_enabled = true;
+
+  emit!(AdminCouncilInitialized {
+    admin: ctx.accounts.admin.key(),
+    members,
+    threshold,
+    initialized_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::InsuranceFundInitialized,
+  states::{InsuranceFund, TreasuryPool},
+};
+
+#[derive(Accounts)]
+pub struct InitializeInsuranceFund<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        init,
+        payer = admin,
+        space = 8 + InsuranceFund::INIT_SPACE,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump
+    )]
+  pub insurance_fund: Account<'info, InsuranceFund>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Create the staker insurance fund PDA
+pub fn initialize_insurance_fund(
+  ctx: Context<InitializeInsuranceFund>,
+  premium_bps: u64,
+  loss_threshold_bps: u64,
+) -> Result<()> {
+  require!(
+    premium_bps <= InsuranceFund::MAX_PREMIUM_BPS,
+    ErrorCode::InvalidInsurancePolicy
+  );
+  require!(
+    loss_threshold_bps <= 10000,
+    ErrorCode::InvalidInsurancePolicy
+  );
+
+  let insurance_fund = &mut ctx.accounts.insurance_fund;
+  insurance_fund.premium_bps = premium_bps;
+  insurance_fund.loss_threshold_bps = loss_threshold_bps;
+  insurance_fund.bump = ctx.bumps.insurance_fund;
+
+  emit!(InsuranceFundInitialized {
+    admin: ctx.accounts.admin.key(),
+    premium_bps,
+    loss_threshold_bps,
+    initialized_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
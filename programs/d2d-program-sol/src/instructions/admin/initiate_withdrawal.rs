@@ -43,7 +43,48 @@ pub fn initiate_withdrawal(
 ) -> Result<()> {
   let treasury_pool = &mut ctx.accounts.treasury_pool;
   let pending_withdrawal = &mut ctx.accounts.pending_withdrawal;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  treasury_pool.check_not_council_governed()?;
+  open_pending_withdrawal(
+    treasury_pool,
+    pending_withdrawal,
+    withdrawal_type.clone(),
+    amount,
+    destination,
+    ctx.accounts.admin.key(),
+    reason.clone(),
+    current_time,
+  )?;
+  pending_withdrawal.bump = ctx.bumps.pending_withdrawal;
+
+  emit!(WithdrawalInitiated {
+    initiator: ctx.accounts.admin.key(),
+    withdrawal_type: withdrawal_type.label().to_string(),
+    amount,
+    destination,
+    execute_after: pending_withdrawal.execute_after,
+    expires_at: pending_withdrawal.expires_at,
+    reason,
+    initiated_at: current_time,
+  });
+
+  Ok(())
+}
 
+/// Validate and record a timelocked withdrawal (shared with the council's
+/// InitiateWithdrawal)
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn open_pending_withdrawal(
+  treasury_pool: &mut TreasuryPool,
+  pending_withdrawal: &mut PendingWithdrawal,
+  withdrawal_type: WithdrawalType,
+  amount: u64,
+  destination: Pubkey,
+  initiator: Pubkey,
+  reason: String,
+  current_time: i64,
+) -> Result<()> {
   require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
   require!(amount > 0, ErrorCode::InvalidAmount);
   require!(
@@ -66,7 +107,6 @@ pub fn initiate_withdrawal(
     }
   }
 
-  let current_time = Clock::get()?.unix_timestamp;
   let remaining_allowance = treasury_pool.get_remaining_daily_allowance(current_time);
   if treasury_pool.daily_withdrawal_limit > 0 {
     require!(
@@ -82,35 +122,18 @@ pub fn initiate_withdrawal(
     .checked_add(PendingWithdrawal::VALIDITY_PERIOD)
     .ok_or(ErrorCode::CalculationOverflow)?;
 
-  pending_withdrawal.withdrawal_type = withdrawal_type.clone();
+  pending_withdrawal.withdrawal_type = withdrawal_type;
   pending_withdrawal.amount = amount;
   pending_withdrawal.destination = destination;
-  pending_withdrawal.initiator = ctx.accounts.admin.key();
+  pending_withdrawal.initiator = initiator;
   pending_withdrawal.initiated_at = current_time;
   pending_withdrawal.execute_after = execute_after;
   pending_withdrawal.expires_at = expires_at;
-  pending_withdrawal.reason = reason.clone();
+  pending_withdrawal.reason = reason;
   pending_withdrawal.executed = false;
   pending_withdrawal.vetoed = false;
-  pending_withdrawal.bump = ctx.bumps.pending_withdrawal;
 
   treasury_pool.pending_withdrawal_count = 1;
 
-  let withdrawal_type_str = match withdrawal_type {
-    WithdrawalType::PlatformPool => "PlatformPool",
-    WithdrawalType::RewardPool => "RewardPool",
-  };
-
-  emit!(WithdrawalInitiated {
-    initiator: ctx.accounts.admin.key(),
-    withdrawal_type: withdrawal_type_str.to_string(),
-    amount,
-    destination,
-    execute_after,
-    expires_at,
-    reason,
-    initiated_at: current_time,
-  });
-
   Ok(())
 }
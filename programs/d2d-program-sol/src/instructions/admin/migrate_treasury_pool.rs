@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
 
-use crate::states::TreasuryPool;
+use crate::{errors::ErrorCode, states::TreasuryPool};
 
+/// Resize the treasury pool to the current layout
+///
+/// A migration only ever extends the layout: every existing field (admin,
+/// balances, queue, roles, config, pause and decentralization state) is kept
+/// and fields added since start zeroed.
 #[derive(Accounts)]
 pub struct MigrateTreasuryPool<'info> {
   /// CHECK: Treasury Pool PDA - will be resized and migrated
@@ -12,6 +17,8 @@ pub struct MigrateTreasuryPool<'info> {
     )]
   pub treasury_pool: UncheckedAccount<'info>,
 
+  /// Must be the admin stored on the pool (checked in the handler, since the
+  /// pool may not deserialize before migration)
   #[account(mut)]
   pub admin: Signer<'info>,
 
@@ -34,79 +41,34 @@ pub fn migrate_treasury_pool(ctx: Context<MigrateTreasuryPool>) -> Result<()> {
   old_pool_data.copy_from_slice(&old_data);
   drop(old_data);
 
-  if current_space != required_space {
-    treasury_pool_info.resize(required_space)?;
-  }
+  // Fields are only ever appended, so the old pool zero-padded to the new
+  // layout keeps every existing field; newly added ones start zeroed
+  let mut new_pool =
+    TreasuryPool::read_padded(&old_pool_data).ok_or(ErrorCode::InvalidAccountData)?;
 
-  let mut data = treasury_pool_info.try_borrow_mut_data()?;
+  // Only the stored admin may migrate - the caller must not become admin
+  require!(
+    ctx.accounts.admin.key() == new_pool.admin,
+    ErrorCode::Unauthorized
+  );
+  // After decentralization a migration may only extend the layout
+  require!(
+    !new_pool.is_decentralized() || current_space <= required_space,
+    ErrorCode::AdminPathRetired
+  );
 
-  let mut new_pool = TreasuryPool {
-    reward_per_share: 0,
-    total_deposited: 0,
-    liquid_balance: 0,
-    reward_pool_balance: 0,
-    platform_pool_balance: 0,
-    reward_fee_bps: TreasuryPool::REWARD_FEE_BPS,
-    platform_fee_bps: TreasuryPool::PLATFORM_FEE_BPS,
-    admin: ctx.accounts.admin.key(),
-    dev_wallet: Pubkey::default(),
-    emergency_pause: false,
-    guardian: Pubkey::default(),
-    timelock_duration: TreasuryPool::DEFAULT_TIMELOCK_DURATION,
-    pending_withdrawal_count: 0,
-    daily_withdrawal_limit: TreasuryPool::DEFAULT_DAILY_LIMIT,
-    last_withdrawal_day: 0,
-    withdrawn_today: 0,
-    total_credited_rewards: 0,
-    total_claimed_rewards: 0,
-    reward_pool_bump: 0,
-    platform_pool_bump: 0,
-    bump: ctx.bumps.treasury_pool,
-    // Debt tracking fields
-    total_borrowed: 0,
-    total_recovered: 0,
-    total_debt_repaid: 0,
-    active_deployment_count: 0,
-    // Fair reward distribution fields
-    total_stake_duration_weight: 0,
-    last_weight_update: 0,
-    pending_undistributed_rewards: 0,
-    // Withdrawal queue fields
-    withdrawal_queue_head: 0,
-    withdrawal_queue_tail: 0,
-    queued_withdrawal_amount: 0,
-    // Dynamic APY fields
-    base_apy_bps: TreasuryPool::DEFAULT_BASE_APY_BPS,
-    max_apy_multiplier_bps: TreasuryPool::DEFAULT_MAX_APY_MULTIPLIER_BPS,
-    target_utilization_bps: TreasuryPool::DEFAULT_TARGET_UTILIZATION_BPS,
-  };
+  new_pool.bump = ctx.bumps.treasury_pool;
+  // Layouts predating vault_bump read it as zero
+  if new_pool.vault_bump == 0 {
+    new_pool.vault_bump =
+      Pubkey::find_program_address(&[TreasuryPool::VAULT_SEED], ctx.program_id).1;
+  }
 
-  if old_pool_data.len() >= 8 {
-    if let Ok(old_pool) = TreasuryPool::try_deserialize(&mut &old_pool_data[..]) {
-      new_pool.reward_per_share = old_pool.reward_per_share;
-      new_pool.total_deposited = old_pool.total_deposited;
-      new_pool.liquid_balance = old_pool.liquid_balance;
-      new_pool.reward_pool_balance = old_pool.reward_pool_balance;
-      new_pool.platform_pool_balance = old_pool.platform_pool_balance;
-      new_pool.reward_fee_bps = old_pool.reward_fee_bps;
-      new_pool.platform_fee_bps = old_pool.platform_fee_bps;
-      new_pool.admin = old_pool.admin;
-      new_pool.dev_wallet = old_pool.dev_wallet;
-      new_pool.emergency_pause = old_pool.emergency_pause;
-      new_pool.guardian = old_pool.guardian;
-      new_pool.timelock_duration = old_pool.timelock_duration;
-      new_pool.pending_withdrawal_count = old_pool.pending_withdrawal_count;
-      new_pool.daily_withdrawal_limit = old_pool.daily_withdrawal_limit;
-      new_pool.last_withdrawal_day = old_pool.last_withdrawal_day;
-      new_pool.withdrawn_today = old_pool.withdrawn_today;
-      new_pool.total_credited_rewards = old_pool.total_credited_rewards;
-      new_pool.total_claimed_rewards = old_pool.total_claimed_rewards;
-      new_pool.reward_pool_bump = old_pool.reward_pool_bump;
-      new_pool.platform_pool_bump = old_pool.platform_pool_bump;
-      new_pool.bump = old_pool.bump;
-    }
+  if current_space != required_space {
+    treasury_pool_info.resize(required_space)?;
   }
 
+  let mut data = treasury_pool_info.try_borrow_mut_data()?;
   new_pool.try_serialize(&mut &mut data[..])?;
 
   Ok(())
@@ -0,0 +1,84 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::TreasuryVaultMigrated,
+  states::TreasuryPool,
+  utils::{payable_lamports, pda_transfer},
+};
+
+/// Move liquid principal off the TreasuryPool state account into the
+/// data-less treasury vault PDA
+///
+/// Admin-only. Leaves the state account at its rent-exempt minimum and tops
+/// the vault up to its own minimum from the admin if needed. Safe to repeat:
+/// later calls sweep any lamports that reached the state account directly.
+#[derive(Accounts)]
+pub struct MigrateTreasuryVault<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Treasury vault PDA (data-less, system-owned)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_treasury_vault(ctx: Context<MigrateTreasuryVault>) -> Result<()> {
+  let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let rent = Rent::get()?;
+
+  // Everything above the state account's own rent is principal (or stray SOL
+  // that absorb_donations picks up from the vault afterwards)
+  let lamports_moved = payable_lamports(
+    treasury_pool_info.lamports(),
+    rent.minimum_balance(treasury_pool_info.data_len()),
+  );
+  pda_transfer(&treasury_pool_info, &treasury_vault_info, lamports_moved)?;
+
+  // A system account must end the transaction rent-exempt or empty
+  let vault_top_up = rent
+    .minimum_balance(0)
+    .saturating_sub(treasury_vault_info.lamports());
+  if vault_top_up > 0 {
+    system_program::transfer(
+      CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+          from: ctx.accounts.admin.to_account_info(),
+          to: treasury_vault_info.clone(),
+        },
+      ),
+      vault_top_up,
+    )?;
+  }
+
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.vault_bump = ctx.bumps.treasury_vault;
+
+  emit!(TreasuryVaultMigrated {
+    admin: ctx.accounts.admin.key(),
+    treasury_vault: treasury_vault_info.key(),
+    lamports_moved,
+    vault_top_up,
+    migrated_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -1,71 +1,209 @@
+pub mod absorb_donations;
 pub mod admin_withdraw;
 pub mod admin_withdraw_reward_pool;
+pub mod attest_developer_identity;
+pub mod check_auto_renew;
+pub mod close_boost_campaign;
 pub mod close_program_and_refund;
+pub mod close_swept_position;
 pub mod close_treasury_pool;
+pub mod collect_borrow_fee;
 pub mod confirm_deployment;
+pub mod create_boost_campaign;
 pub mod create_deploy_request;
 pub mod credit_fee_to_pool;
+pub mod credit_staker_bonus;
+pub mod decentralize;
 pub mod emergency_pause;
+pub mod emit_expiry_warning;
 pub mod force_rebalance;
 pub mod force_reset_deployment;
 pub mod fund_temporary_wallet;
+pub mod initialize_insurance_fund;
 pub mod migrate_treasury_pool;
+pub mod migrate_treasury_vault;
 pub mod reclaim_program_rent;
+pub mod reconcile_reward_pool;
+pub mod record_insurance_loss;
+pub mod redeem_lst_vault;
+pub mod register_collateral_partner;
 pub mod reinitialize_treasury_pool;
+pub mod set_approved_authority;
+pub mod set_coupon;
+pub mod set_prepay_discounts;
+pub mod sweep_dust_position;
 pub mod sync_liquid_balance;
 pub mod transfer_authority_to_pda;
+pub mod verify_upgrade_authorities;
 
 // Security instructions
+pub mod apply_subscription_split;
+pub mod approve_council_proposal;
+pub mod cancel_council_proposal;
+pub mod cancel_subscription_split;
 pub mod cancel_withdrawal;
+pub mod execute_council_proposal;
+pub mod execute_unpause;
 pub mod execute_withdrawal;
+pub mod expire_deploy_request;
 pub mod guardian_pause;
 pub mod guardian_veto;
+pub mod initialize_admin_council;
 pub mod initiate_withdrawal;
+pub mod propose_council_action;
+pub mod propose_subscription_split;
+pub mod schedule_unpause;
+pub mod set_authority_exit_fee;
+pub mod set_boost_nft_policy;
 pub mod set_daily_limit;
+pub mod set_deposit_fee_mode;
+pub mod set_developer_quota;
+pub mod set_donation_policy;
+pub mod set_emergency_exit_during_pause;
+pub mod set_emergency_exit_policy;
+pub mod set_emergency_haircut;
+pub mod set_escrow_token_mints;
+pub mod set_expiry_warning_policy;
+pub mod set_funding_variance_tolerance;
 pub mod set_guardian;
+pub mod set_identity_policy;
+pub mod set_insurance_policy;
+pub mod set_offboarding_policy;
+pub mod set_permissionless_renewal_window;
+pub mod set_plan_tier;
+pub mod set_plan_upgrade_pricing;
+pub mod set_request_bounds;
+pub mod set_role;
+pub mod set_service_fee_refund_policy;
+pub mod set_stablecoin_monthly_fee;
+pub mod set_stake_limits;
+pub mod set_subscription_stablecoin;
+pub mod set_terms_hash;
 pub mod set_timelock_duration;
+pub mod set_unstake_commitment_policy;
+pub mod set_watchtower_bounty;
+pub mod simulate_drawdown;
+pub mod veto_unpause;
 
 // Auto-renewal & Grace period instructions
 pub mod auto_renew_subscription;
 pub mod close_expired_program;
+pub mod offboard_managed_program;
+pub mod snapshot_stakes;
 pub mod start_grace_period;
 
 // Fair reward distribution
 pub mod distribute_pending_rewards;
+pub mod set_bonus_vesting_period;
 
 // Withdrawal queue processing
+pub mod compact_withdrawal_queue;
 pub mod process_withdrawal_queue;
+pub mod set_keeper_tip;
+pub mod set_lst_config;
+pub mod set_max_buffer_rent_loan;
+pub mod set_max_pool_share;
+pub mod set_max_queue_age;
 
+pub use absorb_donations::*;
 pub use admin_withdraw::*;
 pub use admin_withdraw_reward_pool::*;
+pub use apply_subscription_split::*;
+pub use approve_council_proposal::*;
+pub use attest_developer_identity::*;
 // Auto-renewal & Grace period instructions
 pub use auto_renew_subscription::*;
+pub use cancel_council_proposal::*;
 // Security instructions
+pub use cancel_subscription_split::*;
 pub use cancel_withdrawal::*;
+pub use check_auto_renew::*;
+pub use close_boost_campaign::*;
 pub use close_expired_program::*;
 pub use close_program_and_refund::*;
+pub use close_swept_position::*;
 pub use close_treasury_pool::*;
+pub use collect_borrow_fee::*;
+pub use compact_withdrawal_queue::*;
 pub use confirm_deployment::*;
+pub use create_boost_campaign::*;
 pub use create_deploy_request::*;
 pub use credit_fee_to_pool::*;
+pub use credit_staker_bonus::*;
+pub use decentralize::*;
 // Fair reward distribution
 pub use distribute_pending_rewards::*;
 pub use emergency_pause::*;
+pub use emit_expiry_warning::*;
+pub use execute_council_proposal::*;
+pub use execute_unpause::*;
 pub use execute_withdrawal::*;
+pub use expire_deploy_request::*;
 pub use force_rebalance::*;
 pub use force_reset_deployment::*;
 pub use fund_temporary_wallet::*;
 pub use guardian_pause::*;
 pub use guardian_veto::*;
+pub use initialize_admin_council::*;
+pub use initialize_insurance_fund::*;
 pub use initiate_withdrawal::*;
 pub use migrate_treasury_pool::*;
+pub use migrate_treasury_vault::*;
+pub use offboard_managed_program::*;
 // Withdrawal queue processing
 pub use process_withdrawal_queue::*;
+pub use propose_council_action::*;
+pub use propose_subscription_split::*;
 pub use reclaim_program_rent::*;
+pub use reconcile_reward_pool::*;
+pub use record_insurance_loss::*;
+pub use redeem_lst_vault::*;
+pub use register_collateral_partner::*;
 pub use reinitialize_treasury_pool::*;
+pub use schedule_unpause::*;
+pub use set_approved_authority::*;
+pub use set_authority_exit_fee::*;
+pub use set_bonus_vesting_period::*;
+pub use set_boost_nft_policy::*;
+pub use set_coupon::*;
 pub use set_daily_limit::*;
+pub use set_deposit_fee_mode::*;
+pub use set_developer_quota::*;
+pub use set_donation_policy::*;
+pub use set_emergency_exit_during_pause::*;
+pub use set_emergency_exit_policy::*;
+pub use set_emergency_haircut::*;
+pub use set_escrow_token_mints::*;
+pub use set_expiry_warning_policy::*;
+pub use set_funding_variance_tolerance::*;
 pub use set_guardian::*;
+pub use set_identity_policy::*;
+pub use set_insurance_policy::*;
+pub use set_keeper_tip::*;
+pub use set_lst_config::*;
+pub use set_max_buffer_rent_loan::*;
+pub use set_max_pool_share::*;
+pub use set_max_queue_age::*;
+pub use set_offboarding_policy::*;
+pub use set_permissionless_renewal_window::*;
+pub use set_plan_tier::*;
+pub use set_plan_upgrade_pricing::*;
+pub use set_prepay_discounts::*;
+pub use set_request_bounds::*;
+pub use set_role::*;
+pub use set_service_fee_refund_policy::*;
+pub use set_stablecoin_monthly_fee::*;
+pub use set_stake_limits::*;
+pub use set_subscription_stablecoin::*;
+pub use set_terms_hash::*;
 pub use set_timelock_duration::*;
+pub use set_unstake_commitment_policy::*;
+pub use set_watchtower_bounty::*;
+pub use simulate_drawdown::*;
+pub use snapshot_stakes::*;
 pub use start_grace_period::*;
+pub use sweep_dust_position::*;
 pub use sync_liquid_balance::*;
 pub use transfer_authority_to_pda::*;
+pub use verify_upgrade_authorities::*;
+pub use veto_unpause::*;
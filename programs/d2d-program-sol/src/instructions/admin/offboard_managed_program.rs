@@ -0,0 +1,267 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{
+  errors::ErrorCode,
+  events::{DebtRepaid, ProgramOffboarded},
+  instructions::auto_fulfill_withdrawal_queue,
+  states::{
+    DeployRequest, DeployRequestStatus, DeveloperEscrow, ManagedProgram, Role, TokenType,
+    TreasuryPool, UserDeployStats,
+  },
+  utils::payout_from_pda,
+};
+
+/// Admin/Cron calls this instruction to hand a managed program back to its
+/// developer at subscription end instead of closing it
+///
+/// Only allowed when the off-boarding policy is OFFBOARDING_POLICY_RETURN_AUTHORITY
+/// and the program is in good standing (not suspended, closed or failed).
+///
+/// Flow:
+/// 1. Settle any remaining deployment debt from the developer's SOL escrow
+/// 2. Return the upgrade authority from the D2D PDA to the developer (PDA signs)
+/// 3. Mark managed program as inactive and the deploy request as off-boarded
+/// 4. Fulfill the head of the withdrawal queue with the settled debt
+///    (remaining accounts: `[queue_entry, lender_stake, staker]` triples)
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct OffboardManagedProgram<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Treasury vault PDA (receives settled debt)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  /// The program being handed back
+  /// CHECK: Validated by managed_program seeds
+  pub program_account: UncheckedAccount<'info>,
+
+  /// Program data account (authority is rewritten)
+  /// CHECK: Address derived from managed_program; validated by BPF Loader during CPI
+  #[account(
+        mut,
+        constraint = program_data.key() == managed_program.program_data_address() @ ErrorCode::InvalidAccountData
+    )]
+  pub program_data: UncheckedAccount<'info>,
+
+  /// PDA that currently holds the upgrade authority
+  /// CHECK: Validated by seeds and managed_program.authority_pda
+  #[account(
+        seeds = [ManagedProgram::AUTHORITY_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub authority_pda: SystemAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.deploy_request == deploy_request.key() @ ErrorCode::InvalidRequestId,
+        constraint = managed_program.authority_pda == authority_pda.key() @ ErrorCode::InvalidAuthorityPda,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  /// Developer escrow - required only when there is outstanding debt to settle
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == deploy_request.developer @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Option<Account<'info, DeveloperEscrow>>,
+
+  /// New upgrade authority (does not need to sign)
+  /// CHECK: Must be the developer of the deploy request
+  #[account(
+        constraint = developer.key() == deploy_request.developer @ ErrorCode::Unauthorized
+    )]
+  pub developer: UncheckedAccount<'info>,
+
+  #[account(
+        constraint = treasury_pool.has_role(Role::DeployerOperator, &admin.key()) @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  /// BPF Loader Upgradeable Program
+  /// CHECK: Known program ID
+  #[account(
+        constraint = bpf_loader_upgradeable_program.key() == bpf_loader_upgradeable::ID
+    )]
+  pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+
+  /// Developer's deployment stats (frees the request's quota slot)
+  #[account(
+        mut,
+        seeds = [UserDeployStats::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = user_stats.bump
+    )]
+  pub user_stats: Account<'info, UserDeployStats>,
+}
+
+/// Hand a managed program's upgrade authority from its PDA to `new_authority`
+pub(crate) fn return_upgrade_authority<'info>(
+  program_account: &AccountInfo<'info>,
+  program_data: &AccountInfo<'info>,
+  authority_pda: &AccountInfo<'info>,
+  new_authority: &AccountInfo<'info>,
+  authority_bump: u8,
+) -> Result<()> {
+  let set_authority_ix = bpf_loader_upgradeable::set_upgrade_authority(
+    program_account.key,
+    authority_pda.key,
+    Some(new_authority.key),
+  );
+
+  // Prepare PDA signer seeds
+  let program_key = program_account.key();
+  let seeds = &[
+    ManagedProgram::AUTHORITY_SEED,
+    program_key.as_ref(),
+    &[authority_bump],
+  ];
+  let signer_seeds = &[&seeds[..]];
+
+  // Execute CPI with PDA signature
+  anchor_lang::solana_program::program::invoke_signed(
+    &set_authority_ix,
+    &[
+      program_data.clone(),
+      authority_pda.clone(),
+      new_authority.clone(),
+    ],
+    signer_seeds,
+  )?;
+  Ok(())
+}
+
+pub fn offboard_managed_program<'info>(
+  ctx: Context<'_, '_, '_, 'info, OffboardManagedProgram<'info>>,
+  request_id: [u8; 32],
+) -> Result<()> {
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let managed_program = &mut ctx.accounts.managed_program;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    treasury_pool.allows_authority_return(),
+    ErrorCode::OffboardingDisabled
+  );
+
+  // Only programs in good standing are handed back
+  require!(
+    matches!(
+      deploy_request.status,
+      DeployRequestStatus::Active
+        | DeployRequestStatus::SubscriptionExpired
+        | DeployRequestStatus::InGracePeriod
+    ),
+    ErrorCode::InvalidRequestStatus
+  );
+
+  // Off-boarding happens at subscription end
+  require!(
+    !deploy_request.is_subscription_valid()?,
+    ErrorCode::SubscriptionStillActive
+  );
+
+  // === DEBT SETTLEMENT ===
+  // The program keeps its rent, so outstanding debt must be paid from escrow
+  let remaining_debt = deploy_request.get_remaining_debt();
+  let mut escrow_remaining = 0;
+  if remaining_debt > 0 {
+    let developer_escrow = ctx
+      .accounts
+      .developer_escrow
+      .as_mut()
+      .ok_or(ErrorCode::InsufficientEscrowBalance)?;
+
+    developer_escrow.deduct_balance(remaining_debt, TokenType::SOL)?;
+    payout_from_pda(
+      &developer_escrow.to_account_info(),
+      &treasury_vault_info,
+      remaining_debt,
+    )?;
+    escrow_remaining = developer_escrow.sol_balance;
+
+    deploy_request.record_debt_settlement(remaining_debt)?;
+  } else if let Some(developer_escrow) = ctx.accounts.developer_escrow.as_ref() {
+    escrow_remaining = developer_escrow.sol_balance;
+  }
+
+  // Restores liquid_balance for the settled debt and closes out the deployment
+  treasury_pool.record_debt_repayment(remaining_debt, remaining_debt, current_time)?;
+
+  // === RETURN UPGRADE AUTHORITY ===
+  return_upgrade_authority(
+    &ctx.accounts.program_account.to_account_info(),
+    &ctx.accounts.program_data.to_account_info(),
+    &ctx.accounts.authority_pda.to_account_info(),
+    &ctx.accounts.developer.to_account_info(),
+    ctx.bumps.authority_pda,
+  )?;
+  let program_key = ctx.accounts.program_account.key();
+
+  // Update states
+  managed_program.is_active = false;
+  ctx.accounts.user_stats.release_slot(&deploy_request.status);
+  deploy_request.status = DeployRequestStatus::Offboarded;
+
+  // Settled debt restored liquid_balance - fulfill queued withdrawals first
+  if remaining_debt > 0 {
+    auto_fulfill_withdrawal_queue(
+      treasury_pool,
+      &treasury_vault_info,
+      &ctx.accounts.system_program.to_account_info(),
+      ctx.remaining_accounts,
+      &[],
+      ctx.program_id,
+      current_time,
+    )?;
+  }
+
+  emit!(ProgramOffboarded {
+    request_id,
+    program_id: program_key,
+    developer: deploy_request.developer,
+    debt_settled: remaining_debt,
+    escrow_remaining,
+    offboarded_at: current_time,
+  });
+
+  if remaining_debt > 0 {
+    emit!(DebtRepaid {
+      deploy_request_id: deploy_request.request_id,
+      developer: deploy_request.developer,
+      borrowed_amount: deploy_request.borrowed_amount,
+      repaid_amount: deploy_request.repaid_amount,
+      remaining_debt: deploy_request.get_remaining_debt(),
+      recovery_ratio_bps: deploy_request.recovery_ratio_bps,
+      repaid_at: current_time,
+    });
+  }
+
+  Ok(())
+}
@@ -2,13 +2,19 @@ use anchor_lang::prelude::*;
 
 use crate::{
   errors::ErrorCode,
-  events::WithdrawalQueueProcessed,
-  states::{BackerDeposit, TreasuryPool, WithdrawalQueueEntry},
+  events::{KeeperTipPaid, WithdrawalQueueProcessed},
+  states::{BackerDeposit, Role, TreasuryPool, WithdrawalQueueEntry},
+  utils::{close_pda, vault_payable_lamports, vault_transfer},
 };
 
 /// Process a single queued withdrawal entry when liquidity is available
-/// Called by admin/crank after rent recovery or when liquid_balance increases
+/// Permissionless crank: anyone may process the head of the queue (strict FIFO)
+/// Admin/guardian/queue processor may process out of order only with `force` set (e.g. to unblock a stuck entry)
 /// Processes one entry per call - caller should invoke repeatedly for batch processing
+///
+/// Remaining accounts: queue entries from the current head onward. Entries that
+/// were already processed, cancelled or closed are skipped so the head advances
+/// past them. A fully processed entry is closed and its rent returned to the staker.
 #[derive(Accounts)]
 #[instruction(queue_position: u32)]
 pub struct ProcessWithdrawalQueue<'info> {
@@ -20,13 +26,13 @@ pub struct ProcessWithdrawalQueue<'info> {
     )]
   pub treasury_pool: UncheckedAccount<'info>,
 
-  /// CHECK: Treasury Pool PDA (holds deposits) - same PDA, used for lamport transfer
+  /// CHECK: Treasury vault PDA (holds deposits) - source of the payout
   #[account(
         mut,
-        seeds = [TreasuryPool::PREFIX_SEED],
+        seeds = [TreasuryPool::VAULT_SEED],
         bump
     )]
-  pub treasury_pda: UncheckedAccount<'info>,
+  pub treasury_vault: UncheckedAccount<'info>,
 
   #[account(
         mut,
@@ -51,8 +57,9 @@ pub struct ProcessWithdrawalQueue<'info> {
     )]
   pub staker: UncheckedAccount<'info>,
 
+  /// Keeper cranking the queue - receives the keeper tip (if configured)
   #[account(mut)]
-  pub admin: Signer<'info>,
+  pub cranker: Signer<'info>,
 
   pub system_program: Program<'info, System>,
 }
@@ -60,13 +67,8 @@ pub struct ProcessWithdrawalQueue<'info> {
 pub fn process_withdrawal_queue(
   ctx: Context<ProcessWithdrawalQueue>,
   queue_position: u32,
+  force: bool,
 ) -> Result<()> {
-  // Verify treasury accounts match
-  require!(
-    ctx.accounts.treasury_pda.key() == ctx.accounts.treasury_pool.key(),
-    ErrorCode::InvalidAccountOwner
-  );
-
   // Deserialize treasury pool manually (migration compatibility)
   let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
   let required_space = 8 + TreasuryPool::INIT_SPACE;
@@ -79,13 +81,23 @@ pub fn process_withdrawal_queue(
   let mut treasury_pool = TreasuryPool::try_deserialize(&mut &treasury_pool_info.data.borrow()[..])
     .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
 
-  // Admin-only check
-  require!(
-    treasury_pool.is_admin_or_guardian(&ctx.accounts.admin.key()),
-    ErrorCode::Unauthorized
-  );
+  // Skip cancelled/processed entries sitting at the head of the queue
+  skip_processed_queue_entries(&mut treasury_pool, ctx.remaining_accounts, ctx.program_id)?;
 
-  let treasury_pda_info = ctx.accounts.treasury_pda.to_account_info();
+  // Strict FIFO - only the head may be processed unless a privileged caller forces it
+  let is_privileged = treasury_pool.is_admin_or_guardian(&ctx.accounts.cranker.key())
+    || treasury_pool.has_role(Role::QueueProcessor, &ctx.accounts.cranker.key());
+  if force {
+    require!(is_privileged, ErrorCode::Unauthorized);
+  } else {
+    require!(
+      queue_position == treasury_pool.withdrawal_queue_head,
+      ErrorCode::NotQueueHead
+    );
+  }
+
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let system_program_info = ctx.accounts.system_program.to_account_info();
   let queue_entry = &mut ctx.accounts.queue_entry;
   let lender_stake = &mut ctx.accounts.lender_stake;
   let current_time = Clock::get()?.unix_timestamp;
@@ -97,14 +109,7 @@ pub fn process_withdrawal_queue(
   );
 
   // Calculate available balance
-  let treasury_lamports = treasury_pda_info.lamports();
-  let account_data_size = treasury_pda_info.data_len();
-  let rent_exemption =
-    anchor_lang::solana_program::rent::Rent::get()?.minimum_balance(account_data_size);
-
-  let available_balance = treasury_lamports
-    .checked_sub(rent_exemption)
-    .ok_or(ErrorCode::CalculationOverflow)?;
+  let available_balance = vault_payable_lamports(&treasury_vault_info)?;
 
   require!(available_balance > 0, ErrorCode::InsufficientLiquidBalance);
 
@@ -114,14 +119,176 @@ pub fn process_withdrawal_queue(
 
   require!(transfer_amount > 0, ErrorCode::InsufficientLiquidBalance);
 
+  // Keeper tip is carved out of the processed amount - only paid to third-party crankers
+  let keeper_tip = if is_privileged || ctx.accounts.cranker.key() == queue_entry.staker {
+    0
+  } else {
+    treasury_pool.calculate_keeper_tip(transfer_amount)?
+  };
+  let staker_amount = transfer_amount
+    .checked_sub(keeper_tip)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  // Apply the withdrawal to staker, queue entry and treasury accounting
+  apply_queued_withdrawal(
+    &mut treasury_pool,
+    queue_entry,
+    lender_stake,
+    transfer_amount,
+    current_time,
+  )?;
+
+  // Advance queue head if this entry is fully processed
+  if queue_entry.processed && queue_position == treasury_pool.withdrawal_queue_head {
+    treasury_pool.withdrawal_queue_head = treasury_pool
+      .withdrawal_queue_head
+      .checked_add(1)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    // Entries behind it may already be cancelled or force-processed
+    skip_processed_queue_entries(&mut treasury_pool, ctx.remaining_accounts, ctx.program_id)?;
+  }
+
+  // Transfer SOL from the treasury vault to staker (minus keeper tip)
+  vault_transfer(
+    &system_program_info,
+    &treasury_vault_info,
+    &ctx.accounts.staker.to_account_info(),
+    staker_amount,
+    treasury_pool.vault_bump,
+  )?;
+
+  // Transfer keeper tip from the treasury vault to cranker
+  vault_transfer(
+    &system_program_info,
+    &treasury_vault_info,
+    &ctx.accounts.cranker.to_account_info(),
+    keeper_tip,
+    treasury_pool.vault_bump,
+  )?;
+
+  // Fully processed - close the entry and return its rent to the staker
+  if queue_entry.processed {
+    close_pda(
+      &queue_entry.to_account_info(),
+      &ctx.accounts.staker.to_account_info(),
+    )?;
+  }
+
+  // Serialize treasury pool back
+  let mut data = treasury_pool_info.try_borrow_mut_data()?;
+  treasury_pool.try_serialize(&mut &mut data[..])?;
+
+  emit!(WithdrawalQueueProcessed {
+    entries_processed: 1,
+    total_amount: transfer_amount,
+    remaining_queued: treasury_pool.queued_withdrawal_amount,
+    processed_at: current_time,
+  });
+
+  if keeper_tip > 0 {
+    emit!(KeeperTipPaid {
+      cranker: ctx.accounts.cranker.key(),
+      staker: queue_entry.staker,
+      queue_position,
+      processed_amount: transfer_amount,
+      tip_amount: keeper_tip,
+      paid_at: current_time,
+    });
+  }
+
+  Ok(())
+}
+
+/// Advance withdrawal_queue_head past entries that are no longer pending
+///
+/// Walks the supplied queue entries in order; an entry only moves the head if it
+/// is the PDA for the current head position and has already been processed
+/// (fully withdrawn, cancelled, or force-processed). Stops at the first pending
+/// head entry. Returns the number of entries skipped.
+fn skip_processed_queue_entries(
+  treasury_pool: &mut TreasuryPool,
+  entries: &[AccountInfo],
+  program_id: &Pubkey,
+) -> Result<u32> {
+  let mut skipped: u32 = 0;
+
+  for entry_info in entries {
+    if treasury_pool.withdrawal_queue_head >= treasury_pool.withdrawal_queue_tail {
+      break;
+    }
+    let head = treasury_pool.withdrawal_queue_head;
+    if WithdrawalQueueEntry::is_closed_slot(entry_info, head, program_id) {
+      treasury_pool.withdrawal_queue_head =
+        head.checked_add(1).ok_or(ErrorCode::CalculationOverflow)?;
+      skipped = skipped
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationOverflow)?;
+      continue;
+    }
+    if entry_info.owner != program_id {
+      continue;
+    }
+
+    let entry = match WithdrawalQueueEntry::try_deserialize(&mut &entry_info.data.borrow()[..]) {
+      Ok(entry) => entry,
+      Err(_) => continue,
+    };
+
+    if entry.position != head {
+      continue;
+    }
+
+    let expected_key = Pubkey::create_program_address(
+      &[
+        WithdrawalQueueEntry::PREFIX_SEED,
+        &head.to_le_bytes(),
+        &[entry.bump],
+      ],
+      program_id,
+    )
+    .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
+    require!(
+      entry_info.key() == expected_key,
+      ErrorCode::InvalidAccountData
+    );
+
+    if !entry.processed {
+      break;
+    }
+
+    treasury_pool.withdrawal_queue_head =
+      head.checked_add(1).ok_or(ErrorCode::CalculationOverflow)?;
+    skipped = skipped
+      .checked_add(1)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+  }
+
+  if skipped > 0 {
+    msg!(
+      "[QUEUE] Skipped {} processed entries, head now at {}",
+      skipped,
+      treasury_pool.withdrawal_queue_head
+    );
+  }
+
+  Ok(skipped)
+}
+
+/// Apply a (partial) queued withdrawal to the staker position, the queue entry
+/// and the treasury totals. Does not move lamports or advance the queue head.
+pub(crate) fn apply_queued_withdrawal(
+  treasury_pool: &mut TreasuryPool,
+  queue_entry: &mut WithdrawalQueueEntry,
+  lender_stake: &mut BackerDeposit,
+  transfer_amount: u64,
+  current_time: i64,
+) -> Result<u64> {
   // Settle pending rewards before modifying deposit
   lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
 
   // Update duration weight
-  let weight_delta = lender_stake.update_duration_weight(current_time)?;
-  if weight_delta > 0 {
-    treasury_pool.update_stake_duration_weight(weight_delta)?;
-  }
+  lender_stake.settle_duration_weight(treasury_pool, current_time)?;
 
   // Update lender stake - reduce deposited amount
   lender_stake.deposited_amount = lender_stake
@@ -129,6 +296,14 @@ pub fn process_withdrawal_queue(
     .checked_sub(transfer_amount)
     .ok_or(ErrorCode::CalculationOverflow)?;
 
+  // Process the queue entry
+  let processed_amount = queue_entry.process_withdrawal(transfer_amount, current_time);
+
+  // Update lender stake queue tracking
+  lender_stake.process_queued_withdrawal(processed_amount, treasury_pool.queue_fee_per_share)?;
+
+  // Queued funds were not earning, so reward_debt is recomputed only after
+  // both deposited_amount and queued_withdrawal reflect the payout
   if lender_stake.deposited_amount == 0 {
     lender_stake.is_active = false;
     lender_stake.reward_debt = 0;
@@ -136,12 +311,6 @@ pub fn process_withdrawal_queue(
     lender_stake.update_reward_debt(treasury_pool.reward_per_share)?;
   }
 
-  // Process the queue entry
-  let processed_amount = queue_entry.process_withdrawal(transfer_amount, current_time);
-
-  // Update lender stake queue tracking
-  lender_stake.process_queued_withdrawal(processed_amount)?;
-
   // Update treasury pool
   treasury_pool.total_deposited = treasury_pool
     .total_deposited
@@ -155,38 +324,161 @@ pub fn process_withdrawal_queue(
 
   treasury_pool.process_queued_withdrawal(processed_amount)?;
 
-  // Advance queue head if this entry is fully processed
-  if queue_entry.processed && queue_position == treasury_pool.withdrawal_queue_head {
-    treasury_pool.withdrawal_queue_head = treasury_pool
-      .withdrawal_queue_head
-      .checked_add(1)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-  }
-
-  // Transfer SOL from treasury PDA to staker
-  {
-    let staker_info = ctx.accounts.staker.to_account_info();
-    let mut treasury_lamports = treasury_pda_info.try_borrow_mut_lamports()?;
-    let mut staker_lamports = staker_info.try_borrow_mut_lamports()?;
+  Ok(processed_amount)
+}
 
-    **treasury_lamports = (**treasury_lamports)
-      .checked_sub(transfer_amount)
+/// Fulfill the head of the withdrawal queue after a liquidity event
+///
+/// Called from instructions that increase liquid_balance (stake, rent reclaim,
+/// deployment confirmation), paying out of the treasury vault. Remaining
+/// accounts are read as
+/// `[queue_entry, lender_stake, staker]` triples starting at the queue head;
+/// at most `WithdrawalQueueEntry::MAX_AUTO_FULFILL_ENTRIES` pending entries are
+/// paid per call to keep compute bounded. Cancelled entries at the head are
+/// skipped. Processing stops at the first triple that is missing, invalid, or
+/// references an account in `excluded` (accounts the caller serializes itself).
+///
+/// Returns (entries_processed, total_amount).
+pub(crate) fn auto_fulfill_withdrawal_queue<'info>(
+  treasury_pool: &mut TreasuryPool,
+  treasury_vault_info: &AccountInfo<'info>,
+  system_program_info: &AccountInfo<'info>,
+  remaining_accounts: &[AccountInfo<'info>],
+  excluded: &[Pubkey],
+  program_id: &Pubkey,
+  current_time: i64,
+) -> Result<(u32, u64)> {
+  let mut entries_processed: u32 = 0;
+  let mut total_amount: u64 = 0;
+
+  for accounts in remaining_accounts.chunks_exact(3) {
+    if entries_processed as usize >= WithdrawalQueueEntry::MAX_AUTO_FULFILL_ENTRIES
+      || !treasury_pool.has_pending_withdrawals()
+    {
+      break;
+    }
+
+    let (entry_info, stake_info, staker_info) = (&accounts[0], &accounts[1], &accounts[2]);
+    if excluded.contains(entry_info.key) || excluded.contains(stake_info.key) {
+      break;
+    }
+
+    // Entries closed on cancel/processing only advance the head
+    let head = treasury_pool.withdrawal_queue_head;
+    if WithdrawalQueueEntry::is_closed_slot(entry_info, head, program_id) {
+      treasury_pool.withdrawal_queue_head =
+        head.checked_add(1).ok_or(ErrorCode::CalculationOverflow)?;
+      continue;
+    }
+    if entry_info.owner != program_id || stake_info.owner != program_id {
+      break;
+    }
+
+    // Validate queue entry is the PDA for the current head
+    let mut queue_entry =
+      match WithdrawalQueueEntry::try_deserialize(&mut &entry_info.data.borrow()[..]) {
+        Ok(entry) => entry,
+        Err(_) => break,
+      };
+    let expected_entry = Pubkey::create_program_address(
+      &[
+        WithdrawalQueueEntry::PREFIX_SEED,
+        &head.to_le_bytes(),
+        &[queue_entry.bump],
+      ],
+      program_id,
+    );
+    if queue_entry.position != head || expected_entry.ok() != Some(entry_info.key()) {
+      break;
+    }
+
+    // Cancelled/force-processed entries only advance the head
+    if queue_entry.processed {
+      treasury_pool.withdrawal_queue_head =
+        head.checked_add(1).ok_or(ErrorCode::CalculationOverflow)?;
+      continue;
+    }
+
+    // Validate staker position and recipient match the queue entry
+    let mut lender_stake = match BackerDeposit::try_deserialize(&mut &stake_info.data.borrow()[..])
+    {
+      Ok(stake) => stake,
+      Err(_) => break,
+    };
+    let expected_stake = Pubkey::create_program_address(
+      &[
+        BackerDeposit::PREFIX_SEED,
+        queue_entry.staker.as_ref(),
+        &[lender_stake.bump],
+      ],
+      program_id,
+    );
+    if expected_stake.ok() != Some(stake_info.key())
+      || lender_stake.backer != queue_entry.staker
+      || staker_info.key() != queue_entry.staker
+      || !staker_info.is_writable
+    {
+      break;
+    }
+
+    // Liquidity available for withdrawals (never dip into rent or tracked balance)
+    let available_balance =
+      vault_payable_lamports(treasury_vault_info)?.min(treasury_pool.liquid_balance);
+    let transfer_amount = available_balance.min(queue_entry.get_remaining_amount());
+    if transfer_amount == 0 {
+      break;
+    }
+
+    apply_queued_withdrawal(
+      treasury_pool,
+      &mut queue_entry,
+      &mut lender_stake,
+      transfer_amount,
+      current_time,
+    )?;
+
+    if queue_entry.processed {
+      treasury_pool.withdrawal_queue_head =
+        head.checked_add(1).ok_or(ErrorCode::CalculationOverflow)?;
+    }
+
+    vault_transfer(
+      system_program_info,
+      treasury_vault_info,
+      staker_info,
+      transfer_amount,
+      treasury_pool.vault_bump,
+    )?;
+
+    // Fully paid entries are closed, rent back to the staker
+    if queue_entry.processed {
+      close_pda(entry_info, staker_info)?;
+    } else {
+      queue_entry.try_serialize(&mut &mut entry_info.try_borrow_mut_data()?[..])?;
+    }
+    lender_stake.try_serialize(&mut &mut stake_info.try_borrow_mut_data()?[..])?;
+
+    entries_processed = entries_processed
+      .checked_add(1)
       .ok_or(ErrorCode::CalculationOverflow)?;
-    **staker_lamports = (**staker_lamports)
+    total_amount = total_amount
       .checked_add(transfer_amount)
       .ok_or(ErrorCode::CalculationOverflow)?;
-  }
 
-  // Serialize treasury pool back
-  let mut data = treasury_pool_info.try_borrow_mut_data()?;
-  treasury_pool.try_serialize(&mut &mut data[..])?;
+    // Partially filled head - no liquidity left for the next entry
+    if !queue_entry.processed {
+      break;
+    }
+  }
 
-  emit!(WithdrawalQueueProcessed {
-    entries_processed: 1,
-    total_amount: transfer_amount,
-    remaining_queued: treasury_pool.queued_withdrawal_amount,
-    processed_at: current_time,
-  });
+  if entries_processed > 0 {
+    emit!(WithdrawalQueueProcessed {
+      entries_processed,
+      total_amount,
+      remaining_queued: treasury_pool.queued_withdrawal_amount,
+      processed_at: current_time,
+    });
+  }
 
-  Ok(())
+  Ok((entries_processed, total_amount))
 }
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::CouncilProposalCreated,
+  states::{AdminCouncil, CouncilAction, CouncilProposal},
+};
+
+/// A council member proposes an action; the proposal counts as their approval
+#[derive(Accounts)]
+pub struct ProposeCouncilAction<'info> {
+  #[account(
+        mut,
+        seeds = [AdminCouncil::PREFIX_SEED],
+        bump = admin_council.bump
+    )]
+  pub admin_council: Account<'info, AdminCouncil>,
+
+  #[account(
+        init,
+        payer = proposer,
+        space = 8 + CouncilProposal::INIT_SPACE,
+        seeds = [
+          CouncilProposal::PREFIX_SEED,
+          admin_council.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+  pub council_proposal: Account<'info, CouncilProposal>,
+
+  #[account(
+        mut,
+        constraint = admin_council.is_member(&proposer.key()) @ ErrorCode::NotCouncilMember
+    )]
+  pub proposer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn propose_council_action(
+  ctx: Context<ProposeCouncilAction>,
+  action: CouncilAction,
+) -> Result<()> {
+  let admin_council = &mut ctx.accounts.admin_council;
+  let council_proposal = &mut ctx.accounts.council_proposal;
+  let proposer = ctx.accounts.proposer.key();
+  let current_time = Clock::get()?.unix_timestamp;
+
+  action.validate()?;
+
+  let proposal_id = admin_council.proposal_count;
+  admin_council.proposal_count = proposal_id
+    .checked_add(1)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  council_proposal.proposal_id = proposal_id;
+  council_proposal.proposer = proposer;
+  council_proposal.action = action.clone();
+  council_proposal.council_epoch = admin_council.epoch;
+  council_proposal.approvals = 0;
+  council_proposal.approval_count = 0;
+  council_proposal.proposed_at = current_time;
+  council_proposal.expires_at = current_time
+    .checked_add(CouncilProposal::LIFETIME)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  council_proposal.bump = ctx.bumps.council_proposal;
+
+  let member_index = admin_council
+    .member_index(&proposer)
+    .ok_or(ErrorCode::NotCouncilMember)?;
+  council_proposal.approve(member_index)?;
+
+  emit!(CouncilProposalCreated {
+    proposal_id,
+    proposer,
+    action,
+    expires_at: council_proposal.expires_at,
+    proposed_at: current_time,
+  });
+
+  Ok(())
+}
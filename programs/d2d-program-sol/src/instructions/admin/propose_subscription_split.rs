@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::SubscriptionSplitProposed, states::TreasuryPool};
+
+/// Admin proposes a new platform share of subscription revenue
+/// Takes effect via apply_subscription_split once timelock_duration has passed;
+/// a new proposal replaces any pending one and restarts the timelock
+#[derive(Accounts)]
+pub struct ProposeSubscriptionSplit<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn propose_subscription_split(
+  ctx: Context<ProposeSubscriptionSplit>,
+  platform_bps: u64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  treasury_pool.check_not_council_governed()?;
+  let effective_at = schedule_subscription_split(treasury_pool, platform_bps, current_time)?;
+
+  emit!(SubscriptionSplitProposed {
+    admin: ctx.accounts.admin.key(),
+    current_platform_bps: treasury_pool.subscription_platform_bps,
+    proposed_platform_bps: platform_bps,
+    effective_at,
+    proposed_at: current_time,
+  });
+
+  Ok(())
+}
+
+/// Record a pending split and return when it may be applied
+/// (shared with the council's ProposeSubscriptionSplit)
+pub(crate) fn schedule_subscription_split(
+  treasury_pool: &mut TreasuryPool,
+  platform_bps: u64,
+  current_time: i64,
+) -> Result<i64> {
+  require!(
+    platform_bps <= TreasuryPool::MAX_SUBSCRIPTION_PLATFORM_BPS,
+    ErrorCode::InvalidSubscriptionSplit
+  );
+
+  let effective_at = current_time
+    .checked_add(treasury_pool.timelock_duration)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  treasury_pool.pending_subscription_platform_bps = platform_bps;
+  treasury_pool.subscription_split_effective_at = effective_at;
+  Ok(effective_at)
+}
@@ -3,7 +3,12 @@ use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
 use crate::{
   errors::ErrorCode,
   events::{DebtRepaid, ProgramRentReclaimed},
-  states::{DeployRequest, DeployRequestStatus, ManagedProgram, TreasuryPool},
+  instructions::{auto_fulfill_withdrawal_queue, recover_buffer_rent_loan},
+  states::{
+    BufferRentLoan, DeployRequest, DeployRequestStatus, FeeSource, ManagedProgram, Role,
+    TreasuryPool, UserDeployStats,
+  },
+  utils::vault_transfer,
 };
 
 /// Admin/Cron calls this instruction to close expired programs and recover rent
@@ -12,8 +17,11 @@ use crate::{
 /// Flow:
 /// 1. Validate subscription is expired
 /// 2. Close the program via BPF Loader (PDA signs)
-/// 3. Transfer recovered lamports to treasury
+/// 3. Transfer recovered lamports to treasury (repaying an open buffer rent
+///    loan on the program, then the deployment debt)
 /// 4. Mark managed program as inactive
+/// 5. Fulfill the head of the withdrawal queue with the restored liquidity
+///    (remaining accounts: `[queue_entry, lender_stake, staker]` triples)
 #[derive(Accounts)]
 pub struct ReclaimProgramRent<'info> {
   #[account(
@@ -54,23 +62,36 @@ pub struct ReclaimProgramRent<'info> {
   /// Deploy request - check subscription expiration
   #[account(
         mut,
-        seeds = [DeployRequest::PREFIX_SEED, deploy_request.program_hash.as_ref()],
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
         bump = deploy_request.bump,
     )]
   pub deploy_request: Account<'info, DeployRequest>,
 
-  /// Account to receive recovered lamports (treasury pool PDA)
-  /// CHECK: Validated as treasury pool
+  /// Account to receive recovered lamports (treasury vault PDA)
+  /// CHECK: Validated as treasury vault
   #[account(
         mut,
-        seeds = [TreasuryPool::PREFIX_SEED],
-        bump = treasury_pool.bump
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
     )]
   pub close_recipient: UncheckedAccount<'info>,
 
+  /// CHECK: The program's buffer rent loan PDA (may not exist); an open loan
+  /// is repaid out of the recovered rent
+  #[account(
+        mut,
+        seeds = [BufferRentLoan::PREFIX_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub buffer_rent_loan: UncheckedAccount<'info>,
+
+  /// CHECK: Deployment's developer (receives their prepaid extension rent)
+  #[account(mut, address = deploy_request.developer @ ErrorCode::Unauthorized)]
+  pub developer: UncheckedAccount<'info>,
+
   /// Admin who is reclaiming
   #[account(
-        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+        constraint = treasury_pool.has_role(Role::DeployerOperator, &admin.key()) @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
 
@@ -80,37 +101,44 @@ pub struct ReclaimProgramRent<'info> {
         constraint = bpf_loader_upgradeable_program.key() == bpf_loader_upgradeable::ID
     )]
   pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
-}
 
-pub fn reclaim_program_rent(ctx: Context<ReclaimProgramRent>) -> Result<()> {
-  let treasury_pool = &mut ctx.accounts.treasury_pool;
-  let deploy_request = &mut ctx.accounts.deploy_request;
-  let managed_program = &mut ctx.accounts.managed_program;
-  let current_time = Clock::get()?.unix_timestamp;
+  pub system_program: Program<'info, System>,
 
-  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
-
-  // Validate subscription is expired
-  let is_expired = !deploy_request.is_subscription_valid()?;
-  require!(is_expired, ErrorCode::SubscriptionStillActive);
+  /// Developer's deployment stats (frees the request's quota slot)
+  #[account(
+        mut,
+        seeds = [UserDeployStats::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = user_stats.bump
+    )]
+  pub user_stats: Account<'info, UserDeployStats>,
+}
 
+/// Close a managed program through its authority PDA, sending the program
+/// data rent to `recipient`; returns the lamports released
+pub(crate) fn close_managed_program<'info>(
+  program_account: &AccountInfo<'info>,
+  program_data: &AccountInfo<'info>,
+  authority_pda: &AccountInfo<'info>,
+  recipient: &AccountInfo<'info>,
+  authority_bump: u8,
+) -> Result<u64> {
   // Get current program data lamports before closing
-  let program_data_lamports = ctx.accounts.program_data.lamports();
+  let program_data_lamports = program_data.lamports();
 
   // Build the Close instruction for BPF Loader Upgradeable
   let close_ix = bpf_loader_upgradeable::close_any(
-    &ctx.accounts.program_data.key(),
-    &ctx.accounts.close_recipient.key(),
-    Some(&ctx.accounts.authority_pda.key()),
-    Some(&ctx.accounts.program_account.key()),
+    program_data.key,
+    recipient.key,
+    Some(authority_pda.key),
+    Some(program_account.key),
   );
 
   // Prepare PDA signer seeds
-  let program_key = ctx.accounts.program_account.key();
+  let program_key = program_account.key();
   let seeds = &[
     ManagedProgram::AUTHORITY_SEED,
     program_key.as_ref(),
-    &[ctx.bumps.authority_pda],
+    &[authority_bump],
   ];
   let signer_seeds = &[&seeds[..]];
 
@@ -118,42 +146,71 @@ pub fn reclaim_program_rent(ctx: Context<ReclaimProgramRent>) -> Result<()> {
   anchor_lang::solana_program::program::invoke_signed(
     &close_ix,
     &[
-      ctx.accounts.program_data.to_account_info(),
-      ctx.accounts.close_recipient.to_account_info(),
-      ctx.accounts.authority_pda.to_account_info(),
-      ctx.accounts.program_account.to_account_info(),
+      program_data.clone(),
+      recipient.clone(),
+      authority_pda.clone(),
+      program_account.clone(),
     ],
     signer_seeds,
   )?;
 
-  // Update states
-  managed_program.is_active = false;
-  deploy_request.status = DeployRequestStatus::Closed;
+  Ok(program_data_lamports)
+}
+
+/// Apply reclaimed program rent to the request's debt; the excess is
+/// credited to stakers. Emits DebtRepaid.
+///
+/// The developer's prepaid extension rent is carved out first and returned
+/// to `developer` from the vault that received the rent; an open buffer rent
+/// loan on the program is repaid next.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn settle_rent_recovery<'info>(
+  treasury_pool: &mut TreasuryPool,
+  deploy_request: &mut DeployRequest,
+  recovered_lamports: u64,
+  treasury_vault: &AccountInfo<'info>,
+  buffer_rent_loan: &AccountInfo<'info>,
+  developer: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+  current_time: i64,
+) -> Result<u64> {
+  require!(
+    developer.key() == deploy_request.developer,
+    ErrorCode::Unauthorized
+  );
+  let extension_rent_refund = deploy_request.take_extension_rent_refund(recovered_lamports);
+  if extension_rent_refund > 0 {
+    vault_transfer(
+      system_program,
+      treasury_vault,
+      developer,
+      extension_rent_refund,
+      treasury_pool.vault_bump,
+    )?;
+  }
+  let recovered_lamports = recovered_lamports - extension_rent_refund;
+  let loan_repayment = recover_buffer_rent_loan(
+    treasury_pool,
+    buffer_rent_loan,
+    developer,
+    recovered_lamports,
+  )?;
+  let recovered_lamports = recovered_lamports - loan_repayment;
 
-  // === DEBT REPAYMENT LOGIC ===
   // Record rent recovery in deploy_request (tracks per-deployment debt)
   let remaining_debt = deploy_request.get_remaining_debt();
-  let (debt_repayment, excess_to_rewards) =
-    deploy_request.record_rent_recovery(program_data_lamports)?;
+  let (_debt_repayment, excess_to_rewards) =
+    deploy_request.record_rent_recovery(recovered_lamports)?;
 
   // Record debt repayment in treasury pool (tracks global debt)
   // This also restores liquid_balance for the debt_repayment portion
-  treasury_pool.record_debt_repayment(program_data_lamports, remaining_debt)?;
+  treasury_pool.record_debt_repayment(recovered_lamports, remaining_debt, current_time)?;
 
   // If there's excess beyond debt repayment, credit it to reward pool for stakers
   if excess_to_rewards > 0 {
-    treasury_pool.credit_fee_to_pool(excess_to_rewards, 0)?;
+    treasury_pool.collect_fee(FeeSource::RentRecovery, excess_to_rewards, 0)?;
   }
 
-  // Emit events
-  emit!(ProgramRentReclaimed {
-    program_id: ctx.accounts.program_account.key(),
-    developer: managed_program.developer,
-    lamports_recovered: program_data_lamports,
-    reclaimed_at: current_time,
-  });
-
-  // Emit debt repayment event
   emit!(DebtRepaid {
     deploy_request_id: deploy_request.request_id,
     developer: deploy_request.developer,
@@ -164,5 +221,66 @@ pub fn reclaim_program_rent(ctx: Context<ReclaimProgramRent>) -> Result<()> {
     repaid_at: current_time,
   });
 
+  Ok(extension_rent_refund)
+}
+
+pub fn reclaim_program_rent<'info>(
+  ctx: Context<'_, '_, '_, 'info, ReclaimProgramRent<'info>>,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let managed_program = &mut ctx.accounts.managed_program;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+
+  // Validate subscription is expired
+  let is_expired = !deploy_request.is_subscription_valid()?;
+  require!(is_expired, ErrorCode::SubscriptionStillActive);
+
+  let program_data_lamports = close_managed_program(
+    &ctx.accounts.program_account.to_account_info(),
+    &ctx.accounts.program_data.to_account_info(),
+    &ctx.accounts.authority_pda.to_account_info(),
+    &ctx.accounts.close_recipient.to_account_info(),
+    ctx.bumps.authority_pda,
+  )?;
+
+  // Update states
+  managed_program.is_active = false;
+  ctx.accounts.user_stats.release_slot(&deploy_request.status);
+  deploy_request.status = DeployRequestStatus::Closed;
+
+  // === DEBT REPAYMENT LOGIC ===
+  let extension_rent_refunded = settle_rent_recovery(
+    treasury_pool,
+    deploy_request,
+    program_data_lamports,
+    &ctx.accounts.close_recipient.to_account_info(),
+    &ctx.accounts.buffer_rent_loan.to_account_info(),
+    &ctx.accounts.developer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    current_time,
+  )?;
+
+  // Debt repayment restored liquid_balance - fulfill queued withdrawals first
+  auto_fulfill_withdrawal_queue(
+    treasury_pool,
+    &ctx.accounts.close_recipient.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    ctx.remaining_accounts,
+    &[],
+    ctx.program_id,
+    current_time,
+  )?;
+
+  emit!(ProgramRentReclaimed {
+    program_id: ctx.accounts.program_account.key(),
+    developer: managed_program.developer,
+    lamports_recovered: program_data_lamports,
+    extension_rent_refunded,
+    reclaimed_at: current_time,
+  });
+
   Ok(())
 }
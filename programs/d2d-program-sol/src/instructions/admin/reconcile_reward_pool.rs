@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::RewardPoolReconciled,
+  states::{Role, TreasuryPool},
+  utils::pda_payable_lamports,
+};
+
+#[derive(Accounts)]
+pub struct ReconcileRewardPool<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Reward Pool PDA (only its lamports are read)
+  #[account(
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  #[account(
+        constraint = treasury_pool.has_role(Role::Treasurer, &admin.key()) @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Compare the reward pool's lamports with its recorded obligations
+///
+/// Always reports the drift. With `write_down` set, a shortfall is fixed by
+/// writing reward_pool_balance down to the lamports actually held; a surplus
+/// is only reported.
+pub fn reconcile_reward_pool(ctx: Context<ReconcileRewardPool>, write_down: bool) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  let payable_lamports = pda_payable_lamports(&ctx.accounts.reward_pool.to_account_info())?;
+  let obligations = treasury_pool.get_reward_pool_obligations()?;
+  let shortfall = obligations.saturating_sub(payable_lamports);
+  let surplus = payable_lamports.saturating_sub(obligations);
+
+  let written_off = if write_down {
+    treasury_pool.write_down_reward_pool(payable_lamports)?
+  } else {
+    0
+  };
+
+  emit!(RewardPoolReconciled {
+    admin: ctx.accounts.admin.key(),
+    payable_lamports,
+    obligations,
+    shortfall,
+    surplus,
+    written_off,
+    reward_pool_balance: treasury_pool.reward_pool_balance,
+    pending_undistributed_rewards: treasury_pool.pending_undistributed_rewards,
+    reconciled_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
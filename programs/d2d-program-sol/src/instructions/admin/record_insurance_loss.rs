@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::InsuranceLossRecorded,
+  states::{DeployRequest, DeployRequestStatus, InsuranceFund, InsuranceLossEvent, TreasuryPool},
+};
+
+/// Record a loss event for a closed deployment whose rent recovery fell
+/// below the insurance loss threshold
+///
+/// The unrecovered debt (capped by the fund's available balance) is committed
+/// pro-rata to covered stakers. The loss event PDA makes this one-shot per
+/// deploy request.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct RecordInsuranceLoss<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump = insurance_fund.bump
+    )]
+  pub insurance_fund: Account<'info, InsuranceFund>,
+
+  /// CHECK: Deploy request - validated manually for migration compatibility
+  pub deploy_request: UncheckedAccount<'info>,
+
+  #[account(
+        init,
+        payer = admin,
+        space = 8 + InsuranceLossEvent::INIT_SPACE,
+        seeds = [InsuranceLossEvent::PREFIX_SEED, request_id.as_ref()],
+        bump
+    )]
+  pub loss_event: Account<'info, InsuranceLossEvent>,
+
+  #[account(
+        mut,
+        constraint = treasury_pool.is_admin(&admin.key()) @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn record_insurance_loss(
+  ctx: Context<RecordInsuranceLoss>,
+  request_id: [u8; 32],
+) -> Result<()> {
+  let insurance_fund = &mut ctx.accounts.insurance_fund;
+  let loss_event = &mut ctx.accounts.loss_event;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  let deploy_request = DeployRequest::load_padded(&ctx.accounts.deploy_request.to_account_info())?;
+  require!(
+    deploy_request.request_id == request_id,
+    ErrorCode::InvalidRequestId
+  );
+
+  // Only closed deployments have a final recovery ratio
+  require!(
+    deploy_request.status == DeployRequestStatus::Closed,
+    ErrorCode::InvalidRequestStatus
+  );
+  require!(
+    deploy_request.recovery_ratio_bps < insurance_fund.loss_threshold_bps,
+    ErrorCode::LossThresholdNotMet
+  );
+
+  let loss_amount = deploy_request.get_remaining_debt();
+  require!(loss_amount > 0, ErrorCode::LossThresholdNotMet);
+
+  let compensation_committed = insurance_fund.commit_loss(loss_amount)?;
+
+  loss_event.request_id = request_id;
+  loss_event.loss_amount = loss_amount;
+  loss_event.compensation_committed = compensation_committed;
+  loss_event.recovery_ratio_bps = deploy_request.recovery_ratio_bps;
+  loss_event.recorded_at = current_time;
+  loss_event.bump = ctx.bumps.loss_event;
+
+  emit!(InsuranceLossRecorded {
+    request_id,
+    loss_amount,
+    compensation_committed,
+    recovery_ratio_bps: deploy_request.recovery_ratio_bps,
+    total_covered: insurance_fund.total_covered,
+    recorded_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,152 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+  errors::ErrorCode,
+  events::LstVaultRedeemed,
+  instructions::auto_fulfill_withdrawal_queue,
+  states::{LstConfig, TreasuryPool},
+};
+
+/// Convert LST vault tokens into treasury liquidity
+///
+/// The admin takes `token_amount` out of the vault (to unstake/swap them
+/// off-chain) and in the same transaction deposits `sol_amount` lamports into
+/// the treasury. `sol_amount` must cover the tokens' value at the current
+/// rate less LstConfig::MAX_REDEMPTION_SLIPPAGE_BPS. The SOL becomes liquid
+/// balance and the tokens' value is no longer tracked as LST-backed.
+///
+/// Remaining accounts (optional): `[queue_entry, lender_stake, staker]` triples
+/// from the withdrawal queue head, fulfilled with the new liquidity
+#[derive(Accounts)]
+pub struct RedeemLstVault<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Treasury vault PDA (receives the redeemed SOL)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [LstConfig::PREFIX_SEED, lst_config.mint.as_ref()],
+        bump = lst_config.bump
+    )]
+  pub lst_config: Account<'info, LstConfig>,
+
+  #[account(
+        mut,
+        address = lst_config.vault @ ErrorCode::InvalidAccountData
+    )]
+  pub lst_vault: Account<'info, TokenAccount>,
+
+  #[account(
+        mut,
+        token::mint = lst_config.mint
+    )]
+  pub admin_token_account: Account<'info, TokenAccount>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn redeem_lst_vault<'info>(
+  ctx: Context<'_, '_, '_, 'info, RedeemLstVault<'info>>,
+  token_amount: u64,
+  sol_amount: u64,
+) -> Result<()> {
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lst_config = &mut ctx.accounts.lst_config;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  treasury_pool.check_not_council_governed()?;
+  // Tokens leave the vault on a single key, so decentralization retires it
+  require!(
+    !treasury_pool.is_decentralized(),
+    ErrorCode::AdminPathRetired
+  );
+  require!(token_amount > 0 && sol_amount > 0, ErrorCode::InvalidAmount);
+  require!(
+    token_amount <= lst_config.vault_balance,
+    ErrorCode::InsufficientLstVaultBalance
+  );
+  let redeemed_value = lst_config.calculate_sol_value(token_amount)?;
+  require!(
+    sol_amount >= lst_config.calculate_min_redemption(token_amount)?,
+    ErrorCode::RedemptionBelowValue
+  );
+
+  let mint = lst_config.mint;
+  let seeds = &[LstConfig::PREFIX_SEED, mint.as_ref(), &[lst_config.bump]];
+  let signer_seeds = &[&seeds[..]];
+  token::transfer(
+    CpiContext::new_with_signer(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.lst_vault.to_account_info(),
+        to: ctx.accounts.admin_token_account.to_account_info(),
+        authority: lst_config.to_account_info(),
+      },
+      signer_seeds,
+    ),
+    token_amount,
+  )?;
+
+  system_program::transfer(
+    CpiContext::new(
+      ctx.accounts.system_program.to_account_info(),
+      system_program::Transfer {
+        from: ctx.accounts.admin.to_account_info(),
+        to: treasury_vault_info.clone(),
+      },
+    ),
+    sol_amount,
+  )?;
+
+  lst_config.vault_balance -= token_amount;
+  let (previous_value, current_value) = lst_config.revalue_vault()?;
+  treasury_pool.update_normalized_token_value(previous_value, current_value)?;
+  treasury_pool.lst_backed_deposits = treasury_pool
+    .lst_backed_deposits
+    .saturating_sub(redeemed_value);
+  treasury_pool.liquid_balance = treasury_pool
+    .liquid_balance
+    .checked_add(sol_amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  auto_fulfill_withdrawal_queue(
+    treasury_pool,
+    &treasury_vault_info,
+    &ctx.accounts.system_program.to_account_info(),
+    ctx.remaining_accounts,
+    &[],
+    ctx.program_id,
+    current_time,
+  )?;
+
+  emit!(LstVaultRedeemed {
+    admin: ctx.accounts.admin.key(),
+    mint,
+    token_amount,
+    sol_amount,
+    lst_backed_deposits: treasury_pool.lst_backed_deposits,
+    redeemed_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::CollateralPartnerRegistered,
+  states::{CollateralPartner, TreasuryPool},
+};
+
+/// Register (or enable/disable) a partner lending program that may take stake
+/// positions as collateral
+#[derive(Accounts)]
+#[instruction(partner_program: Pubkey)]
+pub struct RegisterCollateralPartner<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CollateralPartner::INIT_SPACE,
+        seeds = [CollateralPartner::PREFIX_SEED, partner_program.as_ref()],
+        bump
+    )]
+  pub collateral_partner: Account<'info, CollateralPartner>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn register_collateral_partner(
+  ctx: Context<RegisterCollateralPartner>,
+  partner_program: Pubkey,
+  enabled: bool,
+) -> Result<()> {
+  let collateral_partner = &mut ctx.accounts.collateral_partner;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  if collateral_partner.program_id == Pubkey::default() {
+    collateral_partner.program_id = partner_program;
+    collateral_partner.authority = CollateralPartner::derive_authority(&partner_program);
+    collateral_partner.registered_at = current_time;
+    collateral_partner.bump = ctx.bumps.collateral_partner;
+  }
+  collateral_partner.enabled = enabled;
+
+  emit!(CollateralPartnerRegistered {
+    admin: ctx.accounts.admin.key(),
+    partner_program,
+    authority: collateral_partner.authority,
+    enabled,
+    registered_at: current_time,
+  });
+
+  Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{events::TreasuryInitialized, states::TreasuryPool};
+use crate::{errors::ErrorCode, events::TreasuryInitialized, states::TreasuryPool};
 
 #[derive(Accounts)]
 pub struct ReinitializeTreasuryPool<'info> {
@@ -49,6 +49,22 @@ pub fn reinitialize_treasury_pool(
   let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
   let required_space = 8 + TreasuryPool::INIT_SPACE;
 
+  // Decentralization permanently retires this path
+  require!(
+    TreasuryPool::read_decentralized_at(&treasury_pool_info.data.borrow()) == 0,
+    ErrorCode::AdminPathRetired
+  );
+  // Wiping the pool would also drop the admin council's control
+  require!(
+    !TreasuryPool::read_admin_council_enabled(&treasury_pool_info.data.borrow()),
+    ErrorCode::CouncilApprovalRequired
+  );
+  // Wiping a paused pool would silently lift the pause
+  require!(
+    !TreasuryPool::read_pause_state(&treasury_pool_info.data.borrow()).0,
+    ErrorCode::ProgramPaused
+  );
+
   let current_space = treasury_pool_info.data_len();
   if current_space < required_space {
     treasury_pool_info.resize(required_space)?;
@@ -96,6 +112,109 @@ pub fn reinitialize_treasury_pool(
     base_apy_bps: TreasuryPool::DEFAULT_BASE_APY_BPS,
     max_apy_multiplier_bps: TreasuryPool::DEFAULT_MAX_APY_MULTIPLIER_BPS,
     target_utilization_bps: TreasuryPool::DEFAULT_TARGET_UTILIZATION_BPS,
+    // Keeper incentive fields
+    keeper_tip_bps: TreasuryPool::DEFAULT_KEEPER_TIP_BPS,
+    // Donation accounting fields
+    donation_policy: TreasuryPool::DONATION_POLICY_REWARDS,
+    // Emergency exit policy fields
+    emergency_exit_policy: TreasuryPool::EMERGENCY_EXIT_RESPECT_QUEUE,
+    queue_jump_fee_bps: TreasuryPool::DEFAULT_QUEUE_JUMP_FEE_BPS,
+    // Pause policy fields
+    allow_emergency_exit_during_pause: TreasuryPool::DEFAULT_ALLOW_EMERGENCY_EXIT_DURING_PAUSE,
+    // Queue expiry fields
+    max_queue_age: TreasuryPool::DEFAULT_MAX_QUEUE_AGE,
+    // Off-boarding fields
+    offboarding_policy: TreasuryPool::OFFBOARDING_POLICY_CLOSE,
+    // Buffer rent loan fields
+    max_buffer_rent_loan: TreasuryPool::DEFAULT_MAX_BUFFER_RENT_LOAN,
+    outstanding_buffer_rent_loans: 0,
+    // Subscription revenue split fields
+    subscription_platform_bps: TreasuryPool::DEFAULT_SUBSCRIPTION_PLATFORM_BPS,
+    pending_subscription_platform_bps: 0,
+    subscription_split_effective_at: 0,
+    // Stake limit fields
+    max_stake_per_tx: TreasuryPool::DEFAULT_MAX_STAKE_PER_TX,
+    daily_stake_limit: TreasuryPool::DEFAULT_DAILY_STAKE_LIMIT,
+    // Service fee refund policy fields
+    service_fee_refund_bps: TreasuryPool::DEFAULT_SERVICE_FEE_REFUND_BPS,
+    service_fee_refund_window: TreasuryPool::DEFAULT_SERVICE_FEE_REFUND_WINDOW,
+    // Bonus vesting fields
+    bonus_vesting_period: TreasuryPool::DEFAULT_BONUS_VESTING_PERIOD,
+    total_vesting_bonus: 0,
+    // Terms of service fields
+    terms_hash: [0u8; 32],
+    terms_updated_at: 0,
+    // Request parameter bounds fields
+    min_service_fee: 0,
+    max_service_fee: 0,
+    min_monthly_fee: 0,
+    max_monthly_fee: 0,
+    min_initial_months: TreasuryPool::DEFAULT_MIN_INITIAL_MONTHS,
+    max_initial_months: TreasuryPool::DEFAULT_MAX_INITIAL_MONTHS,
+    // Emergency haircut fields
+    emergency_haircut_bps: TreasuryPool::DEFAULT_EMERGENCY_HAIRCUT_BPS,
+    // Funding reconciliation fields
+    funding_variance_tolerance: TreasuryPool::DEFAULT_FUNDING_VARIANCE_TOLERANCE,
+    // Developer payment escrow fields
+    total_escrowed_payments: 0,
+    // LST deposit fields
+    lst_backed_deposits: 0,
+    // Multi-asset reporting fields
+    normalized_token_value: 0,
+    // Watchtower fields
+    watchtower_bounty: TreasuryPool::DEFAULT_WATCHTOWER_BOUNTY,
+    watchtower_alert_cooldown: TreasuryPool::DEFAULT_WATCHTOWER_ALERT_COOLDOWN,
+    last_alert_paid_at: [0; 3],
+    // Pool share cap fields
+    max_pool_share_bps: TreasuryPool::DEFAULT_MAX_POOL_SHARE_BPS,
+    // Large unstake commitment fields
+    large_unstake_threshold_bps: TreasuryPool::DEFAULT_LARGE_UNSTAKE_THRESHOLD_BPS,
+    unstake_commit_delay: TreasuryPool::DEFAULT_UNSTAKE_COMMIT_DELAY,
+    committed_unstake_amount: 0,
+    // Treasury vault fields
+    vault_bump: Pubkey::find_program_address(&[TreasuryPool::VAULT_SEED], ctx.program_id).1,
+    // Deposit fee fields
+    deposit_fees_enabled: false,
+    // Recovery rate fields
+    recovery_rate_per_day: 0,
+    last_recovery_at: 0,
+    // Developer identity fields
+    identity_registrar: Pubkey::default(),
+    verified_max_buffer_rent_loan: 0,
+    // Boost NFT fields
+    boost_nft_collection: Pubkey::default(),
+    nft_boost_bps: 0,
+    // Stablecoin subscription fields
+    subscription_stablecoin_mint: Pubkey::default(),
+    subscription_stablecoin_vault: Pubkey::default(),
+    total_stablecoin_subscription_revenue: 0,
+    // Decentralization fields
+    decentralized_at: 0,
+    // Escrow token fields
+    escrow_usdc_mint: Pubkey::default(),
+    escrow_usdt_mint: Pubkey::default(),
+    // Escrow renewal fields
+    permissionless_renewal_window: 0,
+    // Authority buy-out fields
+    authority_exit_fee_bps: 0,
+    prepay_discounts: Default::default(),
+    // Developer quota fields
+    max_active_deployments_per_developer: 0,
+    // Expiry warning fields
+    expiry_warning_window: 0,
+    expiry_warning_interval: 0,
+    // Operational role fields
+    pauser: Pubkey::default(),
+    treasurer: Pubkey::default(),
+    deployer_operator: Pubkey::default(),
+    queue_processor: Pubkey::default(),
+    // Admin council fields
+    admin_council_enabled: false,
+    // Scheduled unpause fields
+    unpause_scheduled_at: 0,
+    unpause_executable_at: 0,
+    // Queue jump fee fields
+    queue_fee_per_share: 0,
   };
 
   treasury_pool.try_serialize(&mut &mut data[..])?;
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::UnpauseScheduled, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct ScheduleUnpause<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Schedule resuming a paused program after the timelock duration
+///
+/// The admin or guardian may veto until execute_unpause runs; pausing again
+/// also cancels the schedule.
+pub fn schedule_unpause(ctx: Context<ScheduleUnpause>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  let executable_at = treasury_pool.schedule_unpause(current_time)?;
+
+  emit!(UnpauseScheduled {
+    admin: ctx.accounts.admin.key(),
+    executable_at,
+    scheduled_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::ApprovedAuthoritySet,
+  states::{ApprovedAuthority, TreasuryPool},
+};
+
+/// Add (or enable/disable) an address managed programs may hand their upgrade
+/// authority to through propose/execute_authority_transfer
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct SetApprovedAuthority<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ApprovedAuthority::INIT_SPACE,
+        seeds = [ApprovedAuthority::PREFIX_SEED, authority.as_ref()],
+        bump
+    )]
+  pub approved_authority: Account<'info, ApprovedAuthority>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn set_approved_authority(
+  ctx: Context<SetApprovedAuthority>,
+  authority: Pubkey,
+  enabled: bool,
+) -> Result<()> {
+  require!(
+    authority != Pubkey::default(),
+    ErrorCode::InvalidAccountData
+  );
+
+  let approved_authority = &mut ctx.accounts.approved_authority;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  if approved_authority.authority == Pubkey::default() {
+    approved_authority.authority = authority;
+    approved_authority.approved_at = current_time;
+    approved_authority.bump = ctx.bumps.approved_authority;
+  }
+  approved_authority.enabled = enabled;
+
+  emit!(ApprovedAuthoritySet {
+    admin: ctx.accounts.admin.key(),
+    authority,
+    enabled,
+    set_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::AuthorityExitFeeChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetAuthorityExitFee<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the exit fee (bps of the borrowed amount) charged by reclaim_authority
+pub fn set_authority_exit_fee(ctx: Context<SetAuthorityExitFee>, new_fee_bps: u64) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
+
+  require!(
+    new_fee_bps <= TreasuryPool::MAX_AUTHORITY_EXIT_FEE_BPS,
+    ErrorCode::InvalidAuthorityExitFee
+  );
+
+  let old_fee_bps = treasury_pool.authority_exit_fee_bps;
+  treasury_pool.authority_exit_fee_bps = new_fee_bps;
+
+  emit!(AuthorityExitFeeChanged {
+    admin: ctx.accounts.admin.key(),
+    old_fee_bps,
+    new_fee_bps,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
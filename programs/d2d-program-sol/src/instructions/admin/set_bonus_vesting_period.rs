@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::BonusVestingPeriodChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetBonusVestingPeriod<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the linear vesting period for duration bonuses (0 = paid in full on claim)
+/// Bonuses already vesting keep their schedule
+pub fn set_bonus_vesting_period(
+  ctx: Context<SetBonusVestingPeriod>,
+  vesting_period: i64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    (0..=TreasuryPool::MAX_BONUS_VESTING_PERIOD).contains(&vesting_period),
+    ErrorCode::InvalidBonusVestingPeriod
+  );
+
+  let old_period = treasury_pool.bonus_vesting_period;
+  treasury_pool.bonus_vesting_period = vesting_period;
+
+  emit!(BonusVestingPeriodChanged {
+    admin: ctx.accounts.admin.key(),
+    old_period,
+    new_period: vesting_period,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
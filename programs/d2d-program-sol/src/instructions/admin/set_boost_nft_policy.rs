@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::BoostNftPolicyChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetBoostNftPolicy<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the boost NFT collection (default pubkey = disabled) and the duration
+/// weight boost granted to positions that present one
+///
+/// Positions keep the boost recorded when they were staked; a change only
+/// applies to NFTs presented afterwards.
+pub fn set_boost_nft_policy(
+  ctx: Context<SetBoostNftPolicy>,
+  new_collection: Pubkey,
+  new_boost_bps: u64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    new_boost_bps <= TreasuryPool::MAX_NFT_BOOST_BPS,
+    ErrorCode::InvalidNftBoost
+  );
+
+  let old_collection = treasury_pool.boost_nft_collection;
+  let old_boost_bps = treasury_pool.nft_boost_bps;
+  treasury_pool.boost_nft_collection = new_collection;
+  treasury_pool.nft_boost_bps = new_boost_bps;
+
+  emit!(BoostNftPolicyChanged {
+    admin: ctx.accounts.admin.key(),
+    old_collection,
+    new_collection,
+    old_boost_bps,
+    new_boost_bps,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::CouponChanged,
+  states::{Coupon, TreasuryPool},
+};
+
+/// Create or update a promo code coupon
+///
+/// The coupon is addressed by the SHA-256 hash of its code (see
+/// Coupon::hash_code). Setting `is_active` to
+/// false withdraws it; the redemption count is kept across updates.
+#[derive(Accounts)]
+#[instruction(code_hash: [u8; 32])]
+pub struct SetCoupon<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Coupon::INIT_SPACE,
+        seeds = [Coupon::PREFIX_SEED, code_hash.as_ref()],
+        bump
+    )]
+  pub coupon: Account<'info, Coupon>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn set_coupon(
+  ctx: Context<SetCoupon>,
+  code_hash: [u8; 32],
+  discount_bps: u64,
+  expires_at: i64,
+  max_uses: u32,
+  is_active: bool,
+) -> Result<()> {
+  let coupon = &mut ctx.accounts.coupon;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  ctx.accounts.treasury_pool.check_not_council_governed()?;
+  write_coupon(
+    coupon,
+    code_hash,
+    discount_bps,
+    expires_at,
+    max_uses,
+    is_active,
+    current_time,
+  )?;
+  coupon.bump = ctx.bumps.coupon;
+
+  emit!(CouponChanged {
+    admin: ctx.accounts.admin.key(),
+    code_hash,
+    discount_bps,
+    expires_at,
+    max_uses,
+    is_active,
+    uses: coupon.uses,
+    changed_at: current_time,
+  });
+
+  Ok(())
+}
+
+/// Validate and store coupon terms, keeping the redemption count (shared
+/// with the council's SetCoupon)
+pub(crate) fn write_coupon(
+  coupon: &mut Coupon,
+  code_hash: [u8; 32],
+  discount_bps: u64,
+  expires_at: i64,
+  max_uses: u32,
+  is_active: bool,
+  current_time: i64,
+) -> Result<()> {
+  require!(
+    discount_bps > 0 && discount_bps <= Coupon::MAX_DISCOUNT_BPS,
+    ErrorCode::InvalidCoupon
+  );
+
+  coupon.code_hash = code_hash;
+  coupon.discount_bps = discount_bps;
+  coupon.expires_at = expires_at;
+  coupon.max_uses = max_uses;
+  coupon.is_active = is_active;
+  coupon.updated_at = current_time;
+  Ok(())
+}
@@ -19,6 +19,7 @@ pub struct SetDailyLimit<'info> {
 
 pub fn set_daily_limit(ctx: Context<SetDailyLimit>, new_limit: u64) -> Result<()> {
   let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
 
   let old_limit = treasury_pool.daily_withdrawal_limit;
   treasury_pool.daily_withdrawal_limit = new_limit;
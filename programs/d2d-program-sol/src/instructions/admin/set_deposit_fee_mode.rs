@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::DepositFeeModeChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetDepositFeeMode<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Turn the stake_sol deposit fee on or off (off by default)
+pub fn set_deposit_fee_mode(ctx: Context<SetDepositFeeMode>, enabled: bool) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
+
+  treasury_pool.deposit_fees_enabled = enabled;
+
+  emit!(DepositFeeModeChanged {
+    admin: ctx.accounts.admin.key(),
+    deposit_fees_enabled: enabled,
+    reward_fee_bps: TreasuryPool::REWARD_FEE_BPS,
+    platform_fee_bps: TreasuryPool::PLATFORM_FEE_BPS,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
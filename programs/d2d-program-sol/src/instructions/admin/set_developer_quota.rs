@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::DeveloperQuotaChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetDeveloperQuota<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the most pending plus live deployments one developer may hold (0 = unlimited)
+///
+/// Lowering the quota does not affect existing deployments; it only blocks
+/// new requests until the developer is back under the limit.
+pub fn set_developer_quota(
+  ctx: Context<SetDeveloperQuota>,
+  max_active_deployments: u32,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  let old_max_active_deployments = treasury_pool.max_active_deployments_per_developer;
+  treasury_pool.max_active_deployments_per_developer = max_active_deployments;
+
+  emit!(DeveloperQuotaChanged {
+    admin: ctx.accounts.admin.key(),
+    old_max_active_deployments,
+    new_max_active_deployments: max_active_deployments,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
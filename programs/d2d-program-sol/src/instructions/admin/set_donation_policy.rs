@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::DonationPolicyChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetDonationPolicy<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn set_donation_policy(ctx: Context<SetDonationPolicy>, new_policy: u8) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    TreasuryPool::is_valid_donation_policy(new_policy),
+    ErrorCode::InvalidDonationPolicy
+  );
+
+  let old_policy = treasury_pool.donation_policy;
+  treasury_pool.donation_policy = new_policy;
+
+  emit!(DonationPolicyChanged {
+    admin: ctx.accounts.admin.key(),
+    old_policy,
+    new_policy,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::EmergencyExitPausePolicyChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetEmergencyExitDuringPause<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn set_emergency_exit_during_pause(
+  ctx: Context<SetEmergencyExitDuringPause>,
+  allow: bool,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  treasury_pool.allow_emergency_exit_during_pause = allow;
+
+  emit!(EmergencyExitPausePolicyChanged {
+    admin: ctx.accounts.admin.key(),
+    allow_emergency_exit_during_pause: allow,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
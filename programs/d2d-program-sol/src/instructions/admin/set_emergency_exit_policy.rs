@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::EmergencyExitPolicyChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetEmergencyExitPolicy<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn set_emergency_exit_policy(
+  ctx: Context<SetEmergencyExitPolicy>,
+  new_policy: u8,
+  queue_jump_fee_bps: u64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
+
+  let old_policy = write_emergency_exit_policy(treasury_pool, new_policy, queue_jump_fee_bps)?;
+
+  emit!(EmergencyExitPolicyChanged {
+    admin: ctx.accounts.admin.key(),
+    old_policy,
+    new_policy,
+    queue_jump_fee_bps,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+/// Validate and store the emergency exit policy and queue jump fee,
+/// returning the old policy (shared with the council's SetEmergencyExitPolicy)
+pub(crate) fn write_emergency_exit_policy(
+  treasury_pool: &mut TreasuryPool,
+  new_policy: u8,
+  queue_jump_fee_bps: u64,
+) -> Result<u8> {
+  require!(
+    TreasuryPool::is_valid_emergency_exit_policy(new_policy),
+    ErrorCode::InvalidEmergencyExitPolicy
+  );
+  require!(
+    queue_jump_fee_bps <= TreasuryPool::MAX_QUEUE_JUMP_FEE_BPS,
+    ErrorCode::InvalidQueueJumpFee
+  );
+
+  let old_policy = treasury_pool.emergency_exit_policy;
+  treasury_pool.emergency_exit_policy = new_policy;
+  treasury_pool.queue_jump_fee_bps = queue_jump_fee_bps;
+  Ok(old_policy)
+}
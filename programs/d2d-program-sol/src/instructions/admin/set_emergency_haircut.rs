@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::EmergencyHaircutChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetEmergencyHaircut<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the haircut charged on emergency_unstake (0 disables it)
+pub fn set_emergency_haircut(ctx: Context<SetEmergencyHaircut>, haircut_bps: u64) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
+
+  require!(
+    haircut_bps <= TreasuryPool::MAX_EMERGENCY_HAIRCUT_BPS,
+    ErrorCode::InvalidEmergencyHaircut
+  );
+
+  let old_haircut_bps = treasury_pool.emergency_haircut_bps;
+  treasury_pool.emergency_haircut_bps = haircut_bps;
+
+  emit!(EmergencyHaircutChanged {
+    admin: ctx.accounts.admin.key(),
+    old_haircut_bps,
+    new_haircut_bps: haircut_bps,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::EscrowTokenMintsChanged, states::TreasuryPool};
+
+/// Configure the USDC/USDT mints accepted by deposit_escrow_token
+///
+/// Escrow token balances are tracked per token type, not per mint, so a
+/// configured mint is permanent: each mint can only be set while unset.
+/// Pass the current value (or default) to leave a mint unchanged.
+#[derive(Accounts)]
+pub struct SetEscrowTokenMints<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn set_escrow_token_mints(
+  ctx: Context<SetEscrowTokenMints>,
+  usdc_mint: Pubkey,
+  usdt_mint: Pubkey,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  let old_usdc_mint = treasury_pool.escrow_usdc_mint;
+  let old_usdt_mint = treasury_pool.escrow_usdt_mint;
+  let new_usdc_mint = if usdc_mint == Pubkey::default() {
+    old_usdc_mint
+  } else {
+    usdc_mint
+  };
+  let new_usdt_mint = if usdt_mint == Pubkey::default() {
+    old_usdt_mint
+  } else {
+    usdt_mint
+  };
+
+  require!(
+    old_usdc_mint == Pubkey::default() || new_usdc_mint == old_usdc_mint,
+    ErrorCode::InvalidEscrowMints
+  );
+  require!(
+    old_usdt_mint == Pubkey::default() || new_usdt_mint == old_usdt_mint,
+    ErrorCode::InvalidEscrowMints
+  );
+  require!(
+    new_usdc_mint == Pubkey::default() || new_usdc_mint != new_usdt_mint,
+    ErrorCode::InvalidEscrowMints
+  );
+
+  treasury_pool.escrow_usdc_mint = new_usdc_mint;
+  treasury_pool.escrow_usdt_mint = new_usdt_mint;
+
+  emit!(EscrowTokenMintsChanged {
+    admin: ctx.accounts.admin.key(),
+    old_usdc_mint,
+    new_usdc_mint,
+    old_usdt_mint,
+    new_usdt_mint,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
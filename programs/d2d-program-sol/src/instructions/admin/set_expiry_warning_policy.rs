@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::ExpiryWarningPolicyChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetExpiryWarningPolicy<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set how close to expiry emit_expiry_warning may fire (seconds, 0 = disabled)
+/// and the minimum spacing between warnings for one subscription
+pub fn set_expiry_warning_policy(
+  ctx: Context<SetExpiryWarningPolicy>,
+  new_window: i64,
+  new_interval: i64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    new_window == 0
+      || ((1..=TreasuryPool::MAX_EXPIRY_WARNING_WINDOW).contains(&new_window)
+        && new_interval >= TreasuryPool::MIN_EXPIRY_WARNING_INTERVAL),
+    ErrorCode::InvalidExpiryWarningPolicy
+  );
+
+  let old_window = treasury_pool.expiry_warning_window;
+  let old_interval = treasury_pool.expiry_warning_interval;
+  treasury_pool.expiry_warning_window = new_window;
+  treasury_pool.expiry_warning_interval = new_interval;
+
+  emit!(ExpiryWarningPolicyChanged {
+    admin: ctx.accounts.admin.key(),
+    old_window,
+    new_window,
+    old_interval,
+    new_interval,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
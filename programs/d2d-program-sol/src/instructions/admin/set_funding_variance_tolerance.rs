@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::FundingVarianceToleranceChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetFundingVarianceTolerance<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set how far recovered + spent may differ from the funded amount at confirmation
+pub fn set_funding_variance_tolerance(
+  ctx: Context<SetFundingVarianceTolerance>,
+  tolerance: u64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    tolerance <= TreasuryPool::MAX_FUNDING_VARIANCE_TOLERANCE,
+    ErrorCode::InvalidFundingVarianceTolerance
+  );
+
+  let old_tolerance = treasury_pool.funding_variance_tolerance;
+  treasury_pool.funding_variance_tolerance = tolerance;
+
+  emit!(FundingVarianceToleranceChanged {
+    admin: ctx.accounts.admin.key(),
+    old_tolerance,
+    new_tolerance: tolerance,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -19,16 +19,9 @@ pub struct SetGuardian<'info> {
 
 pub fn set_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
   let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
 
-  if new_guardian != Pubkey::default() {
-    require!(
-      new_guardian != treasury_pool.admin,
-      ErrorCode::InvalidGuardianAddress
-    );
-  }
-
-  let old_guardian = treasury_pool.guardian;
-  treasury_pool.guardian = new_guardian;
+  let old_guardian = write_guardian(treasury_pool, new_guardian)?;
 
   emit!(GuardianSet {
     admin: ctx.accounts.admin.key(),
@@ -39,3 +32,28 @@ pub fn set_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<(
 
   Ok(())
 }
+
+/// Validate and store the veto guardian, returning the old one (shared with
+/// the council's SetGuardian)
+pub(crate) fn write_guardian(
+  treasury_pool: &mut TreasuryPool,
+  new_guardian: Pubkey,
+) -> Result<Pubkey> {
+  if new_guardian != Pubkey::default() {
+    require!(
+      new_guardian != treasury_pool.admin,
+      ErrorCode::InvalidGuardianAddress
+    );
+  }
+
+  // The guardian's veto backs the timelock once decentralized; it can be
+  // rotated but not removed
+  require!(
+    !treasury_pool.is_decentralized() || new_guardian != Pubkey::default(),
+    ErrorCode::AdminPathRetired
+  );
+
+  let old_guardian = treasury_pool.guardian;
+  treasury_pool.guardian = new_guardian;
+  Ok(old_guardian)
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::IdentityPolicyChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetIdentityPolicy<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the identity registrar (default pubkey = none) and the buffer rent loan
+/// cap for attested developers (0 = same as max_buffer_rent_loan)
+pub fn set_identity_policy(
+  ctx: Context<SetIdentityPolicy>,
+  new_registrar: Pubkey,
+  new_verified_max_buffer_rent_loan: u64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    new_verified_max_buffer_rent_loan <= TreasuryPool::MAX_BUFFER_RENT_LOAN_CAP,
+    ErrorCode::InvalidBufferRentLoanLimit
+  );
+
+  let old_registrar = treasury_pool.identity_registrar;
+  let old_verified_max_buffer_rent_loan = treasury_pool.verified_max_buffer_rent_loan;
+  treasury_pool.identity_registrar = new_registrar;
+  treasury_pool.verified_max_buffer_rent_loan = new_verified_max_buffer_rent_loan;
+
+  emit!(IdentityPolicyChanged {
+    admin: ctx.accounts.admin.key(),
+    old_registrar,
+    new_registrar,
+    old_verified_max_buffer_rent_loan,
+    new_verified_max_buffer_rent_loan,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
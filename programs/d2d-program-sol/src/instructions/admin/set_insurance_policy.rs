@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::InsurancePolicyChanged,
+  states::{InsuranceFund, TreasuryPool},
+};
+
+#[derive(Accounts)]
+pub struct SetInsurancePolicy<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump = insurance_fund.bump
+    )]
+  pub insurance_fund: Account<'info, InsuranceFund>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the insurance premium and loss threshold
+/// Applies to coverage bought and losses recorded from now on
+pub fn set_insurance_policy(
+  ctx: Context<SetInsurancePolicy>,
+  premium_bps: u64,
+  loss_threshold_bps: u64,
+) -> Result<()> {
+  let insurance_fund = &mut ctx.accounts.insurance_fund;
+  ctx.accounts.treasury_pool.check_not_council_governed()?;
+
+  let (old_premium_bps, old_loss_threshold_bps) =
+    write_insurance_policy(insurance_fund, premium_bps, loss_threshold_bps)?;
+
+  emit!(InsurancePolicyChanged {
+    admin: ctx.accounts.admin.key(),
+    old_premium_bps,
+    new_premium_bps: premium_bps,
+    old_loss_threshold_bps,
+    new_loss_threshold_bps: loss_threshold_bps,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+/// Validate and store the insurance premium and loss threshold, returning
+/// the old ones (shared with the council's SetInsurancePolicy)
+pub(crate) fn write_insurance_policy(
+  insurance_fund: &mut InsuranceFund,
+  premium_bps: u64,
+  loss_threshold_bps: u64,
+) -> Result<(u64, u64)> {
+  require!(
+    premium_bps <= InsuranceFund::MAX_PREMIUM_BPS,
+    ErrorCode::InvalidInsurancePolicy
+  );
+  require!(
+    loss_threshold_bps <= 10000,
+    ErrorCode::InvalidInsurancePolicy
+  );
+
+  let old_policy = (
+    insurance_fund.premium_bps,
+    insurance_fund.loss_threshold_bps,
+  );
+  insurance_fund.premium_bps = premium_bps;
+  insurance_fund.loss_threshold_bps = loss_threshold_bps;
+  Ok(old_policy)
+}
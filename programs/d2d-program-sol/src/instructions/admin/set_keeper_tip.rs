@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::KeeperTipChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetKeeperTip<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn set_keeper_tip(ctx: Context<SetKeeperTip>, new_tip_bps: u64) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
+
+  require!(
+    new_tip_bps <= TreasuryPool::MAX_KEEPER_TIP_BPS,
+    ErrorCode::InvalidKeeperTip
+  );
+
+  let old_tip_bps = treasury_pool.keeper_tip_bps;
+  treasury_pool.keeper_tip_bps = new_tip_bps;
+
+  emit!(KeeperTipChanged {
+    admin: ctx.accounts.admin.key(),
+    old_tip_bps,
+    new_tip_bps,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+  errors::ErrorCode,
+  events::LstConfigChanged,
+  states::{LstConfig, TreasuryPool},
+};
+
+/// Accept an LST mint for stake_from_lst, or update its oracle rate
+///
+/// Creates the config and its token vault on first use. Also used by the
+/// admin rate crank to refresh `rate`, which re-values the vault for
+/// treasury reporting.
+#[derive(Accounts)]
+pub struct SetLstConfig<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + LstConfig::INIT_SPACE,
+        seeds = [LstConfig::PREFIX_SEED, lst_mint.key().as_ref()],
+        bump
+    )]
+  pub lst_config: Account<'info, LstConfig>,
+
+  pub lst_mint: Account<'info, Mint>,
+
+  #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [LstConfig::VAULT_SEED, lst_mint.key().as_ref()],
+        bump,
+        token::mint = lst_mint,
+        token::authority = lst_config
+    )]
+  pub lst_vault: Account<'info, TokenAccount>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn set_lst_config(
+  ctx: Context<SetLstConfig>,
+  rate: u64,
+  max_rate_age: i64,
+  enabled: bool,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lst_config = &mut ctx.accounts.lst_config;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  treasury_pool.check_not_council_governed()?;
+  write_lst_config(
+    treasury_pool,
+    lst_config,
+    ctx.accounts.lst_mint.key(),
+    ctx.accounts.lst_vault.key(),
+    rate,
+    max_rate_age,
+    enabled,
+    current_time,
+  )?;
+  lst_config.bump = ctx.bumps.lst_config;
+
+  emit!(LstConfigChanged {
+    admin: ctx.accounts.admin.key(),
+    mint: lst_config.mint,
+    rate,
+    max_rate_age,
+    enabled,
+    normalized_value: lst_config.normalized_value,
+    normalized_token_value: treasury_pool.normalized_token_value,
+    changed_at: current_time,
+  });
+
+  Ok(())
+}
+
+/// Validate and store an LST config and re-value its vault (shared with the
+/// council's SetLstConfig)
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_lst_config(
+  treasury_pool: &mut TreasuryPool,
+  lst_config: &mut LstConfig,
+  mint: Pubkey,
+  vault: Pubkey,
+  rate: u64,
+  max_rate_age: i64,
+  enabled: bool,
+  current_time: i64,
+) -> Result<()> {
+  require!(rate > 0, ErrorCode::InvalidLstConfig);
+  require!(
+    max_rate_age > 0 && max_rate_age <= LstConfig::MAX_RATE_AGE_CAP,
+    ErrorCode::InvalidLstConfig
+  );
+
+  lst_config.mint = mint;
+  lst_config.vault = vault;
+  lst_config.rate = rate;
+  lst_config.rate_updated_at = current_time;
+  lst_config.max_rate_age = max_rate_age;
+  lst_config.enabled = enabled;
+
+  let (previous_value, current_value) = lst_config.revalue_vault()?;
+  treasury_pool.update_normalized_token_value(previous_value, current_value)
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::MaxBufferRentLoanChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetMaxBufferRentLoan<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the max lamports lent per upgrade buffer (0 disables buffer rent loans)
+pub fn set_max_buffer_rent_loan(
+  ctx: Context<SetMaxBufferRentLoan>,
+  max_buffer_rent_loan: u64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
+
+  let old_max = write_max_buffer_rent_loan(treasury_pool, max_buffer_rent_loan)?;
+
+  emit!(MaxBufferRentLoanChanged {
+    admin: ctx.accounts.admin.key(),
+    old_max,
+    new_max: max_buffer_rent_loan,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+/// Validate and store the buffer rent loan cap, returning the old one
+/// (shared with the council's SetMaxBufferRentLoan)
+pub(crate) fn write_max_buffer_rent_loan(
+  treasury_pool: &mut TreasuryPool,
+  max_buffer_rent_loan: u64,
+) -> Result<u64> {
+  require!(
+    max_buffer_rent_loan <= TreasuryPool::MAX_BUFFER_RENT_LOAN_CAP,
+    ErrorCode::InvalidBufferRentLoanLimit
+  );
+
+  let old_max = treasury_pool.max_buffer_rent_loan;
+  treasury_pool.max_buffer_rent_loan = max_buffer_rent_loan;
+  Ok(old_max)
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::MaxPoolShareChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetMaxPoolShare<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the max share of total_deposited a single staker may hold via stake_sol
+/// and stake_from_lst (0 = no cap)
+pub fn set_max_pool_share(ctx: Context<SetMaxPoolShare>, new_max_share_bps: u64) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(new_max_share_bps <= 10000, ErrorCode::InvalidMaxPoolShare);
+
+  let old_max_pool_share_bps = treasury_pool.max_pool_share_bps;
+  treasury_pool.max_pool_share_bps = new_max_share_bps;
+
+  emit!(MaxPoolShareChanged {
+    admin: ctx.accounts.admin.key(),
+    old_max_pool_share_bps,
+    new_max_pool_share_bps: new_max_share_bps,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::MaxQueueAgeChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetMaxQueueAge<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set max queue age in seconds (0 disables queue entry expiry)
+pub fn set_max_queue_age(ctx: Context<SetMaxQueueAge>, new_max_age: i64) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    new_max_age == 0
+      || (TreasuryPool::MIN_MAX_QUEUE_AGE..=TreasuryPool::MAX_MAX_QUEUE_AGE).contains(&new_max_age),
+    ErrorCode::InvalidMaxQueueAge
+  );
+
+  let old_max_age = treasury_pool.max_queue_age;
+  treasury_pool.max_queue_age = new_max_age;
+
+  emit!(MaxQueueAgeChanged {
+    admin: ctx.accounts.admin.key(),
+    old_max_age,
+    new_max_age,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
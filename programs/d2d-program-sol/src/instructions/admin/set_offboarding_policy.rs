@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::OffboardingPolicyChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetOffboardingPolicy<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn set_offboarding_policy(ctx: Context<SetOffboardingPolicy>, new_policy: u8) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    TreasuryPool::is_valid_offboarding_policy(new_policy),
+    ErrorCode::InvalidOffboardingPolicy
+  );
+
+  let old_policy = treasury_pool.offboarding_policy;
+  treasury_pool.offboarding_policy = new_policy;
+
+  emit!(OffboardingPolicyChanged {
+    admin: ctx.accounts.admin.key(),
+    old_policy,
+    new_policy,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
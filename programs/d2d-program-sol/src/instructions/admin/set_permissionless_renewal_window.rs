@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::PermissionlessRenewalWindowChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetPermissionlessRenewalWindow<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set how close to expiry anyone may renew an auto-renewing subscription
+/// from escrow (seconds, 0 = developer only)
+pub fn set_permissionless_renewal_window(
+  ctx: Context<SetPermissionlessRenewalWindow>,
+  new_window: i64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    (0..=TreasuryPool::MAX_PERMISSIONLESS_RENEWAL_WINDOW).contains(&new_window),
+    ErrorCode::InvalidRenewalWindow
+  );
+
+  let old_window = treasury_pool.permissionless_renewal_window;
+  treasury_pool.permissionless_renewal_window = new_window;
+
+  emit!(PermissionlessRenewalWindowChanged {
+    admin: ctx.accounts.admin.key(),
+    old_window,
+    new_window,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::PlanTierChanged,
+  states::{PlanConfig, PlanTier, TreasuryPool},
+};
+
+/// Add or update a subscription tier in the plan catalogue
+///
+/// Creates the PlanConfig on first use. Changes apply to new requests only;
+/// existing requests keep the fees and limits copied from their tier.
+#[derive(Accounts)]
+pub struct SetPlanTier<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + PlanConfig::INIT_SPACE,
+        seeds = [PlanConfig::PREFIX_SEED],
+        bump
+    )]
+  pub plan_config: Account<'info, PlanConfig>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn set_plan_tier(
+  ctx: Context<SetPlanTier>,
+  plan_id: u8,
+  max_program_size: u64,
+  service_fee: u64,
+  monthly_fee: u64,
+  included_upgrades: u32,
+  enabled: bool,
+) -> Result<()> {
+  let plan_config = &mut ctx.accounts.plan_config;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  ctx.accounts.treasury_pool.check_not_council_governed()?;
+  write_plan_tier(
+    plan_config,
+    plan_id,
+    max_program_size,
+    service_fee,
+    monthly_fee,
+    included_upgrades,
+    enabled,
+  )?;
+  plan_config.updated_at = current_time;
+  plan_config.bump = ctx.bumps.plan_config;
+
+  emit!(PlanTierChanged {
+    admin: ctx.accounts.admin.key(),
+    plan_id,
+    max_program_size,
+    service_fee,
+    monthly_fee,
+    included_upgrades,
+    enabled,
+    changed_at: current_time,
+  });
+
+  Ok(())
+}
+
+/// Upsert a tier, keeping its upgrade pricing (shared with the council's SetPlanTier)
+pub(crate) fn write_plan_tier(
+  plan_config: &mut PlanConfig,
+  plan_id: u8,
+  max_program_size: u64,
+  service_fee: u64,
+  monthly_fee: u64,
+  included_upgrades: u32,
+  enabled: bool,
+) -> Result<()> {
+  // Upgrade pricing is kept; it is set by set_plan_upgrade_pricing
+  let existing = plan_config
+    .tiers
+    .iter()
+    .find(|tier| tier.plan_id == plan_id)
+    .copied()
+    .unwrap_or_default();
+  plan_config.upsert_tier(PlanTier {
+    plan_id,
+    max_program_size,
+    service_fee,
+    monthly_fee,
+    included_upgrades,
+    max_upgrades_per_month: existing.max_upgrades_per_month,
+    upgrade_overage_fee: existing.upgrade_overage_fee,
+    enabled,
+  })
+}
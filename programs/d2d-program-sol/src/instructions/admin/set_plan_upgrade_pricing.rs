@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::PlanUpgradePricingChanged,
+  states::{PlanConfig, TreasuryPool},
+};
+
+/// Set a plan tier's monthly upgrade limit and overage fee
+///
+/// Applies to new requests and to requests that switch onto the tier;
+/// existing requests keep the terms copied from their tier.
+#[derive(Accounts)]
+pub struct SetPlanUpgradePricing<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [PlanConfig::PREFIX_SEED],
+        bump = plan_config.bump
+    )]
+  pub plan_config: Account<'info, PlanConfig>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn set_plan_upgrade_pricing(
+  ctx: Context<SetPlanUpgradePricing>,
+  plan_id: u8,
+  max_upgrades_per_month: u32,
+  upgrade_overage_fee: u64,
+) -> Result<()> {
+  let plan_config = &mut ctx.accounts.plan_config;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  ctx.accounts.treasury_pool.check_not_council_governed()?;
+  plan_config.set_upgrade_pricing(plan_id, max_upgrades_per_month, upgrade_overage_fee)?;
+  plan_config.updated_at = current_time;
+
+  emit!(PlanUpgradePricingChanged {
+    admin: ctx.accounts.admin.key(),
+    plan_id,
+    max_upgrades_per_month,
+    upgrade_overage_fee,
+    changed_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::PrepayDiscountsChanged,
+  states::{PrepayDiscount, TreasuryPool},
+};
+
+#[derive(Accounts)]
+pub struct SetPrepayDiscounts<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Replace the multi-month prepayment discount curve (empty = no discounts)
+pub fn set_prepay_discounts(
+  ctx: Context<SetPrepayDiscounts>,
+  steps: Vec<PrepayDiscount>,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  treasury_pool.check_not_council_governed()?;
+  treasury_pool.set_prepay_discounts(&steps)?;
+
+  emit!(PrepayDiscountsChanged {
+    admin: ctx.accounts.admin.key(),
+    steps,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::RequestBoundsChanged, states::TreasuryPool};
+
+/// Min/max bounds for deploy request parameters (max 0 = unbounded)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RequestBounds {
+  pub min_service_fee: u64,
+  pub max_service_fee: u64,
+  pub min_monthly_fee: u64,
+  pub max_monthly_fee: u64,
+  pub min_initial_months: u32,
+  pub max_initial_months: u32,
+}
+
+#[derive(Accounts)]
+pub struct SetRequestBounds<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the bounds validated by create_deploy_request and request_deployment_funds
+pub fn set_request_bounds(ctx: Context<SetRequestBounds>, bounds: RequestBounds) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  // A subscription always covers at least one month
+  require!(
+    bounds.min_initial_months > 0,
+    ErrorCode::InvalidRequestBounds
+  );
+  require!(
+    bounds.max_service_fee == 0 || bounds.min_service_fee <= bounds.max_service_fee,
+    ErrorCode::InvalidRequestBounds
+  );
+  require!(
+    bounds.max_monthly_fee == 0 || bounds.min_monthly_fee <= bounds.max_monthly_fee,
+    ErrorCode::InvalidRequestBounds
+  );
+  require!(
+    bounds.max_initial_months == 0 || bounds.min_initial_months <= bounds.max_initial_months,
+    ErrorCode::InvalidRequestBounds
+  );
+
+  treasury_pool.min_service_fee = bounds.min_service_fee;
+  treasury_pool.max_service_fee = bounds.max_service_fee;
+  treasury_pool.min_monthly_fee = bounds.min_monthly_fee;
+  treasury_pool.max_monthly_fee = bounds.max_monthly_fee;
+  treasury_pool.min_initial_months = bounds.min_initial_months;
+  treasury_pool.max_initial_months = bounds.max_initial_months;
+
+  emit!(RequestBoundsChanged {
+    admin: ctx.accounts.admin.key(),
+    min_service_fee: bounds.min_service_fee,
+    max_service_fee: bounds.max_service_fee,
+    min_monthly_fee: bounds.min_monthly_fee,
+    max_monthly_fee: bounds.max_monthly_fee,
+    min_initial_months: bounds.min_initial_months,
+    max_initial_months: bounds.max_initial_months,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
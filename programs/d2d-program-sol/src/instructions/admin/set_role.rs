@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::RoleAssigned,
+  states::{Role, TreasuryPool},
+};
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Assign an operational role to a key, or revoke it (default pubkey)
+///
+/// The admin keeps every role; assigning one lets a separate key (e.g. the
+/// backend hot key) run only that group of instructions.
+pub fn set_role(ctx: Context<SetRole>, role: Role, new_holder: Pubkey) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  treasury_pool.check_not_council_governed()?;
+  require!(
+    new_holder != treasury_pool.admin,
+    ErrorCode::InvalidRoleHolder
+  );
+
+  let old_holder = treasury_pool.role_holder(role);
+  treasury_pool.set_role_holder(role, new_holder);
+
+  emit!(RoleAssigned {
+    admin: ctx.accounts.admin.key(),
+    role,
+    old_holder,
+    new_holder,
+    assigned_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::ServiceFeeRefundPolicyChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetServiceFeeRefundPolicy<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the service fee refund schedule for new deploy requests
+/// Existing requests keep the schedule they were created with
+pub fn set_service_fee_refund_policy(
+  ctx: Context<SetServiceFeeRefundPolicy>,
+  refund_bps: u64,
+  refund_window: i64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
+
+  let (old_refund_bps, old_refund_window) =
+    write_service_fee_refund_policy(treasury_pool, refund_bps, refund_window)?;
+
+  emit!(ServiceFeeRefundPolicyChanged {
+    admin: ctx.accounts.admin.key(),
+    old_refund_bps,
+    new_refund_bps: refund_bps,
+    old_refund_window,
+    new_refund_window: refund_window,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+/// Validate and store the service fee refund schedule, returning the old
+/// (bps, window) (shared with the council's SetServiceFeeRefundPolicy)
+pub(crate) fn write_service_fee_refund_policy(
+  treasury_pool: &mut TreasuryPool,
+  refund_bps: u64,
+  refund_window: i64,
+) -> Result<(u64, i64)> {
+  require!(
+    refund_bps <= 10000,
+    ErrorCode::InvalidServiceFeeRefundPolicy
+  );
+  require!(
+    (0..=TreasuryPool::MAX_SERVICE_FEE_REFUND_WINDOW).contains(&refund_window),
+    ErrorCode::InvalidServiceFeeRefundPolicy
+  );
+
+  let old_policy = (
+    treasury_pool.service_fee_refund_bps,
+    treasury_pool.service_fee_refund_window,
+  );
+  treasury_pool.service_fee_refund_bps = refund_bps;
+  treasury_pool.service_fee_refund_window = refund_window;
+  Ok(old_policy)
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::StablecoinMonthlyFeeSet,
+  states::{DeployRequest, TreasuryPool},
+};
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct SetStablecoinMonthlyFee<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the fixed stablecoin monthly fee of a request (0 = SOL only)
+pub fn set_stablecoin_monthly_fee(
+  ctx: Context<SetStablecoinMonthlyFee>,
+  request_id: [u8; 32],
+  new_fee: u64,
+) -> Result<()> {
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  ctx.accounts.treasury_pool.check_not_council_governed()?;
+
+  let old_fee = deploy_request.stablecoin_monthly_fee;
+  deploy_request.stablecoin_monthly_fee = new_fee;
+
+  emit!(StablecoinMonthlyFeeSet {
+    request_id,
+    admin: ctx.accounts.admin.key(),
+    old_fee,
+    new_fee,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::StakeLimitsChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetStakeLimits<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the per-transaction stake cap and per-wallet daily stake limit (0 = unlimited)
+pub fn set_stake_limits(
+  ctx: Context<SetStakeLimits>,
+  max_stake_per_tx: u64,
+  daily_stake_limit: u64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  let old_max_stake_per_tx = treasury_pool.max_stake_per_tx;
+  let old_daily_stake_limit = treasury_pool.daily_stake_limit;
+  treasury_pool.max_stake_per_tx = max_stake_per_tx;
+  treasury_pool.daily_stake_limit = daily_stake_limit;
+
+  emit!(StakeLimitsChanged {
+    admin: ctx.accounts.admin.key(),
+    old_max_stake_per_tx,
+    new_max_stake_per_tx: max_stake_per_tx,
+    old_daily_stake_limit,
+    new_daily_stake_limit: daily_stake_limit,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
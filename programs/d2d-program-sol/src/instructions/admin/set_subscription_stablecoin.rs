@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{errors::ErrorCode, events::SubscriptionStablecoinSet, states::TreasuryPool};
+
+/// Accept a stablecoin mint for pay_subscription_spl
+///
+/// Creates the protocol token vault for the mint on first use; the vault is
+/// owned by the treasury pool PDA.
+#[derive(Accounts)]
+pub struct SetSubscriptionStablecoin<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  pub stablecoin_mint: Account<'info, Mint>,
+
+  #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [TreasuryPool::STABLECOIN_VAULT_SEED, stablecoin_mint.key().as_ref()],
+        bump,
+        token::mint = stablecoin_mint,
+        token::authority = treasury_pool
+    )]
+  pub stablecoin_vault: Account<'info, TokenAccount>,
+
+  #[account(
+        mut,
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn set_subscription_stablecoin(ctx: Context<SetSubscriptionStablecoin>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
+
+  treasury_pool.subscription_stablecoin_mint = ctx.accounts.stablecoin_mint.key();
+  treasury_pool.subscription_stablecoin_vault = ctx.accounts.stablecoin_vault.key();
+
+  emit!(SubscriptionStablecoinSet {
+    admin: ctx.accounts.admin.key(),
+    mint: treasury_pool.subscription_stablecoin_mint,
+    vault: treasury_pool.subscription_stablecoin_vault,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
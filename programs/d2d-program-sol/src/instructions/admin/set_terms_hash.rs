@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::TermsUpdated, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetTermsHash<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Publish the hash of new terms of service
+/// New deploy requests must acknowledge this hash; existing requests keep
+/// the hash they were created with
+pub fn set_terms_hash(ctx: Context<SetTermsHash>, terms_hash: [u8; 32]) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  let old_terms_hash = treasury_pool.terms_hash;
+  treasury_pool.terms_hash = terms_hash;
+  treasury_pool.terms_updated_at = current_time;
+
+  emit!(TermsUpdated {
+    admin: ctx.accounts.admin.key(),
+    old_terms_hash,
+    new_terms_hash: terms_hash,
+    updated_at: current_time,
+  });
+
+  Ok(())
+}
@@ -19,7 +19,26 @@ pub struct SetTimelockDuration<'info> {
 
 pub fn set_timelock_duration(ctx: Context<SetTimelockDuration>, new_duration: i64) -> Result<()> {
   let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
 
+  let old_duration = write_timelock_duration(treasury_pool, new_duration)?;
+
+  emit!(TimelockDurationChanged {
+    admin: ctx.accounts.admin.key(),
+    old_duration,
+    new_duration,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+/// Validate and store the withdrawal timelock, returning the old one
+/// (shared with the council's SetTimelockDuration)
+pub(crate) fn write_timelock_duration(
+  treasury_pool: &mut TreasuryPool,
+  new_duration: i64,
+) -> Result<i64> {
   require!(
     new_duration >= TreasuryPool::MIN_TIMELOCK_DURATION,
     ErrorCode::InvalidTimelockDuration
@@ -28,16 +47,13 @@ pub fn set_timelock_duration(ctx: Context<SetTimelockDuration>, new_duration: i6
     new_duration <= TreasuryPool::MAX_TIMELOCK_DURATION,
     ErrorCode::InvalidTimelockDuration
   );
+  // Once decentralized the timelock can only get longer
+  require!(
+    !treasury_pool.is_decentralized() || new_duration >= treasury_pool.timelock_duration,
+    ErrorCode::AdminPathRetired
+  );
 
   let old_duration = treasury_pool.timelock_duration;
   treasury_pool.timelock_duration = new_duration;
-
-  emit!(TimelockDurationChanged {
-    admin: ctx.accounts.admin.key(),
-    old_duration,
-    new_duration,
-    changed_at: Clock::get()?.unix_timestamp,
-  });
-
-  Ok(())
+  Ok(old_duration)
 }
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::UnstakeCommitmentPolicyChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetUnstakeCommitmentPolicy<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+/// Set the share of total_deposited above which unstakes must be committed
+/// (0 = all unstakes instant) and the delay before a commitment executes
+pub fn set_unstake_commitment_policy(
+  ctx: Context<SetUnstakeCommitmentPolicy>,
+  new_threshold_bps: u64,
+  new_commit_delay: i64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(
+    new_threshold_bps <= 10000,
+    ErrorCode::InvalidUnstakeCommitmentPolicy
+  );
+  require!(
+    new_commit_delay > 0 && new_commit_delay <= TreasuryPool::MAX_UNSTAKE_COMMIT_DELAY,
+    ErrorCode::InvalidUnstakeCommitmentPolicy
+  );
+
+  let old_threshold_bps = treasury_pool.large_unstake_threshold_bps;
+  let old_commit_delay = treasury_pool.unstake_commit_delay;
+  treasury_pool.large_unstake_threshold_bps = new_threshold_bps;
+  treasury_pool.unstake_commit_delay = new_commit_delay;
+
+  emit!(UnstakeCommitmentPolicyChanged {
+    admin: ctx.accounts.admin.key(),
+    old_threshold_bps,
+    new_threshold_bps,
+    old_commit_delay,
+    new_commit_delay,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::WatchtowerBountyChanged, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct SetWatchtowerBounty<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+}
+
+pub fn set_watchtower_bounty(
+  ctx: Context<SetWatchtowerBounty>,
+  new_bounty: u64,
+  new_cooldown: i64,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  treasury_pool.check_not_council_governed()?;
+
+  let (old_bounty, old_cooldown) =
+    write_watchtower_bounty(treasury_pool, new_bounty, new_cooldown)?;
+
+  emit!(WatchtowerBountyChanged {
+    admin: ctx.accounts.admin.key(),
+    old_bounty,
+    new_bounty,
+    old_cooldown,
+    new_cooldown,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+/// Validate and store the watchtower bounty and alert cooldown, returning
+/// the old ones (shared with the council's SetWatchtowerBounty)
+pub(crate) fn write_watchtower_bounty(
+  treasury_pool: &mut TreasuryPool,
+  new_bounty: u64,
+  new_cooldown: i64,
+) -> Result<(u64, i64)> {
+  require!(
+    new_bounty <= TreasuryPool::MAX_WATCHTOWER_BOUNTY,
+    ErrorCode::InvalidWatchtowerBounty
+  );
+  require!(
+    (TreasuryPool::MIN_WATCHTOWER_ALERT_COOLDOWN..=TreasuryPool::MAX_WATCHTOWER_ALERT_COOLDOWN)
+      .contains(&new_cooldown),
+    ErrorCode::InvalidWatchtowerBounty
+  );
+
+  let old_config = (
+    treasury_pool.watchtower_bounty,
+    treasury_pool.watchtower_alert_cooldown,
+  );
+  treasury_pool.watchtower_bounty = new_bounty;
+  treasury_pool.watchtower_alert_cooldown = new_cooldown;
+  Ok(old_config)
+}
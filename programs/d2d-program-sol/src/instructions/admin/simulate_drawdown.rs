@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::DrawdownSimulated,
+  states::{DeployRequest, DrawdownReport, DrawdownScenario, TreasuryPool},
+};
+
+/// Guardian stress test: what if `exit_bps` of the stake exits and the given
+/// deployments default?
+///
+/// Remaining accounts: the `deploy_request` PDAs assumed to default (at most
+/// `DrawdownReport::MAX_DEFAULTED_DEPLOYMENTS`); their outstanding debt,
+/// less `default_recovery_bps` recovered from rent, is counted as lost
+/// principal. The result overwrites the drawdown report PDA. Nothing else
+/// is modified.
+#[derive(Accounts)]
+pub struct SimulateDrawdown<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        init_if_needed,
+        payer = guardian,
+        space = 8 + DrawdownReport::INIT_SPACE,
+        seeds = [DrawdownReport::PREFIX_SEED],
+        bump
+    )]
+  pub drawdown_report: Account<'info, DrawdownReport>,
+
+  #[account(mut)]
+  pub guardian: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn simulate_drawdown(
+  ctx: Context<SimulateDrawdown>,
+  exit_bps: u64,
+  default_recovery_bps: u64,
+) -> Result<()> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+
+  require!(treasury_pool.has_guardian(), ErrorCode::GuardianNotSet);
+  require!(
+    ctx.accounts.guardian.key() == treasury_pool.guardian,
+    ErrorCode::OnlyGuardian
+  );
+  require!(
+    ctx.remaining_accounts.len() <= DrawdownReport::MAX_DEFAULTED_DEPLOYMENTS,
+    ErrorCode::InvalidDrawdownScenario
+  );
+
+  // Sum the outstanding debt of each distinct deployment assumed to default
+  let mut counted: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+  let mut defaulted_debt: u64 = 0;
+  for request_info in ctx.remaining_accounts {
+    require!(
+      request_info.owner == ctx.program_id && !counted.contains(request_info.key),
+      ErrorCode::InvalidDrawdownScenario
+    );
+    let deploy_request = DeployRequest::try_deserialize(&mut &request_info.data.borrow()[..])
+      .map_err(|_| ErrorCode::InvalidAccountData)?;
+    let expected_request = Pubkey::create_program_address(
+      &[
+        DeployRequest::PREFIX_SEED,
+        deploy_request.request_id.as_ref(),
+        &[deploy_request.bump],
+      ],
+      ctx.program_id,
+    );
+    require!(
+      expected_request.ok() == Some(request_info.key()),
+      ErrorCode::InvalidAccountData
+    );
+
+    defaulted_debt = defaulted_debt
+      .checked_add(
+        deploy_request
+          .borrowed_amount
+          .saturating_sub(deploy_request.repaid_amount),
+      )
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    counted.push(request_info.key());
+  }
+
+  let scenario = DrawdownScenario {
+    exit_bps,
+    defaulted_debt,
+    defaulted_deployments: counted.len() as u32,
+    default_recovery_bps,
+  };
+
+  let clock = Clock::get()?;
+  let report = &mut ctx.accounts.drawdown_report;
+  report.simulate(treasury_pool, &scenario)?;
+  report.guardian = ctx.accounts.guardian.key();
+  report.simulated_slot = clock.slot;
+  report.simulated_at = clock.unix_timestamp;
+  report.bump = ctx.bumps.drawdown_report;
+
+  emit!(DrawdownSimulated {
+    guardian: report.guardian,
+    exit_bps,
+    defaulted_deployments: report.defaulted_deployments,
+    defaulted_debt: report.defaulted_debt,
+    liquidity_shortfall: report.liquidity_shortfall,
+    exit_coverage_bps: report.exit_coverage_bps,
+    default_loss: report.default_loss,
+    principal_loss_bps: report.principal_loss_bps,
+    protected_rewards_shortfall: report.protected_rewards_shortfall,
+    simulated_at: report.simulated_at,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::StakeSnapshotRecorded,
+  states::{StakeSnapshot, TreasuryPool},
+};
+
+/// Record pool stake at the current epoch for off-chain vote weighting
+///
+/// The snapshot PDA is keyed by epoch, so each epoch can be snapshotted once.
+/// Run by the admin or the guardian keeper each epoch.
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct SnapshotStakes<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        init,
+        payer = admin,
+        space = 8 + StakeSnapshot::INIT_SPACE,
+        seeds = [StakeSnapshot::PREFIX_SEED, epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+  pub stake_snapshot: Account<'info, StakeSnapshot>,
+
+  #[account(
+        mut,
+        constraint = treasury_pool.is_admin_or_guardian(&admin.key()) @ ErrorCode::Unauthorized
+    )]
+  pub admin: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn snapshot_stakes(
+  ctx: Context<SnapshotStakes>,
+  epoch: u64,
+  merkle_root: [u8; 32],
+  staker_count: u32,
+) -> Result<()> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  let stake_snapshot = &mut ctx.accounts.stake_snapshot;
+  let clock = Clock::get()?;
+
+  // Only the current epoch can be snapshotted
+  require!(epoch == clock.epoch, ErrorCode::InvalidSnapshotEpoch);
+
+  stake_snapshot.epoch = epoch;
+  stake_snapshot.slot = clock.slot;
+  stake_snapshot.total_deposited = treasury_pool.total_deposited;
+  stake_snapshot.reward_per_share = treasury_pool.reward_per_share;
+  stake_snapshot.total_stake_duration_weight = treasury_pool.total_stake_duration_weight;
+  stake_snapshot.merkle_root = merkle_root;
+  stake_snapshot.staker_count = staker_count;
+  stake_snapshot.recorded_by = ctx.accounts.admin.key();
+  stake_snapshot.recorded_at = clock.unix_timestamp;
+  stake_snapshot.bump = ctx.bumps.stake_snapshot;
+
+  emit!(StakeSnapshotRecorded {
+    epoch,
+    slot: clock.slot,
+    total_deposited: treasury_pool.total_deposited,
+    reward_per_share: treasury_pool.reward_per_share,
+    merkle_root,
+    staker_count,
+    recorded_at: clock.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::{
   errors::ErrorCode,
   events::GracePeriodStarted,
-  states::{DeployRequest, DeployRequestStatus, TreasuryPool},
+  states::{DeployRequest, DeployRequestStatus, Role, TreasuryPool},
 };
 
 #[derive(Accounts)]
@@ -17,14 +17,14 @@ pub struct StartGracePeriod<'info> {
 
   #[account(
         mut,
-        seeds = [DeployRequest::PREFIX_SEED, deploy_request.program_hash.as_ref()],
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
         bump = deploy_request.bump,
         constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
     )]
   pub deploy_request: Account<'info, DeployRequest>,
 
   #[account(
-        constraint = treasury_pool.is_admin(&admin.key()) @ ErrorCode::Unauthorized
+        constraint = treasury_pool.has_role(Role::DeployerOperator, &admin.key()) @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
 }
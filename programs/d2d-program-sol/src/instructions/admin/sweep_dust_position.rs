@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::DustPositionSwept,
+  instructions::enqueue_withdrawal,
+  states::{BackerDeposit, TreasuryPool, WithdrawalQueueEntry},
+};
+
+/// Permissionless crank: queue an abandoned dust position for withdrawal
+///
+/// Positions below `BackerDeposit::DUST_THRESHOLD` with no action for
+/// `DUST_INACTIVITY_PERIOD` are queued in full to the staker, as if they had
+/// called queue_withdrawal. Once the queue has paid them out,
+/// close_swept_position closes the account. The cranker pays the queue entry
+/// rent, which goes to the staker when the entry is closed; the keeper tip
+/// goes to whoever later processes the entry, not to this cranker.
+#[derive(Accounts)]
+pub struct SweepDustPosition<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender_stake.backer.as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  #[account(
+        init,
+        payer = cranker,
+        space = 8 + WithdrawalQueueEntry::INIT_SPACE,
+        seeds = [WithdrawalQueueEntry::PREFIX_SEED, &treasury_pool.withdrawal_queue_tail.to_le_bytes()],
+        bump
+    )]
+  pub queue_entry: Account<'info, WithdrawalQueueEntry>,
+
+  #[account(mut)]
+  pub cranker: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn sweep_dust_position(ctx: Context<SweepDustPosition>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    lender_stake.is_sweepable_dust(current_time),
+    ErrorCode::PositionNotSweepable
+  );
+
+  let amount = lender_stake.deposited_amount;
+  let last_action_at = lender_stake.last_action_at;
+  let queue_position = treasury_pool.withdrawal_queue_tail;
+  enqueue_withdrawal(
+    treasury_pool,
+    lender_stake,
+    &mut ctx.accounts.queue_entry,
+    ctx.bumps.queue_entry,
+    amount,
+    current_time,
+  )?;
+  lender_stake.dust_swept_at = current_time;
+
+  emit!(DustPositionSwept {
+    staker: lender_stake.backer,
+    cranker: ctx.accounts.cranker.key(),
+    amount,
+    queue_position,
+    last_action_at,
+    swept_at: current_time,
+  });
+
+  Ok(())
+}
@@ -15,9 +15,12 @@ pub struct SyncLiquidBalance<'info> {
   #[account(mut)]
   pub treasury_pool: UncheckedAccount<'info>,
 
-  /// CHECK: Treasury Pool PDA (to get actual account balance)
-  #[account(mut)]
-  pub treasury_pda: UncheckedAccount<'info>,
+  /// CHECK: Treasury vault PDA (to get actual account balance)
+  #[account(
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
 
   pub admin: Signer<'info>,
 }
@@ -25,7 +28,7 @@ pub struct SyncLiquidBalance<'info> {
 /// Sync liquid_balance with actual account balance
 ///
 /// This instruction:
-/// 1. Gets the actual account balance (lamports) from treasury_pda
+/// 1. Gets the actual account balance (lamports) from treasury_vault
 /// 2. Calculates rent exemption
 /// 3. Updates liquid_balance to match (account_balance - rent_exemption)
 ///
@@ -38,31 +41,31 @@ pub fn sync_liquid_balance(ctx: Context<SyncLiquidBalance>) -> Result<()> {
     ctx.accounts.treasury_pool.key() == expected_treasury_pool,
     ErrorCode::InvalidAccountOwner
   );
-  require!(
-    ctx.accounts.treasury_pda.key() == expected_treasury_pool,
-    ErrorCode::InvalidAccountOwner
-  );
 
   // Deserialize treasury pool
   let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
   let mut treasury_pool = TreasuryPool::try_deserialize(&mut &treasury_pool_info.data.borrow()[..])
     .map_err(|_| ErrorCode::InvalidAccountData)?;
 
-  let treasury_pda_info = ctx.accounts.treasury_pda.to_account_info();
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
 
   // Check admin authorization
   require!(
     ctx.accounts.admin.key() == treasury_pool.admin,
     ErrorCode::Unauthorized
   );
+  require!(
+    !treasury_pool.is_decentralized(),
+    ErrorCode::AdminPathRetired
+  );
 
   require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
 
   // Get actual account balance
-  let actual_account_balance = treasury_pda_info.lamports();
+  let actual_account_balance = treasury_vault_info.lamports();
 
   // Calculate rent exemption
-  let account_data_size = treasury_pda_info.data_len();
+  let account_data_size = treasury_vault_info.data_len();
   let rent_exemption = Rent::get()?.minimum_balance(account_data_size);
 
   // Available balance = actual balance - rent exemption
@@ -3,7 +3,7 @@ use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
 use crate::{
   errors::ErrorCode,
   events::AuthorityTransferred,
-  states::{DeployRequest, DeployRequestStatus, ManagedProgram, TreasuryPool},
+  states::{DeployRequest, DeployRequestStatus, ManagedProgram, Role, TreasuryPool},
 };
 
 /// Transfer program upgrade authority from temporary wallet to D2D PDA
@@ -47,7 +47,7 @@ pub struct TransferAuthorityToPda<'info> {
   /// Deploy request for this program
   #[account(
         mut,
-        seeds = [DeployRequest::PREFIX_SEED, deploy_request.program_hash.as_ref()],
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
         bump = deploy_request.bump,
         constraint = deploy_request.status == DeployRequestStatus::Active @ ErrorCode::InvalidDeploymentStatus,
     )]
@@ -66,7 +66,7 @@ pub struct TransferAuthorityToPda<'info> {
   /// Admin who initiated the deployment
   #[account(
         mut,
-        constraint = admin.key() == treasury_pool.admin @ ErrorCode::Unauthorized
+        constraint = treasury_pool.has_role(Role::DeployerOperator, &admin.key()) @ ErrorCode::Unauthorized
     )]
   pub admin: Signer<'info>,
 
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::{UpgradeAuthorityInventoryChecked, UpgradeAuthorityMismatch},
+  states::{ManagedProgram, TreasuryPool},
+};
+
+/// Inventory check of upgrade authorities for managed programs
+///
+/// Verifies that each program's on-chain upgrade authority still equals the
+/// ManagedProgram authority PDA, emitting a mismatch event for any program
+/// whose authority was changed out-of-band.
+///
+/// Remaining accounts are passed in pairs: [managed_program, program_data]
+///
+/// Called periodically by admin/guardian keepers to detect drift
+#[derive(Accounts)]
+pub struct VerifyUpgradeAuthorities<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = treasury_pool.is_admin_or_guardian(&caller.key()) @ ErrorCode::Unauthorized
+    )]
+  pub caller: Signer<'info>,
+}
+
+pub fn verify_upgrade_authorities(ctx: Context<VerifyUpgradeAuthorities>) -> Result<()> {
+  let remaining_accounts = ctx.remaining_accounts;
+  require!(
+    !remaining_accounts.is_empty() && remaining_accounts.len().is_multiple_of(2),
+    ErrorCode::InvalidAccountData
+  );
+
+  let current_time = Clock::get()?.unix_timestamp;
+  let mut programs_checked: u32 = 0;
+  let mut inactive_skipped: u32 = 0;
+  let mut mismatches: u32 = 0;
+
+  for pair in remaining_accounts.chunks(2) {
+    let managed_program_info = &pair[0];
+    let program_data_info = &pair[1];
+
+    let managed_program = load_managed_program(managed_program_info, ctx.program_id)?;
+
+    // Closed programs no longer have a ProgramData account to inspect
+    if !managed_program.is_active {
+      inactive_skipped += 1;
+      continue;
+    }
+
+    let actual_authority = read_upgrade_authority(&managed_program, program_data_info)?;
+
+    programs_checked += 1;
+
+    if actual_authority != Some(managed_program.authority_pda) {
+      mismatches += 1;
+
+      msg!(
+        "Upgrade authority drift for {}: expected {}, found {:?}",
+        managed_program.program_id,
+        managed_program.authority_pda,
+        actual_authority
+      );
+
+      emit!(UpgradeAuthorityMismatch {
+        program_id: managed_program.program_id,
+        managed_program: managed_program_info.key(),
+        developer: managed_program.developer,
+        expected_authority: managed_program.authority_pda,
+        actual_authority,
+        detected_at: current_time,
+      });
+    }
+  }
+
+  emit!(UpgradeAuthorityInventoryChecked {
+    caller: ctx.accounts.caller.key(),
+    programs_checked,
+    inactive_skipped,
+    mismatches,
+    checked_at: current_time,
+  });
+
+  Ok(())
+}
+
+/// Deserialize a ManagedProgram passed as a remaining account, checking that
+/// it is the canonical program-owned PDA
+pub(crate) fn load_managed_program(
+  managed_program_info: &AccountInfo,
+  program_id: &Pubkey,
+) -> Result<ManagedProgram> {
+  require!(
+    managed_program_info.owner == program_id,
+    ErrorCode::InvalidAccountOwner
+  );
+  let managed_program =
+    ManagedProgram::try_deserialize(&mut &managed_program_info.data.borrow()[..])
+      .map_err(|_| error!(ErrorCode::InvalidAccountData))?;
+
+  let expected_managed_program = Pubkey::create_program_address(
+    &[
+      ManagedProgram::PREFIX_SEED,
+      managed_program.program_id.as_ref(),
+      &[managed_program.bump],
+    ],
+    program_id,
+  )
+  .map_err(|_| error!(ErrorCode::ProgramNotManaged))?;
+  require!(
+    managed_program_info.key() == expected_managed_program,
+    ErrorCode::ProgramNotManaged
+  );
+
+  Ok(managed_program)
+}
+
+/// Current upgrade authority of a managed program
+///
+/// A missing or non-ProgramData account means the program itself was closed
+/// out-of-band, which is reported as drift with no authority
+pub(crate) fn read_upgrade_authority(
+  managed_program: &ManagedProgram,
+  program_data_info: &AccountInfo,
+) -> Result<Option<Pubkey>> {
+  require!(
+    program_data_info.key() == managed_program.program_data_address(),
+    ErrorCode::InvalidAccountData
+  );
+
+  let actual_authority = if program_data_info.owner == &ProgramData::owner() {
+    ProgramData::try_deserialize(&mut &program_data_info.data.borrow()[..])
+      .ok()
+      .and_then(|program_data| program_data.upgrade_authority_address)
+  } else {
+    None
+  };
+
+  Ok(actual_authority)
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::UnpauseVetoed, states::TreasuryPool};
+
+#[derive(Accounts)]
+pub struct VetoUnpause<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        constraint = treasury_pool.is_admin_or_guardian(&caller.key()) @ ErrorCode::Unauthorized
+    )]
+  pub caller: Signer<'info>,
+}
+
+/// Admin withdraws, or guardian vetoes, a scheduled unpause
+pub fn veto_unpause(ctx: Context<VetoUnpause>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  let scheduled_at = treasury_pool.unpause_scheduled_at;
+  require!(
+    treasury_pool.cancel_scheduled_unpause(),
+    ErrorCode::UnpauseNotScheduled
+  );
+
+  emit!(UnpauseVetoed {
+    vetoed_by: ctx.accounts.caller.key(),
+    scheduled_at,
+    vetoed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::UpgradeSignerAdded, states::ManagedProgram};
+
+/// Program owner lets a co-developer wallet call proxy_upgrade_program
+#[derive(Accounts)]
+pub struct AddUpgradeSigner<'info> {
+  /// CHECK: Validated by managed_program seeds
+  pub program_account: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.developer == developer.key() @ ErrorCode::Unauthorized,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  pub developer: Signer<'info>,
+}
+
+pub fn add_upgrade_signer(ctx: Context<AddUpgradeSigner>, signer: Pubkey) -> Result<()> {
+  let managed_program = &mut ctx.accounts.managed_program;
+  managed_program.add_upgrade_signer(signer)?;
+
+  emit!(UpgradeSignerAdded {
+    program_id: managed_program.program_id,
+    developer: managed_program.developer,
+    signer,
+    signer_count: managed_program.upgrade_signers.len() as u8,
+    added_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::BufferRentLoanIssued,
+  states::{
+    BufferRentLoan, DeployRequest, DeployRequestStatus, DeveloperIdentity, ManagedProgram,
+    TreasuryPool,
+  },
+  utils::{vault_debit_credit, vault_payable_lamports},
+};
+
+/// Developer borrows lamports from the treasury to fund an upgrade buffer
+///
+/// The loan PDA must then be passed as the spill account to
+/// `proxy_upgrade_program`, so the buffer rent repays the treasury
+/// automatically once the buffer is consumed. A loan still open when the
+/// program's rent is reclaimed (reclaim_program_rent, pause_program) is
+/// repaid from that rent first. One open loan per program. Developers with
+/// an attested identity may borrow up to the verified cap.
+#[derive(Accounts)]
+pub struct BorrowBufferRent<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Treasury vault PDA (source of the loan)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  #[account(
+        seeds = [ManagedProgram::PREFIX_SEED, managed_program.program_id.as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.developer == developer.key() @ ErrorCode::Unauthorized,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  /// CHECK: Deploy request - validated manually for migration compatibility
+  #[account(
+        constraint = deploy_request.key() == managed_program.deploy_request @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: UncheckedAccount<'info>,
+
+  #[account(
+        init,
+        payer = developer,
+        space = 8 + BufferRentLoan::INIT_SPACE,
+        seeds = [BufferRentLoan::PREFIX_SEED, managed_program.program_id.as_ref()],
+        bump
+    )]
+  pub buffer_rent_loan: Account<'info, BufferRentLoan>,
+
+  /// Optional - an attested identity unlocks the verified loan cap
+  #[account(
+        seeds = [DeveloperIdentity::PREFIX_SEED, developer.key().as_ref()],
+        bump = developer_identity.bump
+    )]
+  pub developer_identity: Option<Account<'info, DeveloperIdentity>>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn borrow_buffer_rent(ctx: Context<BorrowBufferRent>, amount: u64) -> Result<()> {
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let buffer_rent_loan = &mut ctx.accounts.buffer_rent_loan;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  let identity_attested = ctx
+    .accounts
+    .developer_identity
+    .as_ref()
+    .is_some_and(|identity| identity.is_attested());
+  let loan_cap = treasury_pool.get_buffer_rent_loan_cap(identity_attested);
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(loan_cap > 0, ErrorCode::BufferRentLoansDisabled);
+  require!(amount > 0, ErrorCode::InvalidAmount);
+  require!(amount <= loan_cap, ErrorCode::BufferRentLoanTooLarge);
+
+  // Only developers who can upgrade right now may borrow
+  let deploy_request = DeployRequest::load_padded(&ctx.accounts.deploy_request.to_account_info())?;
+  require!(
+    deploy_request.status == DeployRequestStatus::Active,
+    ErrorCode::InvalidDeploymentStatus
+  );
+  require!(
+    deploy_request.is_subscription_valid()?,
+    ErrorCode::SubscriptionExpired
+  );
+
+  // Never lend out liquidity reserved for queued withdrawals or committed
+  // unstakes
+  let available_balance =
+    vault_payable_lamports(&treasury_vault_info)?.min(treasury_pool.liquid_balance);
+  let unreserved_balance = treasury_pool.get_unreserved_liquidity(available_balance);
+  require!(
+    amount <= treasury_pool.get_uncommitted_liquidity(unreserved_balance),
+    ErrorCode::InsufficientLiquidBalance
+  );
+
+  vault_debit_credit(
+    &ctx.accounts.system_program.to_account_info(),
+    &treasury_vault_info,
+    &ctx.accounts.developer.to_account_info(),
+    amount,
+    treasury_pool.vault_bump,
+    &mut treasury_pool.liquid_balance,
+  )?;
+  treasury_pool.record_buffer_rent_loan(amount)?;
+
+  buffer_rent_loan.developer = ctx.accounts.developer.key();
+  buffer_rent_loan.program_id = ctx.accounts.managed_program.program_id;
+  buffer_rent_loan.amount_borrowed = amount;
+  buffer_rent_loan.amount_repaid = 0;
+  buffer_rent_loan.borrowed_at = current_time;
+  buffer_rent_loan.due_at = current_time
+    .checked_add(BufferRentLoan::LOAN_DURATION)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  buffer_rent_loan.bump = ctx.bumps.buffer_rent_loan;
+
+  emit!(BufferRentLoanIssued {
+    developer: buffer_rent_loan.developer,
+    program_id: buffer_rent_loan.program_id,
+    buffer_rent_loan: buffer_rent_loan.key(),
+    amount,
+    due_at: buffer_rent_loan.due_at,
+    borrowed_at: current_time,
+  });
+
+  Ok(())
+}
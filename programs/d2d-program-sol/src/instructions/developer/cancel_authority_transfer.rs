@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::AuthorityTransferCancelled,
+  states::{AuthorityTransfer, DeployRequest},
+};
+
+/// Developer withdraws a pending authority transfer (rent returned)
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+  #[account(
+        constraint = deploy_request.key() == authority_transfer.deploy_request @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  #[account(
+        mut,
+        close = developer,
+        seeds = [AuthorityTransfer::PREFIX_SEED, authority_transfer.program_id.as_ref()],
+        bump = authority_transfer.bump,
+        constraint = authority_transfer.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+}
+
+pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+  let authority_transfer = &ctx.accounts.authority_transfer;
+
+  emit!(AuthorityTransferCancelled {
+    request_id: ctx.accounts.deploy_request.request_id,
+    program_id: authority_transfer.program_id,
+    developer: authority_transfer.developer,
+    new_authority: authority_transfer.new_authority,
+    cancelled_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
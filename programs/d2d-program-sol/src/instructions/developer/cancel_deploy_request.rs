@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::{DeployRequestCancelled, ServiceFeeRefunded},
+  instructions::claw_back_rewards,
+  states::{DeployRequest, DeployRequestStatus, FeeSource, TreasuryPool, UserDeployStats},
+  utils::payout_from_pda,
+};
+
+/// Developer cancels a deploy request before the backend has been funded
+///
+/// Nothing has been deployed yet, so everything the developer paid beyond the
+/// service fee is refunded in full; the service fee is refunded per the
+/// schedule stored on the request.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct CancelDeployRequest<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  /// CHECK: Reward Pool PDA (holds the developer payment)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform Pool PDA (covers refunds that would touch protected rewards)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  /// Developer's deployment stats (frees the request's quota slot)
+  #[account(
+        mut,
+        seeds = [UserDeployStats::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = user_stats.bump
+    )]
+  pub user_stats: Account<'info, UserDeployStats>,
+}
+
+pub fn cancel_deploy_request(
+  ctx: Context<CancelDeployRequest>,
+  request_id: [u8; 32],
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    deploy_request.status == DeployRequestStatus::PendingDeployment,
+    ErrorCode::InvalidRequestStatus
+  );
+  // Once the temporary wallet is funded the deployment must be confirmed or failed
+  require!(
+    deploy_request.ephemeral_key.is_none(),
+    ErrorCode::InvalidRequestStatus
+  );
+
+  let (service_fee_refund, subscription_refund) =
+    deploy_request.calculate_cancel_refund(current_time)?;
+  let refund_amount = service_fee_refund
+    .checked_add(subscription_refund)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  ctx.accounts.user_stats.release_slot(&deploy_request.status);
+  deploy_request.status = DeployRequestStatus::Cancelled;
+  deploy_request.record_service_fee_refund(service_fee_refund);
+
+  // Refund from escrow; the retained part of the service fee goes to stakers
+  let escrowed_payment = deploy_request.take_escrowed_payment();
+  let (from_escrow, from_rewards) = treasury_pool.split_escrowed_refund(
+    FeeSource::DeploymentRequest,
+    escrowed_payment,
+    refund_amount,
+  )?;
+  payout_from_pda(
+    &ctx.accounts.reward_pool.to_account_info(),
+    &ctx.accounts.developer.to_account_info(),
+    from_escrow,
+  )?;
+  if from_rewards > 0 {
+    claw_back_rewards(
+      treasury_pool,
+      &ctx.accounts.reward_pool.to_account_info(),
+      &ctx.accounts.platform_pool.to_account_info(),
+      &ctx.accounts.developer.to_account_info(),
+      from_rewards,
+    )?;
+  }
+
+  if service_fee_refund > 0 {
+    emit!(ServiceFeeRefunded {
+      request_id,
+      developer: deploy_request.developer,
+      service_fee: deploy_request.service_fee,
+      refund_amount: service_fee_refund,
+      refunded_at: current_time,
+    });
+  }
+
+  emit!(DeployRequestCancelled {
+    request_id,
+    developer: deploy_request.developer,
+    service_fee_refund,
+    subscription_refund,
+    cancelled_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,198 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::PendingDeploymentCancelled,
+  instructions::claw_back_rewards,
+  states::{DeployRequest, DeployRequestStatus, FeeSource, TreasuryPool, UserDeployStats},
+  utils::payout_from_pda,
+};
+
+/// Developer cancels a deployment the backend never completed
+///
+/// Available once the request's `expiry_at` has passed, whether or not the
+/// temporary wallet was funded. The backend failed to deliver, so the refund is the
+/// same full refund as confirm_deployment_failure (no service fee retained).
+/// Once the temporary wallet was funded it must co-sign: everything it holds
+/// returns to the treasury vault and closes the deployment loan.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct CancelPendingDeployment<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  /// CHECK: Reward Pool PDA (holds the developer payment)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform Pool PDA (covers refunds that would touch protected rewards)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Treasury vault PDA (receives the returned deployment funds)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  /// Funded temporary wallet (required once funded; returns its lamports)
+  #[account(mut)]
+  pub ephemeral_key: Option<Signer<'info>>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+
+  /// Developer's deployment stats (frees the request's quota slot)
+  #[account(
+        mut,
+        seeds = [UserDeployStats::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = user_stats.bump
+    )]
+  pub user_stats: Account<'info, UserDeployStats>,
+}
+
+pub fn cancel_pending_deployment(
+  ctx: Context<CancelPendingDeployment>,
+  request_id: [u8; 32],
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    deploy_request.status == DeployRequestStatus::PendingDeployment,
+    ErrorCode::InvalidRequestStatus
+  );
+  require!(
+    deploy_request.is_pending_deployment_stale(current_time),
+    ErrorCode::PendingDeploymentNotStale
+  );
+
+  let returned_lamports = return_deployment_loan(
+    treasury_pool,
+    deploy_request,
+    ctx.accounts.ephemeral_key.as_ref(),
+    &ctx.accounts.treasury_vault.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    current_time,
+  )?;
+
+  let refund_amount = deploy_request.calculate_failure_refund()?;
+  ctx.accounts.user_stats.release_slot(&deploy_request.status);
+  deploy_request.status = DeployRequestStatus::Cancelled;
+
+  let escrowed_payment = deploy_request.take_escrowed_payment();
+  let (from_escrow, from_rewards) = treasury_pool.split_escrowed_refund(
+    FeeSource::DeploymentRequest,
+    escrowed_payment,
+    refund_amount,
+  )?;
+  payout_from_pda(
+    &ctx.accounts.reward_pool.to_account_info(),
+    &ctx.accounts.developer.to_account_info(),
+    from_escrow,
+  )?;
+  if from_rewards > 0 {
+    claw_back_rewards(
+      treasury_pool,
+      &ctx.accounts.reward_pool.to_account_info(),
+      &ctx.accounts.platform_pool.to_account_info(),
+      &ctx.accounts.developer.to_account_info(),
+      from_rewards,
+    )?;
+  }
+
+  emit!(PendingDeploymentCancelled {
+    request_id,
+    developer: deploy_request.developer,
+    refund_amount,
+    returned_lamports,
+    outstanding_loan: deploy_request.get_remaining_debt(),
+    ephemeral_key: deploy_request.ephemeral_key,
+    pending_since: deploy_request.created_at,
+    expiry_at: deploy_request.pending_expiry_at(),
+    cancelled_at: current_time,
+  });
+
+  Ok(())
+}
+
+/// Return a funded temporary wallet's lamports to the treasury vault and
+/// close the deployment loan against them
+///
+/// Shared by cancel_pending_deployment and expire_deploy_request. Once the
+/// request was funded the wallet must co-sign, so the loan cannot be left
+/// behind; lamports it no longer holds stay on the request as debt. Returns
+/// the lamports returned.
+pub(crate) fn return_deployment_loan<'info>(
+  treasury_pool: &mut TreasuryPool,
+  deploy_request: &mut DeployRequest,
+  ephemeral_key: Option<&Signer<'info>>,
+  treasury_vault: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+  current_time: i64,
+) -> Result<u64> {
+  let Some(expected_key) = deploy_request.ephemeral_key else {
+    return Ok(0);
+  };
+  let ephemeral_key = ephemeral_key.ok_or(ErrorCode::InvalidEphemeralKey)?;
+  require!(
+    ephemeral_key.key() == expected_key,
+    ErrorCode::InvalidEphemeralKey
+  );
+
+  let returned_lamports = ephemeral_key.lamports();
+  if returned_lamports > 0 {
+    system_program::transfer(
+      CpiContext::new(
+        system_program.clone(),
+        system_program::Transfer {
+          from: ephemeral_key.to_account_info(),
+          to: treasury_vault.clone(),
+        },
+      ),
+      returned_lamports,
+    )?;
+  }
+
+  // Restores liquid_balance for the returned loan and closes out the deployment
+  let remaining_debt = deploy_request.get_remaining_debt();
+  let (debt_repayment, excess) =
+    treasury_pool.record_debt_repayment(returned_lamports, remaining_debt, current_time)?;
+  if debt_repayment > 0 {
+    deploy_request.record_debt_settlement(debt_repayment)?;
+  }
+  // Anything above the loan is treasury SOL as well
+  treasury_pool.liquid_balance = treasury_pool
+    .liquid_balance
+    .checked_add(excess)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  Ok(returned_lamports)
+}
@@ -0,0 +1,89 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{errors::ErrorCode, events::ScheduledUpgradeCancelled, states::ManagedProgram};
+
+/// Developer withdraws a scheduled upgrade; the buffer authority returns to
+/// the developer so the buffer can be rewritten or closed
+#[derive(Accounts)]
+pub struct CancelScheduledUpgrade<'info> {
+  /// CHECK: Validated by managed_program seeds
+  pub program_account: UncheckedAccount<'info>,
+
+  /// CHECK: Must be the scheduled buffer
+  #[account(
+        mut,
+        constraint = buffer_account.key() == managed_program.scheduled_buffer @ ErrorCode::NoScheduledUpgrade
+    )]
+  pub buffer_account: UncheckedAccount<'info>,
+
+  /// PDA that holds the buffer authority
+  /// CHECK: Validated by seeds and managed_program.authority_pda
+  #[account(
+        seeds = [ManagedProgram::AUTHORITY_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub authority_pda: SystemAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.developer == developer.key() @ ErrorCode::Unauthorized,
+        constraint = managed_program.authority_pda == authority_pda.key() @ ErrorCode::InvalidAuthorityPda,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  pub developer: Signer<'info>,
+
+  /// BPF Loader Upgradeable Program
+  /// CHECK: Known program ID
+  #[account(
+        constraint = bpf_loader_upgradeable_program.key() == bpf_loader_upgradeable::ID
+    )]
+  pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+}
+
+pub fn cancel_scheduled_upgrade(ctx: Context<CancelScheduledUpgrade>) -> Result<()> {
+  let managed_program = &mut ctx.accounts.managed_program;
+
+  require!(
+    managed_program.has_scheduled_upgrade(),
+    ErrorCode::NoScheduledUpgrade
+  );
+
+  let set_buffer_authority_ix = bpf_loader_upgradeable::set_buffer_authority(
+    &ctx.accounts.buffer_account.key(),
+    &ctx.accounts.authority_pda.key(),
+    &ctx.accounts.developer.key(),
+  );
+
+  let program_key = ctx.accounts.program_account.key();
+  let seeds = &[
+    ManagedProgram::AUTHORITY_SEED,
+    program_key.as_ref(),
+    &[ctx.bumps.authority_pda],
+  ];
+  let signer_seeds = &[&seeds[..]];
+
+  anchor_lang::solana_program::program::invoke_signed(
+    &set_buffer_authority_ix,
+    &[
+      ctx.accounts.buffer_account.to_account_info(),
+      ctx.accounts.authority_pda.to_account_info(),
+      ctx.accounts.developer.to_account_info(),
+    ],
+    signer_seeds,
+  )?;
+
+  let buffer_address = managed_program.scheduled_buffer;
+  managed_program.clear_scheduled_upgrade();
+
+  emit!(ScheduledUpgradeCancelled {
+    program_id: managed_program.program_id,
+    developer: managed_program.developer,
+    buffer_address,
+    cancelled_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
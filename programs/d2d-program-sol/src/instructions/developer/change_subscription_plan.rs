@@ -0,0 +1,164 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::SubscriptionPlanChanged,
+  states::{
+    DeployRequest, DeployRequestStatus, DeveloperEscrow, FeeSource, PlanConfig, TokenType,
+    TreasuryPool,
+  },
+  utils::payout_from_pda,
+};
+
+/// Developer moves an active subscription to another plan tier
+///
+/// The remaining paid period is prorated (see DeployRequest::switch_plan).
+/// An upgrade's difference is charged from the developer escrow's SOL
+/// balance when the escrow is passed, otherwise from the wallet, and split
+/// like pay_subscription. A downgrade is credited as extra subscription time
+/// rather than lamports: the difference was already shared with stakers.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ChangeSubscriptionPlan<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        seeds = [PlanConfig::PREFIX_SEED],
+        bump = plan_config.bump
+    )]
+  pub plan_config: Account<'info, PlanConfig>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  /// Pays an upgrade's difference from its SOL balance (optional)
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, developer.key().as_ref()],
+        bump = developer_escrow.bump
+    )]
+  pub developer_escrow: Option<Account<'info, DeveloperEscrow>>,
+
+  /// CHECK: Reward Pool PDA
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform Pool PDA - receives the platform share of subscription revenue
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn change_subscription_plan(
+  ctx: Context<ChangeSubscriptionPlan>,
+  request_id: [u8; 32],
+  new_plan: u8,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    deploy_request.status == DeployRequestStatus::Active,
+    ErrorCode::InvalidRequestStatus
+  );
+  require!(
+    deploy_request.is_subscription_valid()?,
+    ErrorCode::SubscriptionExpired
+  );
+  require!(
+    deploy_request.plan_id != new_plan,
+    ErrorCode::InvalidPlanChange
+  );
+
+  let tier = *ctx.accounts.plan_config.get_tier(new_plan)?;
+  let old_plan_id = deploy_request.plan_id;
+  let old_monthly_fee = deploy_request.monthly_fee;
+  let (charged_amount, extension) = deploy_request.switch_plan(&tier, current_time)?;
+
+  let paid_from_escrow = ctx.accounts.developer_escrow.is_some();
+  if charged_amount > 0 {
+    let (reward_share, platform_share) =
+      treasury_pool.split_subscription_payment(charged_amount)?;
+
+    if let Some(developer_escrow) = ctx.accounts.developer_escrow.as_mut() {
+      developer_escrow.deduct_balance(charged_amount, TokenType::SOL)?;
+      payout_from_pda(
+        &developer_escrow.to_account_info(),
+        &ctx.accounts.reward_pool.to_account_info(),
+        reward_share,
+      )?;
+      payout_from_pda(
+        &developer_escrow.to_account_info(),
+        &ctx.accounts.platform_pool.to_account_info(),
+        platform_share,
+      )?;
+    } else {
+      system_program::transfer(
+        CpiContext::new(
+          ctx.accounts.system_program.to_account_info(),
+          system_program::Transfer {
+            from: ctx.accounts.developer.to_account_info(),
+            to: ctx.accounts.reward_pool.to_account_info(),
+          },
+        ),
+        reward_share,
+      )?;
+      if platform_share > 0 {
+        system_program::transfer(
+          CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+              from: ctx.accounts.developer.to_account_info(),
+              to: ctx.accounts.platform_pool.to_account_info(),
+            },
+          ),
+          platform_share,
+        )?;
+      }
+    }
+
+    treasury_pool.collect_fee(FeeSource::Subscription, reward_share, platform_share)?;
+    deploy_request.record_developer_payment(charged_amount)?;
+  }
+
+  emit!(SubscriptionPlanChanged {
+    request_id,
+    developer: deploy_request.developer,
+    old_plan_id,
+    new_plan_id: new_plan,
+    old_monthly_fee,
+    new_monthly_fee: deploy_request.monthly_fee,
+    charged_amount,
+    paid_from_escrow: paid_from_escrow && charged_amount > 0,
+    extension_seconds: extension,
+    subscription_paid_until: deploy_request.subscription_paid_until,
+    changed_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+
+use crate::{
+  errors::ErrorCode,
+  events::EscrowClosed,
+  states::{DeveloperEscrow, EscrowTopUp},
+};
+
+/// Close the developer escrow and recover its rent
+///
+/// Remaining SOL is swept to the developer together with the rent. USDC/USDT
+/// must be withdrawn first. Auto-renewal must be switched off (so no
+/// subscription relies on the escrow) and any top-up allowance disabled.
+///
+/// Remaining accounts: the escrow's (empty) token vaults, closed to the
+/// developer as well.
+#[derive(Accounts)]
+pub struct CloseEscrow<'info> {
+  #[account(
+        mut,
+        close = developer,
+        seeds = [DeveloperEscrow::PREFIX_SEED, developer.key().as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Account<'info, DeveloperEscrow>,
+
+  /// CHECK: Top-up config PDA, must not exist
+  #[account(
+        seeds = [EscrowTopUp::PREFIX_SEED, developer.key().as_ref()],
+        bump
+    )]
+  pub escrow_top_up: UncheckedAccount<'info>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+pub fn close_escrow<'info>(ctx: Context<'_, '_, 'info, 'info, CloseEscrow<'info>>) -> Result<()> {
+  let developer_escrow = &ctx.accounts.developer_escrow;
+  let developer_info = ctx.accounts.developer.to_account_info();
+
+  require!(
+    developer_escrow.usdc_balance == 0 && developer_escrow.usdt_balance == 0,
+    ErrorCode::EscrowNotEmpty
+  );
+  require!(!developer_escrow.auto_renew_enabled, ErrorCode::EscrowInUse);
+  require!(
+    ctx.accounts.escrow_top_up.data_is_empty(),
+    ErrorCode::EscrowInUse
+  );
+
+  let escrow_key = developer_escrow.key();
+  let escrow_seeds: &[&[u8]] = &[
+    DeveloperEscrow::PREFIX_SEED,
+    developer_escrow.developer.as_ref(),
+    &[developer_escrow.bump],
+  ];
+  for vault_info in ctx.remaining_accounts {
+    let vault = TokenAccount::try_deserialize(&mut &vault_info.data.borrow()[..])
+      .map_err(|_| ErrorCode::InvalidAccountData)?;
+    let (expected_vault, _) = Pubkey::find_program_address(
+      &[
+        DeveloperEscrow::TOKEN_VAULT_SEED,
+        escrow_key.as_ref(),
+        vault.mint.as_ref(),
+      ],
+      ctx.program_id,
+    );
+    require!(
+      vault_info.key() == expected_vault,
+      ErrorCode::InvalidAccountData
+    );
+    require!(vault.amount == 0, ErrorCode::EscrowNotEmpty);
+
+    token::close_account(CpiContext::new_with_signer(
+      ctx.accounts.token_program.to_account_info(),
+      CloseAccount {
+        account: vault_info.clone(),
+        destination: developer_info.clone(),
+        authority: developer_escrow.to_account_info(),
+      },
+      &[escrow_seeds],
+    ))?;
+  }
+
+  emit!(EscrowClosed {
+    developer: developer_escrow.developer,
+    sol_returned: developer_escrow.sol_balance,
+    token_vaults_closed: ctx.remaining_accounts.len() as u32,
+    closed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::EscrowConfigured,
+  states::{DeveloperEscrow, TreasuryPool},
+};
+
+#[derive(Accounts)]
+pub struct ConfigureEscrow<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, developer.key().as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Account<'info, DeveloperEscrow>,
+
+  pub developer: Signer<'info>,
+}
+
+/// Developer sets the low-balance alert threshold and the limits on
+/// automatic deductions (0 = no cap / no limit)
+///
+/// All amounts are in units of the escrow's preferred token. Changing the
+/// monthly limit does not reset the current period's running total.
+pub fn configure_escrow(
+  ctx: Context<ConfigureEscrow>,
+  min_balance_alert: u64,
+  max_monthly_spend: u64,
+  max_auto_deduct_per_month: u64,
+) -> Result<()> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  let developer_escrow = &mut ctx.accounts.developer_escrow;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  // A monthly limit below the per-month cap could never be reached
+  require!(
+    max_auto_deduct_per_month == 0 || max_auto_deduct_per_month >= max_monthly_spend,
+    ErrorCode::InvalidEscrowConfig
+  );
+
+  developer_escrow.min_balance_alert = min_balance_alert;
+  developer_escrow.max_monthly_spend = max_monthly_spend;
+  developer_escrow.max_auto_deduct_per_month = max_auto_deduct_per_month;
+
+  emit!(EscrowConfigured {
+    developer: developer_escrow.developer,
+    min_balance_alert,
+    max_monthly_spend,
+    max_auto_deduct_per_month,
+    configured_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+  associated_token::AssociatedToken,
+  token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+  errors::ErrorCode,
+  events::EscrowDeposited,
+  states::{DeveloperEscrow, TreasuryPool},
+};
+
+/// Developer deposits USDC/USDT into escrow for auto-renewal
+///
+/// Tokens move from the developer's associated token account into the
+/// escrow's token vault PDA for that mint (created on first deposit). The
+/// mint must be one of the escrow mints configured on the treasury pool.
+#[derive(Accounts)]
+pub struct DepositEscrowToken<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, developer.key().as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Account<'info, DeveloperEscrow>,
+
+  pub mint: Account<'info, Mint>,
+
+  #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = developer
+    )]
+  pub developer_token_account: Account<'info, TokenAccount>,
+
+  #[account(
+        init_if_needed,
+        payer = developer,
+        seeds = [
+            DeveloperEscrow::TOKEN_VAULT_SEED,
+            developer_escrow.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = developer_escrow
+    )]
+  pub escrow_token_vault: Account<'info, TokenAccount>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_escrow_token(ctx: Context<DepositEscrowToken>, amount: u64) -> Result<()> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  let developer_escrow = &mut ctx.accounts.developer_escrow;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(amount > 0, ErrorCode::InvalidAmount);
+  let token_type = treasury_pool
+    .escrow_token_type(&ctx.accounts.mint.key())
+    .ok_or(ErrorCode::UnsupportedEscrowMint)?;
+
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.developer_token_account.to_account_info(),
+        to: ctx.accounts.escrow_token_vault.to_account_info(),
+        authority: ctx.accounts.developer.to_account_info(),
+      },
+    ),
+    amount,
+  )?;
+
+  developer_escrow.add_balance(amount, token_type)?;
+
+  emit!(EscrowDeposited {
+    developer: ctx.accounts.developer.key(),
+    token_type: token_type as u8,
+    amount,
+    new_balance: developer_escrow.get_balance(token_type),
+    deposited_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
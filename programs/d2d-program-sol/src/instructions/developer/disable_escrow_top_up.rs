@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Revoke, Token, TokenAccount};
+
+use crate::{errors::ErrorCode, events::EscrowTopUpDisabled, states::EscrowTopUp};
+
+/// Revoke the top-up allowance and close the top-up config
+#[derive(Accounts)]
+pub struct DisableEscrowTopUp<'info> {
+  #[account(
+        mut,
+        close = developer,
+        seeds = [EscrowTopUp::PREFIX_SEED, developer.key().as_ref()],
+        bump = escrow_top_up.bump
+    )]
+  pub escrow_top_up: Account<'info, EscrowTopUp>,
+
+  #[account(
+        mut,
+        address = escrow_top_up.source_token_account @ ErrorCode::InvalidAccountData,
+        token::authority = developer
+    )]
+  pub source_token_account: Account<'info, TokenAccount>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+pub fn disable_escrow_top_up(ctx: Context<DisableEscrowTopUp>) -> Result<()> {
+  // Only clear the delegate if it is still ours
+  if ctx.accounts.source_token_account.delegate == Some(ctx.accounts.escrow_top_up.key()).into() {
+    token::revoke(CpiContext::new(
+      ctx.accounts.token_program.to_account_info(),
+      Revoke {
+        source: ctx.accounts.source_token_account.to_account_info(),
+        authority: ctx.accounts.developer.to_account_info(),
+      },
+    ))?;
+  }
+
+  emit!(EscrowTopUpDisabled {
+    developer: ctx.accounts.developer.key(),
+    total_topped_up: ctx.accounts.escrow_top_up.total_topped_up,
+    disabled_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
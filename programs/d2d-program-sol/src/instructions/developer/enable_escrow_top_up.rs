@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve, Token, TokenAccount};
+
+use crate::{
+  errors::ErrorCode,
+  events::EscrowTopUpEnabled,
+  states::{DeveloperEscrow, EscrowTopUp, TreasuryPool},
+};
+
+/// Opt in to crank top-ups of the escrow's preferred token
+///
+/// Approves the top-up PDA as delegate for `allowance` on the developer's
+/// source token account (wSOL for SOL escrows) and stores the per-top-up
+/// amount and the escrow's alert threshold. Calling again replaces the
+/// configuration and the allowance.
+#[derive(Accounts)]
+pub struct EnableEscrowTopUp<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, developer.key().as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Account<'info, DeveloperEscrow>,
+
+  #[account(
+        init_if_needed,
+        payer = developer,
+        space = 8 + EscrowTopUp::INIT_SPACE,
+        seeds = [EscrowTopUp::PREFIX_SEED, developer.key().as_ref()],
+        bump
+    )]
+  pub escrow_top_up: Account<'info, EscrowTopUp>,
+
+  #[account(
+        mut,
+        token::authority = developer
+    )]
+  pub source_token_account: Account<'info, TokenAccount>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn enable_escrow_top_up(
+  ctx: Context<EnableEscrowTopUp>,
+  top_up_amount: u64,
+  allowance: u64,
+  min_balance_alert: u64,
+) -> Result<()> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  let developer_escrow = &mut ctx.accounts.developer_escrow;
+  let escrow_top_up = &mut ctx.accounts.escrow_top_up;
+  let source_token_account = &ctx.accounts.source_token_account;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    top_up_amount > 0 && allowance >= top_up_amount && min_balance_alert > 0,
+    ErrorCode::InvalidTopUpConfig
+  );
+
+  let token_type = developer_escrow.preferred_token;
+  let source_mint =
+    EscrowTopUp::source_mint(treasury_pool, token_type).ok_or(ErrorCode::UnsupportedEscrowMint)?;
+  require!(
+    source_token_account.mint == source_mint,
+    ErrorCode::UnsupportedEscrowMint
+  );
+
+  token::approve(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info(),
+      Approve {
+        to: source_token_account.to_account_info(),
+        delegate: escrow_top_up.to_account_info(),
+        authority: ctx.accounts.developer.to_account_info(),
+      },
+    ),
+    allowance,
+  )?;
+
+  let current_time = Clock::get()?.unix_timestamp;
+  developer_escrow.min_balance_alert = min_balance_alert;
+  escrow_top_up.developer = developer_escrow.developer;
+  escrow_top_up.token_type = token_type;
+  escrow_top_up.source_token_account = source_token_account.key();
+  escrow_top_up.top_up_amount = top_up_amount;
+  escrow_top_up.enabled_at = current_time;
+  escrow_top_up.bump = ctx.bumps.escrow_top_up;
+
+  emit!(EscrowTopUpEnabled {
+    developer: developer_escrow.developer,
+    token_type: token_type as u8,
+    source_token_account: source_token_account.key(),
+    top_up_amount,
+    allowance,
+    min_balance_alert,
+    enabled_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,229 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::{AuthorityTransferExecuted, DebtRepaid},
+  instructions::{auto_fulfill_withdrawal_queue, return_upgrade_authority},
+  states::{
+    ApprovedAuthority, AuthorityTransfer, DeployRequest, DeployRequestStatus, FeeSource,
+    ManagedProgram, TreasuryPool,
+  },
+};
+
+/// Hand the upgrade authority to the proposed address once the timelock ran
+///
+/// Subscription validity and the allowlist entry are checked again. The
+/// request stays Active and keeps paying its subscription, but the program
+/// leaves proxy management: rent can no longer be reclaimed by closing it,
+/// which is why only admin-approved authorities are accepted. As in
+/// reclaim_authority, the developer first repays the outstanding deployment
+/// debt and the pool's exit fee.
+///
+/// Remaining accounts: `[queue_entry, lender_stake, staker]` triples from
+/// the withdrawal queue head, fulfilled with the repaid debt.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ExecuteAuthorityTransfer<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Treasury vault PDA (receives the repaid debt)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  /// CHECK: Reward Pool PDA (receives the exit fee)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  /// CHECK: Validated by managed_program seeds
+  pub program_account: UncheckedAccount<'info>,
+
+  /// Program data account (authority is rewritten)
+  /// CHECK: Address derived from managed_program; validated by BPF Loader during CPI
+  #[account(
+        mut,
+        constraint = program_data.key() == managed_program.program_data_address() @ ErrorCode::InvalidAccountData
+    )]
+  pub program_data: UncheckedAccount<'info>,
+
+  /// PDA that currently holds the upgrade authority
+  /// CHECK: Validated by seeds and managed_program.authority_pda
+  #[account(
+        seeds = [ManagedProgram::AUTHORITY_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub authority_pda: SystemAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.deploy_request == deploy_request.key() @ ErrorCode::InvalidRequestId,
+        constraint = managed_program.authority_pda == authority_pda.key() @ ErrorCode::InvalidAuthorityPda,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  #[account(
+        mut,
+        close = developer,
+        seeds = [AuthorityTransfer::PREFIX_SEED, program_account.key().as_ref()],
+        bump = authority_transfer.bump,
+        constraint = authority_transfer.deploy_request == deploy_request.key() @ ErrorCode::InvalidRequestId
+    )]
+  pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+  #[account(
+        seeds = [ApprovedAuthority::PREFIX_SEED, authority_transfer.new_authority.as_ref()],
+        bump = approved_authority.bump,
+        constraint = approved_authority.enabled @ ErrorCode::AuthorityNotApproved
+    )]
+  pub approved_authority: Account<'info, ApprovedAuthority>,
+
+  /// CHECK: Must be the proposed authority
+  #[account(
+        constraint = new_authority.key() == authority_transfer.new_authority @ ErrorCode::AuthorityNotApproved
+    )]
+  pub new_authority: UncheckedAccount<'info>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  /// BPF Loader Upgradeable Program
+  /// CHECK: Known program ID
+  #[account(
+        constraint = bpf_loader_upgradeable_program.key() == bpf_loader_upgradeable::ID
+    )]
+  pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn execute_authority_transfer<'info>(
+  ctx: Context<'_, '_, '_, 'info, ExecuteAuthorityTransfer<'info>>,
+  request_id: [u8; 32],
+) -> Result<()> {
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    ctx.accounts.authority_transfer.is_executable(current_time),
+    ErrorCode::AuthorityTransferTimelocked
+  );
+  require!(
+    deploy_request.status == DeployRequestStatus::Active,
+    ErrorCode::InvalidRequestStatus
+  );
+  require!(
+    deploy_request.is_subscription_valid()?,
+    ErrorCode::SubscriptionExpired
+  );
+
+  // === DEBT REPAYMENT ===
+  let remaining_debt = deploy_request.get_remaining_debt();
+  if remaining_debt > 0 {
+    system_program::transfer(
+      CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+          from: ctx.accounts.developer.to_account_info(),
+          to: treasury_vault_info.clone(),
+        },
+      ),
+      remaining_debt,
+    )?;
+    deploy_request.record_debt_settlement(remaining_debt)?;
+  }
+  // Restores liquid_balance for the repaid debt and closes out the deployment
+  treasury_pool.record_debt_repayment(remaining_debt, remaining_debt, current_time)?;
+
+  // === EXIT FEE ===
+  let exit_fee = treasury_pool.calculate_authority_exit_fee(deploy_request.borrowed_amount)?;
+  if exit_fee > 0 {
+    system_program::transfer(
+      CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+          from: ctx.accounts.developer.to_account_info(),
+          to: ctx.accounts.reward_pool.to_account_info(),
+        },
+      ),
+      exit_fee,
+    )?;
+    treasury_pool.collect_fee(FeeSource::AuthorityExit, exit_fee, 0)?;
+    deploy_request.record_developer_payment(exit_fee)?;
+  }
+
+  // === HAND OVER UPGRADE AUTHORITY ===
+  return_upgrade_authority(
+    &ctx.accounts.program_account.to_account_info(),
+    &ctx.accounts.program_data.to_account_info(),
+    &ctx.accounts.authority_pda.to_account_info(),
+    &ctx.accounts.new_authority.to_account_info(),
+    ctx.bumps.authority_pda,
+  )?;
+
+  ctx.accounts.managed_program.is_active = false;
+
+  // Repaid debt restored liquid_balance - fulfill queued withdrawals first
+  if remaining_debt > 0 {
+    auto_fulfill_withdrawal_queue(
+      treasury_pool,
+      &treasury_vault_info,
+      &ctx.accounts.system_program.to_account_info(),
+      ctx.remaining_accounts,
+      &[],
+      ctx.program_id,
+      current_time,
+    )?;
+  }
+
+  emit!(AuthorityTransferExecuted {
+    request_id,
+    program_id: ctx.accounts.program_account.key(),
+    developer: deploy_request.developer,
+    old_authority: ctx.accounts.authority_pda.key(),
+    new_authority: ctx.accounts.new_authority.key(),
+    debt_repaid: remaining_debt,
+    exit_fee,
+    executed_at: current_time,
+  });
+
+  if remaining_debt > 0 {
+    emit!(DebtRepaid {
+      deploy_request_id: deploy_request.request_id,
+      developer: deploy_request.developer,
+      borrowed_amount: deploy_request.borrowed_amount,
+      repaid_amount: deploy_request.repaid_amount,
+      remaining_debt: deploy_request.get_remaining_debt(),
+      recovery_ratio_bps: deploy_request.recovery_ratio_bps,
+      repaid_at: current_time,
+    });
+  }
+
+  Ok(())
+}
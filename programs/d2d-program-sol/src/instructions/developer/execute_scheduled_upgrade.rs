@@ -0,0 +1,250 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{
+  errors::ErrorCode,
+  events::{ProgramUpgraded, ScheduledUpgradeExecuted},
+  instructions::{charge_upgrade_frequency, settle_spilled_buffer_rent, upgrade_via_authority_pda},
+  states::{
+    BufferRentLoan, DeployRequest, DeployRequestStatus, DeveloperEscrow, ManagedProgram,
+    TreasuryPool, UpgradeRecord,
+  },
+};
+
+/// Developer applies the upgrade announced by schedule_upgrade once its
+/// notice period has passed
+///
+/// Same requirements as proxy_upgrade_program, re-checked at execution; the
+/// buffer is already held by the authority PDA.
+#[derive(Accounts)]
+pub struct ExecuteScheduledUpgrade<'info> {
+  /// The program to be upgraded
+  /// CHECK: Validated by program_data and managed_program
+  #[account(mut)]
+  pub program_account: UncheckedAccount<'info>,
+
+  /// Program data account (will be updated with new bytecode)
+  /// CHECK: Will be validated by BPF Loader during CPI
+  #[account(mut)]
+  pub program_data: UncheckedAccount<'info>,
+
+  /// CHECK: Must be the scheduled buffer
+  #[account(
+        mut,
+        constraint = buffer_account.key() == managed_program.scheduled_buffer @ ErrorCode::NoScheduledUpgrade
+    )]
+  pub buffer_account: UncheckedAccount<'info>,
+
+  /// PDA that holds the upgrade and buffer authority
+  /// CHECK: Validated by seeds and managed_program.authority_pda
+  #[account(
+        seeds = [ManagedProgram::AUTHORITY_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub authority_pda: SystemAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.developer == developer.key() @ ErrorCode::Unauthorized,
+        constraint = managed_program.authority_pda == authority_pda.key() @ ErrorCode::InvalidAuthorityPda,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  /// CHECK: Deploy request - validated manually for migration compatibility
+  #[account(
+        constraint = deploy_request.key() == managed_program.deploy_request @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: UncheckedAccount<'info>,
+
+  /// Receives the excess when a repaid buffer rent loan is closed
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  /// Account to receive any excess lamports from buffer
+  /// CHECK: Can be any account, typically the developer (the loan PDA if one is open)
+  #[account(mut)]
+  pub spill_account: UncheckedAccount<'info>,
+
+  /// BPF Loader Upgradeable Program
+  /// CHECK: Known program ID
+  #[account(
+        constraint = bpf_loader_upgradeable_program.key() == bpf_loader_upgradeable::ID
+    )]
+  pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Treasury vault PDA (receives buffer rent loan repayments)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  pub rent: Sysvar<'info, Rent>,
+  pub clock: Sysvar<'info, Clock>,
+
+  /// Buffer rent loan for this program (may not exist)
+  /// CHECK: Validated by seeds; only deserialized if owned by this program
+  #[account(
+        mut,
+        seeds = [BufferRentLoan::PREFIX_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub buffer_rent_loan: UncheckedAccount<'info>,
+
+  /// History entry for this upgrade (paid by the signer)
+  #[account(
+        init,
+        payer = developer,
+        space = 8 + UpgradeRecord::INIT_SPACE,
+        seeds = [
+          UpgradeRecord::PREFIX_SEED,
+          program_account.key().as_ref(),
+          &managed_program.upgrade_count.to_le_bytes(),
+        ],
+        bump
+    )]
+  pub upgrade_record: Account<'info, UpgradeRecord>,
+
+  /// Owner's escrow, charged for upgrades beyond the monthly limit
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, managed_program.developer.as_ref()],
+        bump = developer_escrow.bump
+    )]
+  pub developer_escrow: Option<Account<'info, DeveloperEscrow>>,
+
+  /// CHECK: Reward pool PDA - receives the reward share of overage fees
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform pool PDA - receives the platform share of overage fees
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn execute_scheduled_upgrade(ctx: Context<ExecuteScheduledUpgrade>) -> Result<()> {
+  let managed_program = &mut ctx.accounts.managed_program;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+  require!(
+    managed_program.has_scheduled_upgrade(),
+    ErrorCode::NoScheduledUpgrade
+  );
+  require!(
+    current_time >= managed_program.scheduled_upgrade_after,
+    ErrorCode::TimelockNotExpired
+  );
+
+  let loan_info = ctx.accounts.buffer_rent_loan.to_account_info();
+  let has_buffer_rent_loan = loan_info.owner == ctx.program_id;
+  if has_buffer_rent_loan {
+    require!(
+      ctx.accounts.spill_account.key() == loan_info.key(),
+      ErrorCode::BufferRentLoanOutstanding
+    );
+  }
+
+  let deploy_request = DeployRequest::load_padded(&ctx.accounts.deploy_request.to_account_info())?;
+  require!(
+    deploy_request.status == DeployRequestStatus::Active,
+    ErrorCode::InvalidDeploymentStatus
+  );
+  require!(
+    deploy_request.is_subscription_valid()?,
+    ErrorCode::SubscriptionExpired
+  );
+  require!(
+    deploy_request.allows_upgrade(managed_program.upgrade_count),
+    ErrorCode::PlanUpgradeLimitReached
+  );
+  let overage_fee = charge_upgrade_frequency(
+    managed_program,
+    &deploy_request,
+    &mut ctx.accounts.treasury_pool,
+    ctx.accounts.developer_escrow.as_mut(),
+    &ctx.accounts.reward_pool.to_account_info(),
+    &ctx.accounts.platform_pool.to_account_info(),
+    current_time,
+  )?;
+
+  upgrade_via_authority_pda(
+    &ctx.accounts.program_account.to_account_info(),
+    &ctx.accounts.program_data.to_account_info(),
+    &ctx.accounts.buffer_account.to_account_info(),
+    &ctx.accounts.spill_account.to_account_info(),
+    &ctx.accounts.authority_pda.to_account_info(),
+    [
+      ctx.accounts.rent.to_account_info(),
+      ctx.accounts.clock.to_account_info(),
+    ],
+    ctx.bumps.authority_pda,
+  )?;
+
+  if has_buffer_rent_loan {
+    settle_spilled_buffer_rent(
+      &mut ctx.accounts.treasury_pool,
+      &loan_info,
+      &ctx.accounts.treasury_vault.to_account_info(),
+      &ctx.accounts.developer.to_account_info(),
+    )?;
+  }
+
+  let buffer_address = managed_program.scheduled_buffer;
+  let scheduled_at = managed_program.upgrade_scheduled_at;
+
+  let upgrade_record = &mut ctx.accounts.upgrade_record;
+  upgrade_record.program_id = managed_program.program_id;
+  upgrade_record.index = managed_program.upgrade_count;
+  upgrade_record.buffer_address = buffer_address;
+  upgrade_record.signer = ctx.accounts.developer.key();
+  upgrade_record.slot = Clock::get()?.slot;
+  upgrade_record.upgraded_at = current_time;
+  upgrade_record.bump = ctx.bumps.upgrade_record;
+
+  managed_program.clear_scheduled_upgrade();
+  managed_program.last_upgraded_at = current_time;
+  managed_program.upgrade_count = managed_program.upgrade_count.saturating_add(1);
+
+  emit!(ScheduledUpgradeExecuted {
+    program_id: managed_program.program_id,
+    developer: managed_program.developer,
+    buffer_address,
+    scheduled_at,
+    executed_at: current_time,
+  });
+  emit!(ProgramUpgraded {
+    program_id: managed_program.program_id,
+    developer: managed_program.developer,
+    upgraded_by: ctx.accounts.developer.key(),
+    buffer_address,
+    buffer_hash: None,
+    overage_fee,
+    upgraded_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::SubscriptionStatusQueried,
+  instructions::escrow_renewal_payment,
+  states::{DeployRequest, DeployRequestStatus, DeveloperEscrow, TokenType, TreasuryPool},
+};
+
+/// Read-only summary of a subscription for dashboards
+///
+/// Applies the same business rules the program enforces (grace period,
+/// borrow fees, escrow renewal pricing) so clients do not re-derive them.
+/// Pass the developer's escrow to price the next auto-renewal in its
+/// preferred token; without it the estimate is one month in SOL. Intended
+/// to be simulated: the result is returned via return data and also
+/// emitted as an event. No account is modified.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct GetSubscriptionStatus<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  #[account(
+        seeds = [DeveloperEscrow::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == deploy_request.developer @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Option<Account<'info, DeveloperEscrow>>,
+}
+
+/// Subscription summary returned by `get_subscription_status`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SubscriptionStatus {
+  pub status: DeployRequestStatus,
+  pub subscription_paid_until: i64,
+  /// Whole days left on the paid subscription (0 once expired)
+  pub days_remaining: u64,
+  pub in_grace_period: bool,
+  /// 0 when not in the grace period
+  pub grace_period_end: i64,
+  /// Whole days left before the program can be closed (0 outside the grace period)
+  pub grace_days_remaining: u64,
+  /// Monthly borrow fees accrued on the uncollateralized borrow and not yet collected
+  pub outstanding_borrow_fees: u64,
+  /// Whether the request and (if given) the escrow both allow auto-renewal
+  pub auto_renewal_enabled: bool,
+  pub next_renewal_token: TokenType,
+  /// One month at current pricing, after any prepayment discount (0 when it cannot be priced)
+  pub next_renewal_amount: u64,
+  /// When the next auto-renewal becomes due
+  pub next_renewal_at: i64,
+  /// The escrow holds enough of the token for the next renewal
+  pub escrow_covers_renewal: bool,
+}
+
+pub fn get_subscription_status(
+  ctx: Context<GetSubscriptionStatus>,
+  request_id: [u8; 32],
+) -> Result<SubscriptionStatus> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  let deploy_request = &ctx.accounts.deploy_request;
+  let developer_escrow = ctx.accounts.developer_escrow.as_ref();
+  let current_time = Clock::get()?.unix_timestamp;
+
+  let days_left = |until: i64| {
+    (until.saturating_sub(current_time).max(0) / DeployRequest::SECONDS_PER_DAY) as u64
+  };
+  let days_remaining = days_left(deploy_request.subscription_paid_until);
+  let in_grace_period = deploy_request.is_in_grace_period();
+  let (grace_period_end, grace_days_remaining) = if in_grace_period {
+    (
+      deploy_request.grace_period_end,
+      days_left(deploy_request.grace_period_end),
+    )
+  } else {
+    (0, 0)
+  };
+
+  let outstanding_borrow_fees = deploy_request.outstanding_borrow_fees()?;
+
+  let next_renewal_token = developer_escrow.map_or(TokenType::SOL, |escrow| escrow.preferred_token);
+  let next_renewal_amount =
+    escrow_renewal_payment(treasury_pool, deploy_request, next_renewal_token, 1)
+      .map(|(amount, _discount)| amount)
+      .unwrap_or(0);
+  let auto_renewal_enabled = deploy_request.auto_renewal_enabled
+    && developer_escrow.is_none_or(|escrow| escrow.auto_renew_enabled);
+  let escrow_covers_renewal = developer_escrow.is_some_and(|escrow| {
+    next_renewal_amount > 0 && escrow.get_balance(next_renewal_token) >= next_renewal_amount
+  });
+  let next_renewal_at = match deploy_request.status {
+    DeployRequestStatus::Active
+    | DeployRequestStatus::SubscriptionExpired
+    | DeployRequestStatus::InGracePeriod => deploy_request.subscription_paid_until,
+    _ => 0,
+  };
+
+  emit!(SubscriptionStatusQueried {
+    request_id,
+    developer: deploy_request.developer,
+    days_remaining,
+    in_grace_period,
+    outstanding_borrow_fees,
+    next_renewal_amount,
+    queried_at: current_time,
+  });
+
+  Ok(SubscriptionStatus {
+    status: deploy_request.status.clone(),
+    subscription_paid_until: deploy_request.subscription_paid_until,
+    days_remaining,
+    in_grace_period,
+    grace_period_end,
+    grace_days_remaining,
+    outstanding_borrow_fees,
+    auto_renewal_enabled,
+    next_renewal_token,
+    next_renewal_amount,
+    next_renewal_at,
+    escrow_covers_renewal,
+  })
+}
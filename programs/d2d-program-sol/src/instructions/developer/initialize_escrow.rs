@@ -53,6 +53,10 @@ pub fn initialize_escrow(ctx: Context<InitializeEscrow>) -> Result<()> {
   developer_escrow.last_deposit_at = 0;
   developer_escrow.last_auto_deduct_at = 0;
   developer_escrow.bump = ctx.bumps.developer_escrow;
+  developer_escrow.max_monthly_spend = 0;
+  developer_escrow.max_auto_deduct_per_month = 0;
+  developer_escrow.deduct_period_start = 0;
+  developer_escrow.deducted_this_period = 0;
 
   emit!(EscrowInitialized {
     developer: developer.key(),
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::DeveloperIdentityLinked, states::DeveloperIdentity};
+
+/// Developer links (or re-links) a hash of their off-chain profile
+///
+/// Changing the profile hash clears any existing attestation; the registrar
+/// must attest the new profile before it counts as verified.
+#[derive(Accounts)]
+pub struct LinkDeveloperIdentity<'info> {
+  #[account(
+        init_if_needed,
+        payer = developer,
+        space = 8 + DeveloperIdentity::INIT_SPACE,
+        seeds = [DeveloperIdentity::PREFIX_SEED, developer.key().as_ref()],
+        bump
+    )]
+  pub developer_identity: Account<'info, DeveloperIdentity>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn link_developer_identity(
+  ctx: Context<LinkDeveloperIdentity>,
+  profile_hash: [u8; 32],
+) -> Result<()> {
+  require!(profile_hash != [0u8; 32], ErrorCode::InvalidProfileHash);
+
+  let developer_identity = &mut ctx.accounts.developer_identity;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  developer_identity.developer = ctx.accounts.developer.key();
+  developer_identity.bump = ctx.bumps.developer_identity;
+  developer_identity.link(profile_hash, current_time);
+
+  emit!(DeveloperIdentityLinked {
+    developer: developer_identity.developer,
+    profile_hash,
+    attested: developer_identity.is_attested(),
+    linked_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::DeveloperCollateralLocked,
+  states::{DeployRequest, DeployRequestStatus, DeveloperCollateral, TreasuryPool},
+};
+
+/// Developer locks SOL as collateral against a deployment's borrowed capital
+///
+/// Collateral offsets `borrowed_amount` for the 1% monthly borrow fee from
+/// the month after it is locked, and can be topped up at any time; it is
+/// only returned by
+/// release_developer_collateral once the program is closed.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct LockDeveloperCollateral<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  #[account(
+        init_if_needed,
+        payer = developer,
+        space = 8 + DeveloperCollateral::INIT_SPACE,
+        seeds = [DeveloperCollateral::PREFIX_SEED, deploy_request.key().as_ref()],
+        bump
+    )]
+  pub developer_collateral: Account<'info, DeveloperCollateral>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn lock_developer_collateral(
+  ctx: Context<LockDeveloperCollateral>,
+  request_id: [u8; 32],
+  amount: u64,
+) -> Result<()> {
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let developer_collateral = &mut ctx.accounts.developer_collateral;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+  require!(amount > 0, ErrorCode::InvalidAmount);
+  require!(
+    matches!(
+      deploy_request.status,
+      DeployRequestStatus::PendingDeployment
+        | DeployRequestStatus::Active
+        | DeployRequestStatus::SubscriptionExpired
+        | DeployRequestStatus::InGracePeriod
+    ),
+    ErrorCode::InvalidRequestStatus
+  );
+
+  system_program::transfer(
+    CpiContext::new(
+      ctx.accounts.system_program.to_account_info(),
+      system_program::Transfer {
+        from: ctx.accounts.developer.to_account_info(),
+        to: developer_collateral.to_account_info(),
+      },
+    ),
+    amount,
+  )?;
+
+  if developer_collateral.locked_at == 0 {
+    developer_collateral.deploy_request = deploy_request.key();
+    developer_collateral.developer = deploy_request.developer;
+    developer_collateral.locked_at = current_time;
+    developer_collateral.bump = ctx.bumps.developer_collateral;
+  }
+  developer_collateral.amount = developer_collateral
+    .amount
+    .checked_add(amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  // Months already accrued keep the fee they were charged at
+  deploy_request.checkpoint_borrow_fees(current_time)?;
+  deploy_request.collateral_amount = developer_collateral.amount;
+
+  emit!(DeveloperCollateralLocked {
+    request_id,
+    developer: deploy_request.developer,
+    amount,
+    total_collateral: developer_collateral.amount,
+    borrowed_amount: deploy_request.borrowed_amount,
+    locked_at: current_time,
+  });
+
+  Ok(())
+}
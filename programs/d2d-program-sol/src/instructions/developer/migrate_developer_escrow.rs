@@ -0,0 +1,84 @@
+use anchor_lang::{prelude::*, solana_program::rent::Rent, system_program, Discriminator};
+
+use crate::{errors::ErrorCode, events::DeveloperEscrowMigrated, states::DeveloperEscrow};
+
+/// Grow a DeveloperEscrow account created before the spending limits or the
+/// per-program budgets
+///
+/// New fields are zero-filled, which means "no cap", a fresh deduction
+/// period and all budget slots unused (the budgets alone add
+/// MAX_BUDGETS * 48 bytes). Permissionless - anyone may pay the extra rent, since no existing
+/// field is touched.
+#[derive(Accounts)]
+pub struct MigrateDeveloperEscrow<'info> {
+  /// CHECK: Validated by seeds; too small to deserialize until migrated
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, developer.key().as_ref()],
+        bump
+    )]
+  pub developer_escrow: UncheckedAccount<'info>,
+
+  /// CHECK: Only used to derive the developer_escrow PDA
+  pub developer: UncheckedAccount<'info>,
+
+  /// Pays for any additional rent required by the larger layout
+  #[account(mut)]
+  pub payer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_developer_escrow(ctx: Context<MigrateDeveloperEscrow>) -> Result<()> {
+  let escrow_info = ctx.accounts.developer_escrow.to_account_info();
+
+  require!(
+    escrow_info.owner == ctx.program_id,
+    ErrorCode::InvalidAccountOwner
+  );
+
+  let required_space = 8 + DeveloperEscrow::INIT_SPACE;
+  let current_space = escrow_info.data_len();
+  require!(
+    current_space >= 8 && escrow_info.data.borrow()[..8] == *DeveloperEscrow::DISCRIMINATOR,
+    ErrorCode::InvalidAccountData
+  );
+
+  // Already on the current layout - nothing to do
+  if current_space >= required_space {
+    return Ok(());
+  }
+
+  let additional_lamports_needed = Rent::get()?
+    .minimum_balance(required_space)
+    .saturating_sub(escrow_info.lamports());
+  if additional_lamports_needed > 0 {
+    system_program::transfer(
+      CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+          from: ctx.accounts.payer.to_account_info(),
+          to: escrow_info.clone(),
+        },
+      ),
+      additional_lamports_needed,
+    )?;
+  }
+
+  escrow_info.resize(required_space)?;
+  escrow_info.try_borrow_mut_data()?[current_space..].fill(0);
+
+  // Verify the grown account deserializes
+  let developer_escrow = DeveloperEscrow::try_deserialize(&mut &escrow_info.data.borrow()[..])
+    .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
+
+  emit!(DeveloperEscrowMigrated {
+    developer_escrow: escrow_info.key(),
+    developer: developer_escrow.developer,
+    old_size: current_space as u32,
+    new_size: required_space as u32,
+    migrated_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::{prelude::*, solana_program::rent::Rent, system_program, Discriminator};
+
+use crate::{errors::ErrorCode, events::ManagedProgramMigrated, states::ManagedProgram};
+
+/// Grow a ManagedProgram account created before the current layout
+///
+/// New fields are zero-filled, which is their "unset" value. Permissionless -
+/// anyone may pay the extra rent, since no existing field is touched.
+#[derive(Accounts)]
+pub struct MigrateManagedProgram<'info> {
+  /// CHECK: Validated by seeds; too small to deserialize until migrated
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub managed_program: UncheckedAccount<'info>,
+
+  /// CHECK: Only used to derive the managed_program PDA
+  pub program_account: UncheckedAccount<'info>,
+
+  /// Pays for any additional rent required by the larger layout
+  #[account(mut)]
+  pub payer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_managed_program(ctx: Context<MigrateManagedProgram>) -> Result<()> {
+  let managed_program_info = ctx.accounts.managed_program.to_account_info();
+
+  require!(
+    managed_program_info.owner == ctx.program_id,
+    ErrorCode::InvalidAccountOwner
+  );
+
+  let required_space = 8 + ManagedProgram::INIT_SPACE;
+  let current_space = managed_program_info.data_len();
+  require!(
+    current_space >= 8 && managed_program_info.data.borrow()[..8] == *ManagedProgram::DISCRIMINATOR,
+    ErrorCode::InvalidAccountData
+  );
+
+  // Already on the current layout - nothing to do
+  if current_space >= required_space {
+    return Ok(());
+  }
+
+  let additional_lamports_needed = Rent::get()?
+    .minimum_balance(required_space)
+    .saturating_sub(managed_program_info.lamports());
+  if additional_lamports_needed > 0 {
+    system_program::transfer(
+      CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+          from: ctx.accounts.payer.to_account_info(),
+          to: managed_program_info.clone(),
+        },
+      ),
+      additional_lamports_needed,
+    )?;
+  }
+
+  managed_program_info.resize(required_space)?;
+  managed_program_info.try_borrow_mut_data()?[current_space..].fill(0);
+
+  // Verify the grown account deserializes
+  let managed_program =
+    ManagedProgram::try_deserialize(&mut &managed_program_info.data.borrow()[..])
+      .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
+
+  emit!(ManagedProgramMigrated {
+    managed_program: managed_program_info.key(),
+    program_id: managed_program.program_id,
+    old_size: current_space as u32,
+    new_size: required_space as u32,
+    migrated_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -1,15 +1,85 @@
+pub mod add_upgrade_signer;
+pub mod borrow_buffer_rent;
+pub mod cancel_authority_transfer;
+pub mod cancel_deploy_request;
+pub mod cancel_pending_deployment;
+pub mod cancel_scheduled_upgrade;
+pub mod change_subscription_plan;
+pub mod close_escrow;
+pub mod configure_escrow;
 pub mod deposit_escrow_sol;
+pub mod deposit_escrow_token;
+pub mod disable_escrow_top_up;
+pub mod enable_escrow_top_up;
+pub mod execute_authority_transfer;
+pub mod execute_scheduled_upgrade;
+pub mod get_subscription_status;
 pub mod initialize_escrow;
+pub mod link_developer_identity;
+pub mod lock_developer_collateral;
+pub mod migrate_developer_escrow;
+pub mod migrate_managed_program;
+pub mod pause_program;
 pub mod pay_subscription;
+pub mod pay_subscription_spl;
+pub mod propose_authority_transfer;
+pub mod proxy_extend_program;
+pub mod proxy_rollback_program;
 pub mod proxy_upgrade_program;
+pub mod reclaim_authority;
+pub mod release_developer_collateral;
+pub mod remove_upgrade_signer;
+pub mod renew_from_escrow;
+pub mod schedule_upgrade;
+pub mod set_escrow_budget;
 pub mod set_preferred_token;
+pub mod set_program_auto_renew;
+pub mod settle_buffer_rent_loan;
 pub mod toggle_auto_renew;
+pub mod top_up_escrow;
+pub mod unlink_developer_identity;
 pub mod withdraw_escrow_sol;
+pub mod withdraw_escrow_token;
 
+pub use add_upgrade_signer::*;
+pub use borrow_buffer_rent::*;
+pub use cancel_authority_transfer::*;
+pub use cancel_deploy_request::*;
+pub use cancel_pending_deployment::*;
+pub use cancel_scheduled_upgrade::*;
+pub use change_subscription_plan::*;
+pub use close_escrow::*;
+pub use configure_escrow::*;
 pub use deposit_escrow_sol::*;
+pub use deposit_escrow_token::*;
+pub use disable_escrow_top_up::*;
+pub use enable_escrow_top_up::*;
+pub use execute_authority_transfer::*;
+pub use execute_scheduled_upgrade::*;
+pub use get_subscription_status::*;
 pub use initialize_escrow::*;
+pub use link_developer_identity::*;
+pub use lock_developer_collateral::*;
+pub use migrate_developer_escrow::*;
+pub use migrate_managed_program::*;
+pub use pause_program::*;
 pub use pay_subscription::*;
+pub use pay_subscription_spl::*;
+pub use propose_authority_transfer::*;
+pub use proxy_extend_program::*;
+pub use proxy_rollback_program::*;
 pub use proxy_upgrade_program::*;
+pub use reclaim_authority::*;
+pub use release_developer_collateral::*;
+pub use remove_upgrade_signer::*;
+pub use renew_from_escrow::*;
+pub use schedule_upgrade::*;
+pub use set_escrow_budget::*;
 pub use set_preferred_token::*;
+pub use set_program_auto_renew::*;
+pub use settle_buffer_rent_loan::*;
 pub use toggle_auto_renew::*;
+pub use top_up_escrow::*;
+pub use unlink_developer_identity::*;
 pub use withdraw_escrow_sol::*;
+pub use withdraw_escrow_token::*;
@@ -0,0 +1,160 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{
+  errors::ErrorCode,
+  events::ProgramHibernated,
+  instructions::{auto_fulfill_withdrawal_queue, close_managed_program, settle_rent_recovery},
+  states::{BufferRentLoan, DeployRequest, DeployRequestStatus, ManagedProgram, TreasuryPool},
+};
+
+/// Developer hibernates a managed program
+///
+/// The program is closed through the authority PDA and its rent repays the
+/// treasury debt exactly like reclaim_program_rent, but the DeployRequest
+/// (with its program hash) is kept in `Hibernated`: the unused paid time is
+/// stretched at the storage fee (`DeployRequest::STORAGE_FEE_BPS` of the
+/// monthly fee), pay_subscription then charges only that fee, and
+/// create_deploy_request can redeploy under the same request once the
+/// storage fee is current, crediting the storage time left. Closed program ids cannot be reused, so the redeployment gets a
+/// new program id.
+///
+/// Remaining accounts: `[queue_entry, lender_stake, staker]` triples from
+/// the withdrawal queue head, fulfilled with the restored liquidity.
+#[derive(Accounts)]
+pub struct PauseProgram<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// The program to be closed
+  /// CHECK: Validated by managed_program
+  #[account(mut)]
+  pub program_account: UncheckedAccount<'info>,
+
+  /// Program data account (will be closed)
+  /// CHECK: Will be validated by BPF Loader during CPI
+  #[account(mut)]
+  pub program_data: UncheckedAccount<'info>,
+
+  /// PDA that holds the upgrade authority
+  /// CHECK: Validated by seeds and managed_program.authority_pda
+  #[account(
+        seeds = [ManagedProgram::AUTHORITY_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub authority_pda: SystemAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.developer == developer.key() @ ErrorCode::Unauthorized,
+        constraint = managed_program.authority_pda == authority_pda.key() @ ErrorCode::InvalidAuthorityPda,
+        constraint = managed_program.deploy_request == deploy_request.key() @ ErrorCode::InvalidRequestId,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  /// CHECK: Treasury vault PDA (receives the reclaimed rent)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  /// CHECK: The program's buffer rent loan PDA (may not exist); an open loan
+  /// is repaid out of the recovered rent
+  #[account(
+        mut,
+        seeds = [BufferRentLoan::PREFIX_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub buffer_rent_loan: UncheckedAccount<'info>,
+
+  /// Receives the prepaid extension rent back
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  /// BPF Loader Upgradeable Program
+  /// CHECK: Known program ID
+  #[account(
+        constraint = bpf_loader_upgradeable_program.key() == bpf_loader_upgradeable::ID
+    )]
+  pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn pause_program<'info>(ctx: Context<'_, '_, '_, 'info, PauseProgram<'info>>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let managed_program = &mut ctx.accounts.managed_program;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    matches!(
+      deploy_request.status,
+      DeployRequestStatus::Active | DeployRequestStatus::SubscriptionExpired
+    ),
+    ErrorCode::InvalidDeploymentStatus
+  );
+
+  let program_data_lamports = close_managed_program(
+    &ctx.accounts.program_account.to_account_info(),
+    &ctx.accounts.program_data.to_account_info(),
+    &ctx.accounts.authority_pda.to_account_info(),
+    &ctx.accounts.treasury_vault.to_account_info(),
+    ctx.bumps.authority_pda,
+  )?;
+
+  managed_program.is_active = false;
+  deploy_request.hibernate(current_time)?;
+
+  let extension_rent_refunded = settle_rent_recovery(
+    treasury_pool,
+    deploy_request,
+    program_data_lamports,
+    &ctx.accounts.treasury_vault.to_account_info(),
+    &ctx.accounts.buffer_rent_loan.to_account_info(),
+    &ctx.accounts.developer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    current_time,
+  )?;
+
+  auto_fulfill_withdrawal_queue(
+    treasury_pool,
+    &ctx.accounts.treasury_vault.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    ctx.remaining_accounts,
+    &[],
+    ctx.program_id,
+    current_time,
+  )?;
+
+  emit!(ProgramHibernated {
+    request_id: deploy_request.request_id,
+    developer: deploy_request.developer,
+    program_id: ctx.accounts.program_account.key(),
+    program_hash: deploy_request.program_hash,
+    lamports_recovered: program_data_lamports,
+    extension_rent_refunded,
+    remaining_debt: deploy_request.get_remaining_debt(),
+    storage_monthly_fee: deploy_request.storage_monthly_fee(),
+    hibernated_at: current_time,
+  });
+
+  Ok(())
+}
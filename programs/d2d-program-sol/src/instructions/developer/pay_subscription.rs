@@ -2,8 +2,8 @@ use anchor_lang::{prelude::*, system_program};
 
 use crate::{
   errors::ErrorCode,
-  events::SubscriptionPaid,
-  states::{DeployRequest, DeployRequestStatus, TreasuryPool},
+  events::{BorrowFeeCollected, GracePeriodEnded, SubscriptionPaid},
+  states::{DeployRequest, DeployRequestStatus, FeeSource, TreasuryPool},
 };
 
 #[derive(Accounts)]
@@ -17,7 +17,7 @@ pub struct PaySubscription<'info> {
   pub treasury_pool: Account<'info, TreasuryPool>,
   #[account(
         mut,
-        seeds = [DeployRequest::PREFIX_SEED, deploy_request.program_hash.as_ref()],
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
         bump = deploy_request.bump
     )]
   pub deploy_request: Account<'info, DeployRequest>,
@@ -31,6 +31,13 @@ pub struct PaySubscription<'info> {
         bump = treasury_pool.reward_pool_bump
     )]
   pub reward_pool: UncheckedAccount<'info>,
+  /// CHECK: Platform pool PDA - receives the platform share of subscription revenue
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
   pub system_program: Program<'info, System>,
 }
 
@@ -54,24 +61,49 @@ pub fn pay_subscription(
   require!(months > 0, ErrorCode::InvalidAmount);
   require!(
     deploy_request.status == DeployRequestStatus::Active
-      || deploy_request.status == DeployRequestStatus::SubscriptionExpired,
+      || deploy_request.status == DeployRequestStatus::SubscriptionExpired
+      || deploy_request.status == DeployRequestStatus::InGracePeriod
+      || deploy_request.status == DeployRequestStatus::Hibernated,
     ErrorCode::InvalidRequestStatus
   );
 
-  // Calculate payment amount
-  let payment_amount = deploy_request.monthly_fee * months as u64;
+  // A hibernated program only pays the storage-only fee and stays hibernated
+  let hibernated = deploy_request.status == DeployRequestStatus::Hibernated;
+  // Paying during the grace period rescues the program from closure
+  let in_grace_period = deploy_request.status == DeployRequestStatus::InGracePeriod;
+  let monthly_fee = if hibernated {
+    deploy_request.storage_monthly_fee()
+  } else {
+    deploy_request.monthly_fee
+  };
+
+  // Calculate payment amount, less the prepayment discount for `months`
+  let (payment_amount, discount) = treasury_pool.apply_prepay_discount(
+    treasury_pool.calculate_subscription_payment(monthly_fee, months)?,
+    months,
+  )?;
 
   // Extend subscription (with overflow protection)
+  deploy_request.record_subscription_payment(payment_amount, Clock::get()?.unix_timestamp)?;
   deploy_request.extend_subscription(months)?;
+  deploy_request.record_developer_payment(payment_amount)?;
 
   // Update status to active
-  deploy_request.status = DeployRequestStatus::Active;
+  if !hibernated {
+    deploy_request.status = DeployRequestStatus::Active;
+  }
+  if in_grace_period {
+    deploy_request.grace_period_end = 0;
+  }
 
-  // SECURITY FIX H-02: Credit reward pool AND transfer to reward_pool PDA
+  // Split revenue between stakers and platform (subscription_platform_bps)
+  let (reward_share, platform_share) = treasury_pool.split_subscription_payment(payment_amount)?;
+
+  // SECURITY FIX H-02: Credit pools AND transfer to the pool PDAs
   // This ensures state and actual lamports are in sync
-  treasury_pool.credit_fee_to_pool(payment_amount, 0)?;
+  treasury_pool.collect_fee(FeeSource::Subscription, reward_share, platform_share)?;
 
-  // Transfer payment to reward_pool PDA (not dev_wallet)
+  // Transfer reward share to reward_pool PDA (not dev_wallet)
   let cpi_context = CpiContext::new(
     ctx.accounts.system_program.to_account_info(),
     system_program::Transfer {
@@ -79,15 +111,61 @@ pub fn pay_subscription(
       to: ctx.accounts.reward_pool.to_account_info(),
     },
   );
-  system_program::transfer(cpi_context, payment_amount)?;
+  system_program::transfer(cpi_context, reward_share)?;
+
+  if platform_share > 0 {
+    let cpi_context = CpiContext::new(
+      ctx.accounts.system_program.to_account_info(),
+      system_program::Transfer {
+        from: ctx.accounts.developer.to_account_info(),
+        to: ctx.accounts.platform_pool.to_account_info(),
+      },
+    );
+    system_program::transfer(cpi_context, platform_share)?;
+  }
+
+  // Accrued 1% monthly borrow fees are settled with the subscription and
+  // go entirely to stakers
+  let borrow_fee = deploy_request.outstanding_borrow_fees()?;
+  if borrow_fee > 0 {
+    let cpi_context = CpiContext::new(
+      ctx.accounts.system_program.to_account_info(),
+      system_program::Transfer {
+        from: ctx.accounts.developer.to_account_info(),
+        to: ctx.accounts.reward_pool.to_account_info(),
+      },
+    );
+    system_program::transfer(cpi_context, borrow_fee)?;
+    treasury_pool.collect_fee(FeeSource::BorrowFee, borrow_fee, 0)?;
+    deploy_request.record_borrow_fee_payment(borrow_fee)?;
+
+    emit!(BorrowFeeCollected {
+      request_id: deploy_request.request_id,
+      developer: deploy_request.developer,
+      amount: borrow_fee,
+      fees_paid: deploy_request.fees_paid,
+      from_escrow: false,
+      collected_at: Clock::get()?.unix_timestamp,
+    });
+  }
 
   emit!(SubscriptionPaid {
     request_id: deploy_request.request_id,
     developer: deploy_request.developer,
     months,
     payment_amount,
+    discount,
     subscription_valid_until: deploy_request.subscription_paid_until,
   });
 
+  if in_grace_period {
+    emit!(GracePeriodEnded {
+      request_id: deploy_request.request_id,
+      developer: deploy_request.developer,
+      action: "renewed".to_string(),
+      ended_at: Clock::get()?.unix_timestamp,
+    });
+  }
+
   Ok(())
 }
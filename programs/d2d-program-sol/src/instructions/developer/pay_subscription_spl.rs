@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+  errors::ErrorCode,
+  events::SubscriptionPaidSpl,
+  states::{DeployRequest, DeployRequestStatus, TreasuryPool},
+};
+
+/// Pay the monthly subscription in the accepted stablecoin
+///
+/// Charges the request's fixed `stablecoin_monthly_fee` per month into the
+/// protocol stablecoin vault. Stablecoin revenue is tracked separately from
+/// the SOL pools and is not counted in total_paid_by_developer.
+#[derive(Accounts)]
+pub struct PaySubscriptionSpl<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  #[account(
+        mut,
+        token::mint = treasury_pool.subscription_stablecoin_mint,
+        token::authority = developer
+    )]
+  pub developer_token_account: Account<'info, TokenAccount>,
+
+  #[account(
+        mut,
+        address = treasury_pool.subscription_stablecoin_vault @ ErrorCode::InvalidAccountData
+    )]
+  pub stablecoin_vault: Account<'info, TokenAccount>,
+
+  pub developer: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+pub fn pay_subscription_spl(
+  ctx: Context<PaySubscriptionSpl>,
+  request_id: [u8; 32],
+  months: u32,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    treasury_pool.is_stablecoin_subscription_enabled(),
+    ErrorCode::StablecoinNotConfigured
+  );
+  require!(
+    deploy_request.request_id == request_id,
+    ErrorCode::InvalidRequestId
+  );
+  require!(
+    deploy_request.developer == ctx.accounts.developer.key(),
+    ErrorCode::Unauthorized
+  );
+  require!(months > 0, ErrorCode::InvalidAmount);
+  require!(
+    deploy_request.status == DeployRequestStatus::Active
+      || deploy_request.status == DeployRequestStatus::SubscriptionExpired,
+    ErrorCode::InvalidRequestStatus
+  );
+
+  let payment_amount = deploy_request.calculate_stablecoin_payment(months)?;
+
+  // Refunds are paid in SOL, so stablecoin months are valued at the list fee
+  let paid_value = deploy_request
+    .monthly_fee
+    .checked_mul(months as u64)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  deploy_request.record_subscription_payment(paid_value, Clock::get()?.unix_timestamp)?;
+  deploy_request.extend_subscription(months)?;
+  deploy_request.status = DeployRequestStatus::Active;
+
+  treasury_pool.total_stablecoin_subscription_revenue = treasury_pool
+    .total_stablecoin_subscription_revenue
+    .checked_add(payment_amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.developer_token_account.to_account_info(),
+        to: ctx.accounts.stablecoin_vault.to_account_info(),
+        authority: ctx.accounts.developer.to_account_info(),
+      },
+    ),
+    payment_amount,
+  )?;
+
+  emit!(SubscriptionPaidSpl {
+    request_id,
+    developer: deploy_request.developer,
+    mint: treasury_pool.subscription_stablecoin_mint,
+    months,
+    payment_amount,
+    subscription_valid_until: deploy_request.subscription_paid_until,
+  });
+
+  Ok(())
+}
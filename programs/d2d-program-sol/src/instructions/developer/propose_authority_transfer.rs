@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::AuthorityTransferProposed,
+  states::{
+    ApprovedAuthority, AuthorityTransfer, DeployRequest, DeployRequestStatus, ManagedProgram,
+    TreasuryPool,
+  },
+};
+
+/// Developer proposes handing the upgrade authority of a managed program to
+/// an admin-approved address (e.g. a Squads multisig)
+///
+/// Starts the `AuthorityTransfer::TIMELOCK`; the hand-over itself happens in
+/// execute_authority_transfer. The subscription must be active and paid.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32], new_authority: Pubkey)]
+pub struct ProposeAuthorityTransfer<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  /// CHECK: Validated by managed_program seeds
+  pub program_account: UncheckedAccount<'info>,
+
+  #[account(
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.developer == developer.key() @ ErrorCode::Unauthorized,
+        constraint = managed_program.deploy_request == deploy_request.key() @ ErrorCode::InvalidRequestId,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  #[account(
+        seeds = [ApprovedAuthority::PREFIX_SEED, new_authority.as_ref()],
+        bump = approved_authority.bump,
+        constraint = approved_authority.enabled @ ErrorCode::AuthorityNotApproved
+    )]
+  pub approved_authority: Account<'info, ApprovedAuthority>,
+
+  #[account(
+        init,
+        payer = developer,
+        space = 8 + AuthorityTransfer::INIT_SPACE,
+        seeds = [AuthorityTransfer::PREFIX_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub authority_transfer: Account<'info, AuthorityTransfer>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn propose_authority_transfer(
+  ctx: Context<ProposeAuthorityTransfer>,
+  request_id: [u8; 32],
+  new_authority: Pubkey,
+) -> Result<()> {
+  let deploy_request = &ctx.accounts.deploy_request;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+  require!(
+    deploy_request.status == DeployRequestStatus::Active,
+    ErrorCode::InvalidRequestStatus
+  );
+  require!(
+    deploy_request.is_subscription_valid()?,
+    ErrorCode::SubscriptionExpired
+  );
+
+  let execute_after = current_time
+    .checked_add(AuthorityTransfer::TIMELOCK)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  let authority_transfer = &mut ctx.accounts.authority_transfer;
+  authority_transfer.program_id = ctx.accounts.program_account.key();
+  authority_transfer.deploy_request = deploy_request.key();
+  authority_transfer.developer = deploy_request.developer;
+  authority_transfer.new_authority = new_authority;
+  authority_transfer.proposed_at = current_time;
+  authority_transfer.execute_after = execute_after;
+  authority_transfer.bump = ctx.bumps.authority_transfer;
+
+  emit!(AuthorityTransferProposed {
+    request_id,
+    program_id: authority_transfer.program_id,
+    developer: deploy_request.developer,
+    new_authority,
+    execute_after,
+    proposed_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,203 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::ProgramExtended,
+  states::{
+    DeployRequest, DeployRequestStatus, DeveloperEscrow, ManagedProgram, PlanConfig, TokenType,
+    TreasuryPool,
+  },
+  utils::payout_from_pda,
+};
+
+/// Developer grows the ProgramData account of a managed program
+///
+/// The extra rent is charged from the developer escrow's SOL balance when the
+/// escrow is passed, otherwise from the wallet, into the authority PDA, which
+/// pays the loader's ExtendProgram. The new size must fit the request's plan.
+/// The rent stays the developer's (developer_extension_rent, outside the
+/// borrowed capital) and is returned to them when the program is closed.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ProxyExtendProgram<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        seeds = [PlanConfig::PREFIX_SEED],
+        bump = plan_config.bump
+    )]
+  pub plan_config: Account<'info, PlanConfig>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  /// The program being extended
+  /// CHECK: Validated by managed_program seeds
+  #[account(mut)]
+  pub program_account: UncheckedAccount<'info>,
+
+  /// Program data account (grows by additional_bytes)
+  /// CHECK: Address derived from managed_program; validated by BPF Loader during CPI
+  #[account(
+        mut,
+        constraint = program_data.key() == managed_program.program_data_address() @ ErrorCode::InvalidAccountData
+    )]
+  pub program_data: UncheckedAccount<'info>,
+
+  /// PDA that holds the upgrade authority; pays the extra rent
+  /// CHECK: Validated by seeds and managed_program.authority_pda
+  #[account(
+        mut,
+        seeds = [ManagedProgram::AUTHORITY_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub authority_pda: SystemAccount<'info>,
+
+  #[account(
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.developer == developer.key() @ ErrorCode::Unauthorized,
+        constraint = managed_program.deploy_request == deploy_request.key() @ ErrorCode::InvalidRequestId,
+        constraint = managed_program.authority_pda == authority_pda.key() @ ErrorCode::InvalidAuthorityPda,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  /// Pays the extra rent from its SOL balance (optional)
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, developer.key().as_ref()],
+        bump = developer_escrow.bump
+    )]
+  pub developer_escrow: Option<Account<'info, DeveloperEscrow>>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  /// BPF Loader Upgradeable Program
+  /// CHECK: Known program ID
+  #[account(
+        constraint = bpf_loader_upgradeable_program.key() == bpf_loader_upgradeable::ID
+    )]
+  pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn proxy_extend_program(
+  ctx: Context<ProxyExtendProgram>,
+  request_id: [u8; 32],
+  additional_bytes: u32,
+) -> Result<()> {
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+  require!(additional_bytes > 0, ErrorCode::InvalidAmount);
+  require!(
+    deploy_request.status == DeployRequestStatus::Active,
+    ErrorCode::InvalidDeploymentStatus
+  );
+  require!(
+    deploy_request.is_subscription_valid()?,
+    ErrorCode::SubscriptionExpired
+  );
+
+  let tier = ctx.accounts.plan_config.get_tier(deploy_request.plan_id)?;
+  let new_program_size = deploy_request
+    .program_size
+    .checked_add(additional_bytes as u64)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  require!(
+    new_program_size <= tier.max_program_size,
+    ErrorCode::ProgramSizeExceedsPlan
+  );
+
+  // Same top-up the loader requires for the grown account
+  let program_data_info = ctx.accounts.program_data.to_account_info();
+  let new_len = program_data_info
+    .data_len()
+    .checked_add(additional_bytes as usize)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  let rent = Rent::get()?
+    .minimum_balance(new_len)
+    .saturating_sub(program_data_info.lamports());
+
+  let authority_pda_info = ctx.accounts.authority_pda.to_account_info();
+  let paid_from_escrow = ctx.accounts.developer_escrow.is_some();
+  if rent > 0 {
+    if let Some(developer_escrow) = ctx.accounts.developer_escrow.as_mut() {
+      developer_escrow.deduct_balance(rent, TokenType::SOL)?;
+      payout_from_pda(
+        &developer_escrow.to_account_info(),
+        &authority_pda_info,
+        rent,
+      )?;
+    } else {
+      system_program::transfer(
+        CpiContext::new(
+          ctx.accounts.system_program.to_account_info(),
+          system_program::Transfer {
+            from: ctx.accounts.developer.to_account_info(),
+            to: authority_pda_info.clone(),
+          },
+        ),
+        rent,
+      )?;
+    }
+  }
+
+  let extend_ix = bpf_loader_upgradeable::extend_program(
+    &ctx.accounts.program_account.key(),
+    Some(&ctx.accounts.authority_pda.key()),
+    additional_bytes,
+  );
+
+  let program_key = ctx.accounts.program_account.key();
+  let seeds = &[
+    ManagedProgram::AUTHORITY_SEED,
+    program_key.as_ref(),
+    &[ctx.bumps.authority_pda],
+  ];
+  let signer_seeds = &[&seeds[..]];
+
+  anchor_lang::solana_program::program::invoke_signed(
+    &extend_ix,
+    &[
+      program_data_info,
+      ctx.accounts.program_account.to_account_info(),
+      ctx.accounts.system_program.to_account_info(),
+      authority_pda_info,
+    ],
+    signer_seeds,
+  )?;
+
+  deploy_request.record_program_extension(additional_bytes as u64, rent)?;
+
+  emit!(ProgramExtended {
+    request_id,
+    program_id: program_key,
+    developer: deploy_request.developer,
+    additional_bytes,
+    rent_paid: rent,
+    paid_from_escrow: paid_from_escrow && rent > 0,
+    program_size: deploy_request.program_size,
+    developer_extension_rent: deploy_request.developer_extension_rent,
+    extended_at: current_time,
+  });
+
+  Ok(())
+}
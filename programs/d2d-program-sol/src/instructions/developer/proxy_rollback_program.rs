@@ -0,0 +1,243 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{
+  errors::ErrorCode,
+  events::ProgramRolledBack,
+  instructions::{settle_spilled_buffer_rent, upgrade_via_authority_pda},
+  states::{
+    BufferRentLoan, DeployRequest, DeployRequestStatus, ManagedProgram, TreasuryPool, UpgradeRecord,
+  },
+  utils::buffer_bytecode_hash,
+};
+
+/// Developer restores an earlier release when the current one is broken
+///
+/// Upgrades consume their buffer, so the developer re-uploads the earlier
+/// bytecode; it must hash to the `buffer_hash` recorded for that release in
+/// its UpgradeRecord (only releases upgraded with `expected_buffer_hash` can
+/// be restored). The rollback is recorded in the upgrade history like any
+/// upgrade, but is not subject to the plan's upgrade limits.
+///
+/// Requirements:
+/// 1. Signer must be the owner or a co-developer upgrade signer
+/// 2. Subscription must be active (not expired)
+/// 3. `restore_record` must precede the current release
+/// 4. No upgrade may be scheduled; an open buffer rent loan must be the spill
+///    account (as in proxy_upgrade_program)
+#[derive(Accounts)]
+pub struct ProxyRollbackProgram<'info> {
+  /// CHECK: Validated by program_data and managed_program
+  #[account(mut)]
+  pub program_account: UncheckedAccount<'info>,
+
+  /// CHECK: Will be validated by BPF Loader during CPI
+  #[account(mut)]
+  pub program_data: UncheckedAccount<'info>,
+
+  /// Buffer holding the re-uploaded bytecode of the release to restore
+  /// CHECK: Hash checked against restore_record; validated by BPF Loader
+  #[account(mut)]
+  pub buffer_account: UncheckedAccount<'info>,
+
+  /// CHECK: Validated by seeds and managed_program.authority_pda
+  #[account(
+        seeds = [ManagedProgram::AUTHORITY_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub authority_pda: SystemAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.can_upgrade(&developer.key()) @ ErrorCode::Unauthorized,
+        constraint = managed_program.authority_pda == authority_pda.key() @ ErrorCode::InvalidAuthorityPda,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  /// CHECK: Deploy request - validated manually for migration compatibility
+  #[account(
+        constraint = deploy_request.key() == managed_program.deploy_request @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: UncheckedAccount<'info>,
+
+  /// History entry of the release to restore
+  #[account(
+        seeds = [
+          UpgradeRecord::PREFIX_SEED,
+          program_account.key().as_ref(),
+          &restore_record.index.to_le_bytes(),
+        ],
+        bump = restore_record.bump
+    )]
+  pub restore_record: Account<'info, UpgradeRecord>,
+
+  /// Program owner or co-developer (must sign; owns the buffer)
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  /// CHECK: Can be any account, typically the developer (the loan PDA if one is open)
+  #[account(mut)]
+  pub spill_account: UncheckedAccount<'info>,
+
+  /// CHECK: Known program ID
+  #[account(
+        constraint = bpf_loader_upgradeable_program.key() == bpf_loader_upgradeable::ID
+    )]
+  pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Treasury vault PDA (receives buffer rent loan repayments)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  pub rent: Sysvar<'info, Rent>,
+  pub clock: Sysvar<'info, Clock>,
+
+  /// Buffer rent loan for this program (may not exist)
+  /// CHECK: Validated by seeds; only deserialized if owned by this program
+  #[account(
+        mut,
+        seeds = [BufferRentLoan::PREFIX_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub buffer_rent_loan: UncheckedAccount<'info>,
+
+  /// History entry for the rollback (paid by the signer)
+  #[account(
+        init,
+        payer = developer,
+        space = 8 + UpgradeRecord::INIT_SPACE,
+        seeds = [
+          UpgradeRecord::PREFIX_SEED,
+          program_account.key().as_ref(),
+          &managed_program.upgrade_count.to_le_bytes(),
+        ],
+        bump
+    )]
+  pub upgrade_record: Account<'info, UpgradeRecord>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn proxy_rollback_program(ctx: Context<ProxyRollbackProgram>) -> Result<()> {
+  let managed_program = &mut ctx.accounts.managed_program;
+  let restore_record = &ctx.accounts.restore_record;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+
+  let loan_info = ctx.accounts.buffer_rent_loan.to_account_info();
+  let has_buffer_rent_loan = loan_info.owner == ctx.program_id;
+  if has_buffer_rent_loan {
+    require!(
+      ctx.accounts.spill_account.key() == loan_info.key(),
+      ErrorCode::BufferRentLoanOutstanding
+    );
+  }
+
+  let deploy_request = DeployRequest::load_padded(&ctx.accounts.deploy_request.to_account_info())?;
+  require!(
+    deploy_request.status == DeployRequestStatus::Active,
+    ErrorCode::InvalidDeploymentStatus
+  );
+  require!(
+    deploy_request.is_subscription_valid()?,
+    ErrorCode::SubscriptionExpired
+  );
+  require!(
+    !managed_program.has_scheduled_upgrade(),
+    ErrorCode::ScheduledUpgradePending
+  );
+
+  // The latest record is the current release; restore an earlier one
+  require!(
+    restore_record.program_id == managed_program.program_id
+      && restore_record.index.saturating_add(1) < managed_program.upgrade_count
+      && restore_record.buffer_hash != [0u8; 32],
+    ErrorCode::InvalidRollbackTarget
+  );
+  let buffer_hash = buffer_bytecode_hash(&ctx.accounts.buffer_account.data.borrow())
+    .ok_or(ErrorCode::InvalidAccountData)?;
+  require!(
+    buffer_hash == restore_record.buffer_hash,
+    ErrorCode::BufferHashMismatch
+  );
+
+  let set_buffer_authority_ix = bpf_loader_upgradeable::set_buffer_authority(
+    &ctx.accounts.buffer_account.key(),
+    &ctx.accounts.developer.key(),
+    &ctx.accounts.authority_pda.key(),
+  );
+  anchor_lang::solana_program::program::invoke(
+    &set_buffer_authority_ix,
+    &[
+      ctx.accounts.buffer_account.to_account_info(),
+      ctx.accounts.developer.to_account_info(),
+      ctx.accounts.authority_pda.to_account_info(),
+    ],
+  )?;
+
+  upgrade_via_authority_pda(
+    &ctx.accounts.program_account.to_account_info(),
+    &ctx.accounts.program_data.to_account_info(),
+    &ctx.accounts.buffer_account.to_account_info(),
+    &ctx.accounts.spill_account.to_account_info(),
+    &ctx.accounts.authority_pda.to_account_info(),
+    [
+      ctx.accounts.rent.to_account_info(),
+      ctx.accounts.clock.to_account_info(),
+    ],
+    ctx.bumps.authority_pda,
+  )?;
+
+  if has_buffer_rent_loan {
+    settle_spilled_buffer_rent(
+      &mut ctx.accounts.treasury_pool,
+      &loan_info,
+      &ctx.accounts.treasury_vault.to_account_info(),
+      &ctx.accounts.developer.to_account_info(),
+    )?;
+  }
+
+  let restored_index = restore_record.index;
+  let upgrade_record = &mut ctx.accounts.upgrade_record;
+  upgrade_record.program_id = managed_program.program_id;
+  upgrade_record.index = managed_program.upgrade_count;
+  upgrade_record.buffer_address = ctx.accounts.buffer_account.key();
+  upgrade_record.buffer_hash = buffer_hash;
+  upgrade_record.signer = ctx.accounts.developer.key();
+  upgrade_record.slot = Clock::get()?.slot;
+  upgrade_record.upgraded_at = current_time;
+  upgrade_record.bump = ctx.bumps.upgrade_record;
+  upgrade_record.is_rollback = true;
+
+  managed_program.last_upgraded_at = current_time;
+  managed_program.upgrade_count = managed_program.upgrade_count.saturating_add(1);
+
+  emit!(ProgramRolledBack {
+    program_id: managed_program.program_id,
+    developer: managed_program.developer,
+    rolled_back_by: ctx.accounts.developer.key(),
+    buffer_address: ctx.accounts.buffer_account.key(),
+    buffer_hash,
+    restored_index,
+    rolled_back_at: current_time,
+  });
+
+  Ok(())
+}
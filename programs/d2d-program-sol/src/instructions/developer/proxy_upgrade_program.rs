@@ -3,16 +3,32 @@ use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
 use crate::{
   errors::ErrorCode,
   events::ProgramUpgraded,
-  states::{DeployRequest, DeployRequestStatus, ManagedProgram, TreasuryPool},
+  instructions::repay_buffer_rent_loan,
+  states::{
+    BufferRentLoan, DeployRequest, DeployRequestStatus, DeveloperEscrow, FeeSource, ManagedProgram,
+    TokenType, TreasuryPool, UpgradeRecord,
+  },
+  utils::{buffer_bytecode_hash, close_pda, payout_from_pda},
 };
 
 /// Developer calls this instruction to upgrade their program
 /// D2D PDA will sign on their behalf via CPI (invoke_signed)
 ///
 /// Requirements:
-/// 1. Developer must be the owner of the managed program
+/// 1. Signer must be the owner of the managed program or one of its
+///    co-developer upgrade signers
 /// 2. Subscription must be active (not expired)
-/// 3. Buffer must be pre-uploaded by developer
+/// 3. The request's plan must still include an upgrade
+/// 4. Buffer must be pre-uploaded by developer
+/// 5. If a buffer rent loan is open, the loan PDA must be the spill account;
+///    the reclaimed buffer rent repays the treasury automatically
+/// 6. No upgrade may be scheduled (see schedule_upgrade)
+/// 7. When `expected_buffer_hash` is given, the buffer's bytecode must hash to
+///    it (see utils::buffer_bytecode_hash), so a buffer swapped or rewritten
+///    after upload is refused. Hashing costs compute per byte; large programs
+///    need a raised compute budget.
+/// 8. Upgrades beyond the plan's monthly limit are charged the plan's overage
+///    fee from the owner's SOL escrow (refused if the plan sets no fee)
 #[derive(Accounts)]
 pub struct ProxyUpgradeProgram<'info> {
   /// The program to be upgraded
@@ -45,7 +61,7 @@ pub struct ProxyUpgradeProgram<'info> {
         seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
         bump = managed_program.bump,
         constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
-        constraint = managed_program.developer == developer.key() @ ErrorCode::Unauthorized,
+        constraint = managed_program.can_upgrade(&developer.key()) @ ErrorCode::Unauthorized,
         constraint = managed_program.authority_pda == authority_pda.key() @ ErrorCode::InvalidAuthorityPda,
     )]
   pub managed_program: Account<'info, ManagedProgram>,
@@ -53,11 +69,13 @@ pub struct ProxyUpgradeProgram<'info> {
   /// CHECK: Deploy request - validated manually for migration compatibility
   pub deploy_request: UncheckedAccount<'info>,
 
-  /// Developer who owns the program (must sign)
+  /// Program owner or co-developer (must sign; owns the buffer)
+  /// Receives the excess when a repaid buffer rent loan is closed
+  #[account(mut)]
   pub developer: Signer<'info>,
 
   /// Account to receive any excess lamports from buffer
-  /// CHECK: Can be any account, typically the developer
+  /// CHECK: Can be any account, typically the developer (the loan PDA if one is open)
   #[account(mut)]
   pub spill_account: UncheckedAccount<'info>,
 
@@ -70,65 +88,102 @@ pub struct ProxyUpgradeProgram<'info> {
 
   /// SECURITY FIX L-02: Add treasury_pool to check emergency_pause
   #[account(
+        mut,
         seeds = [TreasuryPool::PREFIX_SEED],
         bump = treasury_pool.bump
     )]
   pub treasury_pool: Account<'info, TreasuryPool>,
 
+  /// CHECK: Treasury vault PDA (receives buffer rent loan repayments)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
   pub rent: Sysvar<'info, Rent>,
   pub clock: Sysvar<'info, Clock>,
+
+  /// Buffer rent loan for this program (may not exist)
+  /// CHECK: Validated by seeds; only deserialized if owned by this program
+  #[account(
+        mut,
+        seeds = [BufferRentLoan::PREFIX_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub buffer_rent_loan: UncheckedAccount<'info>,
+
+  /// History entry for this upgrade (paid by the signer)
+  #[account(
+        init,
+        payer = developer,
+        space = 8 + UpgradeRecord::INIT_SPACE,
+        seeds = [
+          UpgradeRecord::PREFIX_SEED,
+          program_account.key().as_ref(),
+          &managed_program.upgrade_count.to_le_bytes(),
+        ],
+        bump
+    )]
+  pub upgrade_record: Account<'info, UpgradeRecord>,
+
+  /// Owner's escrow, charged for upgrades beyond the monthly limit
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, managed_program.developer.as_ref()],
+        bump = developer_escrow.bump
+    )]
+  pub developer_escrow: Option<Account<'info, DeveloperEscrow>>,
+
+  /// CHECK: Reward pool PDA - receives the reward share of overage fees
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform pool PDA - receives the platform share of overage fees
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
 }
 
-pub fn proxy_upgrade_program(ctx: Context<ProxyUpgradeProgram>) -> Result<()> {
+pub fn proxy_upgrade_program(
+  ctx: Context<ProxyUpgradeProgram>,
+  expected_buffer_hash: Option<[u8; 32]>,
+) -> Result<()> {
   let managed_program = &mut ctx.accounts.managed_program;
-  let treasury_pool = &ctx.accounts.treasury_pool;
   let current_time = Clock::get()?.unix_timestamp;
 
   // SECURITY FIX L-02: Check emergency pause
-  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
-
-  // Manually deserialize deploy_request with migration support
-  let deploy_request_info = ctx.accounts.deploy_request.to_account_info();
-
-  // Verify account is owned by this program
   require!(
-    deploy_request_info.owner == &crate::ID,
-    ErrorCode::InvalidAccountOwner
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
   );
 
-  // Read account data, pad with zeros if old schema (migration compatibility)
-  // Old accounts may be smaller than the new struct; padding with zeros
-  // gives correct defaults (0) for new debt tracking fields
-  let required_space = 8 + DeployRequest::INIT_SPACE;
-  let account_data = deploy_request_info.data.borrow();
-  let data_to_deserialize = if account_data.len() < required_space {
-    let mut padded = vec![0u8; required_space];
-    padded[..account_data.len()].copy_from_slice(&account_data);
-    padded
-  } else {
-    account_data[..required_space].to_vec()
-  };
-  drop(account_data);
-
-  let deploy_request = DeployRequest::try_deserialize(&mut &data_to_deserialize[..])
-    .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
+  // An open buffer rent loan must be repaid from the buffer's rent
+  let loan_info = ctx.accounts.buffer_rent_loan.to_account_info();
+  let has_buffer_rent_loan = loan_info.owner == ctx.program_id;
+  if has_buffer_rent_loan {
+    require!(
+      ctx.accounts.spill_account.key() == loan_info.key(),
+      ErrorCode::BufferRentLoanOutstanding
+    );
+  }
 
-  // Validate PDA seeds
-  let (expected_pda, _) = Pubkey::find_program_address(
-    &[
-      DeployRequest::PREFIX_SEED,
-      deploy_request.program_hash.as_ref(),
-    ],
-    &crate::ID,
-  );
-  require!(
-    expected_pda == deploy_request_info.key(),
-    ErrorCode::InvalidRequestId
-  );
+  // Manually deserialize deploy_request with migration support
+  let deploy_request = DeployRequest::load_padded(&ctx.accounts.deploy_request.to_account_info())?;
 
   // Validate deploy request constraints
   require!(
-    deploy_request.developer == ctx.accounts.developer.key(),
+    deploy_request.developer == managed_program.developer,
     ErrorCode::Unauthorized
   );
   require!(
@@ -141,6 +196,35 @@ pub fn proxy_upgrade_program(ctx: Context<ProxyUpgradeProgram>) -> Result<()> {
     deploy_request.is_subscription_valid()?,
     ErrorCode::SubscriptionExpired
   );
+  // The plan is read from this program's own request
+  require!(
+    ctx.accounts.deploy_request.key() == managed_program.deploy_request,
+    ErrorCode::InvalidRequestId
+  );
+  require!(
+    deploy_request.allows_upgrade(managed_program.upgrade_count),
+    ErrorCode::PlanUpgradeLimitReached
+  );
+  // A scheduled upgrade must be executed or cancelled first
+  require!(
+    !managed_program.has_scheduled_upgrade(),
+    ErrorCode::ScheduledUpgradePending
+  );
+  let overage_fee = charge_upgrade_frequency(
+    managed_program,
+    &deploy_request,
+    &mut ctx.accounts.treasury_pool,
+    ctx.accounts.developer_escrow.as_mut(),
+    &ctx.accounts.reward_pool.to_account_info(),
+    &ctx.accounts.platform_pool.to_account_info(),
+    current_time,
+  )?;
+
+  if let Some(expected_hash) = expected_buffer_hash {
+    let buffer_hash = buffer_bytecode_hash(&ctx.accounts.buffer_account.data.borrow())
+      .ok_or(ErrorCode::InvalidAccountData)?;
+    require!(buffer_hash == expected_hash, ErrorCode::BufferHashMismatch);
+  }
 
   // 2. Step 1: Transfer buffer authority to the PDA
   let set_buffer_authority_ix = bpf_loader_upgradeable::set_buffer_authority(
@@ -158,48 +242,165 @@ pub fn proxy_upgrade_program(ctx: Context<ProxyUpgradeProgram>) -> Result<()> {
     ],
   )?;
 
-  // 3. Step 2: Build the Upgrade instruction for BPF Loader Upgradeable
+  // 3. Step 2: Upgrade with the PDA's signature
+  upgrade_via_authority_pda(
+    &ctx.accounts.program_account.to_account_info(),
+    &ctx.accounts.program_data.to_account_info(),
+    &ctx.accounts.buffer_account.to_account_info(),
+    &ctx.accounts.spill_account.to_account_info(),
+    &ctx.accounts.authority_pda.to_account_info(),
+    [
+      ctx.accounts.rent.to_account_info(),
+      ctx.accounts.clock.to_account_info(),
+    ],
+    ctx.bumps.authority_pda,
+  )?;
+
+  // Buffer rent landed in the loan PDA - repay the treasury
+  if has_buffer_rent_loan {
+    settle_spilled_buffer_rent(
+      &mut ctx.accounts.treasury_pool,
+      &loan_info,
+      &ctx.accounts.treasury_vault.to_account_info(),
+      &ctx.accounts.developer.to_account_info(),
+    )?;
+  }
+
+  let upgrade_record = &mut ctx.accounts.upgrade_record;
+  upgrade_record.program_id = ctx.accounts.program_account.key();
+  upgrade_record.index = managed_program.upgrade_count;
+  upgrade_record.buffer_address = ctx.accounts.buffer_account.key();
+  upgrade_record.buffer_hash = expected_buffer_hash.unwrap_or_default();
+  upgrade_record.signer = ctx.accounts.developer.key();
+  upgrade_record.slot = Clock::get()?.slot;
+  upgrade_record.upgraded_at = current_time;
+  upgrade_record.bump = ctx.bumps.upgrade_record;
+
+  // Update managed program state
+  managed_program.last_upgraded_at = current_time;
+  managed_program.upgrade_count = managed_program.upgrade_count.saturating_add(1);
+
+  emit!(ProgramUpgraded {
+    program_id: ctx.accounts.program_account.key(),
+    developer: managed_program.developer,
+    upgraded_by: ctx.accounts.developer.key(),
+    buffer_address: ctx.accounts.buffer_account.key(),
+    buffer_hash: expected_buffer_hash,
+    overage_fee,
+    upgraded_at: current_time,
+  });
+
+  Ok(())
+}
+
+/// Count an upgrade against the plan's monthly limit
+///
+/// Beyond the limit the plan's overage fee is taken from the owner's SOL
+/// escrow and split between the reward and platform pools like a
+/// subscription payment. Returns the fee charged.
+pub(crate) fn charge_upgrade_frequency<'info>(
+  managed_program: &mut ManagedProgram,
+  deploy_request: &DeployRequest,
+  treasury_pool: &mut TreasuryPool,
+  developer_escrow: Option<&mut Account<'info, DeveloperEscrow>>,
+  reward_pool: &AccountInfo<'info>,
+  platform_pool: &AccountInfo<'info>,
+  current_time: i64,
+) -> Result<u64> {
+  let mut overage_fee = 0;
+  if deploy_request.is_upgrade_overage(managed_program.period_upgrades(current_time)) {
+    require!(
+      deploy_request.upgrade_overage_fee > 0,
+      ErrorCode::MonthlyUpgradeLimitReached
+    );
+    let developer_escrow = developer_escrow.ok_or(ErrorCode::UpgradeOverageEscrowRequired)?;
+    overage_fee = deploy_request.upgrade_overage_fee;
+    developer_escrow.deduct_balance(overage_fee, TokenType::SOL)?;
+
+    let (reward_share, platform_share) = treasury_pool.split_subscription_payment(overage_fee)?;
+    payout_from_pda(
+      &developer_escrow.to_account_info(),
+      reward_pool,
+      reward_share,
+    )?;
+    payout_from_pda(
+      &developer_escrow.to_account_info(),
+      platform_pool,
+      platform_share,
+    )?;
+    treasury_pool.collect_fee(FeeSource::UpgradeOverage, reward_share, platform_share)?;
+  }
+
+  managed_program.record_period_upgrade(current_time);
+  Ok(overage_fee)
+}
+
+/// Upgrade a managed program from a buffer the authority PDA already owns
+///
+/// Shared by proxy_upgrade_program and execute_scheduled_upgrade. The buffer's
+/// rent goes to `spill_account`; `sysvars` are the rent and clock sysvars.
+pub(crate) fn upgrade_via_authority_pda<'info>(
+  program_account: &AccountInfo<'info>,
+  program_data: &AccountInfo<'info>,
+  buffer_account: &AccountInfo<'info>,
+  spill_account: &AccountInfo<'info>,
+  authority_pda: &AccountInfo<'info>,
+  sysvars: [AccountInfo<'info>; 2],
+  authority_bump: u8,
+) -> Result<()> {
   let upgrade_ix = bpf_loader_upgradeable::upgrade(
-    &ctx.accounts.program_account.key(),
-    &ctx.accounts.buffer_account.key(),
-    &ctx.accounts.authority_pda.key(),
-    &ctx.accounts.spill_account.key(),
+    program_account.key,
+    buffer_account.key,
+    authority_pda.key,
+    spill_account.key,
   );
 
   // Prepare PDA signer seeds
-  let program_key = ctx.accounts.program_account.key();
+  let program_key = program_account.key();
   let seeds = &[
     ManagedProgram::AUTHORITY_SEED,
     program_key.as_ref(),
-    &[ctx.bumps.authority_pda],
+    &[authority_bump],
   ];
   let signer_seeds = &[&seeds[..]];
 
-  // Execute CPI with PDA signature
+  let [rent, clock] = sysvars;
   anchor_lang::solana_program::program::invoke_signed(
     &upgrade_ix,
     &[
-      ctx.accounts.program_data.to_account_info(),
-      ctx.accounts.program_account.to_account_info(),
-      ctx.accounts.buffer_account.to_account_info(),
-      ctx.accounts.spill_account.to_account_info(),
-      ctx.accounts.rent.to_account_info(),
-      ctx.accounts.clock.to_account_info(),
-      ctx.accounts.authority_pda.to_account_info(),
+      program_data.clone(),
+      program_account.clone(),
+      buffer_account.clone(),
+      spill_account.clone(),
+      rent,
+      clock,
+      authority_pda.clone(),
     ],
     signer_seeds,
   )?;
+  Ok(())
+}
 
-  // Update managed program state
-  managed_program.last_upgraded_at = current_time;
-  managed_program.upgrade_count = managed_program.upgrade_count.saturating_add(1);
-
-  emit!(ProgramUpgraded {
-    program_id: ctx.accounts.program_account.key(),
-    developer: ctx.accounts.developer.key(),
-    buffer_address: ctx.accounts.buffer_account.key(),
-    upgraded_at: current_time,
-  });
+/// Repay an open buffer rent loan from the buffer rent an upgrade spilled
+/// into the loan PDA, closing the loan to `developer` once repaid
+pub(crate) fn settle_spilled_buffer_rent<'info>(
+  treasury_pool: &mut TreasuryPool,
+  loan_info: &AccountInfo<'info>,
+  treasury_vault_info: &AccountInfo<'info>,
+  developer_info: &AccountInfo<'info>,
+) -> Result<()> {
+  let mut buffer_rent_loan = BufferRentLoan::try_deserialize(&mut &loan_info.data.borrow()[..])?;
+  repay_buffer_rent_loan(
+    treasury_pool,
+    &mut buffer_rent_loan,
+    loan_info,
+    treasury_vault_info,
+  )?;
 
+  if buffer_rent_loan.is_repaid() {
+    close_pda(loan_info, developer_info)?;
+  } else {
+    buffer_rent_loan.try_serialize(&mut &mut loan_info.try_borrow_mut_data()?[..])?;
+  }
   Ok(())
 }
@@ -0,0 +1,213 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::{AuthorityReclaimed, DebtRepaid},
+  instructions::{auto_fulfill_withdrawal_queue, return_upgrade_authority},
+  states::{
+    DeployRequest, DeployRequestStatus, FeeSource, ManagedProgram, TreasuryPool, UserDeployStats,
+  },
+};
+
+/// Developer buys back the upgrade authority of a managed program
+///
+/// The developer pays the outstanding deployment debt into the treasury
+/// vault plus the pool's exit fee (`authority_exit_fee_bps` of the borrowed
+/// amount) to the reward pool, then the authority PDA hands the upgrade
+/// authority to the developer's wallet and the program leaves management,
+/// like offboard_managed_program but at any time and without escrow.
+///
+/// Remaining accounts: `[queue_entry, lender_stake, staker]` triples from
+/// the withdrawal queue head, fulfilled with the repaid debt.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ReclaimAuthority<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Treasury vault PDA (receives the repaid debt)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  /// CHECK: Reward Pool PDA (receives the exit fee)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  /// The program being handed back
+  /// CHECK: Validated by managed_program seeds
+  pub program_account: UncheckedAccount<'info>,
+
+  /// Program data account (authority is rewritten)
+  /// CHECK: Address derived from managed_program; validated by BPF Loader during CPI
+  #[account(
+        mut,
+        constraint = program_data.key() == managed_program.program_data_address() @ ErrorCode::InvalidAccountData
+    )]
+  pub program_data: UncheckedAccount<'info>,
+
+  /// PDA that currently holds the upgrade authority
+  /// CHECK: Validated by seeds and managed_program.authority_pda
+  #[account(
+        seeds = [ManagedProgram::AUTHORITY_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub authority_pda: SystemAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.deploy_request == deploy_request.key() @ ErrorCode::InvalidRequestId,
+        constraint = managed_program.authority_pda == authority_pda.key() @ ErrorCode::InvalidAuthorityPda,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  /// Pays the buy-out and becomes the upgrade authority
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  /// BPF Loader Upgradeable Program
+  /// CHECK: Known program ID
+  #[account(
+        constraint = bpf_loader_upgradeable_program.key() == bpf_loader_upgradeable::ID
+    )]
+  pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+
+  /// Developer's deployment stats (frees the request's quota slot)
+  #[account(
+        mut,
+        seeds = [UserDeployStats::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = user_stats.bump
+    )]
+  pub user_stats: Account<'info, UserDeployStats>,
+}
+
+pub fn reclaim_authority<'info>(
+  ctx: Context<'_, '_, '_, 'info, ReclaimAuthority<'info>>,
+  request_id: [u8; 32],
+) -> Result<()> {
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let managed_program = &mut ctx.accounts.managed_program;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    matches!(
+      deploy_request.status,
+      DeployRequestStatus::Active
+        | DeployRequestStatus::SubscriptionExpired
+        | DeployRequestStatus::InGracePeriod
+    ),
+    ErrorCode::InvalidRequestStatus
+  );
+
+  // === DEBT REPAYMENT ===
+  let remaining_debt = deploy_request.get_remaining_debt();
+  if remaining_debt > 0 {
+    system_program::transfer(
+      CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+          from: ctx.accounts.developer.to_account_info(),
+          to: treasury_vault_info.clone(),
+        },
+      ),
+      remaining_debt,
+    )?;
+    deploy_request.record_debt_settlement(remaining_debt)?;
+  }
+  // Restores liquid_balance for the repaid debt and closes out the deployment
+  treasury_pool.record_debt_repayment(remaining_debt, remaining_debt, current_time)?;
+
+  // === EXIT FEE ===
+  let exit_fee = treasury_pool.calculate_authority_exit_fee(deploy_request.borrowed_amount)?;
+  if exit_fee > 0 {
+    system_program::transfer(
+      CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+          from: ctx.accounts.developer.to_account_info(),
+          to: ctx.accounts.reward_pool.to_account_info(),
+        },
+      ),
+      exit_fee,
+    )?;
+    treasury_pool.collect_fee(FeeSource::AuthorityExit, exit_fee, 0)?;
+    deploy_request.record_developer_payment(exit_fee)?;
+  }
+
+  // === RETURN UPGRADE AUTHORITY ===
+  return_upgrade_authority(
+    &ctx.accounts.program_account.to_account_info(),
+    &ctx.accounts.program_data.to_account_info(),
+    &ctx.accounts.authority_pda.to_account_info(),
+    &ctx.accounts.developer.to_account_info(),
+    ctx.bumps.authority_pda,
+  )?;
+
+  managed_program.is_active = false;
+  ctx.accounts.user_stats.release_slot(&deploy_request.status);
+  deploy_request.status = DeployRequestStatus::Offboarded;
+
+  // Repaid debt restored liquid_balance - fulfill queued withdrawals first
+  if remaining_debt > 0 {
+    auto_fulfill_withdrawal_queue(
+      treasury_pool,
+      &treasury_vault_info,
+      &ctx.accounts.system_program.to_account_info(),
+      ctx.remaining_accounts,
+      &[],
+      ctx.program_id,
+      current_time,
+    )?;
+  }
+
+  emit!(AuthorityReclaimed {
+    request_id,
+    program_id: ctx.accounts.program_account.key(),
+    developer: deploy_request.developer,
+    debt_repaid: remaining_debt,
+    exit_fee,
+    reclaimed_at: current_time,
+  });
+
+  if remaining_debt > 0 {
+    emit!(DebtRepaid {
+      deploy_request_id: deploy_request.request_id,
+      developer: deploy_request.developer,
+      borrowed_amount: deploy_request.borrowed_amount,
+      repaid_amount: deploy_request.repaid_amount,
+      remaining_debt: deploy_request.get_remaining_debt(),
+      recovery_ratio_bps: deploy_request.recovery_ratio_bps,
+      repaid_at: current_time,
+    });
+  }
+
+  Ok(())
+}
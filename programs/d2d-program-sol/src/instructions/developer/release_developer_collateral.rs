@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::DeveloperCollateralReleased,
+  states::{DeployRequest, DeployRequestStatus, DeveloperCollateral, TreasuryPool},
+  utils::payout_from_pda,
+};
+
+/// Developer recovers their collateral once the program is closed
+///
+/// Debt the rent recovery did not cover is settled from the collateral into
+/// the treasury vault first; the rest, with the account rent, is returned
+/// and the collateral account closed.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ReleaseDeveloperCollateral<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Treasury vault PDA (receives any outstanding debt)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  #[account(
+        mut,
+        close = developer,
+        seeds = [DeveloperCollateral::PREFIX_SEED, deploy_request.key().as_ref()],
+        bump = developer_collateral.bump
+    )]
+  pub developer_collateral: Account<'info, DeveloperCollateral>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+}
+
+pub fn release_developer_collateral(
+  ctx: Context<ReleaseDeveloperCollateral>,
+  request_id: [u8; 32],
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let developer_collateral = &mut ctx.accounts.developer_collateral;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  // Only once no program is running on the borrowed capital
+  require!(
+    matches!(
+      deploy_request.status,
+      DeployRequestStatus::Closed
+        | DeployRequestStatus::Offboarded
+        | DeployRequestStatus::Cancelled
+        | DeployRequestStatus::Failed
+    ),
+    ErrorCode::InvalidRequestStatus
+  );
+
+  let debt_settled = deploy_request
+    .get_remaining_debt()
+    .min(developer_collateral.amount);
+  if debt_settled > 0 {
+    payout_from_pda(
+      &developer_collateral.to_account_info(),
+      &ctx.accounts.treasury_vault.to_account_info(),
+      debt_settled,
+    )?;
+    deploy_request.record_debt_settlement(debt_settled)?;
+    treasury_pool.record_collateral_settlement(debt_settled, current_time)?;
+  }
+
+  let returned = developer_collateral.amount - debt_settled;
+  developer_collateral.amount = 0;
+  deploy_request.checkpoint_borrow_fees(current_time)?;
+  deploy_request.collateral_amount = 0;
+
+  emit!(DeveloperCollateralReleased {
+    request_id,
+    developer: deploy_request.developer,
+    debt_settled,
+    returned,
+    released_at: current_time,
+  });
+
+  Ok(())
+}
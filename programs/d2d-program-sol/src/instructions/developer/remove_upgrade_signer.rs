@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::UpgradeSignerRemoved, states::ManagedProgram};
+
+/// Program owner revokes a co-developer's upgrade permission
+#[derive(Accounts)]
+pub struct RemoveUpgradeSigner<'info> {
+  /// CHECK: Validated by managed_program seeds
+  pub program_account: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.developer == developer.key() @ ErrorCode::Unauthorized,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  pub developer: Signer<'info>,
+}
+
+pub fn remove_upgrade_signer(ctx: Context<RemoveUpgradeSigner>, signer: Pubkey) -> Result<()> {
+  let managed_program = &mut ctx.accounts.managed_program;
+  managed_program.remove_upgrade_signer(&signer)?;
+
+  emit!(UpgradeSignerRemoved {
+    program_id: managed_program.program_id,
+    developer: managed_program.developer,
+    signer,
+    signer_count: managed_program.upgrade_signers.len() as u8,
+    removed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+  errors::ErrorCode,
+  events::SubscriptionRenewedFromEscrow,
+  instructions::{escrow_renewal_payment, settle_escrow_renewal, RenewalPayees},
+  states::{DeployRequest, DeployRequestStatus, DeveloperEscrow, TreasuryPool},
+};
+
+/// Renew a subscription from escrow without the platform backend
+///
+/// The developer may renew any number of months at any time. Anyone else may
+/// renew exactly one month of an auto-renewing subscription once it is within
+/// the pool's `permissionless_renewal_window` of expiry (or past it). Payment
+/// and settlement are identical to auto_renew_subscription.
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct RenewFromEscrow<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, deploy_request.developer.as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == deploy_request.developer @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Account<'info, DeveloperEscrow>,
+
+  /// CHECK: Reward pool PDA - receives renewal payments for staker rewards
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform pool PDA - receives the platform share of renewal payments
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  /// Developer, or any keeper inside the permissionless window
+  pub caller: Signer<'info>,
+
+  /// Escrow token vault for the preferred token's mint (USDC/USDT only)
+  #[account(mut)]
+  pub escrow_token_vault: Option<Account<'info, TokenAccount>>,
+
+  /// Protocol stablecoin vault for the same mint (USDC/USDT only)
+  #[account(mut)]
+  pub stablecoin_vault: Option<Account<'info, TokenAccount>>,
+
+  pub token_program: Option<Program<'info, Token>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn renew_from_escrow(
+  ctx: Context<RenewFromEscrow>,
+  request_id: [u8; 32],
+  months: u32,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+  let developer_escrow = &mut ctx.accounts.developer_escrow;
+  let caller = ctx.accounts.caller.key();
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(months > 0, ErrorCode::InvalidAmount);
+  require!(
+    deploy_request.status == DeployRequestStatus::Active
+      || deploy_request.status == DeployRequestStatus::SubscriptionExpired
+      || deploy_request.status == DeployRequestStatus::InGracePeriod,
+    ErrorCode::InvalidRequestStatus
+  );
+
+  // Keepers act on the developer's standing auto-renewal consent only, one
+  // month at a time and only close to expiry
+  let permissionless = caller != deploy_request.developer;
+  if permissionless {
+    require!(
+      developer_escrow.auto_renew_enabled && deploy_request.auto_renewal_enabled,
+      ErrorCode::AutoRenewalDisabled
+    );
+    require!(months == 1, ErrorCode::Unauthorized);
+    let window = treasury_pool.permissionless_renewal_window;
+    require!(
+      window > 0
+        && current_time
+          >= deploy_request
+            .subscription_paid_until
+            .saturating_sub(window),
+      ErrorCode::RenewalWindowClosed
+    );
+  }
+
+  let token_type = developer_escrow.preferred_token;
+  let (payment_amount, discount) =
+    escrow_renewal_payment(treasury_pool, deploy_request, token_type, months)?;
+  require!(
+    developer_escrow.get_balance(token_type) >= payment_amount,
+    ErrorCode::InsufficientEscrowBalance
+  );
+  // Keeper renewals are automatic deductions and respect the spending limits
+  if permissionless {
+    developer_escrow.apply_auto_deduction(&request_id, payment_amount, months, current_time)?;
+  }
+
+  settle_escrow_renewal(
+    treasury_pool,
+    deploy_request,
+    developer_escrow,
+    RenewalPayees {
+      reward_pool: &ctx.accounts.reward_pool.to_account_info(),
+      platform_pool: &ctx.accounts.platform_pool.to_account_info(),
+      escrow_token_vault: ctx.accounts.escrow_token_vault.as_ref(),
+      stablecoin_vault: ctx.accounts.stablecoin_vault.as_ref(),
+      token_program: ctx.accounts.token_program.as_ref(),
+    },
+    ctx.program_id,
+    payment_amount,
+    months,
+  )?;
+
+  emit!(SubscriptionRenewedFromEscrow {
+    request_id,
+    developer: deploy_request.developer,
+    renewed_by: caller,
+    permissionless,
+    token_type: token_type as u8,
+    amount_deducted: payment_amount,
+    discount,
+    months_renewed: months,
+    new_expiry: deploy_request.subscription_paid_until,
+    escrow_remaining: developer_escrow.get_balance(token_type),
+    renewed_at: current_time,
+  });
+
+  Ok(())
+}
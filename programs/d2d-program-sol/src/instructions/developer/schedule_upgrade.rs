@@ -0,0 +1,125 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{
+  errors::ErrorCode,
+  events::UpgradeScheduled,
+  states::{DeployRequest, DeployRequestStatus, ManagedProgram, TreasuryPool},
+};
+
+/// Developer announces an upgrade ahead of time
+///
+/// The buffer's authority moves to the authority PDA right away, so its
+/// bytecode can no longer change; users can inspect it until `execute_after`,
+/// when execute_scheduled_upgrade applies it. cancel_scheduled_upgrade hands
+/// the buffer back. Direct proxy upgrades are blocked while one is scheduled.
+#[derive(Accounts)]
+pub struct ScheduleUpgrade<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Validated by managed_program seeds
+  pub program_account: UncheckedAccount<'info>,
+
+  /// Buffer with the announced bytecode
+  /// CHECK: Validated by BPF Loader during CPI
+  #[account(mut)]
+  pub buffer_account: UncheckedAccount<'info>,
+
+  /// PDA that holds the upgrade authority (becomes the buffer authority)
+  /// CHECK: Validated by seeds and managed_program.authority_pda
+  #[account(
+        seeds = [ManagedProgram::AUTHORITY_SEED, program_account.key().as_ref()],
+        bump
+    )]
+  pub authority_pda: SystemAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [ManagedProgram::PREFIX_SEED, program_account.key().as_ref()],
+        bump = managed_program.bump,
+        constraint = managed_program.is_active @ ErrorCode::ProgramNotManaged,
+        constraint = managed_program.developer == developer.key() @ ErrorCode::Unauthorized,
+        constraint = managed_program.authority_pda == authority_pda.key() @ ErrorCode::InvalidAuthorityPda,
+    )]
+  pub managed_program: Account<'info, ManagedProgram>,
+
+  /// CHECK: Deploy request - validated manually for migration compatibility
+  #[account(
+        constraint = deploy_request.key() == managed_program.deploy_request @ ErrorCode::InvalidRequestId
+    )]
+  pub deploy_request: UncheckedAccount<'info>,
+
+  /// Current buffer authority
+  pub developer: Signer<'info>,
+
+  /// BPF Loader Upgradeable Program
+  /// CHECK: Known program ID
+  #[account(
+        constraint = bpf_loader_upgradeable_program.key() == bpf_loader_upgradeable::ID
+    )]
+  pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+}
+
+pub fn schedule_upgrade(ctx: Context<ScheduleUpgrade>, execute_after: i64) -> Result<()> {
+  let managed_program = &mut ctx.accounts.managed_program;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+  require!(
+    !managed_program.has_scheduled_upgrade(),
+    ErrorCode::ScheduledUpgradePending
+  );
+  require!(
+    execute_after >= current_time.saturating_add(ManagedProgram::MIN_UPGRADE_NOTICE),
+    ErrorCode::InvalidUpgradeSchedule
+  );
+
+  let deploy_request = DeployRequest::load_padded(&ctx.accounts.deploy_request.to_account_info())?;
+  require!(
+    deploy_request.status == DeployRequestStatus::Active,
+    ErrorCode::InvalidDeploymentStatus
+  );
+  require!(
+    deploy_request.is_subscription_valid()?,
+    ErrorCode::SubscriptionExpired
+  );
+  require!(
+    deploy_request.allows_upgrade(managed_program.upgrade_count),
+    ErrorCode::PlanUpgradeLimitReached
+  );
+
+  // Lock the buffer: only the PDA can write to or use it from now on
+  let set_buffer_authority_ix = bpf_loader_upgradeable::set_buffer_authority(
+    &ctx.accounts.buffer_account.key(),
+    &ctx.accounts.developer.key(),
+    &ctx.accounts.authority_pda.key(),
+  );
+  anchor_lang::solana_program::program::invoke(
+    &set_buffer_authority_ix,
+    &[
+      ctx.accounts.buffer_account.to_account_info(),
+      ctx.accounts.developer.to_account_info(),
+      ctx.accounts.authority_pda.to_account_info(),
+    ],
+  )?;
+
+  managed_program.scheduled_buffer = ctx.accounts.buffer_account.key();
+  managed_program.scheduled_upgrade_after = execute_after;
+  managed_program.upgrade_scheduled_at = current_time;
+
+  emit!(UpgradeScheduled {
+    program_id: managed_program.program_id,
+    developer: managed_program.developer,
+    buffer_address: managed_program.scheduled_buffer,
+    execute_after,
+    scheduled_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::EscrowBudgetSet,
+  states::{DeployRequest, DeveloperEscrow, TreasuryPool},
+};
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct SetEscrowBudget<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, developer.key().as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Account<'info, DeveloperEscrow>,
+
+  #[account(
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  pub developer: Signer<'info>,
+}
+
+/// Developer caps how much automatic renewals of one program may take from
+/// the shared escrow in total (0 = remove the cap)
+///
+/// The limit is in units of the renewal token. Changing it keeps what was
+/// already spent against the budget; removing and re-adding starts afresh.
+pub fn set_escrow_budget(
+  ctx: Context<SetEscrowBudget>,
+  request_id: [u8; 32],
+  limit: u64,
+) -> Result<()> {
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+
+  let developer_escrow = &mut ctx.accounts.developer_escrow;
+  developer_escrow.set_budget(request_id, limit)?;
+
+  emit!(EscrowBudgetSet {
+    developer: developer_escrow.developer,
+    request_id,
+    limit,
+    spent: developer_escrow
+      .budget(&request_id)
+      .map_or(0, |budget| budget.spent),
+    set_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
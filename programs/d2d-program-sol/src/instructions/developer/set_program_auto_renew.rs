@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::ProgramAutoRenewChanged,
+  states::{DeployRequest, TreasuryPool},
+};
+
+/// Developer toggles auto-renewal for one program and caps how many months
+/// a single automatic renewal may buy (0 = no per-program limit)
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct SetProgramAutoRenew<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeployRequest::PREFIX_SEED, deploy_request.request_id.as_ref()],
+        bump = deploy_request.bump,
+        constraint = deploy_request.request_id == request_id @ ErrorCode::InvalidRequestId,
+        constraint = deploy_request.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub deploy_request: Account<'info, DeployRequest>,
+
+  pub developer: Signer<'info>,
+}
+
+pub fn set_program_auto_renew(
+  ctx: Context<SetProgramAutoRenew>,
+  request_id: [u8; 32],
+  enabled: bool,
+  max_months_per_renewal: u32,
+) -> Result<()> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  let deploy_request = &mut ctx.accounts.deploy_request;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    max_months_per_renewal <= DeployRequest::MAX_EXTENSION_MONTHS,
+    ErrorCode::SubscriptionExtensionTooLarge
+  );
+
+  deploy_request.auto_renewal_enabled = enabled;
+  deploy_request.max_months_per_renewal = max_months_per_renewal;
+
+  emit!(ProgramAutoRenewChanged {
+    request_id,
+    developer: deploy_request.developer,
+    enabled,
+    max_months_per_renewal,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
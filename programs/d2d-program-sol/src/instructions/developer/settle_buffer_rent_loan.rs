@@ -0,0 +1,159 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::BufferRentLoanRepaid,
+  states::{BufferRentLoan, TreasuryPool},
+  utils::{close_pda, payable_lamports, pda_transfer},
+};
+
+/// Settle a buffer rent loan from lamports held by the loan PDA
+///
+/// Used when the buffer was closed (rent sent to the loan PDA) rather than
+/// consumed by `proxy_upgrade_program`, or to top up a shortfall. Anyone may
+/// call; the optional top-up is paid by the caller. Once fully repaid the
+/// loan is closed and any excess returned to the developer.
+#[derive(Accounts)]
+pub struct SettleBufferRentLoan<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Treasury vault PDA (receives the repayment)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [BufferRentLoan::PREFIX_SEED, buffer_rent_loan.program_id.as_ref()],
+        bump = buffer_rent_loan.bump
+    )]
+  pub buffer_rent_loan: Account<'info, BufferRentLoan>,
+
+  /// CHECK: Borrowing developer - receives the excess when the loan closes
+  #[account(
+        mut,
+        constraint = developer.key() == buffer_rent_loan.developer @ ErrorCode::Unauthorized
+    )]
+  pub developer: UncheckedAccount<'info>,
+
+  #[account(mut)]
+  pub payer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn settle_buffer_rent_loan(ctx: Context<SettleBufferRentLoan>, top_up: u64) -> Result<()> {
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let loan_info = ctx.accounts.buffer_rent_loan.to_account_info();
+
+  if top_up > 0 {
+    let top_up_cpi = CpiContext::new(
+      ctx.accounts.system_program.to_account_info(),
+      system_program::Transfer {
+        from: ctx.accounts.payer.to_account_info(),
+        to: loan_info.clone(),
+      },
+    );
+    system_program::transfer(top_up_cpi, top_up)?;
+  }
+
+  repay_buffer_rent_loan(
+    &mut ctx.accounts.treasury_pool,
+    &mut ctx.accounts.buffer_rent_loan,
+    &loan_info,
+    &treasury_vault_info,
+  )?;
+
+  if ctx.accounts.buffer_rent_loan.is_repaid() {
+    close_pda(&loan_info, &ctx.accounts.developer.to_account_info())?;
+  }
+
+  Ok(())
+}
+
+/// Repay a buffer rent loan from lamports held above rent by the loan PDA
+///
+/// Shared by settle_buffer_rent_loan and proxy_upgrade_program (where the
+/// loan PDA is the spill account). Closing the repaid loan is left to the
+/// caller. Returns the amount repaid.
+pub(crate) fn repay_buffer_rent_loan(
+  treasury_pool: &mut TreasuryPool,
+  buffer_rent_loan: &mut BufferRentLoan,
+  loan_info: &AccountInfo,
+  treasury_vault_info: &AccountInfo,
+) -> Result<u64> {
+  let rent_exemption = Rent::get()?.minimum_balance(loan_info.data_len());
+  let repayment =
+    payable_lamports(loan_info.lamports(), rent_exemption).min(buffer_rent_loan.get_outstanding());
+
+  pda_transfer(loan_info, treasury_vault_info, repayment)?;
+  treasury_pool.record_buffer_rent_repayment(repayment)?;
+
+  buffer_rent_loan.amount_repaid = buffer_rent_loan
+    .amount_repaid
+    .checked_add(repayment)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  emit!(BufferRentLoanRepaid {
+    developer: buffer_rent_loan.developer,
+    program_id: buffer_rent_loan.program_id,
+    amount_repaid: repayment,
+    outstanding: buffer_rent_loan.get_outstanding(),
+    closed: buffer_rent_loan.is_repaid(),
+    repaid_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(repayment)
+}
+
+/// Repay an open buffer rent loan out of program rent already recovered
+/// into the treasury vault
+///
+/// Used by the rent reclaim flows, so a loan the buffer never repaid is
+/// recovered along with the deployment debt. `loan_info` is the program's
+/// loan PDA and may not exist; a fully repaid loan is closed to `developer`.
+/// Returns the amount repaid.
+pub(crate) fn recover_buffer_rent_loan(
+  treasury_pool: &mut TreasuryPool,
+  loan_info: &AccountInfo,
+  developer: &AccountInfo,
+  recovered_lamports: u64,
+) -> Result<u64> {
+  if loan_info.owner != &crate::ID || loan_info.data_is_empty() {
+    return Ok(0);
+  }
+  let mut buffer_rent_loan = BufferRentLoan::try_deserialize(&mut &loan_info.data.borrow()[..])?;
+  let repayment = buffer_rent_loan.get_outstanding().min(recovered_lamports);
+
+  treasury_pool.record_buffer_rent_repayment(repayment)?;
+  buffer_rent_loan.amount_repaid = buffer_rent_loan
+    .amount_repaid
+    .checked_add(repayment)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  let closed = buffer_rent_loan.is_repaid() && developer.key() == buffer_rent_loan.developer;
+  if closed {
+    close_pda(loan_info, developer)?;
+  } else {
+    buffer_rent_loan.try_serialize(&mut &mut loan_info.try_borrow_mut_data()?[..])?;
+  }
+
+  emit!(BufferRentLoanRepaid {
+    developer: buffer_rent_loan.developer,
+    program_id: buffer_rent_loan.program_id,
+    amount_repaid: repayment,
+    outstanding: buffer_rent_loan.get_outstanding(),
+    closed,
+    repaid_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(repayment)
+}
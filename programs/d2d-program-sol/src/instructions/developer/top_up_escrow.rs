@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::{
+  errors::ErrorCode,
+  events::EscrowToppedUp,
+  states::{DeveloperEscrow, EscrowTopUp, TokenType, TreasuryPool},
+  utils::payout_from_pda,
+};
+
+/// Permissionless crank: refill an escrow below its alert threshold
+///
+/// Pulls `top_up_amount` from the developer's approved source account into
+/// the escrow token vault for the mint. For SOL escrows the vault only holds
+/// the wSOL for the duration of the instruction: it is closed into the escrow
+/// PDA to unwrap, and the cranker's vault rent is refunded from it.
+#[derive(Accounts)]
+pub struct TopUpEscrow<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, escrow_top_up.developer.as_ref()],
+        bump = developer_escrow.bump
+    )]
+  pub developer_escrow: Account<'info, DeveloperEscrow>,
+
+  #[account(
+        mut,
+        seeds = [EscrowTopUp::PREFIX_SEED, escrow_top_up.developer.as_ref()],
+        bump = escrow_top_up.bump
+    )]
+  pub escrow_top_up: Account<'info, EscrowTopUp>,
+
+  #[account(
+        mut,
+        address = escrow_top_up.source_token_account @ ErrorCode::InvalidAccountData
+    )]
+  pub source_token_account: Account<'info, TokenAccount>,
+
+  pub mint: Account<'info, Mint>,
+
+  #[account(
+        init_if_needed,
+        payer = cranker,
+        seeds = [
+            DeveloperEscrow::TOKEN_VAULT_SEED,
+            developer_escrow.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = developer_escrow
+    )]
+  pub escrow_token_vault: Account<'info, TokenAccount>,
+
+  #[account(mut)]
+  pub cranker: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn top_up_escrow(ctx: Context<TopUpEscrow>) -> Result<()> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  let developer_escrow = &mut ctx.accounts.developer_escrow;
+  let escrow_top_up = &mut ctx.accounts.escrow_top_up;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    escrow_top_up.is_due(developer_escrow),
+    ErrorCode::TopUpNotDue
+  );
+
+  let token_type = escrow_top_up.token_type;
+  let source_mint =
+    EscrowTopUp::source_mint(treasury_pool, token_type).ok_or(ErrorCode::UnsupportedEscrowMint)?;
+  require!(
+    ctx.accounts.mint.key() == source_mint,
+    ErrorCode::UnsupportedEscrowMint
+  );
+
+  let amount = escrow_top_up.top_up_amount;
+  let vault_info = ctx.accounts.escrow_token_vault.to_account_info();
+  let vault_rent = vault_info.lamports();
+
+  // The top-up PDA spends the developer's allowance as delegate
+  let top_up_seeds: &[&[u8]] = &[
+    EscrowTopUp::PREFIX_SEED,
+    escrow_top_up.developer.as_ref(),
+    &[escrow_top_up.bump],
+  ];
+  token::transfer(
+    CpiContext::new_with_signer(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.source_token_account.to_account_info(),
+        to: vault_info.clone(),
+        authority: escrow_top_up.to_account_info(),
+      },
+      &[top_up_seeds],
+    ),
+    amount,
+  )?;
+
+  if token_type == TokenType::SOL {
+    // Unwrap: closing the wSOL vault moves rent + amount into the escrow PDA
+    let escrow_seeds: &[&[u8]] = &[
+      DeveloperEscrow::PREFIX_SEED,
+      escrow_top_up.developer.as_ref(),
+      &[developer_escrow.bump],
+    ];
+    token::close_account(CpiContext::new_with_signer(
+      ctx.accounts.token_program.to_account_info(),
+      CloseAccount {
+        account: vault_info,
+        destination: developer_escrow.to_account_info(),
+        authority: developer_escrow.to_account_info(),
+      },
+      &[escrow_seeds],
+    ))?;
+    payout_from_pda(
+      &developer_escrow.to_account_info(),
+      &ctx.accounts.cranker.to_account_info(),
+      vault_rent,
+    )?;
+  }
+
+  let current_time = Clock::get()?.unix_timestamp;
+  developer_escrow.add_balance(amount, token_type)?;
+  escrow_top_up.record_top_up(amount, current_time);
+
+  emit!(EscrowToppedUp {
+    developer: escrow_top_up.developer,
+    cranker: ctx.accounts.cranker.key(),
+    token_type: token_type as u8,
+    amount,
+    new_balance: developer_escrow.get_balance(token_type),
+    topped_up_at: current_time,
+  });
+
+  Ok(())
+}
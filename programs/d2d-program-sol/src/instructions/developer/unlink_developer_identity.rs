@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::DeveloperIdentityUnlinked, states::DeveloperIdentity};
+
+/// Developer removes their identity link, reclaiming the account rent
+#[derive(Accounts)]
+pub struct UnlinkDeveloperIdentity<'info> {
+  #[account(
+        mut,
+        seeds = [DeveloperIdentity::PREFIX_SEED, developer.key().as_ref()],
+        bump = developer_identity.bump,
+        close = developer,
+    )]
+  pub developer_identity: Account<'info, DeveloperIdentity>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+}
+
+pub fn unlink_developer_identity(ctx: Context<UnlinkDeveloperIdentity>) -> Result<()> {
+  emit!(DeveloperIdentityUnlinked {
+    developer: ctx.accounts.developer.key(),
+    unlinked_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -4,6 +4,7 @@ use crate::{
   errors::ErrorCode,
   events::EscrowWithdrawn,
   states::{DeveloperEscrow, TreasuryPool},
+  utils::pool_debit_credit,
 };
 
 #[derive(Accounts)]
@@ -40,26 +41,14 @@ pub fn withdraw_escrow_sol(ctx: Context<WithdrawEscrowSol>, amount: u64) -> Resu
     ErrorCode::InsufficientEscrowBalance
   );
 
-  // Update escrow balance first
-  developer_escrow.sol_balance = developer_escrow
-    .sol_balance
-    .checked_sub(amount)
-    .ok_or(ErrorCode::CalculationOverflow)?;
-
-  // Transfer SOL from escrow PDA to developer
-  // We need to transfer lamports from the escrow account
+  // Transfer SOL from escrow PDA to developer and update escrow balance
   let escrow_account_info = developer_escrow.to_account_info();
-  let developer_account_info = developer.to_account_info();
-
-  **escrow_account_info.try_borrow_mut_lamports()? = escrow_account_info
-    .lamports()
-    .checked_sub(amount)
-    .ok_or(ErrorCode::CalculationOverflow)?;
-
-  **developer_account_info.try_borrow_mut_lamports()? = developer_account_info
-    .lamports()
-    .checked_add(amount)
-    .ok_or(ErrorCode::CalculationOverflow)?;
+  pool_debit_credit(
+    &escrow_account_info,
+    &developer.to_account_info(),
+    amount,
+    &mut developer_escrow.sol_balance,
+  )?;
 
   emit!(EscrowWithdrawn {
     developer: developer.key(),
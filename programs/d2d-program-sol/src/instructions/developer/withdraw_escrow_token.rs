@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+  associated_token::AssociatedToken,
+  token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+  errors::ErrorCode,
+  events::EscrowWithdrawn,
+  states::{DeveloperEscrow, TreasuryPool},
+};
+
+/// Developer withdraws USDC/USDT from escrow to their associated token account
+#[derive(Accounts)]
+pub struct WithdrawEscrowToken<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [DeveloperEscrow::PREFIX_SEED, developer.key().as_ref()],
+        bump = developer_escrow.bump,
+        constraint = developer_escrow.developer == developer.key() @ ErrorCode::Unauthorized
+    )]
+  pub developer_escrow: Account<'info, DeveloperEscrow>,
+
+  pub mint: Account<'info, Mint>,
+
+  #[account(
+        init_if_needed,
+        payer = developer,
+        associated_token::mint = mint,
+        associated_token::authority = developer
+    )]
+  pub developer_token_account: Account<'info, TokenAccount>,
+
+  #[account(
+        mut,
+        seeds = [
+            DeveloperEscrow::TOKEN_VAULT_SEED,
+            developer_escrow.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = developer_escrow
+    )]
+  pub escrow_token_vault: Account<'info, TokenAccount>,
+
+  #[account(mut)]
+  pub developer: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_escrow_token(ctx: Context<WithdrawEscrowToken>, amount: u64) -> Result<()> {
+  let treasury_pool = &ctx.accounts.treasury_pool;
+  let developer_escrow = &mut ctx.accounts.developer_escrow;
+  let developer_key = ctx.accounts.developer.key();
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(amount > 0, ErrorCode::InvalidAmount);
+  let token_type = treasury_pool
+    .escrow_token_type(&ctx.accounts.mint.key())
+    .ok_or(ErrorCode::UnsupportedEscrowMint)?;
+
+  developer_escrow.withdraw_balance(amount, token_type)?;
+
+  let escrow_seeds: &[&[u8]] = &[
+    DeveloperEscrow::PREFIX_SEED,
+    developer_key.as_ref(),
+    &[developer_escrow.bump],
+  ];
+  token::transfer(
+    CpiContext::new_with_signer(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.escrow_token_vault.to_account_info(),
+        to: ctx.accounts.developer_token_account.to_account_info(),
+        authority: developer_escrow.to_account_info(),
+      },
+      &[escrow_seeds],
+    ),
+    amount,
+  )?;
+
+  emit!(EscrowWithdrawn {
+    developer: developer_key,
+    token_type: token_type as u8,
+    amount,
+    remaining_balance: developer_escrow.get_balance(token_type),
+    withdrawn_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
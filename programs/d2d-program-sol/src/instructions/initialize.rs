@@ -1,4 +1,4 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program};
 
 use crate::{events::TreasuryInitialized, states::TreasuryPool};
 
@@ -33,6 +33,14 @@ pub struct Initialize<'info> {
   )]
   pub platform_pool: UncheckedAccount<'info>,
 
+  /// CHECK: Treasury vault PDA (data-less, holds liquid principal)
+  #[account(
+    mut,
+    seeds = [TreasuryPool::VAULT_SEED],
+    bump
+  )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
   #[account(mut)]
   pub admin: Signer<'info>,
 
@@ -56,6 +64,8 @@ pub fn initialize(ctx: Context<Initialize>, _initial_apy: u64, dev_wallet: Pubke
   treasury_pool.admin = ctx.accounts.admin.key();
   treasury_pool.dev_wallet = dev_wallet;
   treasury_pool.emergency_pause = false;
+  treasury_pool.allow_emergency_exit_during_pause =
+    TreasuryPool::DEFAULT_ALLOW_EMERGENCY_EXIT_DURING_PAUSE;
 
   treasury_pool.guardian = Pubkey::default();
   treasury_pool.timelock_duration = TreasuryPool::DEFAULT_TIMELOCK_DURATION;
@@ -68,9 +78,38 @@ pub fn initialize(ctx: Context<Initialize>, _initial_apy: u64, dev_wallet: Pubke
   treasury_pool.total_credited_rewards = 0;
   treasury_pool.total_claimed_rewards = 0;
 
+  treasury_pool.service_fee_refund_bps = TreasuryPool::DEFAULT_SERVICE_FEE_REFUND_BPS;
+  treasury_pool.service_fee_refund_window = TreasuryPool::DEFAULT_SERVICE_FEE_REFUND_WINDOW;
+
+  treasury_pool.min_initial_months = TreasuryPool::DEFAULT_MIN_INITIAL_MONTHS;
+  treasury_pool.max_initial_months = TreasuryPool::DEFAULT_MAX_INITIAL_MONTHS;
+
+  treasury_pool.emergency_haircut_bps = TreasuryPool::DEFAULT_EMERGENCY_HAIRCUT_BPS;
+  treasury_pool.funding_variance_tolerance = TreasuryPool::DEFAULT_FUNDING_VARIANCE_TOLERANCE;
+  treasury_pool.unstake_commit_delay = TreasuryPool::DEFAULT_UNSTAKE_COMMIT_DELAY;
+  treasury_pool.watchtower_alert_cooldown = TreasuryPool::DEFAULT_WATCHTOWER_ALERT_COOLDOWN;
+
   treasury_pool.reward_pool_bump = ctx.bumps.reward_pool;
   treasury_pool.platform_pool_bump = ctx.bumps.platform_pool;
   treasury_pool.bump = ctx.bumps.treasury_pool;
+  treasury_pool.vault_bump = ctx.bumps.treasury_vault;
+
+  // Fund the vault to its rent-exempt minimum so payouts never close it
+  let vault_rent = Rent::get()?
+    .minimum_balance(0)
+    .saturating_sub(ctx.accounts.treasury_vault.lamports());
+  if vault_rent > 0 {
+    system_program::transfer(
+      CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+          from: ctx.accounts.admin.to_account_info(),
+          to: ctx.accounts.treasury_vault.to_account_info(),
+        },
+      ),
+      vault_rent,
+    )?;
+  }
 
   emit!(TreasuryInitialized {
     admin: treasury_pool.admin,
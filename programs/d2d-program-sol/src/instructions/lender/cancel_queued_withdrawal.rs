@@ -8,6 +8,7 @@ use crate::{
 
 /// Cancel a queued withdrawal request
 /// This allows a staker to cancel their pending withdrawal and keep funds staked
+/// The queue entry is closed and its rent returned to the staker
 #[derive(Accounts)]
 pub struct CancelQueuedWithdrawal<'info> {
   #[account(
@@ -23,6 +24,7 @@ pub struct CancelQueuedWithdrawal<'info> {
         bump = queue_entry.bump,
         constraint = queue_entry.staker == staker.key() @ ErrorCode::Unauthorized,
         constraint = !queue_entry.processed @ ErrorCode::WithdrawalAlreadyProcessed,
+        close = staker,
     )]
   pub queue_entry: Account<'info, WithdrawalQueueEntry>,
 
@@ -45,6 +47,9 @@ pub fn cancel_queued_withdrawal(ctx: Context<CancelQueuedWithdrawal>) -> Result<
   let lender_stake = &mut ctx.accounts.lender_stake;
   let current_time = Clock::get()?.unix_timestamp;
 
+  // Settle while the queued amount is still excluded from reward accrual
+  lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
+
   // Calculate remaining amount to cancel
   let amount_to_cancel = queue_entry.get_remaining_amount();
 
@@ -54,8 +59,20 @@ pub fn cancel_queued_withdrawal(ctx: Context<CancelQueuedWithdrawal>) -> Result<
   // Mark queue entry as processed (cancelled)
   queue_entry.cancel(current_time);
 
+  // Cancelling the head entry unblocks the queue for the next staker
+  if queue_entry.position == treasury_pool.withdrawal_queue_head {
+    treasury_pool.withdrawal_queue_head = treasury_pool
+      .withdrawal_queue_head
+      .checked_add(1)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+  }
+
   // Update lender stake - cancel the queued withdrawal
-  let cancelled_amount = lender_stake.cancel_queued_withdrawal()?;
+  let cancelled_amount =
+    lender_stake.cancel_queued_withdrawal(treasury_pool.queue_fee_per_share)?;
+
+  // The cancelled amount resumes earning from the current reward_per_share
+  lender_stake.update_reward_debt(treasury_pool.reward_per_share)?;
 
   emit!(StakerWithdrawalCancelled {
     staker: ctx.accounts.staker.key(),
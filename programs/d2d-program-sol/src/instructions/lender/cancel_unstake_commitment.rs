@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::UnstakeCommitmentCancelled,
+  states::{BackerDeposit, TreasuryPool},
+};
+
+/// Drop a pending unstake commitment, returning the amount to borrowable
+/// liquidity
+#[derive(Accounts)]
+pub struct CancelUnstakeCommitment<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  pub lender: Signer<'info>,
+}
+
+pub fn cancel_unstake_commitment(ctx: Context<CancelUnstakeCommitment>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+
+  require!(
+    lender_stake.has_unstake_commitment(),
+    ErrorCode::NoUnstakeCommitment
+  );
+
+  let amount = lender_stake.committed_unstake_amount;
+  treasury_pool.release_unstake_commitment(amount);
+  lender_stake.committed_unstake_amount = 0;
+  lender_stake.unstake_executable_at = 0;
+
+  emit!(UnstakeCommitmentCancelled {
+    staker: ctx.accounts.lender.key(),
+    amount,
+    cancelled_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::RewardsBatchClaimed,
+  instructions::pay_claimable_rewards,
+  states::{BackerDeposit, TreasuryPool},
+};
+
+/// Claim rewards on several of the staker's positions in one transaction
+///
+/// Remaining accounts: writable `lender_stake` PDAs of the signer (primary
+/// and/or indexed), at most `BackerDeposit::MAX_CLAIM_BATCH` per call. Each
+/// position is settled and paid exactly like claim_rewards, always to the
+/// signer's wallet; every position must have something to claim.
+#[derive(Accounts)]
+pub struct ClaimAllRewards<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Reward Pool PDA
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn claim_all_rewards(ctx: Context<ClaimAllRewards>) -> Result<()> {
+  let reward_pool_info = ctx.accounts.reward_pool.to_account_info();
+  let lender_info = ctx.accounts.lender.to_account_info();
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    !ctx.remaining_accounts.is_empty()
+      && ctx.remaining_accounts.len() <= BackerDeposit::MAX_CLAIM_BATCH,
+    ErrorCode::InvalidClaimBatch
+  );
+
+  let mut claimed_positions: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+  let mut total_amount: u64 = 0;
+
+  for stake_info in ctx.remaining_accounts {
+    require!(
+      !claimed_positions.contains(stake_info.key),
+      ErrorCode::DuplicateClaimPosition
+    );
+    require!(
+      stake_info.owner == ctx.program_id && stake_info.is_writable,
+      ErrorCode::InvalidAccountOwner
+    );
+
+    let mut lender_stake = BackerDeposit::try_deserialize(&mut &stake_info.data.borrow()[..])
+      .map_err(|_| ErrorCode::InvalidAccountData)?;
+    require!(
+      lender_stake.is_position_address(stake_info.key, ctx.program_id),
+      ErrorCode::InvalidAccountData
+    );
+    require!(
+      lender_stake.backer == lender_info.key(),
+      ErrorCode::Unauthorized
+    );
+
+    let amount = pay_claimable_rewards(
+      treasury_pool,
+      &mut lender_stake,
+      &reward_pool_info,
+      &lender_info,
+    )?;
+
+    lender_stake.try_serialize(&mut &mut stake_info.try_borrow_mut_data()?[..])?;
+
+    claimed_positions.push(stake_info.key());
+    total_amount = total_amount
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+  }
+
+  emit!(RewardsBatchClaimed {
+    staker: lender_info.key(),
+    positions_claimed: claimed_positions.len() as u32,
+    total_amount,
+    claimed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
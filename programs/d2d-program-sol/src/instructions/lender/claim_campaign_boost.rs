@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::CampaignBoostClaimed,
+  states::{BackerDeposit, BoostCampaign, CampaignEnrollment, TreasuryPool},
+  utils::{close_pda, payout_from_pda},
+};
+
+/// Staker claims the boost accrued by their enrolled position
+///
+/// Paid from the campaign budget while it lasts. Once the campaign has ended
+/// the enrollment is closed after the claim (even with nothing left to pay),
+/// refunding its rent.
+#[derive(Accounts)]
+pub struct ClaimCampaignBoost<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [BoostCampaign::PREFIX_SEED, &boost_campaign.campaign_id.to_le_bytes()],
+        bump = boost_campaign.bump
+    )]
+  pub boost_campaign: Account<'info, BoostCampaign>,
+
+  #[account(
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  #[account(
+        mut,
+        seeds = [CampaignEnrollment::PREFIX_SEED, boost_campaign.key().as_ref(), lender.key().as_ref()],
+        bump = campaign_enrollment.bump
+    )]
+  pub campaign_enrollment: Account<'info, CampaignEnrollment>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+}
+
+pub fn claim_campaign_boost(ctx: Context<ClaimCampaignBoost>) -> Result<()> {
+  let boost_campaign = &mut ctx.accounts.boost_campaign;
+  let campaign_enrollment = &mut ctx.accounts.campaign_enrollment;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+
+  let reward_per_share = boost_campaign
+    .get_accrual_reward_per_share(ctx.accounts.treasury_pool.reward_per_share, current_time);
+  campaign_enrollment.accrue(
+    reward_per_share,
+    ctx.accounts.lender_stake.get_reward_eligible_amount(),
+    boost_campaign.boost_bps,
+  )?;
+
+  let amount = campaign_enrollment
+    .get_unclaimed_boost()
+    .min(boost_campaign.get_remaining_budget());
+  let has_ended = boost_campaign.has_ended(current_time);
+  require!(amount > 0 || has_ended, ErrorCode::NoCampaignBoost);
+
+  campaign_enrollment.claimed_boost = campaign_enrollment
+    .claimed_boost
+    .checked_add(amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  boost_campaign.paid_out = boost_campaign
+    .paid_out
+    .checked_add(amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  payout_from_pda(
+    &boost_campaign.to_account_info(),
+    &ctx.accounts.lender.to_account_info(),
+    amount,
+  )?;
+
+  emit!(CampaignBoostClaimed {
+    campaign: boost_campaign.key(),
+    staker: ctx.accounts.lender.key(),
+    amount,
+    remaining_budget: boost_campaign.get_remaining_budget(),
+    claimed_at: current_time,
+  });
+
+  // Nothing more can accrue once the window has closed
+  if has_ended {
+    close_pda(
+      &campaign_enrollment.to_account_info(),
+      &ctx.accounts.lender.to_account_info(),
+    )?;
+  }
+
+  Ok(())
+}
+
+/// Checkpoint a position's campaign boost after its deposit decreased
+///
+/// Shared by every instruction that lowers the effective deposit: the boost
+/// earned so far is accrued on the principal held until now before it is
+/// lowered, so stake withdrawn and re-added between checkpoints is never
+/// boosted. Only needed while the campaign runs.
+pub(crate) fn checkpoint_campaign_boost(
+  lender_stake: &BackerDeposit,
+  boost_campaign: Option<&mut Account<BoostCampaign>>,
+  campaign_enrollment: Option<&mut Account<CampaignEnrollment>>,
+  reward_per_share: u128,
+  current_time: i64,
+) -> Result<()> {
+  if !lender_stake.is_in_live_campaign(current_time) {
+    return Ok(());
+  }
+  let (Some(boost_campaign), Some(campaign_enrollment)) = (boost_campaign, campaign_enrollment)
+  else {
+    return err!(ErrorCode::CampaignAccountsRequired);
+  };
+  require!(
+    boost_campaign.key() == lender_stake.boost_campaign
+      && campaign_enrollment.campaign == boost_campaign.key()
+      && campaign_enrollment.backer == lender_stake.backer,
+    ErrorCode::CampaignAccountsRequired
+  );
+
+  let reward_per_share =
+    boost_campaign.get_accrual_reward_per_share(reward_per_share, current_time);
+  campaign_enrollment.accrue(
+    reward_per_share,
+    lender_stake.get_reward_eligible_amount(),
+    boost_campaign.boost_bps,
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::InsuranceClaimed,
+  states::{BackerDeposit, InsuranceFund, TreasuryPool},
+  utils::payout_from_pda,
+};
+
+/// Claim pro-rata compensation for recorded loss events
+#[derive(Accounts)]
+pub struct ClaimInsurance<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump = insurance_fund.bump
+    )]
+  pub insurance_fund: Account<'info, InsuranceFund>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+}
+
+pub fn claim_insurance(ctx: Context<ClaimInsurance>) -> Result<()> {
+  let insurance_fund = &mut ctx.accounts.insurance_fund;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+
+  lender_stake.settle_insurance_compensation(insurance_fund.compensation_per_coverage)?;
+
+  let amount = lender_stake.pending_compensation;
+  require!(amount > 0, ErrorCode::NoInsuranceCompensation);
+
+  lender_stake.pending_compensation = 0;
+  insurance_fund.reserved_compensation =
+    insurance_fund.reserved_compensation.saturating_sub(amount);
+  insurance_fund.total_compensation_paid = insurance_fund
+    .total_compensation_paid
+    .checked_add(amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  payout_from_pda(
+    &insurance_fund.to_account_info(),
+    &ctx.accounts.lender.to_account_info(),
+    amount,
+  )?;
+
+  emit!(InsuranceClaimed {
+    staker: lender_stake.backer,
+    amount,
+    insured_amount: lender_stake.insured_amount,
+    claimed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
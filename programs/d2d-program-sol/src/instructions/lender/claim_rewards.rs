@@ -2,8 +2,9 @@ use anchor_lang::prelude::*;
 
 use crate::{
   errors::ErrorCode,
-  events::{DurationBonusClaimed, RewardsClaimed},
-  states::{LenderStake, TreasuryPool},
+  events::{BonusVestingScheduled, DurationBonusClaimed, RewardsClaimed},
+  states::{BackerDeposit, RewardHistory, TreasuryPool},
+  utils::{payout_from_pda, pda_payable_lamports},
 };
 
 #[derive(Accounts)]
@@ -25,10 +26,26 @@ pub struct ClaimRewards<'info> {
 
   #[account(
         mut,
-        seeds = [LenderStake::PREFIX_SEED, lender.key().as_ref()],
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
         bump = lender_stake.bump
     )]
-  pub lender_stake: Account<'info, LenderStake>,
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  /// Optional - records the claim when the staker has a reward history
+  #[account(
+        mut,
+        seeds = [RewardHistory::PREFIX_SEED, lender.key().as_ref()],
+        bump = reward_history.bump
+    )]
+  pub reward_history: Option<Account<'info, RewardHistory>>,
+
+  /// CHECK: Optional - receives the rewards instead of the signer; must be
+  /// the payout address registered on the position
+  #[account(
+        mut,
+        constraint = lender_stake.is_payout_address(&destination.key()) @ ErrorCode::InvalidPayoutDestination
+    )]
+  pub destination: Option<UncheckedAccount<'info>>,
 
   #[account(mut)]
   pub lender: Signer<'info>,
@@ -38,18 +55,51 @@ pub struct ClaimRewards<'info> {
 
 pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
   let reward_pool_info = ctx.accounts.reward_pool.to_account_info();
+  let recipient_info = match ctx.accounts.destination.as_ref() {
+    Some(destination) => destination.to_account_info(),
+    None => ctx.accounts.lender.to_account_info(),
+  };
 
-  let treasury_pool = &mut ctx.accounts.treasury_pool;
-  let lender_stake = &mut ctx.accounts.lender_stake;
-  let current_time = Clock::get()?.unix_timestamp;
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+
+  let amount = pay_claimable_rewards(
+    &mut ctx.accounts.treasury_pool,
+    &mut ctx.accounts.lender_stake,
+    &reward_pool_info,
+    &recipient_info,
+  )?;
+
+  if let Some(reward_history) = ctx.accounts.reward_history.as_mut() {
+    reward_history.record(
+      amount,
+      Clock::get()?.unix_timestamp,
+      ctx.accounts.treasury_pool.reward_per_share,
+    );
+  }
 
-  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  Ok(())
+}
+
+/// Settle and pay out base rewards + duration bonus to the backer wallet (or
+/// its registered payout address)
+/// Shared by claim_rewards and delegated claims. Returns the amount paid.
+///
+/// With bonus vesting enabled the new duration bonus is locked in the
+/// backer's vesting schedule instead; bonus vested from earlier claims is
+/// paid out alongside the base rewards.
+pub(crate) fn pay_claimable_rewards(
+  treasury_pool: &mut TreasuryPool,
+  lender_stake: &mut BackerDeposit,
+  reward_pool_info: &AccountInfo,
+  lender_info: &AccountInfo,
+) -> Result<u64> {
+  let current_time = Clock::get()?.unix_timestamp;
 
   // Update duration weight before calculating rewards
-  let weight_delta = lender_stake.update_duration_weight(current_time)?;
-  if weight_delta > 0 {
-    treasury_pool.update_stake_duration_weight(weight_delta)?;
-  }
+  lender_stake.settle_duration_weight(treasury_pool, current_time)?;
 
   // Calculate base claimable rewards from reward_per_share
   let base_claimable = lender_stake.calculate_claimable_rewards(treasury_pool.reward_per_share)?;
@@ -58,16 +108,28 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
   let duration_bonus =
     treasury_pool.calculate_duration_bonus(lender_stake.stake_duration_weight)?;
 
-  // Total claimable = base + duration bonus
+  // Split the bonus into the part paid now and the part that vests
+  let (bonus_paid_now, bonus_to_vest) = if treasury_pool.is_bonus_vesting_enabled() {
+    (0, duration_bonus)
+  } else {
+    (duration_bonus, 0)
+  };
+  let vested_bonus = lender_stake.get_vested_bonus(current_time)?;
+
+  // Total claimable = base + duration bonus (+ bonus vested from earlier claims)
   let total_claimable = base_claimable
-    .checked_add(duration_bonus)
+    .checked_add(bonus_paid_now)
+    .and_then(|x| x.checked_add(vested_bonus))
     .ok_or(ErrorCode::CalculationOverflow)?;
 
-  require!(total_claimable > 0, ErrorCode::NoRewardsToClaim);
+  require!(
+    total_claimable > 0 || bonus_to_vest > 0,
+    ErrorCode::NoRewardsToClaim
+  );
 
   // Verify we have enough funds
   require!(
-    treasury_pool.reward_pool_balance >= base_claimable,
+    treasury_pool.reward_pool_balance >= total_claimable,
     ErrorCode::InsufficientTreasuryFunds
   );
 
@@ -88,33 +150,52 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
   lender_stake.pending_rewards = 0;
   lender_stake.update_reward_debt(treasury_pool.reward_per_share)?;
 
-  // Debit base from reward_pool_balance
-  treasury_pool.debit_reward_pool(base_claimable)?;
-  treasury_pool.record_claimed_rewards(base_claimable)?;
+  // Debit everything paid out from reward_pool_balance (claimed first so the
+  // backing invariant is checked against the post-claim protected total).
+  // Duration bonuses are carved out of the balance, so they are debited too.
+  treasury_pool.record_claimed_rewards(total_claimable)?;
+  treasury_pool.debit_reward_pool(total_claimable)?;
 
-  // Debit duration bonus from pending_undistributed_rewards
+  // Take the duration bonus out of pending_undistributed_rewards
   if duration_bonus > 0 {
     treasury_pool.pending_undistributed_rewards = treasury_pool
       .pending_undistributed_rewards
       .saturating_sub(duration_bonus);
   }
 
+  // Pay out what has vested, then lock the new bonus
+  if vested_bonus > 0 {
+    lender_stake.release_vested_bonus(current_time)?;
+    treasury_pool.total_vesting_bonus = treasury_pool
+      .total_vesting_bonus
+      .saturating_sub(vested_bonus);
+  }
+  if bonus_to_vest > 0 {
+    lender_stake.add_vesting_bonus(
+      bonus_to_vest,
+      current_time,
+      treasury_pool.bonus_vesting_period,
+    )?;
+    treasury_pool.total_vesting_bonus = treasury_pool
+      .total_vesting_bonus
+      .checked_add(bonus_to_vest)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    emit!(BonusVestingScheduled {
+      staker: lender_stake.backer,
+      amount: bonus_to_vest,
+      vesting_total: lender_stake.vesting_bonus_amount,
+      vesting_end_at: lender_stake.vesting_end_at,
+      scheduled_at: current_time,
+    });
+  }
+
   // Reset staker's duration weight after claiming
   lender_stake.reset_duration_weight(current_time);
 
-  // Transfer SOL from reward pool to lender
-  {
-    let lender_info = ctx.accounts.lender.to_account_info();
-    let mut reward_pool_lamports = reward_pool_info.try_borrow_mut_lamports()?;
-    let mut lender_lamports = lender_info.try_borrow_mut_lamports()?;
-
-    **reward_pool_lamports = (**reward_pool_lamports)
-      .checked_sub(total_claimable)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-    **lender_lamports = (**lender_lamports)
-      .checked_add(total_claimable)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-  }
+  // Transfer SOL from reward pool to lender (reward pool stays rent-exempt)
+  payout_from_pda(reward_pool_info, lender_info, total_claimable)?;
+  treasury_pool.check_reward_pool_solvency(pda_payable_lamports(reward_pool_info)?)?;
 
   // Emit events
   emit!(RewardsClaimed {
@@ -123,10 +204,10 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
     total_claimed: lender_stake.claimed_total,
   });
 
-  if duration_bonus > 0 {
+  if bonus_paid_now > 0 {
     emit!(DurationBonusClaimed {
       staker: lender_stake.backer,
-      duration_bonus,
+      duration_bonus: bonus_paid_now,
       stake_duration_weight: lender_stake.stake_duration_weight,
       claimed_at: current_time,
     });
@@ -140,5 +221,5 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
     claimed_at: current_time,
   });
 
-  Ok(())
+  Ok(total_claimable)
 }
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::VestedBonusClaimed,
+  states::{BackerDeposit, TreasuryPool},
+  utils::{payout_from_pda, pda_payable_lamports},
+};
+
+/// Claim the duration bonus vested so far, without claiming base rewards
+#[derive(Accounts)]
+pub struct ClaimVestedBonus<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Reward Pool PDA (holds vesting bonuses)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+}
+
+pub fn claim_vested_bonus(ctx: Context<ClaimVestedBonus>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+
+  let amount = lender_stake.release_vested_bonus(current_time)?;
+  require!(amount > 0, ErrorCode::NoVestedBonus);
+
+  lender_stake.claimed_total = lender_stake
+    .claimed_total
+    .checked_add(amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  treasury_pool.total_vesting_bonus = treasury_pool.total_vesting_bonus.saturating_sub(amount);
+  treasury_pool.record_claimed_rewards(amount)?;
+  treasury_pool.debit_reward_pool(amount)?;
+
+  let reward_pool_info = ctx.accounts.reward_pool.to_account_info();
+  payout_from_pda(
+    &reward_pool_info,
+    &ctx.accounts.lender.to_account_info(),
+    amount,
+  )?;
+  treasury_pool.check_reward_pool_solvency(pda_payable_lamports(&reward_pool_info)?)?;
+
+  emit!(VestedBonusClaimed {
+    staker: lender_stake.backer,
+    amount,
+    still_vesting: lender_stake.get_unvested_bonus(current_time)?,
+    claimed_at: current_time,
+  });
+
+  Ok(())
+}
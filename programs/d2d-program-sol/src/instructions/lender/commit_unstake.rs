@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::UnstakeCommitted,
+  states::{BackerDeposit, TreasuryPool},
+};
+
+/// Declare an unstake amount ahead of time. Required for unstakes above the
+/// pool's large_unstake_threshold_bps; the committed amount is withheld from
+/// new borrows and can be withdrawn with unstake_sol once the delay passes
+#[derive(Accounts)]
+pub struct CommitUnstake<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  pub lender: Signer<'info>,
+}
+
+pub fn commit_unstake(ctx: Context<CommitUnstake>, amount: u64) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(amount > 0, ErrorCode::InvalidAmount);
+  require!(!lender_stake.is_pledged(), ErrorCode::StakePledged);
+  require!(
+    !lender_stake.has_unstake_commitment(),
+    ErrorCode::UnstakeCommitmentPending
+  );
+  require!(
+    !lender_stake.has_queued_withdrawal(),
+    ErrorCode::WithdrawalAlreadyQueued
+  );
+  require!(
+    amount <= lender_stake.deposited_amount,
+    ErrorCode::InsufficientStake
+  );
+
+  let executable_at = current_time
+    .checked_add(treasury_pool.unstake_commit_delay)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  lender_stake.committed_unstake_amount = amount;
+  lender_stake.unstake_executable_at = executable_at;
+  treasury_pool.record_unstake_commitment(amount)?;
+
+  emit!(UnstakeCommitted {
+    staker: ctx.accounts.lender.key(),
+    amount,
+    executable_at,
+    committed_unstake_total: treasury_pool.committed_unstake_amount,
+    committed_at: current_time,
+  });
+
+  Ok(())
+}
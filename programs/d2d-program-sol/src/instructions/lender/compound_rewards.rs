@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::{CompoundBatchProcessed, RewardsCompounded},
+  states::{BackerDeposit, TreasuryPool},
+  utils::payout_from_pda,
+};
+
+/// Permissionless crank: restake settled rewards of opted-in stakers
+///
+/// Remaining accounts: writable `lender_stake` PDAs, at most
+/// `BackerDeposit::MAX_COMPOUND_BATCH` per call. Positions that are not
+/// program-owned, not canonical PDAs, inactive, not opted in, without
+/// settled rewards or that would exceed max_pool_share_bps are skipped.
+///
+/// For each compounded position the base rewards move from the reward pool
+/// PDA to the treasury vault and are added to deposited_amount, total_deposited
+/// and liquid_balance, so tracked balances stay backed by lamports.
+/// Duration bonus is left for an explicit claim.
+#[derive(Accounts)]
+pub struct CompoundRewards<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Reward Pool PDA
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Treasury vault PDA (receives the compounded rewards)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump = treasury_pool.vault_bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  pub cranker: Signer<'info>,
+}
+
+pub fn compound_rewards(ctx: Context<CompoundRewards>) -> Result<()> {
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let reward_pool_info = ctx.accounts.reward_pool.to_account_info();
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    ctx.remaining_accounts.len() <= BackerDeposit::MAX_COMPOUND_BATCH,
+    ErrorCode::CompoundBatchTooLarge
+  );
+
+  let mut stakers_compounded: u32 = 0;
+  let mut total_compounded: u64 = 0;
+
+  for stake_info in ctx.remaining_accounts {
+    if stake_info.owner != ctx.program_id || !stake_info.is_writable {
+      continue;
+    }
+
+    let mut lender_stake = match BackerDeposit::try_deserialize(&mut &stake_info.data.borrow()[..])
+    {
+      Ok(stake) => stake,
+      Err(_) => continue,
+    };
+    let expected_stake = Pubkey::create_program_address(
+      &[
+        BackerDeposit::PREFIX_SEED,
+        lender_stake.backer.as_ref(),
+        &[lender_stake.bump],
+      ],
+      ctx.program_id,
+    );
+    if expected_stake.ok() != Some(stake_info.key())
+      || !lender_stake.is_active
+      || !lender_stake.auto_compound_enabled
+    {
+      continue;
+    }
+
+    lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
+    let amount = lender_stake.pending_rewards;
+    if amount == 0 {
+      continue;
+    }
+    // Compounding is a new stake for the pool share cap; over-cap rewards
+    // stay pending for an explicit claim
+    let staker_deposit_after = lender_stake
+      .staker_total_deposit()?
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    if treasury_pool
+      .check_pool_share(staker_deposit_after, amount)
+      .is_err()
+    {
+      continue;
+    }
+
+    // Principal changes - accrue duration weight on the old amount first
+    lender_stake.settle_duration_weight(treasury_pool, current_time)?;
+
+    // Rewards leave the reward pool as if claimed...
+    treasury_pool.record_claimed_rewards(amount)?;
+    treasury_pool.debit_reward_pool(amount)?;
+    payout_from_pda(&reward_pool_info, &treasury_vault_info, amount)?;
+
+    // ...and re-enter the treasury as a deposit
+    lender_stake.compound_pending_rewards()?;
+    lender_stake.update_reward_debt(treasury_pool.reward_per_share)?;
+
+    treasury_pool.total_deposited = treasury_pool
+      .total_deposited
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    treasury_pool.liquid_balance = treasury_pool
+      .liquid_balance
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    lender_stake.try_serialize(&mut &mut stake_info.try_borrow_mut_data()?[..])?;
+
+    stakers_compounded = stakers_compounded
+      .checked_add(1)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    total_compounded = total_compounded
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    emit!(RewardsCompounded {
+      staker: lender_stake.backer,
+      amount,
+      new_deposited_amount: lender_stake.deposited_amount,
+      compounded_at: current_time,
+    });
+  }
+
+  emit!(CompoundBatchProcessed {
+    cranker: ctx.accounts.cranker.key(),
+    stakers_compounded,
+    total_compounded,
+    total_deposited: treasury_pool.total_deposited,
+    processed_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::DelegatedClaimExecuted,
+  instructions::pay_claimable_rewards,
+  states::{BackerDeposit, RewardHistory, TreasuryPool},
+};
+
+/// Claim rewards on behalf of a staker by their registered claim delegate
+/// Rewards are paid to the staker wallet, never to the delegate
+#[derive(Accounts)]
+pub struct DelegatedClaimRewards<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Reward Pool PDA
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump,
+        constraint = lender_stake.is_claim_delegate(&delegate.key()) @ ErrorCode::Unauthorized
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  /// Optional - records the claim when the staker has a reward history
+  #[account(
+        mut,
+        seeds = [RewardHistory::PREFIX_SEED, lender.key().as_ref()],
+        bump = reward_history.bump
+    )]
+  pub reward_history: Option<Account<'info, RewardHistory>>,
+
+  /// CHECK: Staker wallet that receives the rewards (does not need to sign)
+  #[account(mut)]
+  pub lender: UncheckedAccount<'info>,
+
+  pub delegate: Signer<'info>,
+}
+
+pub fn delegated_claim_rewards(ctx: Context<DelegatedClaimRewards>) -> Result<()> {
+  let reward_pool_info = ctx.accounts.reward_pool.to_account_info();
+  let lender_info = ctx.accounts.lender.to_account_info();
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+
+  let amount = pay_claimable_rewards(
+    &mut ctx.accounts.treasury_pool,
+    &mut ctx.accounts.lender_stake,
+    &reward_pool_info,
+    &lender_info,
+  )?;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  if let Some(reward_history) = ctx.accounts.reward_history.as_mut() {
+    reward_history.record(
+      amount,
+      current_time,
+      ctx.accounts.treasury_pool.reward_per_share,
+    );
+  }
+
+  emit!(DelegatedClaimExecuted {
+    staker: ctx.accounts.lender.key(),
+    delegate: ctx.accounts.delegate.key(),
+    amount,
+    claimed_at: current_time,
+  });
+
+  Ok(())
+}
@@ -2,10 +2,25 @@ use anchor_lang::prelude::*;
 
 use crate::{
   errors::ErrorCode,
-  events::EmergencyUnstake,
-  states::{BackerDeposit, TreasuryPool},
+  events::{EmergencyHaircutCharged, EmergencyUnstake, QueueJumpFeeCharged},
+  instructions::{checkpoint_campaign_boost, sync_insurance_coverage},
+  states::{
+    BackerDeposit, BoostCampaign, CampaignEnrollment, FeeSource, InsuranceFund, TreasuryPool,
+  },
+  utils::{vault_debit_credit, vault_payable_lamports},
 };
 
+/// Immediate unstake without going through the withdrawal queue
+///
+/// Liquidity up to `queued_withdrawal_amount` is reserved for stakers already in
+/// the queue. Drawing on it is governed by `TreasuryPool::emergency_exit_policy`:
+/// rejected (default) or allowed for a queue jump fee on the reserved portion,
+/// paid through the reward pool to the stakers waiting in the queue, pro rata
+/// to their queued amounts.
+/// The exit stays open while `emergency_pause` is set (unless
+/// `allow_emergency_exit_during_pause` is disabled). In exchange, a haircut of
+/// `emergency_haircut_bps` is credited to the reward pool and rewards accrued
+/// since the last settlement are forfeited back to stakers.
 #[derive(Accounts)]
 pub struct EmergencyUnstakeSol<'info> {
   /// CHECK: Treasury Pool - will be migrated if needed
@@ -16,13 +31,18 @@ pub struct EmergencyUnstakeSol<'info> {
     )]
   pub treasury_pool: UncheckedAccount<'info>,
 
-  /// CHECK: Treasury Pool PDA (holds deposits)
+  /// CHECK: Treasury vault PDA (holds deposits)
   #[account(
         mut,
-        seeds = [TreasuryPool::PREFIX_SEED],
+        seeds = [TreasuryPool::VAULT_SEED],
         bump
     )]
-  pub treasury_pda: UncheckedAccount<'info>,
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  /// CHECK: Reward Pool PDA (receives the haircut and queue jump fee) -
+  /// checked against the stored reward_pool_bump in the handler
+  #[account(mut)]
+  pub reward_pool: UncheckedAccount<'info>,
 
   #[account(
         mut,
@@ -34,15 +54,26 @@ pub struct EmergencyUnstakeSol<'info> {
   #[account(mut)]
   pub lender: Signer<'info>,
 
+  /// Insurance fund (required when the position's coverage must shrink)
+  #[account(
+        mut,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump = insurance_fund.bump
+    )]
+  pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+  /// Running boost campaign the position is enrolled in (required until it ends)
+  #[account(mut)]
+  pub boost_campaign: Option<Account<'info, BoostCampaign>>,
+
+  /// The position's enrollment in boost_campaign
+  #[account(mut)]
+  pub campaign_enrollment: Option<Account<'info, CampaignEnrollment>>,
+
   pub system_program: Program<'info, System>,
 }
 
 pub fn emergency_unstake_sol(ctx: Context<EmergencyUnstakeSol>, amount: u64) -> Result<()> {
-  require!(
-    ctx.accounts.treasury_pda.key() == ctx.accounts.treasury_pool.key(),
-    ErrorCode::InvalidAccountOwner
-  );
-
   let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
   let required_space = 8 + TreasuryPool::INIT_SPACE;
   let current_space = treasury_pool_info.data_len();
@@ -53,43 +84,76 @@ pub fn emergency_unstake_sol(ctx: Context<EmergencyUnstakeSol>, amount: u64) ->
 
   let mut treasury_pool = TreasuryPool::try_deserialize(&mut &treasury_pool_info.data.borrow()[..])
     .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
-
-  let treasury_pda_info = ctx.accounts.treasury_pda.to_account_info();
+  TreasuryPool::check_pda(
+    &ctx.accounts.reward_pool.key(),
+    TreasuryPool::REWARD_POOL_SEED,
+    treasury_pool.reward_pool_bump,
+    ctx.program_id,
+  )?;
+
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let system_program_info = ctx.accounts.system_program.to_account_info();
   let lender_stake = &mut ctx.accounts.lender_stake;
   let current_time = Clock::get()?.unix_timestamp;
 
+  // Pause policy is explicit: exits stay open during a pause only if configured
+  require!(
+    treasury_pool.is_emergency_exit_allowed(),
+    ErrorCode::ProgramPaused
+  );
   require!(amount > 0, ErrorCode::InvalidAmount);
+  require!(!lender_stake.is_pledged(), ErrorCode::StakePledged);
+  require!(
+    !lender_stake.has_unstake_commitment(),
+    ErrorCode::UnstakeCommitmentPending
+  );
   require!(
     amount <= lender_stake.deposited_amount,
     ErrorCode::InsufficientStake
   );
 
+  // Staker's own queued amount is already promised to the queue
+  require!(
+    amount <= lender_stake.get_effective_deposit(),
+    ErrorCode::InsufficientStake
+  );
+
   if lender_stake.deposited_amount == 0 {
     return Err(ErrorCode::InsufficientStake.into());
   }
 
-  // SECURITY FIX M-04: Settle pending rewards BEFORE reducing deposited_amount
-  // This ensures users don't lose accrued rewards during emergency unstake
-  lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
+  // Emergency exits forfeit rewards accrued since the last settlement
+  // (already settled pending_rewards stay claimable)
+  let forfeited_rewards = lender_stake.forfeit_unsettled_rewards(treasury_pool.reward_per_share)?;
 
   // Update duration weight before withdrawal
-  let weight_delta = lender_stake.update_duration_weight(current_time)?;
-  if weight_delta > 0 {
-    treasury_pool.update_stake_duration_weight(weight_delta)?;
-  }
+  lender_stake.settle_duration_weight(&mut treasury_pool, current_time)?;
 
-  let treasury_lamports = treasury_pda_info.lamports();
-  let account_data_size = treasury_pda_info.data_len();
-  let rent_exemption =
-    anchor_lang::solana_program::rent::Rent::get()?.minimum_balance(account_data_size);
-  let available_balance = treasury_lamports
-    .checked_sub(rent_exemption)
-    .ok_or(ErrorCode::CalculationOverflow)?;
+  let available_balance = vault_payable_lamports(&treasury_vault_info)?;
 
   if available_balance < amount {
     return Err(ErrorCode::InsufficientLiquidBalance.into());
   }
 
+  // === QUEUE RESERVATION ===
+  // Only liquidity above the queued obligations is free for emergency exits
+  let unreserved_balance = treasury_pool.get_unreserved_liquidity(available_balance);
+  let reserved_portion = amount.saturating_sub(unreserved_balance);
+  let queue_jump_fee = if reserved_portion > 0 {
+    require!(
+      treasury_pool.emergency_exit_policy == TreasuryPool::EMERGENCY_EXIT_QUEUE_JUMP_FEE,
+      ErrorCode::QueueLiquidityReserved
+    );
+    treasury_pool.calculate_queue_jump_fee(reserved_portion)?
+  } else {
+    0
+  };
+  let haircut_amount = treasury_pool.calculate_emergency_haircut(amount)?;
+  let payout_amount = amount
+    .checked_sub(queue_jump_fee)
+    .and_then(|remaining| remaining.checked_sub(haircut_amount))
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
   lender_stake.deposited_amount = lender_stake
     .deposited_amount
     .checked_sub(amount)
@@ -107,24 +171,56 @@ pub fn emergency_unstake_sol(ctx: Context<EmergencyUnstakeSol>, amount: u64) ->
     .checked_sub(amount)
     .ok_or(ErrorCode::CalculationOverflow)?;
 
-  treasury_pool.liquid_balance = treasury_pool
-    .liquid_balance
-    .checked_sub(amount)
-    .ok_or(ErrorCode::CalculationOverflow)?;
+  // Coverage and campaign boost follow the stake that remains
+  sync_insurance_coverage(lender_stake, ctx.accounts.insurance_fund.as_mut())?;
+  checkpoint_campaign_boost(
+    lender_stake,
+    ctx.accounts.boost_campaign.as_mut(),
+    ctx.accounts.campaign_enrollment.as_mut(),
+    treasury_pool.reward_per_share,
+    current_time,
+  )?;
+
+  // Forfeited rewards are redistributed to the remaining stakers
+  if forfeited_rewards > 0 {
+    treasury_pool.move_to_pending_rewards(forfeited_rewards)?;
+  }
 
-  {
-    let lender_info = ctx.accounts.lender.to_account_info();
-    let mut treasury_lamports = treasury_pda_info.try_borrow_mut_lamports()?;
-    let mut lender_lamports = lender_info.try_borrow_mut_lamports()?;
-
-    **treasury_lamports = (**treasury_lamports)
-      .checked_sub(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-    **lender_lamports = (**lender_lamports)
-      .checked_add(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
+  // Haircut goes to the stakers who stay, via the reward pool
+  if haircut_amount > 0 {
+    vault_debit_credit(
+      &system_program_info,
+      &treasury_vault_info,
+      &ctx.accounts.reward_pool.to_account_info(),
+      haircut_amount,
+      treasury_pool.vault_bump,
+      &mut treasury_pool.liquid_balance,
+    )?;
+    treasury_pool.collect_fee(FeeSource::EmergencyHaircut, haircut_amount, 0)?;
   }
 
+  // Queue jump fee goes to the stakers whose queued withdrawals were jumped
+  if queue_jump_fee > 0 {
+    vault_debit_credit(
+      &system_program_info,
+      &treasury_vault_info,
+      &ctx.accounts.reward_pool.to_account_info(),
+      queue_jump_fee,
+      treasury_pool.vault_bump,
+      &mut treasury_pool.liquid_balance,
+    )?;
+    treasury_pool.credit_queue_jump_fee(queue_jump_fee)?;
+  }
+
+  vault_debit_credit(
+    &system_program_info,
+    &treasury_vault_info,
+    &ctx.accounts.lender.to_account_info(),
+    payout_amount,
+    treasury_pool.vault_bump,
+    &mut treasury_pool.liquid_balance,
+  )?;
+
   let mut data = treasury_pool_info.try_borrow_mut_data()?;
   treasury_pool.try_serialize(&mut &mut data[..])?;
 
@@ -132,8 +228,28 @@ pub fn emergency_unstake_sol(ctx: Context<EmergencyUnstakeSol>, amount: u64) ->
     lender: lender_stake.backer,
     amount,
     remaining_staked: lender_stake.deposited_amount,
-    unstaked_at: Clock::get()?.unix_timestamp,
+    unstaked_at: current_time,
   });
 
+  if haircut_amount > 0 || forfeited_rewards > 0 {
+    emit!(EmergencyHaircutCharged {
+      lender: lender_stake.backer,
+      amount,
+      haircut_amount,
+      forfeited_rewards,
+      charged_at: current_time,
+    });
+  }
+
+  if queue_jump_fee > 0 {
+    emit!(QueueJumpFeeCharged {
+      lender: lender_stake.backer,
+      amount,
+      reserved_portion,
+      fee_amount: queue_jump_fee,
+      charged_at: current_time,
+    });
+  }
+
   Ok(())
 }
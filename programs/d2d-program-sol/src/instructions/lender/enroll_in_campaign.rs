@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::CampaignEnrolled,
+  states::{BackerDeposit, BoostCampaign, CampaignEnrollment, TreasuryPool},
+};
+
+/// Staker enrolls their position in an active boost campaign
+///
+/// The boost accrues on base rewards earned from now on, on at most the
+/// reward-eligible stake held at enrollment. A position can be in one
+/// running campaign at a time; until it ends, instructions that lower the
+/// deposit must pass the campaign and enrollment to checkpoint the boost.
+#[derive(Accounts)]
+pub struct EnrollInCampaign<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [BoostCampaign::PREFIX_SEED, &boost_campaign.campaign_id.to_le_bytes()],
+        bump = boost_campaign.bump
+    )]
+  pub boost_campaign: Account<'info, BoostCampaign>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  #[account(
+        init,
+        payer = lender,
+        space = 8 + CampaignEnrollment::INIT_SPACE,
+        seeds = [CampaignEnrollment::PREFIX_SEED, boost_campaign.key().as_ref(), lender.key().as_ref()],
+        bump
+    )]
+  pub campaign_enrollment: Account<'info, CampaignEnrollment>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn enroll_in_campaign(ctx: Context<EnrollInCampaign>) -> Result<()> {
+  let boost_campaign = &mut ctx.accounts.boost_campaign;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let campaign_enrollment = &mut ctx.accounts.campaign_enrollment;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+  require!(
+    boost_campaign.is_active(current_time),
+    ErrorCode::CampaignNotActive
+  );
+  require!(
+    boost_campaign.is_eligible(lender_stake),
+    ErrorCode::NotEligibleForCampaign
+  );
+
+  require!(
+    !lender_stake.is_in_live_campaign(current_time),
+    ErrorCode::AlreadyInCampaign
+  );
+
+  let boosted_amount = lender_stake.get_reward_eligible_amount();
+  require!(boosted_amount > 0, ErrorCode::InsufficientStake);
+
+  let reward_per_share = boost_campaign
+    .get_accrual_reward_per_share(ctx.accounts.treasury_pool.reward_per_share, current_time);
+
+  campaign_enrollment.campaign = boost_campaign.key();
+  campaign_enrollment.backer = lender_stake.backer;
+  campaign_enrollment.boosted_amount = boosted_amount;
+  campaign_enrollment.reward_per_share_checkpoint = reward_per_share;
+  campaign_enrollment.enrolled_at = current_time;
+  campaign_enrollment.bump = ctx.bumps.campaign_enrollment;
+
+  lender_stake.boost_campaign = boost_campaign.key();
+  lender_stake.boost_campaign_end_at = boost_campaign.end_at;
+  boost_campaign.enrolled_count = boost_campaign.enrolled_count.saturating_add(1);
+
+  emit!(CampaignEnrolled {
+    campaign: boost_campaign.key(),
+    staker: lender_stake.backer,
+    boosted_amount,
+    enrolled_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::QueueEntryExpired,
+  states::{BackerDeposit, TreasuryPool, WithdrawalQueueEntry},
+};
+
+/// Expire a queue entry that was not fulfilled within max_queue_age
+/// Permissionless - the unfulfilled remainder is re-staked and the entry is
+/// cancelled so the queue head can move on
+#[derive(Accounts)]
+#[instruction(queue_position: u32)]
+pub struct ExpireQueueEntry<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [WithdrawalQueueEntry::PREFIX_SEED, &queue_position.to_le_bytes()],
+        bump = queue_entry.bump,
+        constraint = !queue_entry.processed @ ErrorCode::WithdrawalAlreadyProcessed,
+    )]
+  pub queue_entry: Account<'info, WithdrawalQueueEntry>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, queue_entry.staker.as_ref()],
+        bump = lender_stake.bump,
+        constraint = lender_stake.backer == queue_entry.staker @ ErrorCode::Unauthorized,
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  pub caller: Signer<'info>,
+}
+
+pub fn expire_queue_entry(ctx: Context<ExpireQueueEntry>, queue_position: u32) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let queue_entry = &mut ctx.accounts.queue_entry;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    treasury_pool.is_queue_entry_expired(queue_entry.queued_at, current_time),
+    ErrorCode::QueueEntryNotExpired
+  );
+
+  // Settle while the remainder is still excluded from reward accrual
+  lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
+
+  // Remove the unfulfilled remainder from queue tracking
+  let amount_restaked = queue_entry.get_remaining_amount();
+  treasury_pool.process_queued_withdrawal(amount_restaked)?;
+
+  // Mark queue entry as processed (cancelled)
+  queue_entry.cancel(current_time);
+
+  // Reinstate the position - the remainder was never withdrawn, so it keeps earning
+  lender_stake.cancel_queued_withdrawal(treasury_pool.queue_fee_per_share)?;
+  if lender_stake.deposited_amount > 0 {
+    lender_stake.is_active = true;
+  }
+  lender_stake.update_reward_debt(treasury_pool.reward_per_share)?;
+
+  // Expiring the head entry unblocks the queue for the next staker
+  if queue_position == treasury_pool.withdrawal_queue_head {
+    treasury_pool.withdrawal_queue_head = treasury_pool
+      .withdrawal_queue_head
+      .checked_add(1)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+  }
+
+  emit!(QueueEntryExpired {
+    staker: queue_entry.staker,
+    queue_position,
+    amount_requested: queue_entry.amount,
+    amount_withdrawn: queue_entry.amount_withdrawn,
+    amount_restaked,
+    expired_by: ctx.accounts.caller.key(),
+    expired_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::ClaimableRewardsQueried,
+  states::{BackerDeposit, TreasuryPool},
+};
+
+/// Read-only view of what `claim_rewards` would pay out right now
+///
+/// Intended to be simulated by frontends: the result is returned via
+/// return data and also emitted as an event. No account is modified.
+#[derive(Accounts)]
+pub struct GetClaimableRewards<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        seeds = [BackerDeposit::PREFIX_SEED, staker.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  /// CHECK: Staker whose rewards are queried (does not need to sign)
+  pub staker: UncheckedAccount<'info>,
+}
+
+/// Claimable rewards breakdown returned by `get_claimable_rewards`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimableRewards {
+  pub base_claimable: u64,
+  pub duration_bonus: u64,
+  /// Bonus vested from earlier claims (paid by claim_rewards or claim_vested_bonus)
+  pub vested_bonus: u64,
+  /// Bonus still locked in the vesting schedule (excludes duration_bonus)
+  pub unvested_bonus: u64,
+  /// Paid out by claim_rewards now; duration_bonus is excluded while vesting is on
+  pub total_claimable: u64,
+}
+
+pub fn get_claimable_rewards(ctx: Context<GetClaimableRewards>) -> Result<ClaimableRewards> {
+  let current_time = Clock::get()?.unix_timestamp;
+
+  // Work on copies so the duration weight can be brought up to date exactly
+  // as claim_rewards does, without writing anything back
+  let mut treasury_pool = (*ctx.accounts.treasury_pool).clone();
+  let mut lender_stake = (*ctx.accounts.lender_stake).clone();
+
+  lender_stake.settle_duration_weight(&mut treasury_pool, current_time)?;
+
+  let base_claimable = lender_stake.calculate_claimable_rewards(treasury_pool.reward_per_share)?;
+  let duration_bonus =
+    treasury_pool.calculate_duration_bonus(lender_stake.stake_duration_weight)?;
+  let bonus_paid_now = if treasury_pool.is_bonus_vesting_enabled() {
+    0
+  } else {
+    duration_bonus
+  };
+  let vested_bonus = lender_stake.get_vested_bonus(current_time)?;
+  let unvested_bonus = lender_stake.get_unvested_bonus(current_time)?;
+  let total_claimable = base_claimable
+    .checked_add(bonus_paid_now)
+    .and_then(|x| x.checked_add(vested_bonus))
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  emit!(ClaimableRewardsQueried {
+    staker: lender_stake.backer,
+    base_claimable,
+    duration_bonus,
+    total_claimable,
+    queried_at: current_time,
+  });
+
+  Ok(ClaimableRewards {
+    base_claimable,
+    duration_bonus,
+    vested_bonus,
+    unvested_bonus,
+    total_claimable,
+  })
+}
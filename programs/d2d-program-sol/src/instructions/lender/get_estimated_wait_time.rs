@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::WaitTimeEstimated,
+  states::{TreasuryPool, WithdrawalQueueEntry},
+};
+
+/// Read-only estimate of when a queued withdrawal will be fulfilled
+///
+/// Uses the rolling recovery rate kept on the treasury pool (updated on each
+/// debt repayment). Intended to be simulated: the result is returned via
+/// return data and also emitted as an event. No account is modified.
+#[derive(Accounts)]
+#[instruction(queue_position: u32)]
+pub struct GetEstimatedWaitTime<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        seeds = [WithdrawalQueueEntry::PREFIX_SEED, &queue_position.to_le_bytes()],
+        bump = queue_entry.bump,
+        constraint = queue_entry.is_pending() @ ErrorCode::WithdrawalAlreadyProcessed,
+    )]
+  pub queue_entry: Account<'info, WithdrawalQueueEntry>,
+}
+
+/// Wait time estimate returned by `get_estimated_wait_time`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EstimatedWaitTime {
+  pub remaining_amount: u64,
+  /// Rolling lamports/day of debt repaid (0 = no recent recovery)
+  pub recovery_rate_per_day: u64,
+  /// i64::MAX when there is no recovery rate to estimate from
+  pub estimated_wait_seconds: i64,
+  pub estimated_fulfilled_at: i64,
+}
+
+pub fn get_estimated_wait_time(
+  ctx: Context<GetEstimatedWaitTime>,
+  queue_position: u32,
+) -> Result<EstimatedWaitTime> {
+  let current_time = Clock::get()?.unix_timestamp;
+  let queue_entry = &ctx.accounts.queue_entry;
+
+  let recovery_rate_per_day = ctx
+    .accounts
+    .treasury_pool
+    .get_recovery_rate_per_day(current_time);
+  let remaining_amount = queue_entry.get_remaining_amount();
+  let estimated_wait_seconds = queue_entry.estimate_wait_time(recovery_rate_per_day);
+
+  emit!(WaitTimeEstimated {
+    staker: queue_entry.staker,
+    queue_position,
+    remaining_amount,
+    recovery_rate_per_day,
+    estimated_wait_seconds,
+    queried_at: current_time,
+  });
+
+  Ok(EstimatedWaitTime {
+    remaining_amount,
+    recovery_rate_per_day,
+    estimated_wait_seconds,
+    estimated_fulfilled_at: current_time.saturating_add(estimated_wait_seconds),
+  })
+}
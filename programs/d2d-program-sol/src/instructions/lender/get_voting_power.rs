@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::VotingPowerVerified, states::StakeSnapshot};
+
+/// Read-only proof check of a staker's weight in an epoch snapshot
+///
+/// Lets partner governance programs use D2D stake as voting power: they call
+/// this via CPI (or simulate it) and read the verified weight from return
+/// data. Fails if the proof does not match the snapshot's merkle root.
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct GetVotingPower<'info> {
+  #[account(
+        seeds = [StakeSnapshot::PREFIX_SEED, epoch.to_le_bytes().as_ref()],
+        bump = stake_snapshot.bump
+    )]
+  pub stake_snapshot: Account<'info, StakeSnapshot>,
+}
+
+/// Verified voting power returned by `get_voting_power`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VotingPower {
+  pub epoch: u64,
+  pub staker: Pubkey,
+  pub weight: u64,
+  /// Snapshot total, for computing the staker's share
+  pub total_deposited: u64,
+}
+
+pub fn get_voting_power(
+  ctx: Context<GetVotingPower>,
+  epoch: u64,
+  staker: Pubkey,
+  weight: u64,
+  proof: Vec<[u8; 32]>,
+) -> Result<VotingPower> {
+  let stake_snapshot = &ctx.accounts.stake_snapshot;
+
+  let leaf = StakeSnapshot::voting_power_leaf(&staker, weight);
+  require!(
+    stake_snapshot.verify_leaf(leaf, &proof),
+    ErrorCode::InvalidVotingPowerProof
+  );
+
+  emit!(VotingPowerVerified {
+    epoch,
+    staker,
+    weight,
+    total_deposited: stake_snapshot.total_deposited,
+  });
+
+  Ok(VotingPower {
+    epoch,
+    staker,
+    weight,
+    total_deposited: stake_snapshot.total_deposited,
+  })
+}
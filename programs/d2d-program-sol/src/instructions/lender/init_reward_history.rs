@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::states::RewardHistory;
+
+/// Create the staker's reward history; claims that pass it record a checkpoint
+#[derive(Accounts)]
+pub struct InitRewardHistory<'info> {
+  #[account(
+        init,
+        payer = lender,
+        space = 8 + RewardHistory::INIT_SPACE,
+        seeds = [RewardHistory::PREFIX_SEED, lender.key().as_ref()],
+        bump
+    )]
+  pub reward_history: Account<'info, RewardHistory>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn init_reward_history(ctx: Context<InitRewardHistory>) -> Result<()> {
+  let reward_history = &mut ctx.accounts.reward_history;
+  reward_history.backer = ctx.accounts.lender.key();
+  reward_history.bump = ctx.bumps.reward_history;
+  Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::StakePositionsMerged,
+  states::{BackerDeposit, TreasuryPool},
+};
+
+/// Consolidate two of the staker's positions into one
+///
+/// Both positions are settled first; principal, pending rewards and duration
+/// weight move to `target` and the emptied `source` PDA is closed with its
+/// rent refunded. `source` must be an indexed position (the primary position
+/// holds the index counter and any insurance coverage) with nothing queued
+/// or pledged.
+#[derive(Accounts)]
+pub struct MergeStakePositions<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        constraint = target.backer == lender.key() @ ErrorCode::Unauthorized
+    )]
+  pub target: Account<'info, BackerDeposit>,
+
+  #[account(
+        mut,
+        close = lender,
+        constraint = source.backer == lender.key() @ ErrorCode::Unauthorized,
+        constraint = source.key() != target.key() @ ErrorCode::InvalidPositionMerge,
+        constraint = source.position_index != 0 @ ErrorCode::InvalidPositionMerge,
+        constraint = source.queued_withdrawal == 0 @ ErrorCode::InvalidPositionMerge,
+        constraint = !source.is_pledged() @ ErrorCode::StakePledged,
+        constraint = !source.has_unstake_commitment() @ ErrorCode::UnstakeCommitmentPending
+    )]
+  pub source: Account<'info, BackerDeposit>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+}
+
+pub fn merge_stake_positions(ctx: Context<MergeStakePositions>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let target = &mut ctx.accounts.target;
+  let source = &mut ctx.accounts.source;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+
+  for stake in [&mut **target, &mut **source] {
+    stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
+    stake.settle_duration_weight(treasury_pool, current_time)?;
+  }
+
+  target.merge_from(source, treasury_pool.reward_per_share)?;
+  // The staker's total is unchanged, but positions split before the primary
+  // tracked indexed_deposits are only counted once merged back into it
+  if target.position_index == 0 {
+    treasury_pool.check_pool_share(target.staker_total_deposit()?, 0)?;
+  }
+
+  emit!(StakePositionsMerged {
+    staker: target.backer,
+    target: target.key(),
+    source: source.key(),
+    target_index: target.position_index,
+    source_index: source.position_index,
+    amount: source.deposited_amount,
+    total_deposit: target.deposited_amount,
+    merged_at: current_time,
+  });
+
+  Ok(())
+}
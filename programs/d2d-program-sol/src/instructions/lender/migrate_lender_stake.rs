@@ -0,0 +1,102 @@
+use anchor_lang::{prelude::*, solana_program::rent::Rent, system_program, Discriminator};
+
+use crate::{errors::ErrorCode, events::LenderStakeMigrated, states::BackerDeposit};
+
+/// One-time migration of a staker position from the legacy `LenderStake`
+/// account name to the canonical `BackerDeposit` name.
+///
+/// The PDA address is unchanged (same "lender_stake" seed); only the account
+/// discriminator is rewritten and the account is grown to the current layout.
+/// Permissionless - anyone may pay to migrate any position, which is safe
+/// because no balances or ownership fields are touched.
+#[derive(Accounts)]
+pub struct MigrateLenderStake<'info> {
+  /// CHECK: Staker position PDA - may still carry the legacy discriminator,
+  /// so it is deserialized manually after migration
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, staker.key().as_ref()],
+        bump
+    )]
+  pub lender_stake: UncheckedAccount<'info>,
+
+  /// CHECK: Staker who owns the position (does not need to sign)
+  pub staker: UncheckedAccount<'info>,
+
+  /// Pays for any additional rent required by the larger layout
+  #[account(mut)]
+  pub payer: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_lender_stake(ctx: Context<MigrateLenderStake>) -> Result<()> {
+  let lender_stake_info = ctx.accounts.lender_stake.to_account_info();
+
+  require!(
+    lender_stake_info.owner == ctx.program_id,
+    ErrorCode::InvalidAccountOwner
+  );
+
+  let required_space = 8 + BackerDeposit::INIT_SPACE;
+  let current_space = lender_stake_info.data_len();
+  require!(current_space >= 8, ErrorCode::InvalidAccountData);
+
+  let is_legacy = BackerDeposit::has_legacy_discriminator(&lender_stake_info.data.borrow());
+  let is_canonical = lender_stake_info.data.borrow()[..8] == *BackerDeposit::DISCRIMINATOR;
+  require!(is_legacy || is_canonical, ErrorCode::InvalidAccountData);
+
+  // Already migrated - nothing to do
+  if is_canonical && current_space >= required_space {
+    return Ok(());
+  }
+
+  // Grow the account to the current layout (new fields default to zero)
+  if current_space < required_space {
+    let rent = Rent::get()?;
+    let additional_lamports_needed = rent
+      .minimum_balance(required_space)
+      .saturating_sub(lender_stake_info.lamports());
+
+    if additional_lamports_needed > 0 {
+      let transfer_cpi = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+          from: ctx.accounts.payer.to_account_info(),
+          to: lender_stake_info.clone(),
+        },
+      );
+      system_program::transfer(transfer_cpi, additional_lamports_needed)?;
+    }
+
+    lender_stake_info.resize(required_space)?;
+
+    let mut data = lender_stake_info.try_borrow_mut_data()?;
+    data[current_space..].fill(0);
+  }
+
+  // Rewrite the discriminator to the canonical account name
+  {
+    let mut data = lender_stake_info.try_borrow_mut_data()?;
+    data[..8].copy_from_slice(BackerDeposit::DISCRIMINATOR);
+  }
+
+  // Verify the migrated account deserializes and belongs to the staker
+  let lender_stake = BackerDeposit::try_deserialize(&mut &lender_stake_info.data.borrow()[..])
+    .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
+  require!(
+    lender_stake.backer == ctx.accounts.staker.key(),
+    ErrorCode::Unauthorized
+  );
+
+  emit!(LenderStakeMigrated {
+    staker: lender_stake.backer,
+    lender_stake: lender_stake_info.key(),
+    from_legacy_name: is_legacy,
+    old_size: current_space as u32,
+    new_size: lender_stake_info.data_len() as u32,
+    migrated_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
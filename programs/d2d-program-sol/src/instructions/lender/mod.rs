@@ -1,13 +1,73 @@
 pub mod cancel_queued_withdrawal;
+pub mod cancel_unstake_commitment;
+pub mod claim_all_rewards;
+pub mod claim_campaign_boost;
+pub mod claim_insurance;
 pub mod claim_rewards;
+pub mod claim_vested_bonus;
+pub mod commit_unstake;
+pub mod compound_rewards;
+pub mod delegated_claim_rewards;
 pub mod emergency_unstake;
+pub mod enroll_in_campaign;
+pub mod expire_queue_entry;
+pub mod get_claimable_rewards;
+pub mod get_estimated_wait_time;
+pub mod get_voting_power;
+pub mod init_reward_history;
+pub mod merge_stake_positions;
+pub mod migrate_lender_stake;
+pub mod opt_in_insurance;
+pub mod pledge_stake;
 pub mod queue_withdrawal;
+pub mod register_watchtower;
+pub mod release_stake_pledge;
+pub mod revoke_nft_boost;
+pub mod set_auto_compound;
+pub mod set_auto_unstake;
+pub mod set_claim_delegate;
+pub mod set_payout_address;
+pub mod split_stake_position;
+pub mod stake_from_lst;
 pub mod stake_sol;
+pub mod submit_watchtower_alert;
+pub mod trigger_auto_unstake;
+pub mod unstake_or_queue;
 pub mod unstake_sol;
 
 pub use cancel_queued_withdrawal::*;
+pub use cancel_unstake_commitment::*;
+pub use claim_all_rewards::*;
+pub use claim_campaign_boost::*;
+pub use claim_insurance::*;
 pub use claim_rewards::*;
+pub use claim_vested_bonus::*;
+pub use commit_unstake::*;
+pub use compound_rewards::*;
+pub use delegated_claim_rewards::*;
 pub use emergency_unstake::*;
+pub use enroll_in_campaign::*;
+pub use expire_queue_entry::*;
+pub use get_claimable_rewards::*;
+pub use get_estimated_wait_time::*;
+pub use get_voting_power::*;
+pub use init_reward_history::*;
+pub use merge_stake_positions::*;
+pub use migrate_lender_stake::*;
+pub use opt_in_insurance::*;
+pub use pledge_stake::*;
 pub use queue_withdrawal::*;
+pub use register_watchtower::*;
+pub use release_stake_pledge::*;
+pub use revoke_nft_boost::*;
+pub use set_auto_compound::*;
+pub use set_auto_unstake::*;
+pub use set_claim_delegate::*;
+pub use set_payout_address::*;
+pub use split_stake_position::*;
+pub use stake_from_lst::*;
 pub use stake_sol::*;
+pub use submit_watchtower_alert::*;
+pub use trigger_auto_unstake::*;
+pub use unstake_or_queue::*;
 pub use unstake_sol::*;
@@ -0,0 +1,135 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::{InsuranceCoveragePurchased, InsuranceCoverageReduced},
+  states::{BackerDeposit, InsuranceFund, TreasuryPool},
+};
+
+/// Buy insurance coverage for (part of) the staker's deposit
+///
+/// A one-time premium of `premium_bps` on the coverage is paid into the
+/// insurance fund. Coverage cannot exceed the effective deposit; if the
+/// deposit later shrinks, coverage shrinks with it (see
+/// sync_insurance_coverage) and the premium is not refunded.
+#[derive(Accounts)]
+pub struct OptInInsurance<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump = insurance_fund.bump
+    )]
+  pub insurance_fund: Account<'info, InsuranceFund>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn opt_in_insurance(ctx: Context<OptInInsurance>, coverage_amount: u64) -> Result<()> {
+  let insurance_fund = &mut ctx.accounts.insurance_fund;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+  require!(coverage_amount > 0, ErrorCode::InvalidAmount);
+
+  let new_insured_amount = lender_stake
+    .insured_amount
+    .checked_add(coverage_amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  require!(
+    new_insured_amount <= lender_stake.get_effective_deposit(),
+    ErrorCode::CoverageExceedsStake
+  );
+
+  // Settle against the old coverage before it changes
+  lender_stake.settle_insurance_compensation(insurance_fund.compensation_per_coverage)?;
+
+  let premium = insurance_fund.calculate_premium(coverage_amount)?;
+  if premium > 0 {
+    system_program::transfer(
+      CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+          from: ctx.accounts.lender.to_account_info(),
+          to: insurance_fund.to_account_info(),
+        },
+      ),
+      premium,
+    )?;
+  }
+
+  insurance_fund.available_balance = insurance_fund
+    .available_balance
+    .checked_add(premium)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  insurance_fund.total_premiums = insurance_fund
+    .total_premiums
+    .checked_add(premium)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  insurance_fund.total_covered = insurance_fund
+    .total_covered
+    .checked_add(coverage_amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  lender_stake.insured_amount = new_insured_amount;
+  lender_stake.compensation_debt = (new_insured_amount as u128)
+    .checked_mul(insurance_fund.compensation_per_coverage)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  emit!(InsuranceCoveragePurchased {
+    staker: lender_stake.backer,
+    coverage_amount,
+    premium,
+    insured_amount: new_insured_amount,
+    purchased_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+/// Cut a position's coverage down to its effective deposit after the
+/// deposit shrank
+///
+/// Shared by every instruction that lowers the effective deposit. Loss
+/// events recorded so far are settled on the old coverage first, so
+/// compensation always reflects the coverage staked when the loss was
+/// recorded. The insurance fund account is only needed when coverage
+/// actually shrinks.
+pub(crate) fn sync_insurance_coverage(
+  lender_stake: &mut BackerDeposit,
+  insurance_fund: Option<&mut Account<InsuranceFund>>,
+) -> Result<()> {
+  if !lender_stake.is_over_insured() {
+    return Ok(());
+  }
+  let insurance_fund = insurance_fund.ok_or(ErrorCode::InsuranceFundRequired)?;
+  let released_coverage = insurance_fund.shrink_coverage(lender_stake)?;
+
+  emit!(InsuranceCoverageReduced {
+    staker: lender_stake.backer,
+    released_coverage,
+    insured_amount: lender_stake.insured_amount,
+    total_covered: insurance_fund.total_covered,
+    reduced_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
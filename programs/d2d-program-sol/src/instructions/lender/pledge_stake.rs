@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::StakePledged,
+  states::{BackerDeposit, CollateralPartner, TreasuryPool},
+};
+
+/// Pledge a stake position as collateral to a registered partner program
+///
+/// Called by the partner program via CPI, co-signed by the staker. While
+/// pledged the position cannot be unstaked, queued, split or merged away;
+/// rewards keep accruing and can still be claimed.
+#[derive(Accounts)]
+pub struct PledgeStake<'info> {
+  #[account(
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [CollateralPartner::PREFIX_SEED, collateral_partner.program_id.as_ref()],
+        bump = collateral_partner.bump,
+        constraint = collateral_partner.enabled @ ErrorCode::CollateralPartnerDisabled
+    )]
+  pub collateral_partner: Account<'info, CollateralPartner>,
+
+  #[account(
+        mut,
+        constraint = lender_stake.backer == lender.key() @ ErrorCode::Unauthorized
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  pub lender: Signer<'info>,
+
+  /// Partner program's CPI signer
+  #[account(
+        constraint = partner_authority.key() == collateral_partner.authority @ ErrorCode::Unauthorized
+    )]
+  pub partner_authority: Signer<'info>,
+}
+
+pub fn pledge_stake(ctx: Context<PledgeStake>) -> Result<()> {
+  let collateral_partner = &mut ctx.accounts.collateral_partner;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    !ctx.accounts.treasury_pool.emergency_pause,
+    ErrorCode::ProgramPaused
+  );
+  require!(!lender_stake.is_pledged(), ErrorCode::StakePledged);
+  require!(
+    !lender_stake.has_unstake_commitment(),
+    ErrorCode::UnstakeCommitmentPending
+  );
+  require!(
+    !lender_stake.has_queued_withdrawal(),
+    ErrorCode::WithdrawalAlreadyQueued
+  );
+  require!(
+    lender_stake.deposited_amount > 0,
+    ErrorCode::InsufficientStake
+  );
+
+  lender_stake.pledged_to = collateral_partner.program_id;
+  lender_stake.pledged_at = current_time;
+  collateral_partner.active_pledges = collateral_partner
+    .active_pledges
+    .checked_add(1)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  emit!(StakePledged {
+    staker: lender_stake.backer,
+    position: lender_stake.key(),
+    partner_program: collateral_partner.program_id,
+    deposited_amount: lender_stake.deposited_amount,
+    pledged_at: current_time,
+  });
+
+  Ok(())
+}
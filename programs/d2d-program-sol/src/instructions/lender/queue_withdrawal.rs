@@ -3,7 +3,11 @@ use anchor_lang::prelude::*;
 use crate::{
   errors::ErrorCode,
   events::StakerWithdrawalQueued,
-  states::{BackerDeposit, TreasuryPool, WithdrawalQueueEntry},
+  instructions::{checkpoint_campaign_boost, sync_insurance_coverage},
+  states::{
+    BackerDeposit, BoostCampaign, CampaignEnrollment, InsuranceFund, TreasuryPool,
+    WithdrawalQueueEntry,
+  },
 };
 
 /// Queue a withdrawal request when liquid_balance is insufficient
@@ -39,6 +43,22 @@ pub struct QueueWithdrawal<'info> {
   #[account(mut)]
   pub staker: Signer<'info>,
 
+  /// Insurance fund (required when the position's coverage must shrink)
+  #[account(
+        mut,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump = insurance_fund.bump
+    )]
+  pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+  /// Running boost campaign the position is enrolled in (required until it ends)
+  #[account(mut)]
+  pub boost_campaign: Option<Account<'info, BoostCampaign>>,
+
+  /// The position's enrollment in boost_campaign
+  #[account(mut)]
+  pub campaign_enrollment: Option<Account<'info, CampaignEnrollment>>,
+
   pub system_program: Program<'info, System>,
 }
 
@@ -50,6 +70,11 @@ pub fn queue_withdrawal(ctx: Context<QueueWithdrawal>, amount: u64) -> Result<()
 
   require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
   require!(amount > 0, ErrorCode::InvalidAmount);
+  require!(!lender_stake.is_pledged(), ErrorCode::StakePledged);
+  require!(
+    !lender_stake.has_unstake_commitment(),
+    ErrorCode::UnstakeCommitmentPending
+  );
   require!(
     amount <= lender_stake.deposited_amount,
     ErrorCode::InsufficientStake
@@ -61,27 +86,68 @@ pub fn queue_withdrawal(ctx: Context<QueueWithdrawal>, amount: u64) -> Result<()
     ErrorCode::WithdrawalAlreadyQueued
   );
 
+  enqueue_withdrawal(
+    treasury_pool,
+    lender_stake,
+    queue_entry,
+    ctx.bumps.queue_entry,
+    amount,
+    current_time,
+  )?;
+
+  // Coverage and campaign boost follow the stake that remains
+  sync_insurance_coverage(lender_stake, ctx.accounts.insurance_fund.as_mut())?;
+  checkpoint_campaign_boost(
+    lender_stake,
+    ctx.accounts.boost_campaign.as_mut(),
+    ctx.accounts.campaign_enrollment.as_mut(),
+    treasury_pool.reward_per_share,
+    current_time,
+  )?;
+
+  Ok(())
+}
+
+/// Append `amount` of the staker's position to the tail of the withdrawal queue
+/// `queue_entry` must be the freshly created PDA at withdrawal_queue_tail
+pub(crate) fn enqueue_withdrawal(
+  treasury_pool: &mut TreasuryPool,
+  lender_stake: &mut BackerDeposit,
+  queue_entry: &mut WithdrawalQueueEntry,
+  queue_entry_bump: u8,
+  amount: u64,
+  current_time: i64,
+) -> Result<()> {
   // Get the queue position
   let position = treasury_pool.withdrawal_queue_tail;
 
   // Initialize queue entry
   queue_entry.position = position;
-  queue_entry.staker = ctx.accounts.staker.key();
+  queue_entry.staker = lender_stake.backer;
   queue_entry.amount = amount;
   queue_entry.queued_at = current_time;
   queue_entry.processed = false;
   queue_entry.amount_withdrawn = 0;
   queue_entry.processed_at = 0;
-  queue_entry.bump = ctx.bumps.queue_entry;
+  queue_entry.bump = queue_entry_bump;
+
+  // Settle rewards earned so far - the queued amount stops accruing once queued
+  lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
 
   // Update lender stake
-  lender_stake.queue_withdrawal(amount, position, current_time)?;
+  lender_stake.queue_withdrawal(
+    amount,
+    position,
+    current_time,
+    treasury_pool.queue_fee_per_share,
+  )?;
+  lender_stake.update_reward_debt(treasury_pool.reward_per_share)?;
 
   // Update treasury pool queue tracking
   treasury_pool.add_to_withdrawal_queue(amount)?;
 
   emit!(StakerWithdrawalQueued {
-    staker: ctx.accounts.staker.key(),
+    staker: lender_stake.backer,
     amount,
     queue_position: position,
     queued_withdrawal_total: treasury_pool.queued_withdrawal_amount,
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::WatchtowerRegistered, states::Watchtower};
+
+/// Permissionless: register as a watchtower to submit alerts for bounties
+#[derive(Accounts)]
+pub struct RegisterWatchtower<'info> {
+  #[account(
+        init,
+        payer = operator,
+        space = 8 + Watchtower::INIT_SPACE,
+        seeds = [Watchtower::PREFIX_SEED, operator.key().as_ref()],
+        bump
+    )]
+  pub watchtower: Account<'info, Watchtower>,
+
+  #[account(mut)]
+  pub operator: Signer<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn register_watchtower(ctx: Context<RegisterWatchtower>) -> Result<()> {
+  let watchtower = &mut ctx.accounts.watchtower;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  watchtower.operator = ctx.accounts.operator.key();
+  watchtower.registered_at = current_time;
+  watchtower.bump = ctx.bumps.watchtower;
+
+  emit!(WatchtowerRegistered {
+    operator: watchtower.operator,
+    registered_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::StakePledgeReleased,
+  states::{BackerDeposit, CollateralPartner},
+};
+
+/// Release a pledged stake position
+///
+/// Only the partner program holding the pledge can release it (via CPI), e.g.
+/// once the loan against it is repaid. Works for disabled partners too.
+#[derive(Accounts)]
+pub struct ReleaseStakePledge<'info> {
+  #[account(
+        mut,
+        seeds = [CollateralPartner::PREFIX_SEED, collateral_partner.program_id.as_ref()],
+        bump = collateral_partner.bump
+    )]
+  pub collateral_partner: Account<'info, CollateralPartner>,
+
+  #[account(
+        mut,
+        constraint = lender_stake.pledged_to == collateral_partner.program_id @ ErrorCode::StakeNotPledged
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  /// Partner program's CPI signer
+  #[account(
+        constraint = partner_authority.key() == collateral_partner.authority @ ErrorCode::Unauthorized
+    )]
+  pub partner_authority: Signer<'info>,
+}
+
+pub fn release_stake_pledge(ctx: Context<ReleaseStakePledge>) -> Result<()> {
+  let collateral_partner = &mut ctx.accounts.collateral_partner;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  lender_stake.pledged_to = Pubkey::default();
+  lender_stake.pledged_at = 0;
+  collateral_partner.active_pledges = collateral_partner.active_pledges.saturating_sub(1);
+
+  emit!(StakePledgeReleased {
+    staker: lender_stake.backer,
+    position: lender_stake.key(),
+    partner_program: collateral_partner.program_id,
+    released_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{
+  errors::ErrorCode,
+  events::BoostNftRevoked,
+  states::{BackerDeposit, BoostNftUsage, TreasuryPool},
+};
+
+/// Drop a position's NFT boost and free the NFT for another position
+///
+/// The staker may always give the boost up. Anyone else must pass
+/// `holder_token`, the token account now holding the NFT, owned by someone
+/// other than the staker. A position that was closed meanwhile just frees
+/// the NFT.
+#[derive(Accounts)]
+pub struct RevokeNftBoost<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [BoostNftUsage::PREFIX_SEED, boost_nft_usage.mint.as_ref()],
+        bump = boost_nft_usage.bump,
+        constraint = boost_nft_usage.position != Pubkey::default() @ ErrorCode::InvalidBoostNft
+    )]
+  pub boost_nft_usage: Account<'info, BoostNftUsage>,
+
+  /// CHECK: Position the NFT boosts; may have been closed since
+  #[account(mut, address = boost_nft_usage.position @ ErrorCode::InvalidBoostNft)]
+  pub lender_stake: UncheckedAccount<'info>,
+
+  /// Token account currently holding the NFT (required unless the caller is
+  /// the staker)
+  pub holder_token: Option<Account<'info, TokenAccount>>,
+
+  pub caller: Signer<'info>,
+}
+
+pub fn revoke_nft_boost(ctx: Context<RevokeNftBoost>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let boost_nft_usage = &mut ctx.accounts.boost_nft_usage;
+  let caller = ctx.accounts.caller.key();
+  let current_time = Clock::get()?.unix_timestamp;
+
+  let lender_stake_info = ctx.accounts.lender_stake.to_account_info();
+  let mut staker = Pubkey::default();
+  if lender_stake_info.owner == ctx.program_id && !lender_stake_info.data_is_empty() {
+    let mut lender_stake =
+      BackerDeposit::try_deserialize(&mut &lender_stake_info.data.borrow()[..])?;
+    staker = lender_stake.backer;
+
+    if caller != staker {
+      let holder_token = ctx
+        .accounts
+        .holder_token
+        .as_ref()
+        .ok_or(ErrorCode::BoostNftStillHeld)?;
+      require!(
+        holder_token.mint == boost_nft_usage.mint
+          && holder_token.amount == 1
+          && holder_token.owner != staker,
+        ErrorCode::BoostNftStillHeld
+      );
+    }
+
+    if lender_stake.boost_nft_mint == boost_nft_usage.mint {
+      // Weight up to now keeps the boost; none from here on
+      lender_stake.settle_duration_weight(treasury_pool, current_time)?;
+      lender_stake.apply_nft_boost(Pubkey::default(), 0);
+      lender_stake.try_serialize(&mut &mut lender_stake_info.try_borrow_mut_data()?[..])?;
+    }
+  }
+
+  boost_nft_usage.position = Pubkey::default();
+
+  emit!(BoostNftRevoked {
+    staker,
+    mint: boost_nft_usage.mint,
+    revoked_by: caller,
+    revoked_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::AutoCompoundToggled, states::BackerDeposit};
+
+/// Opt in (or out) of having settled rewards restaked into principal by the
+/// permissionless compound_rewards crank
+#[derive(Accounts)]
+pub struct SetAutoCompound<'info> {
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  pub lender: Signer<'info>,
+}
+
+pub fn set_auto_compound(ctx: Context<SetAutoCompound>, enabled: bool) -> Result<()> {
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  lender_stake.auto_compound_enabled = enabled;
+
+  emit!(AutoCompoundToggled {
+    staker: ctx.accounts.lender.key(),
+    enabled,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::AutoUnstakeScheduled, states::BackerDeposit};
+
+/// Schedule (or clear with 0) the time after which the position may be queued
+/// for withdrawal by the permissionless trigger_auto_unstake crank
+#[derive(Accounts)]
+pub struct SetAutoUnstake<'info> {
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  pub lender: Signer<'info>,
+}
+
+pub fn set_auto_unstake(ctx: Context<SetAutoUnstake>, auto_unstake_at: i64) -> Result<()> {
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(
+    auto_unstake_at == 0 || auto_unstake_at > current_time,
+    ErrorCode::InvalidAutoUnstakeTime
+  );
+
+  lender_stake.auto_unstake_at = auto_unstake_at;
+
+  emit!(AutoUnstakeScheduled {
+    staker: ctx.accounts.lender.key(),
+    auto_unstake_at,
+    scheduled_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::ClaimDelegateChanged, states::BackerDeposit};
+
+/// Register (or clear with `None`) a key allowed to claim rewards on the
+/// staker's behalf, e.g. an automation bot or custodian
+#[derive(Accounts)]
+pub struct SetClaimDelegate<'info> {
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  pub lender: Signer<'info>,
+}
+
+pub fn set_claim_delegate(ctx: Context<SetClaimDelegate>, delegate: Option<Pubkey>) -> Result<()> {
+  let lender_stake = &mut ctx.accounts.lender_stake;
+
+  let old_delegate = lender_stake
+    .has_claim_delegate()
+    .then_some(lender_stake.claim_delegate);
+  lender_stake.claim_delegate = delegate.unwrap_or_default();
+
+  emit!(ClaimDelegateChanged {
+    staker: ctx.accounts.lender.key(),
+    old_delegate,
+    new_delegate: delegate,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::PayoutAddressChanged, states::BackerDeposit};
+
+/// Register (or clear with `None`) a wallet that claim_rewards may pay to
+/// instead of the signing wallet, e.g. a cold wallet or treasury
+#[derive(Accounts)]
+pub struct SetPayoutAddress<'info> {
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  pub lender: Signer<'info>,
+}
+
+pub fn set_payout_address(
+  ctx: Context<SetPayoutAddress>,
+  payout_address: Option<Pubkey>,
+) -> Result<()> {
+  let lender_stake = &mut ctx.accounts.lender_stake;
+
+  let old_payout_address =
+    (lender_stake.payout_address != Pubkey::default()).then_some(lender_stake.payout_address);
+  lender_stake.payout_address = payout_address.unwrap_or_default();
+
+  emit!(PayoutAddressChanged {
+    staker: ctx.accounts.lender.key(),
+    old_payout_address,
+    new_payout_address: payout_address,
+    changed_at: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
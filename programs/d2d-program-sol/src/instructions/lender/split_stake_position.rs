@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::StakePositionSplit,
+  instructions::checkpoint_campaign_boost,
+  states::{BackerDeposit, BoostCampaign, CampaignEnrollment, TreasuryPool},
+};
+
+/// Carve part of the staker's position into a new indexed position
+///
+/// Rewards are settled first, so everything accrued so far stays with the
+/// primary position. The new position starts with its own empty withdrawal
+/// queue state and keeps the original first_deposit_at; its duration weight
+/// starts accruing now.
+#[derive(Accounts)]
+pub struct SplitStakePosition<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  #[account(
+        init,
+        payer = lender,
+        space = 8 + BackerDeposit::INIT_SPACE,
+        seeds = [
+          BackerDeposit::PREFIX_SEED,
+          lender.key().as_ref(),
+          &(lender_stake.positions_created + 1).to_le_bytes()
+        ],
+        bump
+    )]
+  pub new_position: Account<'info, BackerDeposit>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+
+  /// Running boost campaign the position is enrolled in (required until it ends)
+  #[account(mut)]
+  pub boost_campaign: Option<Account<'info, BoostCampaign>>,
+
+  /// The position's enrollment in boost_campaign
+  #[account(mut)]
+  pub campaign_enrollment: Option<Account<'info, CampaignEnrollment>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn split_stake_position(ctx: Context<SplitStakePosition>, amount: u64) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let new_position = &mut ctx.accounts.new_position;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(!lender_stake.is_pledged(), ErrorCode::StakePledged);
+  require!(
+    !lender_stake.has_unstake_commitment(),
+    ErrorCode::UnstakeCommitmentPending
+  );
+
+  lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
+  lender_stake.settle_duration_weight(treasury_pool, current_time)?;
+
+  lender_stake.split_into(
+    new_position,
+    amount,
+    treasury_pool.reward_per_share,
+    current_time,
+  )?;
+  // The boost follows the stake that remains on this position
+  checkpoint_campaign_boost(
+    lender_stake,
+    ctx.accounts.boost_campaign.as_mut(),
+    ctx.accounts.campaign_enrollment.as_mut(),
+    treasury_pool.reward_per_share,
+    current_time,
+  )?;
+
+  let position_index = lender_stake.positions_created + 1;
+  lender_stake.positions_created = position_index;
+  new_position.position_index = position_index;
+  new_position.bump = ctx.bumps.new_position;
+
+  emit!(StakePositionSplit {
+    staker: lender_stake.backer,
+    position: new_position.key(),
+    position_index,
+    amount,
+    remaining_deposit: lender_stake.deposited_amount,
+    split_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+  errors::ErrorCode,
+  events::{RewardsMovedToPending, StakedFromLst},
+  states::{BackerDeposit, LstConfig, TreasuryPool},
+};
+
+/// Stake with a liquid staking token instead of SOL
+///
+/// The tokens move into the LST vault and the deposit is credited at the
+/// config's oracle rate. The credited value is tracked as lst_backed_deposits
+/// until the vault is redeemed for SOL, so it never counts as liquid balance.
+#[derive(Accounts)]
+pub struct StakeFromLst<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [LstConfig::PREFIX_SEED, lst_config.mint.as_ref()],
+        bump = lst_config.bump,
+        constraint = lst_config.enabled @ ErrorCode::LstNotAccepted
+    )]
+  pub lst_config: Account<'info, LstConfig>,
+
+  #[account(
+        mut,
+        address = lst_config.vault @ ErrorCode::InvalidAccountData
+    )]
+  pub lst_vault: Account<'info, TokenAccount>,
+
+  #[account(
+        mut,
+        token::mint = lst_config.mint,
+        token::authority = lender
+    )]
+  pub lender_token_account: Account<'info, TokenAccount>,
+
+  #[account(
+        init_if_needed,
+        payer = lender,
+        space = 8 + BackerDeposit::INIT_SPACE,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn stake_from_lst(ctx: Context<StakeFromLst>, token_amount: u64) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lst_config = &mut ctx.accounts.lst_config;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(token_amount > 0, ErrorCode::InvalidAmount);
+  require!(
+    lst_config.is_rate_fresh(current_time),
+    ErrorCode::StaleLstRate
+  );
+
+  let deposit_amount = lst_config.calculate_sol_value(token_amount)?;
+  require!(deposit_amount > 0, ErrorCode::InvalidAmount);
+  treasury_pool.check_stake_amount(deposit_amount)?;
+  treasury_pool.check_pool_share(
+    lender_stake
+      .staker_total_deposit()?
+      .checked_add(deposit_amount)
+      .ok_or(ErrorCode::CalculationOverflow)?,
+    deposit_amount,
+  )?;
+
+  if lender_stake.backer == Pubkey::default() {
+    lender_stake.backer = ctx.accounts.lender.key();
+    lender_stake.is_active = true;
+    lender_stake.bump = ctx.bumps.lender_stake;
+    lender_stake.initialize_timestamps(current_time);
+  } else {
+    lender_stake.is_active = true;
+    lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
+
+    lender_stake.settle_duration_weight(treasury_pool, current_time)?;
+  }
+
+  lender_stake.check_and_update_daily_stake(
+    deposit_amount,
+    treasury_pool.daily_stake_limit,
+    current_time,
+  )?;
+
+  // Same first depositor protection as stake_sol
+  if treasury_pool.total_deposited == 0 && treasury_pool.reward_pool_balance > 0 {
+    let excess_rewards = treasury_pool.reward_pool_balance;
+    treasury_pool.move_to_pending_rewards(excess_rewards)?;
+
+    emit!(RewardsMovedToPending {
+      amount: excess_rewards,
+      reason: "First depositor protection - rewards moved to pending".to_string(),
+      moved_at: current_time,
+    });
+  }
+
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.lender_token_account.to_account_info(),
+        to: ctx.accounts.lst_vault.to_account_info(),
+        authority: ctx.accounts.lender.to_account_info(),
+      },
+    ),
+    token_amount,
+  )?;
+
+  lender_stake.deposited_amount = lender_stake
+    .deposited_amount
+    .checked_add(deposit_amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  lender_stake.update_reward_debt(treasury_pool.reward_per_share)?;
+
+  treasury_pool.total_deposited = treasury_pool
+    .total_deposited
+    .checked_add(deposit_amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  treasury_pool.lst_backed_deposits = treasury_pool
+    .lst_backed_deposits
+    .checked_add(deposit_amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  lst_config.vault_balance = lst_config
+    .vault_balance
+    .checked_add(token_amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  let (previous_value, current_value) = lst_config.revalue_vault()?;
+  treasury_pool.update_normalized_token_value(previous_value, current_value)?;
+  lst_config.total_tokens_received = lst_config
+    .total_tokens_received
+    .checked_add(token_amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  lst_config.total_sol_credited = lst_config
+    .total_sol_credited
+    .checked_add(deposit_amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  emit!(StakedFromLst {
+    lender: lender_stake.backer,
+    mint: lst_config.mint,
+    token_amount,
+    rate: lst_config.rate,
+    sol_value: deposit_amount,
+    total_staked: lender_stake.deposited_amount,
+    staked_at: current_time,
+  });
+
+  Ok(())
+}
@@ -1,20 +1,44 @@
 use anchor_lang::{prelude::*, solana_program::rent::Rent, system_program};
+use anchor_spl::token::TokenAccount;
 
 use crate::{
   errors::ErrorCode,
-  events::{RewardsMovedToPending, SolStaked},
-  states::{BackerDeposit, TreasuryPool},
+  events::{BoostNftApplied, RewardsMovedToPending, SolStaked, SolStakedV2},
+  instructions::auto_fulfill_withdrawal_queue,
+  states::{BackerDeposit, FeeSource, TreasuryPool},
+  utils::{claim_boost_nft_usage, verify_boost_nft},
 };
 
+/// Remaining accounts (optional): `[queue_entry, lender_stake, staker]` triples
+/// from the withdrawal queue head, fulfilled with the new liquidity
+///
+/// Optional `boost_nft_token` + `boost_nft_metadata` + `boost_nft_usage`: an
+/// NFT of the configured boost collection held by the staker; records the
+/// pool's nft_boost_bps on the position and binds the NFT to it, so the same
+/// NFT cannot boost a second position until revoke_nft_boost frees it
 #[derive(Accounts)]
 pub struct StakeSol<'info> {
   /// CHECK: Treasury Pool - will be migrated if needed
   #[account(mut)]
   pub treasury_pool: UncheckedAccount<'info>,
 
-  /// CHECK: Treasury Pool PDA
+  /// CHECK: Treasury vault PDA (receives the deposit)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  /// CHECK: Reward Pool PDA (receives the reward share of the deposit fee) -
+  /// checked against the stored reward_pool_bump in the handler
   #[account(mut)]
-  pub treasury_pda: UncheckedAccount<'info>,
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform Pool PDA (receives the platform share of the deposit
+  /// fee) - checked against the stored platform_pool_bump in the handler
+  #[account(mut)]
+  pub platform_pool: UncheckedAccount<'info>,
 
   #[account(
         init_if_needed,
@@ -29,19 +53,31 @@ pub struct StakeSol<'info> {
   pub lender: Signer<'info>,
 
   pub system_program: Program<'info, System>,
+
+  /// Optional - staker's token account holding a boost NFT
+  pub boost_nft_token: Option<Account<'info, TokenAccount>>,
+
+  /// CHECK: Optional - Metaplex metadata PDA of the boost NFT, validated in
+  /// verify_boost_nft
+  pub boost_nft_metadata: Option<UncheckedAccount<'info>>,
+
+  /// CHECK: Optional - BoostNftUsage PDA of the boost NFT's mint, validated
+  /// and created if needed in claim_boost_nft_usage
+  #[account(mut)]
+  pub boost_nft_usage: Option<UncheckedAccount<'info>>,
 }
 
-pub fn stake_sol(ctx: Context<StakeSol>, deposit_amount: u64, _lock_period: i64) -> Result<()> {
+pub fn stake_sol<'info>(
+  ctx: Context<'_, '_, '_, 'info, StakeSol<'info>>,
+  deposit_amount: u64,
+  _lock_period: i64,
+) -> Result<()> {
   let (expected_treasury_pool, _bump) =
     Pubkey::find_program_address(&[TreasuryPool::PREFIX_SEED], ctx.program_id);
   require!(
     ctx.accounts.treasury_pool.key() == expected_treasury_pool,
     ErrorCode::InvalidAccountOwner
   );
-  require!(
-    ctx.accounts.treasury_pda.key() == expected_treasury_pool,
-    ErrorCode::InvalidAccountOwner
-  );
 
   let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
   let required_space = 8 + TreasuryPool::INIT_SPACE;
@@ -53,11 +89,31 @@ pub fn stake_sol(ctx: Context<StakeSol>, deposit_amount: u64, _lock_period: i64)
 
   let mut treasury_pool = TreasuryPool::try_deserialize(&mut &treasury_pool_info.data.borrow()[..])
     .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
+  TreasuryPool::check_pda(
+    &ctx.accounts.reward_pool.key(),
+    TreasuryPool::REWARD_POOL_SEED,
+    treasury_pool.reward_pool_bump,
+    ctx.program_id,
+  )?;
+  TreasuryPool::check_pda(
+    &ctx.accounts.platform_pool.key(),
+    TreasuryPool::PLATFORM_POOL_SEED,
+    treasury_pool.platform_pool_bump,
+    ctx.program_id,
+  )?;
 
   let lender_stake = &mut ctx.accounts.lender_stake;
 
   require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
   require!(deposit_amount > 0, ErrorCode::InvalidAmount);
+  treasury_pool.check_stake_amount(deposit_amount)?;
+  treasury_pool.check_pool_share(
+    lender_stake
+      .staker_total_deposit()?
+      .checked_add(deposit_amount)
+      .ok_or(ErrorCode::CalculationOverflow)?,
+    deposit_amount,
+  )?;
 
   let lender_lamports = ctx.accounts.lender.lamports();
   let is_new_account = lender_stake.backer == Pubkey::default();
@@ -80,6 +136,14 @@ pub fn stake_sol(ctx: Context<StakeSol>, deposit_amount: u64, _lock_period: i64)
     ErrorCode::InsufficientDeposit
   );
 
+  // Deposit fee (0 unless the admin enabled deposit fees)
+  let (reward_fee, platform_fee) = treasury_pool.calculate_deposit_fees(deposit_amount)?;
+  let net_deposit = deposit_amount
+    .checked_sub(reward_fee)
+    .and_then(|x| x.checked_sub(platform_fee))
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  require!(net_deposit > 0, ErrorCode::InvalidAmount);
+
   let is_new_deposit = lender_stake.backer == Pubkey::default();
   let current_time = Clock::get()?.unix_timestamp;
 
@@ -101,10 +165,80 @@ pub fn stake_sol(ctx: Context<StakeSol>, deposit_amount: u64, _lock_period: i64)
     lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
 
     // Update duration weight for existing staker before adding more
-    let weight_delta = lender_stake.update_duration_weight(current_time)?;
-    if weight_delta > 0 {
-      treasury_pool.update_stake_duration_weight(weight_delta)?;
+    lender_stake.settle_duration_weight(&mut treasury_pool, current_time)?;
+  }
+
+  // Boost NFT (weight was settled above, so the boost applies from now on)
+  match (
+    ctx.accounts.boost_nft_token.as_ref(),
+    ctx.accounts.boost_nft_metadata.as_ref(),
+    ctx.accounts.boost_nft_usage.as_ref(),
+  ) {
+    (Some(nft_token), Some(nft_metadata), Some(nft_usage)) => {
+      require!(
+        treasury_pool.is_boost_nft_enabled(),
+        ErrorCode::BoostNftDisabled
+      );
+      let mint = verify_boost_nft(
+        &nft_metadata.to_account_info(),
+        nft_token,
+        &ctx.accounts.lender.key(),
+        &treasury_pool.boost_nft_collection,
+      )?;
+      require!(
+        lender_stake.boost_nft_mint == Pubkey::default() || lender_stake.boost_nft_mint == mint,
+        ErrorCode::BoostNftAlreadyApplied
+      );
+      claim_boost_nft_usage(
+        &nft_usage.to_account_info(),
+        mint,
+        lender_stake.key(),
+        &ctx.accounts.lender.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.program_id,
+        current_time,
+      )?;
+      lender_stake.apply_nft_boost(mint, treasury_pool.nft_boost_bps);
+
+      emit!(BoostNftApplied {
+        staker: lender_stake.backer,
+        mint,
+        boost_bps: lender_stake.nft_boost_bps,
+        applied_at: current_time,
+      });
     }
+    (None, None, None) => {}
+    _ => return Err(ErrorCode::InvalidBoostNft.into()),
+  }
+
+  // Per-wallet daily stake limit
+  lender_stake.check_and_update_daily_stake(
+    deposit_amount,
+    treasury_pool.daily_stake_limit,
+    current_time,
+  )?;
+
+  // Fees go to existing stakers, before the depositor's new stake is added
+  // (with no stakers yet, the first-depositor protection below moves them to pending)
+  if reward_fee > 0 || platform_fee > 0 {
+    for (pool, fee) in [
+      (&ctx.accounts.reward_pool, reward_fee),
+      (&ctx.accounts.platform_pool, platform_fee),
+    ] {
+      if fee > 0 {
+        system_program::transfer(
+          CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+              from: ctx.accounts.lender.to_account_info(),
+              to: pool.to_account_info(),
+            },
+          ),
+          fee,
+        )?;
+      }
+    }
+    treasury_pool.collect_fee(FeeSource::Deposit, reward_fee, platform_fee)?;
   }
 
   // === FIX: FIRST DEPOSITOR ARBITRAGE ===
@@ -131,46 +265,66 @@ pub fn stake_sol(ctx: Context<StakeSol>, deposit_amount: u64, _lock_period: i64)
 
   lender_stake.deposited_amount = lender_stake
     .deposited_amount
-    .checked_add(deposit_amount)
+    .checked_add(net_deposit)
     .ok_or(ErrorCode::CalculationOverflow)?;
 
   treasury_pool.total_deposited = treasury_pool
     .total_deposited
-    .checked_add(deposit_amount)
+    .checked_add(net_deposit)
     .ok_or(ErrorCode::CalculationOverflow)?;
 
   treasury_pool.liquid_balance = treasury_pool
     .liquid_balance
-    .checked_add(deposit_amount)
+    .checked_add(net_deposit)
     .ok_or(ErrorCode::CalculationOverflow)?;
 
   let deposit_cpi = CpiContext::new(
     ctx.accounts.system_program.to_account_info(),
     system_program::Transfer {
       from: ctx.accounts.lender.to_account_info(),
-      to: ctx.accounts.treasury_pda.to_account_info(),
+      to: ctx.accounts.treasury_vault.to_account_info(),
     },
   );
-  system_program::transfer(deposit_cpi, deposit_amount)?;
+  system_program::transfer(deposit_cpi, net_deposit)?;
 
   lender_stake.update_reward_debt(treasury_pool.reward_per_share)?;
 
+  // New liquidity - fulfill the head of the withdrawal queue (compute-bounded)
+  // The depositor's own position is serialized by Anchor, so it is never touched here
+  auto_fulfill_withdrawal_queue(
+    &mut treasury_pool,
+    &ctx.accounts.treasury_vault.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    ctx.remaining_accounts,
+    &[lender_stake.key()],
+    ctx.program_id,
+    current_time,
+  )?;
+
   let mut data = treasury_pool_info.try_borrow_mut_data()?;
   treasury_pool.try_serialize(&mut &mut data[..])?;
 
   emit!(SolStaked {
     lender: lender_stake.backer,
-    amount: deposit_amount,
+    amount: net_deposit,
     total_staked: lender_stake.deposited_amount,
     lock_period: 0,
   });
 
+  emit!(SolStakedV2 {
+    lender: lender_stake.backer,
+    amount: net_deposit,
+    total_staked: lender_stake.deposited_amount,
+    pool: treasury_pool.snapshot(),
+    staked_at: current_time,
+  });
+
   emit!(crate::events::DepositMade {
     backer: lender_stake.backer,
     deposit_amount,
-    net_deposit: deposit_amount,
-    reward_fee: 0,
-    platform_fee: 0,
+    net_deposit,
+    reward_fee,
+    platform_fee,
     total_deposited: treasury_pool.total_deposited,
     liquid_balance: treasury_pool.liquid_balance,
     deposited_at: Clock::get()?.unix_timestamp,
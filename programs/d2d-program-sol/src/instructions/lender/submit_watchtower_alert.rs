@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::WatchtowerAlertValidated,
+  instructions::{load_managed_program, read_upgrade_authority},
+  states::{LstConfig, TreasuryPool, Watchtower, WatchtowerAlert},
+  utils::pool_debit_credit,
+};
+
+/// Watchtower reports a protocol condition; the program re-checks it and pays
+/// the configured bounty from the platform pool
+///
+/// Alerts that do not hold on-chain fail. Only one alert per kind is paid per
+/// cooldown window, so a persisting condition cannot be farmed. See
+/// WatchtowerAlert for the remaining accounts each kind expects.
+#[derive(Accounts)]
+pub struct SubmitWatchtowerAlert<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  /// CHECK: Platform Pool PDA (pays bounties)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [Watchtower::PREFIX_SEED, operator.key().as_ref()],
+        bump = watchtower.bump
+    )]
+  pub watchtower: Account<'info, Watchtower>,
+
+  #[account(mut)]
+  pub operator: Signer<'info>,
+}
+
+pub fn submit_watchtower_alert(
+  ctx: Context<SubmitWatchtowerAlert>,
+  alert: WatchtowerAlert,
+) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let watchtower = &mut ctx.accounts.watchtower;
+  let remaining_accounts = ctx.remaining_accounts;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  let (validated, subject) = match alert {
+    WatchtowerAlert::UtilizationBreach => {
+      (treasury_pool.is_utilization_breached(), treasury_pool.key())
+    }
+    WatchtowerAlert::StaleCrank => {
+      let lst_config_info = remaining_accounts
+        .first()
+        .ok_or(ErrorCode::InvalidAccountData)?;
+      require!(
+        lst_config_info.owner == ctx.program_id,
+        ErrorCode::InvalidAccountOwner
+      );
+      let lst_config = LstConfig::try_deserialize(&mut &lst_config_info.data.borrow()[..])
+        .map_err(|_| error!(ErrorCode::InvalidAccountData))?;
+      (
+        lst_config.enabled && !lst_config.is_rate_fresh(current_time),
+        lst_config.mint,
+      )
+    }
+    WatchtowerAlert::AuthorityDrift => {
+      require!(remaining_accounts.len() == 2, ErrorCode::InvalidAccountData);
+      let managed_program = load_managed_program(&remaining_accounts[0], ctx.program_id)?;
+      require!(managed_program.is_active, ErrorCode::AlertNotValidated);
+      let actual_authority = read_upgrade_authority(&managed_program, &remaining_accounts[1])?;
+      (
+        actual_authority != Some(managed_program.authority_pda),
+        managed_program.program_id,
+      )
+    }
+  };
+  require!(validated, ErrorCode::AlertNotValidated);
+
+  let bounty = treasury_pool.claim_alert_bounty(alert.index(), current_time)?;
+  pool_debit_credit(
+    &ctx.accounts.platform_pool.to_account_info(),
+    &ctx.accounts.operator.to_account_info(),
+    bounty,
+    &mut treasury_pool.platform_pool_balance,
+  )?;
+
+  watchtower.alerts_validated = watchtower.alerts_validated.saturating_add(1);
+  watchtower.bounties_earned = watchtower
+    .bounties_earned
+    .checked_add(bounty)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  watchtower.last_alert_at = current_time;
+
+  emit!(WatchtowerAlertValidated {
+    operator: watchtower.operator,
+    alert,
+    subject,
+    bounty,
+    validated_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  events::AutoUnstakeTriggered,
+  instructions::{checkpoint_campaign_boost, enqueue_withdrawal, sync_insurance_coverage},
+  states::{
+    BackerDeposit, BoostCampaign, CampaignEnrollment, InsuranceFund, TreasuryPool,
+    WithdrawalQueueEntry,
+  },
+};
+
+/// Permissionless crank: queue a position whose auto_unstake_at has passed
+///
+/// The whole effective deposit goes to the tail of the withdrawal queue, as if
+/// the staker had called queue_withdrawal. The cranker pays the queue entry
+/// rent, which goes to the staker when the entry is closed; the keeper tip
+/// goes to whoever later processes the entry, not to this cranker.
+#[derive(Accounts)]
+pub struct TriggerAutoUnstake<'info> {
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump = treasury_pool.bump
+    )]
+  pub treasury_pool: Account<'info, TreasuryPool>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender_stake.backer.as_ref()],
+        bump = lender_stake.bump,
+        constraint = lender_stake.is_active @ ErrorCode::InsufficientStake
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  #[account(
+        init,
+        payer = cranker,
+        space = 8 + WithdrawalQueueEntry::INIT_SPACE,
+        seeds = [WithdrawalQueueEntry::PREFIX_SEED, &treasury_pool.withdrawal_queue_tail.to_le_bytes()],
+        bump
+    )]
+  pub queue_entry: Account<'info, WithdrawalQueueEntry>,
+
+  #[account(mut)]
+  pub cranker: Signer<'info>,
+
+  /// Insurance fund (required when the position's coverage must shrink)
+  #[account(
+        mut,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump = insurance_fund.bump
+    )]
+  pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+  /// Running boost campaign the position is enrolled in (required until it ends)
+  #[account(mut)]
+  pub boost_campaign: Option<Account<'info, BoostCampaign>>,
+
+  /// The position's enrollment in boost_campaign
+  #[account(mut)]
+  pub campaign_enrollment: Option<Account<'info, CampaignEnrollment>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn trigger_auto_unstake(ctx: Context<TriggerAutoUnstake>) -> Result<()> {
+  let treasury_pool = &mut ctx.accounts.treasury_pool;
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(
+    lender_stake.is_auto_unstake_due(current_time),
+    ErrorCode::AutoUnstakeNotDue
+  );
+  require!(!lender_stake.is_pledged(), ErrorCode::StakePledged);
+  require!(
+    !lender_stake.has_unstake_commitment(),
+    ErrorCode::UnstakeCommitmentPending
+  );
+  require!(
+    !lender_stake.has_queued_withdrawal(),
+    ErrorCode::WithdrawalAlreadyQueued
+  );
+
+  let amount = lender_stake.deposited_amount;
+  require!(amount > 0, ErrorCode::InsufficientStake);
+
+  let queue_position = treasury_pool.withdrawal_queue_tail;
+  enqueue_withdrawal(
+    treasury_pool,
+    lender_stake,
+    &mut ctx.accounts.queue_entry,
+    ctx.bumps.queue_entry,
+    amount,
+    current_time,
+  )?;
+  // Coverage and campaign boost follow the stake that remains
+  sync_insurance_coverage(lender_stake, ctx.accounts.insurance_fund.as_mut())?;
+  checkpoint_campaign_boost(
+    lender_stake,
+    ctx.accounts.boost_campaign.as_mut(),
+    ctx.accounts.campaign_enrollment.as_mut(),
+    treasury_pool.reward_per_share,
+    current_time,
+  )?;
+
+  lender_stake.auto_unstake_at = 0;
+
+  emit!(AutoUnstakeTriggered {
+    staker: lender_stake.backer,
+    cranker: ctx.accounts.cranker.key(),
+    amount,
+    queue_position,
+    triggered_at: current_time,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,257 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+  errors::ErrorCode,
+  events::{SolUnstaked, SolUnstakedV2, StakerWithdrawalQueued},
+  instructions::{checkpoint_campaign_boost, sync_insurance_coverage},
+  states::{
+    BackerDeposit, BoostCampaign, CampaignEnrollment, InsuranceFund, TreasuryPool,
+    WithdrawalQueueEntry,
+  },
+  utils::{vault_debit_credit, vault_payable_lamports},
+};
+
+/// Unstake with automatic overflow into the withdrawal queue
+///
+/// Withdraws whatever unreserved liquidity is available immediately and queues
+/// the remainder in the same transaction, so stakers don't have to split the
+/// amount between unstake_sol and queue_withdrawal themselves.
+/// Liquidity reserved for already-queued stakers is never used for the
+/// immediate part.
+#[derive(Accounts)]
+#[instruction(amount: u64, queue_position: u32)]
+pub struct UnstakeOrQueue<'info> {
+  /// CHECK: Treasury Pool - will be migrated if needed
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PREFIX_SEED],
+        bump
+    )]
+  pub treasury_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Treasury vault PDA (holds deposits)
+  #[account(
+        mut,
+        seeds = [TreasuryPool::VAULT_SEED],
+        bump
+    )]
+  pub treasury_vault: UncheckedAccount<'info>,
+
+  #[account(
+        mut,
+        seeds = [BackerDeposit::PREFIX_SEED, lender.key().as_ref()],
+        bump = lender_stake.bump
+    )]
+  pub lender_stake: Account<'info, BackerDeposit>,
+
+  /// CHECK: Queue entry PDA at the current queue tail - only created if a
+  /// remainder has to be queued (queue_position must equal withdrawal_queue_tail)
+  #[account(
+        mut,
+        seeds = [WithdrawalQueueEntry::PREFIX_SEED, &queue_position.to_le_bytes()],
+        bump
+    )]
+  pub queue_entry: UncheckedAccount<'info>,
+
+  #[account(mut)]
+  pub lender: Signer<'info>,
+
+  /// Insurance fund (required when the position's coverage must shrink)
+  #[account(
+        mut,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump = insurance_fund.bump
+    )]
+  pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+  /// Running boost campaign the position is enrolled in (required until it ends)
+  #[account(mut)]
+  pub boost_campaign: Option<Account<'info, BoostCampaign>>,
+
+  /// The position's enrollment in boost_campaign
+  #[account(mut)]
+  pub campaign_enrollment: Option<Account<'info, CampaignEnrollment>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn unstake_or_queue(
+  ctx: Context<UnstakeOrQueue>,
+  amount: u64,
+  queue_position: u32,
+) -> Result<()> {
+  let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
+  let required_space = 8 + TreasuryPool::INIT_SPACE;
+  let current_space = treasury_pool_info.data_len();
+
+  if current_space < required_space {
+    treasury_pool_info.resize(required_space)?;
+  }
+
+  let mut treasury_pool = TreasuryPool::try_deserialize(&mut &treasury_pool_info.data.borrow()[..])
+    .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
+
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
+  let lender_stake = &mut ctx.accounts.lender_stake;
+  let current_time = Clock::get()?.unix_timestamp;
+
+  require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
+  require!(amount > 0, ErrorCode::InvalidAmount);
+  require!(!lender_stake.is_pledged(), ErrorCode::StakePledged);
+  require!(
+    !lender_stake.has_unstake_commitment(),
+    ErrorCode::UnstakeCommitmentPending
+  );
+  require!(
+    amount <= lender_stake.deposited_amount,
+    ErrorCode::InsufficientStake
+  );
+
+  // Check if staker has a pending queued withdrawal
+  require!(
+    !lender_stake.has_queued_withdrawal(),
+    ErrorCode::WithdrawalAlreadyQueued
+  );
+
+  lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
+
+  // Update duration weight before withdrawal
+  lender_stake.settle_duration_weight(&mut treasury_pool, current_time)?;
+
+  // Split the request: unreserved liquidity now, the rest into the queue
+  let available_balance =
+    vault_payable_lamports(&treasury_vault_info)?.min(treasury_pool.liquid_balance);
+  let withdraw_now = amount.min(treasury_pool.get_unreserved_liquidity(available_balance));
+  require!(
+    !treasury_pool
+      .requires_unstake_commitment(lender_stake.instant_unstake_total(withdraw_now, current_time)),
+    ErrorCode::UnstakeCommitmentRequired
+  );
+  if withdraw_now > 0 {
+    lender_stake.record_instant_unstake(withdraw_now, current_time);
+  }
+  let queued_amount = amount
+    .checked_sub(withdraw_now)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+
+  // === IMMEDIATE PART ===
+  if withdraw_now > 0 {
+    lender_stake.deposited_amount = lender_stake
+      .deposited_amount
+      .checked_sub(withdraw_now)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    if lender_stake.deposited_amount == 0 {
+      lender_stake.is_active = false;
+    }
+
+    treasury_pool.total_deposited = treasury_pool
+      .total_deposited
+      .checked_sub(withdraw_now)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    vault_debit_credit(
+      &ctx.accounts.system_program.to_account_info(),
+      &treasury_vault_info,
+      &ctx.accounts.lender.to_account_info(),
+      withdraw_now,
+      treasury_pool.vault_bump,
+      &mut treasury_pool.liquid_balance,
+    )?;
+  }
+
+  // === QUEUED PART ===
+  if queued_amount > 0 {
+    require!(
+      queue_position == treasury_pool.withdrawal_queue_tail,
+      ErrorCode::InvalidQueuePosition
+    );
+
+    // Create the queue entry PDA (payer: lender)
+    let queue_entry_info = ctx.accounts.queue_entry.to_account_info();
+    let entry_space = 8 + WithdrawalQueueEntry::INIT_SPACE;
+    let position_bytes = queue_position.to_le_bytes();
+    let entry_seeds: &[&[u8]] = &[
+      WithdrawalQueueEntry::PREFIX_SEED,
+      &position_bytes,
+      &[ctx.bumps.queue_entry],
+    ];
+    system_program::create_account(
+      CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::CreateAccount {
+          from: ctx.accounts.lender.to_account_info(),
+          to: queue_entry_info.clone(),
+        },
+        &[entry_seeds],
+      ),
+      Rent::get()?.minimum_balance(entry_space),
+      entry_space as u64,
+      ctx.program_id,
+    )?;
+
+    let queue_entry = WithdrawalQueueEntry {
+      position: queue_position,
+      staker: ctx.accounts.lender.key(),
+      amount: queued_amount,
+      queued_at: current_time,
+      processed: false,
+      amount_withdrawn: 0,
+      processed_at: 0,
+      bump: ctx.bumps.queue_entry,
+    };
+    queue_entry.try_serialize(&mut &mut queue_entry_info.try_borrow_mut_data()?[..])?;
+
+    lender_stake.queue_withdrawal(
+      queued_amount,
+      queue_position,
+      current_time,
+      treasury_pool.queue_fee_per_share,
+    )?;
+    treasury_pool.add_to_withdrawal_queue(queued_amount)?;
+  }
+
+  // The queued remainder stops accruing rewards from here on
+  lender_stake.update_reward_debt(treasury_pool.reward_per_share)?;
+
+  // Coverage and campaign boost follow the stake that remains
+  sync_insurance_coverage(lender_stake, ctx.accounts.insurance_fund.as_mut())?;
+  checkpoint_campaign_boost(
+    lender_stake,
+    ctx.accounts.boost_campaign.as_mut(),
+    ctx.accounts.campaign_enrollment.as_mut(),
+    treasury_pool.reward_per_share,
+    current_time,
+  )?;
+
+  let mut data = treasury_pool_info.try_borrow_mut_data()?;
+  treasury_pool.try_serialize(&mut &mut data[..])?;
+
+  if withdraw_now > 0 {
+    emit!(SolUnstaked {
+      lender: lender_stake.backer,
+      amount: withdraw_now,
+      remaining_staked: lender_stake.deposited_amount,
+    });
+
+    emit!(SolUnstakedV2 {
+      lender: lender_stake.backer,
+      amount: withdraw_now,
+      remaining_staked: lender_stake.deposited_amount,
+      pool: treasury_pool.snapshot(),
+      unstaked_at: current_time,
+    });
+  }
+
+  if queued_amount > 0 {
+    emit!(StakerWithdrawalQueued {
+      staker: lender_stake.backer,
+      amount: queued_amount,
+      queue_position,
+      queued_withdrawal_total: treasury_pool.queued_withdrawal_amount,
+      queued_at: current_time,
+    });
+  }
+
+  Ok(())
+}
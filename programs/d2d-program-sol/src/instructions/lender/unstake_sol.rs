@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 
 use crate::{
   errors::ErrorCode,
-  events::SolUnstaked,
-  states::{BackerDeposit, TreasuryPool},
+  events::{SolUnstaked, SolUnstakedV2},
+  instructions::{checkpoint_campaign_boost, sync_insurance_coverage},
+  states::{BackerDeposit, BoostCampaign, CampaignEnrollment, InsuranceFund, TreasuryPool},
+  utils::{vault_debit_credit, vault_payable_lamports},
 };
 
 #[derive(Accounts)]
@@ -16,13 +18,13 @@ pub struct UnstakeSol<'info> {
     )]
   pub treasury_pool: UncheckedAccount<'info>,
 
-  /// CHECK: Treasury Pool PDA (holds deposits)
+  /// CHECK: Treasury vault PDA (holds deposits)
   #[account(
         mut,
-        seeds = [TreasuryPool::PREFIX_SEED],
+        seeds = [TreasuryPool::VAULT_SEED],
         bump
     )]
-  pub treasury_pda: UncheckedAccount<'info>,
+  pub treasury_vault: UncheckedAccount<'info>,
 
   #[account(
         mut,
@@ -34,15 +36,26 @@ pub struct UnstakeSol<'info> {
   #[account(mut)]
   pub lender: Signer<'info>,
 
+  /// Insurance fund (required when the position's coverage must shrink)
+  #[account(
+        mut,
+        seeds = [InsuranceFund::PREFIX_SEED],
+        bump = insurance_fund.bump
+    )]
+  pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+  /// Running boost campaign the position is enrolled in (required until it ends)
+  #[account(mut)]
+  pub boost_campaign: Option<Account<'info, BoostCampaign>>,
+
+  /// The position's enrollment in boost_campaign
+  #[account(mut)]
+  pub campaign_enrollment: Option<Account<'info, CampaignEnrollment>>,
+
   pub system_program: Program<'info, System>,
 }
 
 pub fn unstake_sol(ctx: Context<UnstakeSol>, amount: u64) -> Result<()> {
-  require!(
-    ctx.accounts.treasury_pda.key() == ctx.accounts.treasury_pool.key(),
-    ErrorCode::InvalidAccountOwner
-  );
-
   let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
   let required_space = 8 + TreasuryPool::INIT_SPACE;
   let current_space = treasury_pool_info.data_len();
@@ -54,11 +67,12 @@ pub fn unstake_sol(ctx: Context<UnstakeSol>, amount: u64) -> Result<()> {
   let mut treasury_pool = TreasuryPool::try_deserialize(&mut &treasury_pool_info.data.borrow()[..])
     .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
 
-  let treasury_pda_info = ctx.accounts.treasury_pda.to_account_info();
+  let treasury_vault_info = ctx.accounts.treasury_vault.to_account_info();
   let lender_stake = &mut ctx.accounts.lender_stake;
 
   require!(!treasury_pool.emergency_pause, ErrorCode::ProgramPaused);
   require!(amount > 0, ErrorCode::InvalidAmount);
+  require!(!lender_stake.is_pledged(), ErrorCode::StakePledged);
   require!(
     amount <= lender_stake.deposited_amount,
     ErrorCode::InsufficientStake
@@ -74,23 +88,34 @@ pub fn unstake_sol(ctx: Context<UnstakeSol>, amount: u64) -> Result<()> {
     ErrorCode::WithdrawalAlreadyQueued
   );
 
-  lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
-
-  // Update duration weight before withdrawal
+  // Large unstakes execute only as a matured commitment of the same amount
   let current_time = Clock::get()?.unix_timestamp;
-  let weight_delta = lender_stake.update_duration_weight(current_time)?;
-  if weight_delta > 0 {
-    treasury_pool.update_stake_duration_weight(weight_delta)?;
+  if lender_stake.has_unstake_commitment() {
+    require!(
+      amount == lender_stake.committed_unstake_amount,
+      ErrorCode::UnstakeCommitmentMismatch
+    );
+    require!(
+      lender_stake.is_unstake_commitment_ready(current_time),
+      ErrorCode::UnstakeCommitmentNotReady
+    );
+  } else {
+    // Uncommitted unstakes add up over the window, so splitting a large
+    // exit into smaller calls still needs a commitment
+    require!(
+      !treasury_pool
+        .requires_unstake_commitment(lender_stake.instant_unstake_total(amount, current_time)),
+      ErrorCode::UnstakeCommitmentRequired
+    );
+    lender_stake.record_instant_unstake(amount, current_time);
   }
 
-  let treasury_lamports = treasury_pda_info.lamports();
-  let account_data_size = treasury_pda_info.data_len();
-  let rent_exemption =
-    anchor_lang::solana_program::rent::Rent::get()?.minimum_balance(account_data_size);
+  lender_stake.settle_pending_rewards(treasury_pool.reward_per_share)?;
 
-  let available_balance = treasury_lamports
-    .checked_sub(rent_exemption)
-    .ok_or(ErrorCode::CalculationOverflow)?;
+  // Update duration weight before withdrawal
+  lender_stake.settle_duration_weight(&mut treasury_pool, current_time)?;
+
+  let available_balance = vault_payable_lamports(&treasury_vault_info)?;
 
   if available_balance < amount {
     return Err(ErrorCode::InsufficientLiquidBalance.into());
@@ -119,24 +144,31 @@ pub fn unstake_sol(ctx: Context<UnstakeSol>, amount: u64) -> Result<()> {
     .checked_sub(amount)
     .ok_or(ErrorCode::CalculationOverflow)?;
 
-  treasury_pool.liquid_balance = treasury_pool
-    .liquid_balance
-    .checked_sub(amount)
-    .ok_or(ErrorCode::CalculationOverflow)?;
-
-  {
-    let lender_info = ctx.accounts.lender.to_account_info();
-    let mut treasury_lamports = treasury_pda_info.try_borrow_mut_lamports()?;
-    let mut lender_lamports = lender_info.try_borrow_mut_lamports()?;
-
-    **treasury_lamports = (**treasury_lamports)
-      .checked_sub(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-    **lender_lamports = (**lender_lamports)
-      .checked_add(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
+  if lender_stake.has_unstake_commitment() {
+    treasury_pool.release_unstake_commitment(amount);
+    lender_stake.committed_unstake_amount = 0;
+    lender_stake.unstake_executable_at = 0;
   }
 
+  // Coverage and campaign boost follow the stake that remains
+  sync_insurance_coverage(lender_stake, ctx.accounts.insurance_fund.as_mut())?;
+  checkpoint_campaign_boost(
+    lender_stake,
+    ctx.accounts.boost_campaign.as_mut(),
+    ctx.accounts.campaign_enrollment.as_mut(),
+    treasury_pool.reward_per_share,
+    current_time,
+  )?;
+
+  vault_debit_credit(
+    &ctx.accounts.system_program.to_account_info(),
+    &treasury_vault_info,
+    &ctx.accounts.lender.to_account_info(),
+    amount,
+    treasury_pool.vault_bump,
+    &mut treasury_pool.liquid_balance,
+  )?;
+
   let mut data = treasury_pool_info.try_borrow_mut_data()?;
   treasury_pool.try_serialize(&mut &mut data[..])?;
 
@@ -146,5 +178,13 @@ pub fn unstake_sol(ctx: Context<UnstakeSol>, amount: u64) -> Result<()> {
     remaining_staked: lender_stake.deposited_amount,
   });
 
+  emit!(SolUnstakedV2 {
+    lender: lender_stake.backer,
+    amount,
+    remaining_staked: lender_stake.deposited_amount,
+    pool: treasury_pool.snapshot(),
+    unstaked_at: current_time,
+  });
+
   Ok(())
 }
@@ -2,18 +2,34 @@ use anchor_lang::{prelude::*, system_program};
 
 use crate::{
   errors::ErrorCode,
-  events::DeploymentFundsRequested,
-  states::{DeployRequest, DeployRequestStatus, TreasuryPool, UserDeployStats},
+  events::{DeploymentFundsRequested, TermsAccepted},
+  states::{
+    Coupon, DeployRequest, DeployRequestStatus, FeeSource, PlanConfig, TreasuryPool,
+    UserDeployStats,
+  },
+  utils::{apply_coupon, payout_from_pda},
 };
 
 /// Request deployment funds from treasury pool
 /// This instruction:
-/// 1. Developer pays service fee + subscription
+/// 1. Developer pays the plan tier's service fee (less any coupon discount)
+///    and subscription to the Reward Pool, and the 0.1% deployment platform
+///    fee to the Platform Pool, credited in this same instruction (no
+///    separate credit_fee_to_pool call)
 /// 2. Validates treasury has sufficient funds for deployment
-/// 3. Creates a deploy_request with status PendingDeployment
+/// 3. Creates a deploy_request with status PendingDeployment, recording the
+///    terms hash the developer accepted (must match the current terms)
 /// 4. Backend will then call fund_temporary_wallet to get deployment funds
+///
+/// An existing request on the same PDA is only replaced once it has failed or
+/// been cancelled without being funded; any payment still escrowed for it is
+/// refunded to the developer
+///
+/// `nonce` selects the deployment of `program_hash`: 0 is the default one,
+/// others deploy the same bytecode again (see DeployRequest::derive_request_id).
+/// A live deployment is never overwritten - deploying again takes a new nonce
 #[derive(Accounts)]
-#[instruction(program_hash: [u8; 32])]
+#[instruction(program_hash: [u8; 32], nonce: u32)]
 pub struct RequestDeploymentFunds<'info> {
   #[account(
         mut,
@@ -22,11 +38,20 @@ pub struct RequestDeploymentFunds<'info> {
     )]
   pub treasury_pool: Account<'info, TreasuryPool>,
 
+  #[account(
+        seeds = [PlanConfig::PREFIX_SEED],
+        bump = plan_config.bump
+    )]
+  pub plan_config: Account<'info, PlanConfig>,
+
   #[account(
         init_if_needed,
         payer = developer,
         space = 8 + DeployRequest::INIT_SPACE,
-        seeds = [DeployRequest::PREFIX_SEED, program_hash.as_ref()],
+        seeds = [
+          DeployRequest::PREFIX_SEED,
+          DeployRequest::derive_request_id(&developer.key(), &program_hash, nonce).as_ref(),
+        ],
         bump
     )]
   pub deploy_request: Account<'info, DeployRequest>,
@@ -55,25 +80,57 @@ pub struct RequestDeploymentFunds<'info> {
     )]
   pub dev_wallet: UncheckedAccount<'info>,
 
+  /// CHECK: Reward Pool PDA - receives the developer payment
+  #[account(
+        mut,
+        seeds = [TreasuryPool::REWARD_POOL_SEED],
+        bump = treasury_pool.reward_pool_bump
+    )]
+  pub reward_pool: UncheckedAccount<'info>,
+
+  /// CHECK: Platform Pool PDA - receives the deployment platform fee
+  #[account(
+        mut,
+        seeds = [TreasuryPool::PLATFORM_POOL_SEED],
+        bump = treasury_pool.platform_pool_bump
+    )]
+  pub platform_pool: UncheckedAccount<'info>,
+
+  /// Optional promo code discounting the service fee
+  #[account(
+        mut,
+        seeds = [Coupon::PREFIX_SEED, coupon.code_hash.as_ref()],
+        bump = coupon.bump
+    )]
+  pub coupon: Option<Account<'info, Coupon>>,
+
   pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn request_deployment_funds(
   ctx: Context<RequestDeploymentFunds>,
   program_hash: [u8; 32],
-  service_fee: u64,
-  monthly_fee: u64,
+  nonce: u32,
+  plan_id: u8,
+  program_size: u64,
   initial_months: u32,
   deployment_cost: u64,
+  terms_hash: [u8; 32],
+  coupon_code: Option<String>,
 ) -> Result<()> {
-  // Get account infos before mutable borrows to avoid borrow checker issues
-  let treasury_pool_info = ctx.accounts.treasury_pool.to_account_info();
   let _treasury_pool_bump = ctx.accounts.treasury_pool.bump;
 
   let treasury_pool = &mut ctx.accounts.treasury_pool;
   let deploy_request = &mut ctx.accounts.deploy_request;
   let user_stats = &mut ctx.accounts.user_stats;
   let current_time = Clock::get()?.unix_timestamp;
+  let request_id =
+    DeployRequest::derive_request_id(&ctx.accounts.developer.key(), &program_hash, nonce);
+
+  // Fees come from the referenced plan tier, not the caller
+  let tier = *ctx.accounts.plan_config.get_tier(plan_id)?;
+  let (service_fee, monthly_fee) = (tier.service_fee, tier.monthly_fee);
 
   let is_new_deploy_request =
     deploy_request.request_id == [0u8; 32] && deploy_request.developer == Pubkey::default();
@@ -87,6 +144,23 @@ pub fn request_deployment_funds(
   require!(monthly_fee > 0, ErrorCode::InvalidAmount);
   require!(initial_months > 0, ErrorCode::InvalidAmount);
   require!(deployment_cost > 0, ErrorCode::InvalidAmount);
+  treasury_pool.check_request_params(service_fee, monthly_fee, initial_months)?;
+
+  // An optional coupon waives part of the plan's service fee
+  let service_fee = apply_coupon(
+    ctx.accounts.coupon.as_mut(),
+    coupon_code.as_deref(),
+    request_id,
+    ctx.accounts.developer.key(),
+    service_fee,
+    current_time,
+  )?;
+
+  // Developer must have accepted the terms currently in force
+  require!(
+    terms_hash == treasury_pool.terms_hash,
+    ErrorCode::TermsHashMismatch
+  );
 
   // Check if treasury has enough funds for deployment
   require!(
@@ -111,11 +185,16 @@ pub fn request_deployment_funds(
   }
 
   // Calculate total payment (service fee + subscription)
-  let total_payment = service_fee + (monthly_fee * initial_months as u64);
+  let subscription_payment =
+    treasury_pool.calculate_subscription_payment(monthly_fee, initial_months)?;
+  let total_payment = subscription_payment
+    .checked_add(service_fee)
+    .ok_or(ErrorCode::MaxPaymentExceeded)?;
+  let platform_fee = TreasuryPool::calculate_deployment_platform_fee(deployment_cost);
 
   // Initialize deploy request with PendingDeployment status
   if is_new_deploy_request {
-    deploy_request.request_id = program_hash;
+    deploy_request.request_id = request_id;
     deploy_request.developer = ctx.accounts.developer.key();
     deploy_request.program_hash = program_hash;
     deploy_request.created_at = current_time;
@@ -126,49 +205,105 @@ pub fn request_deployment_funds(
         && deploy_request.developer == ctx.accounts.developer.key(),
       ErrorCode::InvalidRequestId
     );
+    deploy_request.check_retryable()?;
+    // The retried request gives back the quota slot it held
+    user_stats.release_slot(&deploy_request.status);
   }
 
+  // A previous request on this PDA never reached confirmation - any payment
+  // still held for it goes back to the developer, not to stakers
+  let previous_escrow = deploy_request.take_escrowed_payment();
+  let (previous_refund, _) = treasury_pool.split_escrowed_refund(
+    FeeSource::DeploymentRequest,
+    previous_escrow,
+    previous_escrow,
+  )?;
+
+  deploy_request.apply_plan(&tier, program_size)?;
   deploy_request.service_fee = service_fee;
-  deploy_request.monthly_fee = monthly_fee;
   deploy_request.deployment_cost = deployment_cost;
-  deploy_request.subscription_paid_until =
-    current_time + (initial_months as i64 * 30 * 24 * 60 * 60);
+  deploy_request.start_subscription(initial_months, subscription_payment, current_time);
   deploy_request.ephemeral_key = None; // Will be set when backend funds temporary wallet
   deploy_request.deployed_program_id = None; // Will be set after backend deploys
   deploy_request.status = DeployRequestStatus::PendingDeployment;
+  deploy_request.set_pending_expiry(current_time);
+  deploy_request.set_service_fee_refund_schedule(
+    treasury_pool.service_fee_refund_bps,
+    treasury_pool.service_fee_refund_window,
+  );
+  deploy_request.accepted_terms_hash = terms_hash;
+  deploy_request.terms_accepted_at = current_time;
+  deploy_request.total_funded = 0;
+  deploy_request.reported_spent = 0;
+  deploy_request.funding_variance = 0;
+  deploy_request.total_paid_by_developer = 0;
+  deploy_request.record_developer_payment(total_payment)?;
 
-  // Update user stats
-  user_stats.active_sessions += 1;
-  user_stats.daily_deploys += 1;
-  user_stats.total_deploys += 1;
+  // Update user stats, enforcing the per-developer deployment quota
+  user_stats.check_quota(treasury_pool.max_active_deployments_per_developer)?;
+  user_stats.record_request(
+    total_payment
+      .checked_add(platform_fee)
+      .ok_or(ErrorCode::CalculationOverflow)?,
+  )?;
 
-  // Transfer developer payment (service fee + subscription) directly to Treasury Pool PDA
+  // Transfer developer payment (service fee + subscription) to the Reward Pool PDA
   let developer_payment_cpi = CpiContext::new(
     ctx.accounts.system_program.to_account_info(),
     system_program::Transfer {
       from: ctx.accounts.developer.to_account_info(),
-      to: treasury_pool_info.clone(),
+      to: ctx.accounts.reward_pool.to_account_info(),
     },
   );
   system_program::transfer(developer_payment_cpi, total_payment)?;
 
+  // Transfer and credit the platform fee in the same instruction
+  if platform_fee > 0 {
+    let platform_fee_cpi = CpiContext::new(
+      ctx.accounts.system_program.to_account_info(),
+      system_program::Transfer {
+        from: ctx.accounts.developer.to_account_info(),
+        to: ctx.accounts.platform_pool.to_account_info(),
+      },
+    );
+    system_program::transfer(platform_fee_cpi, platform_fee)?;
+    treasury_pool.collect_fee(FeeSource::DeploymentRequest, 0, platform_fee)?;
+  }
+
+  payout_from_pda(
+    &ctx.accounts.reward_pool.to_account_info(),
+    &ctx.accounts.developer.to_account_info(),
+    previous_refund,
+  )?;
+
   // Note: Deployment cost will be transferred later via fund_temporary_wallet instruction
   // This separates developer payment from backend deployment funding
 
-  // Update treasury pool - credit developer payment to reward pool
-  treasury_pool.credit_reward_pool(total_payment as u128)?;
+  // Hold the payment in escrow until confirm_deployment_success credits it to
+  // stakers; a failed deployment is refunded from escrow
+  treasury_pool.hold_developer_payment(total_payment)?;
+  deploy_request.escrowed_payment = total_payment;
 
   emit!(DeploymentFundsRequested {
     request_id: deploy_request.request_id,
     developer: deploy_request.developer,
     program_hash: deploy_request.program_hash,
+    plan_id,
     service_fee,
     monthly_fee,
     initial_months,
     deployment_cost,
     total_payment,
+    platform_fee,
     requested_at: current_time,
   });
 
+  emit!(TermsAccepted {
+    request_id: deploy_request.request_id,
+    developer: deploy_request.developer,
+    terms_hash,
+    accepted_at: current_time,
+  });
+
   Ok(())
 }
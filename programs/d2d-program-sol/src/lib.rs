@@ -6,6 +6,7 @@ pub mod events;
 pub mod instructions;
 pub mod program_id;
 pub mod states;
+pub mod utils;
 
 pub use events::*;
 use instructions::*;
@@ -22,7 +23,11 @@ pub mod d2d_program_sol {
     instructions::initialize(ctx, initial_apy, dev_wallet)
   }
 
-  pub fn stake_sol(ctx: Context<StakeSol>, amount: u64, lock_period: i64) -> Result<()> {
+  pub fn stake_sol<'info>(
+    ctx: Context<'_, '_, '_, 'info, StakeSol<'info>>,
+    amount: u64,
+    lock_period: i64,
+  ) -> Result<()> {
     instructions::stake_sol(ctx, amount, lock_period)
   }
 
@@ -30,29 +35,101 @@ pub mod d2d_program_sol {
     instructions::unstake_sol(ctx, amount)
   }
 
+  /// Unstake what liquidity allows now and queue the remainder atomically
+  /// `queue_position` must be the current withdrawal_queue_tail
+  pub fn unstake_or_queue(
+    ctx: Context<UnstakeOrQueue>,
+    amount: u64,
+    queue_position: u32,
+  ) -> Result<()> {
+    instructions::unstake_or_queue(ctx, amount, queue_position)
+  }
+
   pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
     instructions::claim_rewards(ctx)
   }
 
+  /// Staker claims rewards on several positions at once (remaining accounts)
+  pub fn claim_all_rewards(ctx: Context<ClaimAllRewards>) -> Result<()> {
+    instructions::claim_all_rewards(ctx)
+  }
+
+  /// Claim the duration bonus vested so far
+  pub fn claim_vested_bonus(ctx: Context<ClaimVestedBonus>) -> Result<()> {
+    instructions::claim_vested_bonus(ctx)
+  }
+
+  /// Register (or clear) a delegate allowed to claim rewards for the staker
+  pub fn set_claim_delegate(
+    ctx: Context<SetClaimDelegate>,
+    delegate: Option<Pubkey>,
+  ) -> Result<()> {
+    instructions::set_claim_delegate(ctx, delegate)
+  }
+
+  /// Register (or clear) a wallet claim_rewards may pay to instead of the signer
+  pub fn set_payout_address(
+    ctx: Context<SetPayoutAddress>,
+    payout_address: Option<Pubkey>,
+  ) -> Result<()> {
+    instructions::set_payout_address(ctx, payout_address)
+  }
+
+  /// Claim delegate claims rewards on the staker's behalf (paid to the staker)
+  pub fn delegated_claim_rewards(ctx: Context<DelegatedClaimRewards>) -> Result<()> {
+    instructions::delegated_claim_rewards(ctx)
+  }
+
+  /// Opt in (or out) of restaking settled rewards into principal
+  pub fn set_auto_compound(ctx: Context<SetAutoCompound>, enabled: bool) -> Result<()> {
+    instructions::set_auto_compound(ctx, enabled)
+  }
+
+  /// Permissionless crank: restake settled rewards for opted-in stakers (batch)
+  pub fn compound_rewards(ctx: Context<CompoundRewards>) -> Result<()> {
+    instructions::compound_rewards(ctx)
+  }
+
+  /// Read-only: base claimable + duration bonus for a staker (simulate to query)
+  pub fn get_claimable_rewards(ctx: Context<GetClaimableRewards>) -> Result<ClaimableRewards> {
+    instructions::get_claimable_rewards(ctx)
+  }
+
+  /// Read-only: estimated fulfillment time of a queued withdrawal (simulate to query)
+  pub fn get_estimated_wait_time(
+    ctx: Context<GetEstimatedWaitTime>,
+    queue_position: u32,
+  ) -> Result<EstimatedWaitTime> {
+    instructions::get_estimated_wait_time(ctx, queue_position)
+  }
+
   pub fn emergency_unstake_sol(ctx: Context<EmergencyUnstakeSol>, amount: u64) -> Result<()> {
     instructions::emergency_unstake_sol(ctx, amount)
   }
 
+  /// `terms_hash` must match the current terms on the treasury pool
+  #[allow(clippy::too_many_arguments)]
   pub fn request_deployment_funds(
     ctx: Context<RequestDeploymentFunds>,
     program_hash: [u8; 32],
-    service_fee: u64,
-    monthly_fee: u64,
+    nonce: u32,
+    plan_id: u8,
+    program_size: u64,
     initial_months: u32,
     deployment_cost: u64,
+    terms_hash: [u8; 32],
+    coupon_code: Option<String>,
   ) -> Result<()> {
     instructions::request_deployment_funds(
       ctx,
       program_hash,
-      service_fee,
-      monthly_fee,
+      nonce,
+      plan_id,
+      program_size,
       initial_months,
       deployment_cost,
+      terms_hash,
+      coupon_code,
     )
   }
 
@@ -64,21 +141,101 @@ pub mod d2d_program_sol {
     instructions::pay_subscription(ctx, request_id, months)
   }
 
+  /// Developer hibernates a program: closes it and keeps the request for redeployment
+  pub fn pause_program<'info>(ctx: Context<'_, '_, '_, 'info, PauseProgram<'info>>) -> Result<()> {
+    instructions::pause_program(ctx)
+  }
+
+  /// Developer switches plan tier; the remaining paid period is prorated
+  pub fn change_subscription_plan(
+    ctx: Context<ChangeSubscriptionPlan>,
+    request_id: [u8; 32],
+    new_plan: u8,
+  ) -> Result<()> {
+    instructions::change_subscription_plan(ctx, request_id, new_plan)
+  }
+
+  /// Developer pays the subscription in the accepted stablecoin (fixed fee per month)
+  pub fn pay_subscription_spl(
+    ctx: Context<PaySubscriptionSpl>,
+    request_id: [u8; 32],
+    months: u32,
+  ) -> Result<()> {
+    instructions::pay_subscription_spl(ctx, request_id, months)
+  }
+
+  /// Read-only: days left, grace state, borrow fees and next renewal (simulate to query)
+  pub fn get_subscription_status(
+    ctx: Context<GetSubscriptionStatus>,
+    request_id: [u8; 32],
+  ) -> Result<SubscriptionStatus> {
+    instructions::get_subscription_status(ctx, request_id)
+  }
+
+  /// Developer cancels an unfunded deploy request
+  /// Refunds the prepaid subscription and the scheduled service fee share
+  pub fn cancel_deploy_request(
+    ctx: Context<CancelDeployRequest>,
+    request_id: [u8; 32],
+  ) -> Result<()> {
+    instructions::cancel_deploy_request(ctx, request_id)
+  }
+
+  /// Developer cancels a deployment stuck in PendingDeployment past the timeout
+  /// Refunds the service fee and prepaid subscription in full; a funded temporary wallet co-signs
+  pub fn cancel_pending_deployment(
+    ctx: Context<CancelPendingDeployment>,
+    request_id: [u8; 32],
+  ) -> Result<()> {
+    instructions::cancel_pending_deployment(ctx, request_id)
+  }
+
+  /// Permissionless: fail a PendingDeployment request past its expiry_at
+  /// Refunds the developer in full; a funded temporary wallet co-signs and returns its lamports
+  pub fn expire_deploy_request(
+    ctx: Context<ExpireDeployRequest>,
+    request_id: [u8; 32],
+  ) -> Result<()> {
+    instructions::expire_deploy_request(ctx, request_id)
+  }
+
   pub fn emergency_pause(ctx: Context<EmergencyPause>, pause: bool) -> Result<()> {
     instructions::emergency_pause(ctx, pause)
   }
 
-  pub fn confirm_deployment_success(
-    ctx: Context<ConfirmDeployment>,
+  /// Admin schedules resuming a paused program after the timelock duration
+  pub fn schedule_unpause(ctx: Context<ScheduleUnpause>) -> Result<()> {
+    instructions::schedule_unpause(ctx)
+  }
+
+  /// Anyone lifts the pause once a scheduled unpause's delay has passed
+  pub fn execute_unpause(ctx: Context<ExecuteUnpause>) -> Result<()> {
+    instructions::execute_unpause(ctx)
+  }
+
+  /// Admin or guardian vetoes a scheduled unpause
+  pub fn veto_unpause(ctx: Context<VetoUnpause>) -> Result<()> {
+    instructions::veto_unpause(ctx)
+  }
+
+  pub fn confirm_deployment_success<'info>(
+    ctx: Context<'_, '_, '_, 'info, ConfirmDeployment<'info>>,
     request_id: [u8; 32],
     deployed_program_id: Pubkey,
     recovered_funds: u64,
+    spent_funds: u64,
   ) -> Result<()> {
-    instructions::confirm_deployment_success(ctx, request_id, deployed_program_id, recovered_funds)
+    instructions::confirm_deployment_success(
+      ctx,
+      request_id,
+      deployed_program_id,
+      recovered_funds,
+      spent_funds,
+    )
   }
 
-  pub fn confirm_deployment_failure(
-    ctx: Context<ConfirmDeployment>,
+  pub fn confirm_deployment_failure<'info>(
+    ctx: Context<'_, '_, '_, 'info, ConfirmDeployment<'info>>,
     request_id: [u8; 32],
     failure_reason: String,
   ) -> Result<()> {
@@ -101,24 +258,37 @@ pub mod d2d_program_sol {
     instructions::fund_temporary_wallet(ctx, request_id, amount)
   }
 
+  /// `terms_hash` must match the current terms on the treasury pool
+  #[allow(clippy::too_many_arguments)]
   pub fn create_deploy_request(
     ctx: Context<CreateDeployRequest>,
     program_hash: [u8; 32],
-    service_fee: u64,
-    monthly_fee: u64,
+    nonce: u32,
+    plan_id: u8,
+    program_size: u64,
     initial_months: u32,
     deployment_cost: u64,
+    terms_hash: [u8; 32],
+    coupon_code: Option<String>,
   ) -> Result<()> {
     instructions::create_deploy_request(
       ctx,
       program_hash,
-      service_fee,
-      monthly_fee,
+      nonce,
+      plan_id,
+      program_size,
       initial_months,
       deployment_cost,
+      terms_hash,
+      coupon_code,
     )
   }
 
+  /// Admin publishes the hash of new terms of service
+  pub fn set_terms_hash(ctx: Context<SetTermsHash>, terms_hash: [u8; 32]) -> Result<()> {
+    instructions::set_terms_hash(ctx, terms_hash)
+  }
+
   pub fn admin_withdraw(ctx: Context<AdminWithdraw>, amount: u64, reason: String) -> Result<()> {
     instructions::admin_withdraw(ctx, amount, reason)
   }
@@ -151,6 +321,15 @@ pub mod d2d_program_sol {
     instructions::credit_fee_to_pool(ctx, fee_reward, fee_platform)
   }
 
+  /// Admin credits a bonus to one staker, claimable via claim_rewards
+  pub fn credit_staker_bonus(
+    ctx: Context<CreditStakerBonus>,
+    staker: Pubkey,
+    amount: u64,
+  ) -> Result<()> {
+    instructions::credit_staker_bonus(ctx, staker, amount)
+  }
+
   pub fn sync_liquid_balance(ctx: Context<SyncLiquidBalance>) -> Result<()> {
     instructions::sync_liquid_balance(ctx)
   }
@@ -159,10 +338,61 @@ pub mod d2d_program_sol {
     instructions::force_rebalance(ctx)
   }
 
+  /// Anyone credits SOL sent directly to the treasury/reward/platform PDAs
+  pub fn absorb_donations(ctx: Context<AbsorbDonations>) -> Result<()> {
+    instructions::absorb_donations(ctx)
+  }
+
+  /// Admin sets where treasury donations are routed (rewards or platform)
+  pub fn set_donation_policy(ctx: Context<SetDonationPolicy>, new_policy: u8) -> Result<()> {
+    instructions::set_donation_policy(ctx, new_policy)
+  }
+
+  /// Admin sets whether managed programs are closed or handed back at subscription end
+  pub fn set_offboarding_policy(ctx: Context<SetOffboardingPolicy>, new_policy: u8) -> Result<()> {
+    instructions::set_offboarding_policy(ctx, new_policy)
+  }
+
+  /// Admin sets the exit fee (bps of borrowed amount) for reclaim_authority
+  pub fn set_authority_exit_fee(ctx: Context<SetAuthorityExitFee>, new_fee_bps: u64) -> Result<()> {
+    instructions::set_authority_exit_fee(ctx, new_fee_bps)
+  }
+
+  /// Admin approves (or revokes) an address managed programs may hand their
+  /// upgrade authority to
+  pub fn set_approved_authority(
+    ctx: Context<SetApprovedAuthority>,
+    authority: Pubkey,
+    enabled: bool,
+  ) -> Result<()> {
+    instructions::set_approved_authority(ctx, authority, enabled)
+  }
+
+  /// Admin sets the service fee refund schedule for new deploy requests
+  pub fn set_service_fee_refund_policy(
+    ctx: Context<SetServiceFeeRefundPolicy>,
+    refund_bps: u64,
+    refund_window: i64,
+  ) -> Result<()> {
+    instructions::set_service_fee_refund_policy(ctx, refund_bps, refund_window)
+  }
+
   pub fn migrate_treasury_pool(ctx: Context<MigrateTreasuryPool>) -> Result<()> {
     instructions::migrate_treasury_pool(ctx)
   }
 
+  /// Admin moves liquid principal from the TreasuryPool state account into the
+  /// treasury vault PDA (repeatable; sweeps stray lamports)
+  pub fn migrate_treasury_vault(ctx: Context<MigrateTreasuryVault>) -> Result<()> {
+    instructions::migrate_treasury_vault(ctx)
+  }
+
+  /// Rewrite a legacy LenderStake account to the canonical BackerDeposit name
+  /// Same PDA - only the discriminator and layout size change
+  pub fn migrate_lender_stake(ctx: Context<MigrateLenderStake>) -> Result<()> {
+    instructions::migrate_lender_stake(ctx)
+  }
+
   pub fn force_reset_deployment(ctx: Context<ForceResetDeployment>) -> Result<()> {
     instructions::force_reset_deployment(ctx)
   }
@@ -171,6 +401,12 @@ pub mod d2d_program_sol {
     instructions::set_guardian(ctx, new_guardian)
   }
 
+  /// Admin assigns or revokes an operational role (pauser, treasurer,
+  /// deployer operator, queue processor)
+  pub fn set_role(ctx: Context<SetRole>, role: Role, new_holder: Pubkey) -> Result<()> {
+    instructions::set_role(ctx, role, new_holder)
+  }
+
   pub fn guardian_pause(ctx: Context<GuardianPause>) -> Result<()> {
     instructions::guardian_pause(ctx)
   }
@@ -183,6 +419,150 @@ pub mod d2d_program_sol {
     instructions::set_daily_limit(ctx, new_limit)
   }
 
+  /// Admin sets the per-transaction stake cap and per-wallet daily stake limit (0 = unlimited)
+  pub fn set_stake_limits(
+    ctx: Context<SetStakeLimits>,
+    max_stake_per_tx: u64,
+    daily_stake_limit: u64,
+  ) -> Result<()> {
+    instructions::set_stake_limits(ctx, max_stake_per_tx, daily_stake_limit)
+  }
+
+  /// Admin caps the share of the pool a single staker may hold (0 = no cap)
+  pub fn set_max_pool_share(ctx: Context<SetMaxPoolShare>, new_max_share_bps: u64) -> Result<()> {
+    instructions::set_max_pool_share(ctx, new_max_share_bps)
+  }
+
+  /// Admin turns the stake_sol deposit fee on or off (off by default)
+  pub fn set_deposit_fee_mode(ctx: Context<SetDepositFeeMode>, enabled: bool) -> Result<()> {
+    instructions::set_deposit_fee_mode(ctx, enabled)
+  }
+
+  /// Admin sets min/max bounds for deploy request parameters (max 0 = unbounded)
+  pub fn set_request_bounds(ctx: Context<SetRequestBounds>, bounds: RequestBounds) -> Result<()> {
+    instructions::set_request_bounds(ctx, bounds)
+  }
+
+  /// Admin adds or updates a subscription plan tier (size limit, fees, included upgrades)
+  pub fn set_plan_tier(
+    ctx: Context<SetPlanTier>,
+    plan_id: u8,
+    max_program_size: u64,
+    service_fee: u64,
+    monthly_fee: u64,
+    included_upgrades: u32,
+    enabled: bool,
+  ) -> Result<()> {
+    instructions::set_plan_tier(
+      ctx,
+      plan_id,
+      max_program_size,
+      service_fee,
+      monthly_fee,
+      included_upgrades,
+      enabled,
+    )
+  }
+
+  /// Admin creates, updates or withdraws a service fee coupon
+  pub fn set_coupon(
+    ctx: Context<SetCoupon>,
+    code_hash: [u8; 32],
+    discount_bps: u64,
+    expires_at: i64,
+    max_uses: u32,
+    is_active: bool,
+  ) -> Result<()> {
+    instructions::set_coupon(
+      ctx,
+      code_hash,
+      discount_bps,
+      expires_at,
+      max_uses,
+      is_active,
+    )
+  }
+
+  /// Admin sets the discount curve for multi-month subscription prepayment
+  pub fn set_prepay_discounts(
+    ctx: Context<SetPrepayDiscounts>,
+    steps: Vec<PrepayDiscount>,
+  ) -> Result<()> {
+    instructions::set_prepay_discounts(ctx, steps)
+  }
+
+  /// Admin caps pending plus live deployments per developer (0 = unlimited)
+  pub fn set_developer_quota(
+    ctx: Context<SetDeveloperQuota>,
+    max_active_deployments: u32,
+  ) -> Result<()> {
+    instructions::set_developer_quota(ctx, max_active_deployments)
+  }
+
+  /// Admin sets a plan tier's monthly upgrade limit and overage fee
+  pub fn set_plan_upgrade_pricing(
+    ctx: Context<SetPlanUpgradePricing>,
+    plan_id: u8,
+    max_upgrades_per_month: u32,
+    upgrade_overage_fee: u64,
+  ) -> Result<()> {
+    instructions::set_plan_upgrade_pricing(
+      ctx,
+      plan_id,
+      max_upgrades_per_month,
+      upgrade_overage_fee,
+    )
+  }
+
+  /// Admin proposes the platform share (bps) of subscription revenue (timelocked)
+  pub fn propose_subscription_split(
+    ctx: Context<ProposeSubscriptionSplit>,
+    platform_bps: u64,
+  ) -> Result<()> {
+    instructions::propose_subscription_split(ctx, platform_bps)
+  }
+
+  /// Admin applies the proposed subscription split once the timelock has passed
+  pub fn apply_subscription_split(ctx: Context<ApplySubscriptionSplit>) -> Result<()> {
+    instructions::apply_subscription_split(ctx)
+  }
+
+  /// Admin or guardian cancels a pending subscription split change
+  pub fn cancel_subscription_split(ctx: Context<CancelSubscriptionSplit>) -> Result<()> {
+    instructions::cancel_subscription_split(ctx)
+  }
+
+  /// Admin sets whether emergency_unstake may draw on queue-reserved liquidity
+  /// (and the queue jump fee charged when it does)
+  pub fn set_emergency_exit_policy(
+    ctx: Context<SetEmergencyExitPolicy>,
+    new_policy: u8,
+    queue_jump_fee_bps: u64,
+  ) -> Result<()> {
+    instructions::set_emergency_exit_policy(ctx, new_policy, queue_jump_fee_bps)
+  }
+
+  /// Admin sets the tolerance for reconciling funded vs. recovered + spent lamports
+  pub fn set_funding_variance_tolerance(
+    ctx: Context<SetFundingVarianceTolerance>,
+    tolerance: u64,
+  ) -> Result<()> {
+    instructions::set_funding_variance_tolerance(ctx, tolerance)
+  }
+
+  /// Admin sets the haircut (bps) on emergency_unstake, credited to the reward pool
+  pub fn set_emergency_haircut(ctx: Context<SetEmergencyHaircut>, haircut_bps: u64) -> Result<()> {
+    instructions::set_emergency_haircut(ctx, haircut_bps)
+  }
+
+  /// Admin sets whether emergency_unstake remains available during emergency pause
+  pub fn set_emergency_exit_during_pause(
+    ctx: Context<SetEmergencyExitDuringPause>,
+    allow: bool,
+  ) -> Result<()> {
+    instructions::set_emergency_exit_during_pause(ctx, allow)
+  }
+
   pub fn initiate_withdrawal(
     ctx: Context<InitiateWithdrawal>,
     withdrawal_type: states::WithdrawalType,
@@ -217,16 +597,91 @@ pub mod d2d_program_sol {
 
   /// Developer upgrades their program via PDA proxy
   /// No platform approval needed - trustless upgrade if subscription is active
-  pub fn proxy_upgrade_program(ctx: Context<ProxyUpgradeProgram>) -> Result<()> {
-    instructions::proxy_upgrade_program(ctx)
+  pub fn proxy_upgrade_program(
+    ctx: Context<ProxyUpgradeProgram>,
+    expected_buffer_hash: Option<[u8; 32]>,
+  ) -> Result<()> {
+    instructions::proxy_upgrade_program(ctx, expected_buffer_hash)
+  }
+
+  /// Developer restores an earlier release from a re-uploaded buffer
+  pub fn proxy_rollback_program(ctx: Context<ProxyRollbackProgram>) -> Result<()> {
+    instructions::proxy_rollback_program(ctx)
+  }
+
+  /// Program owner adds a co-developer allowed to call proxy_upgrade_program
+  pub fn add_upgrade_signer(ctx: Context<AddUpgradeSigner>, signer: Pubkey) -> Result<()> {
+    instructions::add_upgrade_signer(ctx, signer)
+  }
+
+  /// Program owner removes a co-developer upgrade signer
+  pub fn remove_upgrade_signer(ctx: Context<RemoveUpgradeSigner>, signer: Pubkey) -> Result<()> {
+    instructions::remove_upgrade_signer(ctx, signer)
+  }
+
+  /// Developer announces an upgrade: the buffer is locked under the authority
+  /// PDA until execute_after
+  pub fn schedule_upgrade(ctx: Context<ScheduleUpgrade>, execute_after: i64) -> Result<()> {
+    instructions::schedule_upgrade(ctx, execute_after)
+  }
+
+  /// Developer applies a scheduled upgrade after its notice period
+  pub fn execute_scheduled_upgrade(ctx: Context<ExecuteScheduledUpgrade>) -> Result<()> {
+    instructions::execute_scheduled_upgrade(ctx)
+  }
+
+  /// Developer cancels a scheduled upgrade and gets the buffer back
+  pub fn cancel_scheduled_upgrade(ctx: Context<CancelScheduledUpgrade>) -> Result<()> {
+    instructions::cancel_scheduled_upgrade(ctx)
+  }
+
+  /// Grow a ManagedProgram account to the current layout (permissionless)
+  pub fn migrate_managed_program(ctx: Context<MigrateManagedProgram>) -> Result<()> {
+    instructions::migrate_managed_program(ctx)
+  }
+
+  /// Developer grows the ProgramData of a managed program, paying the extra rent
+  pub fn proxy_extend_program(
+    ctx: Context<ProxyExtendProgram>,
+    request_id: [u8; 32],
+    additional_bytes: u32,
+  ) -> Result<()> {
+    instructions::proxy_extend_program(ctx, request_id, additional_bytes)
+  }
+
+  /// Developer borrows treasury SOL to fund an upgrade buffer
+  /// Repaid from the buffer rent via the loan PDA spill account
+  pub fn borrow_buffer_rent(ctx: Context<BorrowBufferRent>, amount: u64) -> Result<()> {
+    instructions::borrow_buffer_rent(ctx, amount)
+  }
+
+  /// Repay a buffer rent loan from the loan PDA balance (plus optional top-up)
+  pub fn settle_buffer_rent_loan(ctx: Context<SettleBufferRentLoan>, top_up: u64) -> Result<()> {
+    instructions::settle_buffer_rent_loan(ctx, top_up)
+  }
+
+  /// Admin sets the max buffer rent loan per upgrade (0 disables)
+  pub fn set_max_buffer_rent_loan(
+    ctx: Context<SetMaxBufferRentLoan>,
+    max_buffer_rent_loan: u64,
+  ) -> Result<()> {
+    instructions::set_max_buffer_rent_loan(ctx, max_buffer_rent_loan)
   }
 
   /// Admin reclaims program rent when subscription expires
   /// Returns SOL to treasury pool
-  pub fn reclaim_program_rent(ctx: Context<ReclaimProgramRent>) -> Result<()> {
+  pub fn reclaim_program_rent<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReclaimProgramRent<'info>>,
+  ) -> Result<()> {
     instructions::reclaim_program_rent(ctx)
   }
 
+  /// Admin/guardian checks managed programs' upgrade authorities still
+  /// match their authority PDAs; emits mismatch events on drift
+  pub fn verify_upgrade_authorities(ctx: Context<VerifyUpgradeAuthorities>) -> Result<()> {
+    instructions::verify_upgrade_authorities(ctx)
+  }
+
   // ========================================================================
   // Developer Escrow & Auto-Renewal Instructions
   // ========================================================================
@@ -241,16 +696,116 @@ pub mod d2d_program_sol {
     instructions::deposit_escrow_sol(ctx, amount)
   }
 
+  /// Developer locks SOL collateral against a deployment to cut its borrow fee
+  pub fn lock_developer_collateral(
+    ctx: Context<LockDeveloperCollateral>,
+    request_id: [u8; 32],
+    amount: u64,
+  ) -> Result<()> {
+    instructions::lock_developer_collateral(ctx, request_id, amount)
+  }
+
+  /// Developer recovers collateral after closure, net of any unpaid debt
+  pub fn release_developer_collateral(
+    ctx: Context<ReleaseDeveloperCollateral>,
+    request_id: [u8; 32],
+  ) -> Result<()> {
+    instructions::release_developer_collateral(ctx, request_id)
+  }
+
   /// Developer withdraws SOL from escrow
   pub fn withdraw_escrow_sol(ctx: Context<WithdrawEscrowSol>, amount: u64) -> Result<()> {
     instructions::withdraw_escrow_sol(ctx, amount)
   }
 
+  /// Developer deposits USDC/USDT into the escrow's token vault
+  pub fn deposit_escrow_token(ctx: Context<DepositEscrowToken>, amount: u64) -> Result<()> {
+    instructions::deposit_escrow_token(ctx, amount)
+  }
+
+  /// Developer withdraws USDC/USDT from the escrow's token vault
+  pub fn withdraw_escrow_token(ctx: Context<WithdrawEscrowToken>, amount: u64) -> Result<()> {
+    instructions::withdraw_escrow_token(ctx, amount)
+  }
+
+  /// Developer approves a standing allowance for crank top-ups of the escrow
+  pub fn enable_escrow_top_up(
+    ctx: Context<EnableEscrowTopUp>,
+    top_up_amount: u64,
+    allowance: u64,
+    min_balance_alert: u64,
+  ) -> Result<()> {
+    instructions::enable_escrow_top_up(ctx, top_up_amount, allowance, min_balance_alert)
+  }
+
+  /// Developer revokes the top-up allowance
+  pub fn disable_escrow_top_up(ctx: Context<DisableEscrowTopUp>) -> Result<()> {
+    instructions::disable_escrow_top_up(ctx)
+  }
+
+  /// Anyone refills an escrow below its alert threshold from the approved allowance
+  pub fn top_up_escrow(ctx: Context<TopUpEscrow>) -> Result<()> {
+    instructions::top_up_escrow(ctx)
+  }
+
+  /// Admin sets the USDC/USDT mints accepted for escrow deposits (each set once)
+  pub fn set_escrow_token_mints(
+    ctx: Context<SetEscrowTokenMints>,
+    usdc_mint: Pubkey,
+    usdt_mint: Pubkey,
+  ) -> Result<()> {
+    instructions::set_escrow_token_mints(ctx, usdc_mint, usdt_mint)
+  }
+
+  /// Developer sets the escrow alert threshold, monthly spending cap and auto-deduct limit
+  pub fn configure_escrow(
+    ctx: Context<ConfigureEscrow>,
+    min_balance_alert: u64,
+    max_monthly_spend: u64,
+    max_auto_deduct_per_month: u64,
+  ) -> Result<()> {
+    instructions::configure_escrow(
+      ctx,
+      min_balance_alert,
+      max_monthly_spend,
+      max_auto_deduct_per_month,
+    )
+  }
+
+  /// Developer caps what automatic renewals of one program may take from the escrow (0 = no cap)
+  pub fn set_escrow_budget(
+    ctx: Context<SetEscrowBudget>,
+    request_id: [u8; 32],
+    limit: u64,
+  ) -> Result<()> {
+    instructions::set_escrow_budget(ctx, request_id, limit)
+  }
+
+  /// Grow a DeveloperEscrow account to the current layout (permissionless)
+  pub fn migrate_developer_escrow(ctx: Context<MigrateDeveloperEscrow>) -> Result<()> {
+    instructions::migrate_developer_escrow(ctx)
+  }
+
+  /// Developer closes their escrow, sweeping SOL and rent back to their wallet
+  pub fn close_escrow<'info>(ctx: Context<'_, '_, 'info, 'info, CloseEscrow<'info>>) -> Result<()> {
+    instructions::close_escrow(ctx)
+  }
+
   /// Developer toggles auto-renewal on/off
   pub fn toggle_auto_renew(ctx: Context<ToggleAutoRenew>, enabled: bool) -> Result<()> {
     instructions::toggle_auto_renew(ctx, enabled)
   }
 
+  /// Developer toggles auto-renewal for one program and caps months per renewal
+  pub fn set_program_auto_renew(
+    ctx: Context<SetProgramAutoRenew>,
+    request_id: [u8; 32],
+    enabled: bool,
+    max_months_per_renewal: u32,
+  ) -> Result<()> {
+    instructions::set_program_auto_renew(ctx, request_id, enabled, max_months_per_renewal)
+  }
+
   /// Developer sets preferred token type for auto-renewal (0=SOL, 1=USDC, 2=USDT)
   pub fn set_preferred_token(ctx: Context<SetPreferredToken>, token_type: u8) -> Result<()> {
     instructions::set_preferred_token(ctx, token_type)
@@ -265,6 +820,51 @@ pub mod d2d_program_sol {
     instructions::auto_renew_subscription(ctx, request_id, months)
   }
 
+  /// Read-only: would auto_renew_subscription succeed now, and if not why (simulate to query)
+  pub fn check_auto_renew(
+    ctx: Context<CheckAutoRenew>,
+    request_id: [u8; 32],
+    months: u32,
+  ) -> Result<AutoRenewCheck> {
+    instructions::check_auto_renew(ctx, request_id, months)
+  }
+
+  /// Developer (or anyone within the window before expiry) renews from escrow
+  pub fn renew_from_escrow(
+    ctx: Context<RenewFromEscrow>,
+    request_id: [u8; 32],
+    months: u32,
+  ) -> Result<()> {
+    instructions::renew_from_escrow(ctx, request_id, months)
+  }
+
+  /// Admin sets how close to expiry anyone may trigger renew_from_escrow (0 = developer only)
+  pub fn set_permissionless_renewal_window(
+    ctx: Context<SetPermissionlessRenewalWindow>,
+    new_window: i64,
+  ) -> Result<()> {
+    instructions::set_permissionless_renewal_window(ctx, new_window)
+  }
+
+  /// Permissionless crank: emit SubscriptionExpiring for a subscription close to expiry
+  pub fn emit_expiry_warning(ctx: Context<EmitExpiryWarning>, request_id: [u8; 32]) -> Result<()> {
+    instructions::emit_expiry_warning(ctx, request_id)
+  }
+
+  /// Permissionless crank: collect accrued 1% monthly borrow fees from the developer's SOL escrow
+  pub fn collect_borrow_fee(ctx: Context<CollectBorrowFee>, request_id: [u8; 32]) -> Result<()> {
+    instructions::collect_borrow_fee(ctx, request_id)
+  }
+
+  /// Admin sets the expiry warning window and minimum spacing (0 window = disabled)
+  pub fn set_expiry_warning_policy(
+    ctx: Context<SetExpiryWarningPolicy>,
+    new_window: i64,
+    new_interval: i64,
+  ) -> Result<()> {
+    instructions::set_expiry_warning_policy(ctx, new_window, new_interval)
+  }
+
   /// Admin starts grace period for expired subscription
   pub fn start_grace_period(ctx: Context<StartGracePeriod>, request_id: [u8; 32]) -> Result<()> {
     instructions::start_grace_period(ctx, request_id)
@@ -278,6 +878,46 @@ pub mod d2d_program_sol {
     instructions::close_expired_program(ctx, request_id)
   }
 
+  /// Admin returns upgrade authority to the developer at subscription end
+  /// Settles remaining debt from the developer's escrow
+  pub fn offboard_managed_program<'info>(
+    ctx: Context<'_, '_, '_, 'info, OffboardManagedProgram<'info>>,
+    request_id: [u8; 32],
+  ) -> Result<()> {
+    instructions::offboard_managed_program(ctx, request_id)
+  }
+
+  /// Developer buys back the upgrade authority: repays the debt plus the exit fee
+  pub fn reclaim_authority<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReclaimAuthority<'info>>,
+    request_id: [u8; 32],
+  ) -> Result<()> {
+    instructions::reclaim_authority(ctx, request_id)
+  }
+
+  /// Developer proposes a timelocked hand-over of the upgrade authority to an
+  /// approved address (e.g. a multisig)
+  pub fn propose_authority_transfer(
+    ctx: Context<ProposeAuthorityTransfer>,
+    request_id: [u8; 32],
+    new_authority: Pubkey,
+  ) -> Result<()> {
+    instructions::propose_authority_transfer(ctx, request_id, new_authority)
+  }
+
+  /// Developer executes a proposed authority transfer after the timelock
+  pub fn execute_authority_transfer<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteAuthorityTransfer<'info>>,
+    request_id: [u8; 32],
+  ) -> Result<()> {
+    instructions::execute_authority_transfer(ctx, request_id)
+  }
+
+  /// Developer cancels a pending authority transfer
+  pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    instructions::cancel_authority_transfer(ctx)
+  }
+
   // ========================================================================
   // Withdrawal Queue Instructions (Economic Model Fix)
   // ========================================================================
@@ -292,13 +932,36 @@ pub mod d2d_program_sol {
     instructions::cancel_queued_withdrawal(ctx)
   }
 
-  /// Admin processes a queued withdrawal when liquidity becomes available
+  /// Anyone expires a queue entry older than max_queue_age (remainder is re-staked)
+  pub fn expire_queue_entry(ctx: Context<ExpireQueueEntry>, queue_position: u32) -> Result<()> {
+    instructions::expire_queue_entry(ctx, queue_position)
+  }
+
+  /// Anyone processes the head of the queue when liquidity becomes available
+  /// Admin/guardian may set `force` to process out of FIFO order
   /// Processes one entry per call - invoke repeatedly for batch processing
   pub fn process_withdrawal_queue(
     ctx: Context<ProcessWithdrawalQueue>,
     queue_position: u32,
+    force: bool,
   ) -> Result<()> {
-    instructions::process_withdrawal_queue(ctx, queue_position)
+    instructions::process_withdrawal_queue(ctx, queue_position, force)
+  }
+
+  /// Anyone closes finished queue entries (rent back to each staker) and
+  /// advances the head past cancelled ones
+  pub fn compact_withdrawal_queue(ctx: Context<CompactWithdrawalQueue>) -> Result<()> {
+    instructions::compact_withdrawal_queue(ctx)
+  }
+
+  /// Admin sets the tip paid to permissionless queue crankers (bps of processed amount)
+  pub fn set_keeper_tip(ctx: Context<SetKeeperTip>, new_tip_bps: u64) -> Result<()> {
+    instructions::set_keeper_tip(ctx, new_tip_bps)
+  }
+
+  /// Admin sets max queue age in seconds before entries can be expired (0 = never)
+  pub fn set_max_queue_age(ctx: Context<SetMaxQueueAge>, new_max_age: i64) -> Result<()> {
+    instructions::set_max_queue_age(ctx, new_max_age)
   }
 
   // ========================================================================
@@ -313,4 +976,379 @@ pub mod d2d_program_sol {
   ) -> Result<()> {
     instructions::distribute_pending_rewards(ctx, distribution_percentage_bps)
   }
+
+  /// Admin sets the linear vesting period for duration bonuses (0 = no vesting)
+  pub fn set_bonus_vesting_period(
+    ctx: Context<SetBonusVestingPeriod>,
+    vesting_period: i64,
+  ) -> Result<()> {
+    instructions::set_bonus_vesting_period(ctx, vesting_period)
+  }
+
+  // === GOVERNANCE SNAPSHOTS ===
+
+  /// Admin/crank records pool stake (and a per-staker merkle root) for the current epoch
+  pub fn snapshot_stakes(
+    ctx: Context<SnapshotStakes>,
+    epoch: u64,
+    merkle_root: [u8; 32],
+    staker_count: u32,
+  ) -> Result<()> {
+    instructions::snapshot_stakes(ctx, epoch, merkle_root, staker_count)
+  }
+
+  /// View: verify a staker's weight in an epoch snapshot (return data, CPI-friendly)
+  pub fn get_voting_power(
+    ctx: Context<GetVotingPower>,
+    epoch: u64,
+    staker: Pubkey,
+    weight: u64,
+    proof: Vec<[u8; 32]>,
+  ) -> Result<VotingPower> {
+    instructions::get_voting_power(ctx, epoch, staker, weight, proof)
+  }
+
+  // === LST STAKING ===
+
+  /// Admin accepts an LST mint (creating its vault) or refreshes its oracle rate
+  pub fn set_lst_config(
+    ctx: Context<SetLstConfig>,
+    rate: u64,
+    max_rate_age: i64,
+    enabled: bool,
+  ) -> Result<()> {
+    instructions::set_lst_config(ctx, rate, max_rate_age, enabled)
+  }
+
+  /// Staker deposits LST tokens, credited at the oracle rate
+  pub fn stake_from_lst(ctx: Context<StakeFromLst>, token_amount: u64) -> Result<()> {
+    instructions::stake_from_lst(ctx, token_amount)
+  }
+
+  /// Admin withdraws vault tokens and deposits the SOL they were redeemed for
+  pub fn redeem_lst_vault<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemLstVault<'info>>,
+    token_amount: u64,
+    sol_amount: u64,
+  ) -> Result<()> {
+    instructions::redeem_lst_vault(ctx, token_amount, sol_amount)
+  }
+
+  // === REWARD HISTORY ===
+
+  /// Staker creates a reward history; claims passing it record the last 16 payouts
+  pub fn init_reward_history(ctx: Context<InitRewardHistory>) -> Result<()> {
+    instructions::init_reward_history(ctx)
+  }
+
+  // === STAKE POSITIONS ===
+
+  /// Staker carves part of their position into a new indexed position
+  pub fn split_stake_position(ctx: Context<SplitStakePosition>, amount: u64) -> Result<()> {
+    instructions::split_stake_position(ctx, amount)
+  }
+
+  /// Staker consolidates two of their positions, closing the source PDA
+  pub fn merge_stake_positions(ctx: Context<MergeStakePositions>) -> Result<()> {
+    instructions::merge_stake_positions(ctx)
+  }
+
+  // === STAKE COLLATERAL ===
+
+  /// Admin registers (or enables/disables) a partner lending program
+  pub fn register_collateral_partner(
+    ctx: Context<RegisterCollateralPartner>,
+    partner_program: Pubkey,
+    enabled: bool,
+  ) -> Result<()> {
+    instructions::register_collateral_partner(ctx, partner_program, enabled)
+  }
+
+  /// Partner program (via CPI) and staker pledge a position as collateral
+  pub fn pledge_stake(ctx: Context<PledgeStake>) -> Result<()> {
+    instructions::pledge_stake(ctx)
+  }
+
+  /// Partner program (via CPI) releases a pledged position
+  pub fn release_stake_pledge(ctx: Context<ReleaseStakePledge>) -> Result<()> {
+    instructions::release_stake_pledge(ctx)
+  }
+
+  // === AUTO UNSTAKE ===
+
+  /// Staker schedules when their position may be queued for withdrawal (0 = clear)
+  pub fn set_auto_unstake(ctx: Context<SetAutoUnstake>, auto_unstake_at: i64) -> Result<()> {
+    instructions::set_auto_unstake(ctx, auto_unstake_at)
+  }
+
+  /// Permissionless crank queues a position whose auto_unstake_at has passed
+  pub fn trigger_auto_unstake(ctx: Context<TriggerAutoUnstake>) -> Result<()> {
+    instructions::trigger_auto_unstake(ctx)
+  }
+
+  // === WATCHTOWERS ===
+
+  /// Anyone registers as a watchtower
+  pub fn register_watchtower(ctx: Context<RegisterWatchtower>) -> Result<()> {
+    instructions::register_watchtower(ctx)
+  }
+
+  /// Watchtower submits an alert; validated alerts pay a platform pool bounty
+  pub fn submit_watchtower_alert(
+    ctx: Context<SubmitWatchtowerAlert>,
+    alert: WatchtowerAlert,
+  ) -> Result<()> {
+    instructions::submit_watchtower_alert(ctx, alert)
+  }
+
+  /// Admin sets the watchtower bounty and per-kind alert cooldown
+  pub fn set_watchtower_bounty(
+    ctx: Context<SetWatchtowerBounty>,
+    new_bounty: u64,
+    new_cooldown: i64,
+  ) -> Result<()> {
+    instructions::set_watchtower_bounty(ctx, new_bounty, new_cooldown)
+  }
+
+  // === DUST SWEEP ===
+
+  /// Crank queues an inactive dust position for withdrawal to its staker
+  pub fn sweep_dust_position(ctx: Context<SweepDustPosition>) -> Result<()> {
+    instructions::sweep_dust_position(ctx)
+  }
+
+  /// Crank closes a swept position once paid out, refunding rent to the staker
+  pub fn close_swept_position(ctx: Context<CloseSweptPosition>) -> Result<()> {
+    instructions::close_swept_position(ctx)
+  }
+
+  // === UNSTAKE COMMITMENTS ===
+
+  /// Staker declares a large unstake; unstake_sol executes it after the delay
+  pub fn commit_unstake(ctx: Context<CommitUnstake>, amount: u64) -> Result<()> {
+    instructions::commit_unstake(ctx, amount)
+  }
+
+  /// Staker drops a pending unstake commitment
+  pub fn cancel_unstake_commitment(ctx: Context<CancelUnstakeCommitment>) -> Result<()> {
+    instructions::cancel_unstake_commitment(ctx)
+  }
+
+  /// Admin sets the large-unstake threshold (0 = all instant) and commit delay
+  pub fn set_unstake_commitment_policy(
+    ctx: Context<SetUnstakeCommitmentPolicy>,
+    new_threshold_bps: u64,
+    new_commit_delay: i64,
+  ) -> Result<()> {
+    instructions::set_unstake_commitment_policy(ctx, new_threshold_bps, new_commit_delay)
+  }
+
+  // === DEVELOPER IDENTITY ===
+
+  /// Developer links a hash of their off-chain profile (clears attestation on change)
+  pub fn link_developer_identity(
+    ctx: Context<LinkDeveloperIdentity>,
+    profile_hash: [u8; 32],
+  ) -> Result<()> {
+    instructions::link_developer_identity(ctx, profile_hash)
+  }
+
+  /// Developer removes their identity link and reclaims its rent
+  pub fn unlink_developer_identity(ctx: Context<UnlinkDeveloperIdentity>) -> Result<()> {
+    instructions::unlink_developer_identity(ctx)
+  }
+
+  /// Identity registrar attests or revokes a developer's linked profile
+  pub fn attest_developer_identity(
+    ctx: Context<AttestDeveloperIdentity>,
+    profile_hash: [u8; 32],
+    attested: bool,
+  ) -> Result<()> {
+    instructions::attest_developer_identity(ctx, profile_hash, attested)
+  }
+
+  /// Admin sets the identity registrar and the verified buffer rent loan cap
+  pub fn set_identity_policy(
+    ctx: Context<SetIdentityPolicy>,
+    new_registrar: Pubkey,
+    new_verified_max_buffer_rent_loan: u64,
+  ) -> Result<()> {
+    instructions::set_identity_policy(ctx, new_registrar, new_verified_max_buffer_rent_loan)
+  }
+
+  // === BOOST CAMPAIGNS ===
+
+  /// Admin creates a reward boost campaign funded from the platform pool
+  pub fn create_boost_campaign(
+    ctx: Context<CreateBoostCampaign>,
+    campaign_id: u64,
+    start_at: i64,
+    end_at: i64,
+    boost_bps: u64,
+    eligibility: CampaignEligibility,
+    budget: u64,
+  ) -> Result<()> {
+    instructions::create_boost_campaign(
+      ctx,
+      campaign_id,
+      start_at,
+      end_at,
+      boost_bps,
+      eligibility,
+      budget,
+    )
+  }
+
+  /// Staker enrolls their position in an active campaign
+  pub fn enroll_in_campaign(ctx: Context<EnrollInCampaign>) -> Result<()> {
+    instructions::enroll_in_campaign(ctx)
+  }
+
+  /// Staker claims the boost accrued during the campaign window
+  pub fn claim_campaign_boost(ctx: Context<ClaimCampaignBoost>) -> Result<()> {
+    instructions::claim_campaign_boost(ctx)
+  }
+
+  /// Admin closes a campaign after its claim grace period, returning unspent budget
+  pub fn close_boost_campaign(ctx: Context<CloseBoostCampaign>) -> Result<()> {
+    instructions::close_boost_campaign(ctx)
+  }
+
+  // === REWARD POOL SOLVENCY ===
+
+  /// Admin reports reward pool lamport drift and optionally writes it down
+  pub fn reconcile_reward_pool(ctx: Context<ReconcileRewardPool>, write_down: bool) -> Result<()> {
+    instructions::reconcile_reward_pool(ctx, write_down)
+  }
+
+  // === BOOST NFTS ===
+
+  /// Admin sets the boost NFT collection and the duration weight boost it grants
+  pub fn set_boost_nft_policy(
+    ctx: Context<SetBoostNftPolicy>,
+    new_collection: Pubkey,
+    new_boost_bps: u64,
+  ) -> Result<()> {
+    instructions::set_boost_nft_policy(ctx, new_collection, new_boost_bps)
+  }
+
+  /// Staker, or anyone once the NFT has left the staker, drops a position's NFT boost
+  pub fn revoke_nft_boost(ctx: Context<RevokeNftBoost>) -> Result<()> {
+    instructions::revoke_nft_boost(ctx)
+  }
+
+  // === STABLECOIN SUBSCRIPTIONS ===
+
+  /// Admin accepts a stablecoin mint for subscriptions (creating the protocol vault)
+  pub fn set_subscription_stablecoin(ctx: Context<SetSubscriptionStablecoin>) -> Result<()> {
+    instructions::set_subscription_stablecoin(ctx)
+  }
+
+  /// Admin sets a request's fixed stablecoin monthly fee (0 = SOL only)
+  pub fn set_stablecoin_monthly_fee(
+    ctx: Context<SetStablecoinMonthlyFee>,
+    request_id: [u8; 32],
+    new_fee: u64,
+  ) -> Result<()> {
+    instructions::set_stablecoin_monthly_fee(ctx, request_id, new_fee)
+  }
+
+  // === STRESS TESTING ===
+
+  /// Guardian stores a stress test of exits and deployment defaults in the report PDA
+  pub fn simulate_drawdown(
+    ctx: Context<SimulateDrawdown>,
+    exit_bps: u64,
+    default_recovery_bps: u64,
+  ) -> Result<()> {
+    instructions::simulate_drawdown(ctx, exit_bps, default_recovery_bps)
+  }
+
+  // === DECENTRALIZATION ===
+
+  /// Admin permanently retires the admin-only paths (one-way)
+  pub fn decentralize(ctx: Context<Decentralize>) -> Result<()> {
+    instructions::decentralize(ctx)
+  }
+
+  // === ADMIN COUNCIL ===
+
+  /// Admin hands high-risk operations to an M-of-N council (one-way)
+  pub fn initialize_admin_council(
+    ctx: Context<InitializeAdminCouncil>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+  ) -> Result<()> {
+    instructions::initialize_admin_council(ctx, members, threshold)
+  }
+
+  /// Council member proposes a high-risk action (counts as their approval)
+  pub fn propose_council_action(
+    ctx: Context<ProposeCouncilAction>,
+    action: CouncilAction,
+  ) -> Result<()> {
+    instructions::propose_council_action(ctx, action)
+  }
+
+  /// Council member approves an open proposal
+  pub fn approve_council_proposal(
+    ctx: Context<ApproveCouncilProposal>,
+    proposal_id: u64,
+  ) -> Result<()> {
+    instructions::approve_council_proposal(ctx, proposal_id)
+  }
+
+  /// Council member executes a proposal that reached the threshold
+  pub fn execute_council_proposal(
+    ctx: Context<ExecuteCouncilProposal>,
+    proposal_id: u64,
+  ) -> Result<()> {
+    instructions::execute_council_proposal(ctx, proposal_id)
+  }
+
+  /// Proposer withdraws a proposal, or a member clears an expired/stale one
+  pub fn cancel_council_proposal(
+    ctx: Context<CancelCouncilProposal>,
+    proposal_id: u64,
+  ) -> Result<()> {
+    instructions::cancel_council_proposal(ctx, proposal_id)
+  }
+
+  // === STAKER INSURANCE ===
+
+  /// Admin creates the staker insurance fund
+  pub fn initialize_insurance_fund(
+    ctx: Context<InitializeInsuranceFund>,
+    premium_bps: u64,
+    loss_threshold_bps: u64,
+  ) -> Result<()> {
+    instructions::initialize_insurance_fund(ctx, premium_bps, loss_threshold_bps)
+  }
+
+  /// Admin sets the insurance premium and loss threshold
+  pub fn set_insurance_policy(
+    ctx: Context<SetInsurancePolicy>,
+    premium_bps: u64,
+    loss_threshold_bps: u64,
+  ) -> Result<()> {
+    instructions::set_insurance_policy(ctx, premium_bps, loss_threshold_bps)
+  }
+
+  /// Staker buys coverage for part of their deposit (one-time premium)
+  pub fn opt_in_insurance(ctx: Context<OptInInsurance>, coverage_amount: u64) -> Result<()> {
+    instructions::opt_in_insurance(ctx, coverage_amount)
+  }
+
+  /// Admin records a loss event for a closed deployment below the loss threshold
+  pub fn record_insurance_loss(
+    ctx: Context<RecordInsuranceLoss>,
+    request_id: [u8; 32],
+  ) -> Result<()> {
+    instructions::record_insurance_loss(ctx, request_id)
+  }
+
+  /// Covered staker claims pro-rata loss compensation
+  pub fn claim_insurance(ctx: Context<ClaimInsurance>) -> Result<()> {
+    instructions::claim_insurance(ctx)
+  }
 }
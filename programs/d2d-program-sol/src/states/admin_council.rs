@@ -0,0 +1,495 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  states::{Coupon, InsuranceFund, LstConfig, PrepayDiscount, Role, TreasuryPool, WithdrawalType},
+};
+
+/// M-of-N council that must approve high-risk admin operations
+///
+/// Created once by the admin; from then on the wrapped operations are only
+/// reachable through a council proposal. Membership changes are themselves
+/// council proposals.
+#[account]
+#[derive(InitSpace)]
+pub struct AdminCouncil {
+  /// Members in slots [0, member_count); the rest are default
+  pub members: [Pubkey; AdminCouncil::MAX_MEMBERS],
+  pub member_count: u8,
+  /// Approvals required to execute a proposal
+  pub threshold: u8,
+  /// Id of the next proposal (proposal PDAs are seeded by id)
+  pub proposal_count: u64,
+  /// Incremented on every membership change; proposals from an older
+  /// council can no longer be approved or executed
+  pub epoch: u32,
+  pub bump: u8,
+}
+
+impl AdminCouncil {
+  pub const PREFIX_SEED: &'static [u8] = b"admin_council";
+  pub const MAX_MEMBERS: usize = 10;
+
+  /// Replace the member set, validating the threshold
+  pub fn set_members(&mut self, members: &[Pubkey], threshold: u8) -> Result<()> {
+    Self::validate_members(members, threshold)?;
+    self.members = [Pubkey::default(); Self::MAX_MEMBERS];
+    self.members[..members.len()].copy_from_slice(members);
+    self.member_count = members.len() as u8;
+    self.threshold = threshold;
+    Ok(())
+  }
+
+  /// 1..=MAX_MEMBERS distinct non-default members and 1 <= threshold <= N
+  pub fn validate_members(members: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+      !members.is_empty() && members.len() <= Self::MAX_MEMBERS,
+      ErrorCode::InvalidCouncilConfig
+    );
+    require!(
+      threshold > 0 && threshold as usize <= members.len(),
+      ErrorCode::InvalidCouncilConfig
+    );
+    for (i, member) in members.iter().enumerate() {
+      require!(
+        *member != Pubkey::default() && !members[..i].contains(member),
+        ErrorCode::InvalidCouncilConfig
+      );
+    }
+    Ok(())
+  }
+
+  /// Slot of `key` among the current members
+  pub fn member_index(&self, key: &Pubkey) -> Option<usize> {
+    self.members[..self.member_count as usize]
+      .iter()
+      .position(|member| member == key)
+  }
+
+  pub fn is_member(&self, key: &Pubkey) -> bool {
+    self.member_index(key).is_some()
+  }
+}
+
+/// Operation a council proposal executes once approved
+// Stored inline in the proposal account, so SetCouncil cannot be boxed
+#[allow(clippy::large_enum_variant)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum CouncilAction {
+  /// admin_withdraw: platform pool -> destination
+  AdminWithdraw { amount: u64, destination: Pubkey },
+  /// set_daily_limit
+  SetDailyLimit { new_limit: u64 },
+  /// force_reset_deployment
+  ForceResetDeployment { request_id: [u8; 32] },
+  /// set_keeper_tip
+  SetKeeperTip { new_tip_bps: u64 },
+  /// set_authority_exit_fee
+  SetAuthorityExitFee { new_fee_bps: u64 },
+  /// set_emergency_haircut
+  SetEmergencyHaircut { haircut_bps: u64 },
+  /// Replace the council's members and threshold
+  SetCouncil {
+    members: [Pubkey; AdminCouncil::MAX_MEMBERS],
+    member_count: u8,
+    threshold: u8,
+  },
+  /// admin_withdraw_reward_pool: excess rewards -> destination
+  AdminWithdrawRewardPool { amount: u64, destination: Pubkey },
+  /// set_role
+  SetRole { role: Role, new_holder: Pubkey },
+  /// set_prepay_discounts (steps after the first with min_months = 0 are unused)
+  SetPrepayDiscounts {
+    steps: [PrepayDiscount; TreasuryPool::MAX_PREPAY_DISCOUNT_STEPS],
+  },
+  /// set_plan_tier
+  SetPlanTier {
+    plan_id: u8,
+    max_program_size: u64,
+    service_fee: u64,
+    monthly_fee: u64,
+    included_upgrades: u32,
+    enabled: bool,
+  },
+  /// set_coupon
+  SetCoupon {
+    code_hash: [u8; 32],
+    discount_bps: u64,
+    expires_at: i64,
+    max_uses: u32,
+    is_active: bool,
+  },
+  /// propose_subscription_split (apply_subscription_split still waits out the timelock)
+  ProposeSubscriptionSplit { platform_bps: u64 },
+  /// initiate_withdrawal (still waits out the timelock and guardian veto)
+  InitiateWithdrawal {
+    withdrawal_type: WithdrawalType,
+    amount: u64,
+    destination: Pubkey,
+  },
+  /// execute_withdrawal of the pending withdrawal once its timelock has passed
+  ExecuteWithdrawal,
+  /// set_timelock_duration
+  SetTimelockDuration { new_duration: i64 },
+  /// set_guardian
+  SetGuardian { new_guardian: Pubkey },
+  /// set_deposit_fee_mode
+  SetDepositFeeMode { enabled: bool },
+  /// set_stablecoin_monthly_fee
+  SetStablecoinMonthlyFee { request_id: [u8; 32], new_fee: u64 },
+  /// set_plan_upgrade_pricing
+  SetPlanUpgradePricing {
+    plan_id: u8,
+    max_upgrades_per_month: u32,
+    upgrade_overage_fee: u64,
+  },
+  /// set_service_fee_refund_policy
+  SetServiceFeeRefundPolicy { refund_bps: u64, refund_window: i64 },
+  /// set_insurance_policy
+  SetInsurancePolicy {
+    premium_bps: u64,
+    loss_threshold_bps: u64,
+  },
+  /// set_watchtower_bounty
+  SetWatchtowerBounty { new_bounty: u64, new_cooldown: i64 },
+  /// set_emergency_exit_policy
+  SetEmergencyExitPolicy {
+    new_policy: u8,
+    queue_jump_fee_bps: u64,
+  },
+  /// set_lst_config
+  SetLstConfig {
+    mint: Pubkey,
+    rate: u64,
+    max_rate_age: i64,
+    enabled: bool,
+  },
+  /// set_subscription_stablecoin
+  SetSubscriptionStablecoin { mint: Pubkey },
+  /// credit_staker_bonus (funded by the executor)
+  CreditStakerBonus { staker: Pubkey, amount: u64 },
+  /// set_max_buffer_rent_loan
+  SetMaxBufferRentLoan { max_buffer_rent_loan: u64 },
+}
+
+impl CouncilAction {
+  /// Parameter checks run when the action is proposed, so a proposal that
+  /// could never execute is rejected up front (execution re-checks state)
+  pub fn validate(&self) -> Result<()> {
+    match self {
+      CouncilAction::AdminWithdraw { amount, .. } => {
+        require!(*amount > 0, ErrorCode::InvalidAmount)
+      }
+      CouncilAction::SetDailyLimit { .. } | CouncilAction::ForceResetDeployment { .. } => {}
+      CouncilAction::SetKeeperTip { new_tip_bps } => require!(
+        *new_tip_bps <= TreasuryPool::MAX_KEEPER_TIP_BPS,
+        ErrorCode::InvalidKeeperTip
+      ),
+      CouncilAction::SetAuthorityExitFee { new_fee_bps } => require!(
+        *new_fee_bps <= TreasuryPool::MAX_AUTHORITY_EXIT_FEE_BPS,
+        ErrorCode::InvalidAuthorityExitFee
+      ),
+      CouncilAction::SetEmergencyHaircut { haircut_bps } => require!(
+        *haircut_bps <= TreasuryPool::MAX_EMERGENCY_HAIRCUT_BPS,
+        ErrorCode::InvalidEmergencyHaircut
+      ),
+      CouncilAction::SetCouncil {
+        members,
+        member_count,
+        threshold,
+      } => {
+        require!(
+          *member_count as usize <= AdminCouncil::MAX_MEMBERS,
+          ErrorCode::InvalidCouncilConfig
+        );
+        AdminCouncil::validate_members(&members[..*member_count as usize], *threshold)?;
+      }
+      CouncilAction::AdminWithdrawRewardPool { amount, .. } => {
+        require!(*amount > 0, ErrorCode::InvalidAmount)
+      }
+      CouncilAction::SetRole { .. } => {}
+      CouncilAction::SetPrepayDiscounts { steps } => {
+        TreasuryPool::validate_prepay_discounts(Self::active_prepay_steps(steps))?
+      }
+      CouncilAction::SetPlanTier {
+        plan_id,
+        max_program_size,
+        service_fee,
+        monthly_fee,
+        ..
+      } => require!(
+        *plan_id != 0 && *max_program_size > 0 && *service_fee > 0 && *monthly_fee > 0,
+        ErrorCode::InvalidPlanTier
+      ),
+      CouncilAction::SetCoupon { discount_bps, .. } => require!(
+        *discount_bps > 0 && *discount_bps <= Coupon::MAX_DISCOUNT_BPS,
+        ErrorCode::InvalidCoupon
+      ),
+      CouncilAction::ProposeSubscriptionSplit { platform_bps } => require!(
+        *platform_bps <= TreasuryPool::MAX_SUBSCRIPTION_PLATFORM_BPS,
+        ErrorCode::InvalidSubscriptionSplit
+      ),
+      CouncilAction::InitiateWithdrawal { amount, .. } => {
+        require!(*amount > 0, ErrorCode::InvalidAmount)
+      }
+      CouncilAction::ExecuteWithdrawal
+      | CouncilAction::SetGuardian { .. }
+      | CouncilAction::SetDepositFeeMode { .. }
+      | CouncilAction::SetStablecoinMonthlyFee { .. }
+      | CouncilAction::SetPlanUpgradePricing { .. }
+      | CouncilAction::SetSubscriptionStablecoin { .. } => {}
+      CouncilAction::SetTimelockDuration { new_duration } => require!(
+        (TreasuryPool::MIN_TIMELOCK_DURATION..=TreasuryPool::MAX_TIMELOCK_DURATION)
+          .contains(new_duration),
+        ErrorCode::InvalidTimelockDuration
+      ),
+      CouncilAction::SetServiceFeeRefundPolicy {
+        refund_bps,
+        refund_window,
+      } => require!(
+        *refund_bps <= 10000
+          && (0..=TreasuryPool::MAX_SERVICE_FEE_REFUND_WINDOW).contains(refund_window),
+        ErrorCode::InvalidServiceFeeRefundPolicy
+      ),
+      CouncilAction::SetInsurancePolicy {
+        premium_bps,
+        loss_threshold_bps,
+      } => require!(
+        *premium_bps <= InsuranceFund::MAX_PREMIUM_BPS && *loss_threshold_bps <= 10000,
+        ErrorCode::InvalidInsurancePolicy
+      ),
+      CouncilAction::SetWatchtowerBounty {
+        new_bounty,
+        new_cooldown,
+      } => require!(
+        *new_bounty <= TreasuryPool::MAX_WATCHTOWER_BOUNTY
+          && (TreasuryPool::MIN_WATCHTOWER_ALERT_COOLDOWN
+            ..=TreasuryPool::MAX_WATCHTOWER_ALERT_COOLDOWN)
+            .contains(new_cooldown),
+        ErrorCode::InvalidWatchtowerBounty
+      ),
+      CouncilAction::SetEmergencyExitPolicy {
+        new_policy,
+        queue_jump_fee_bps,
+      } => {
+        require!(
+          TreasuryPool::is_valid_emergency_exit_policy(*new_policy),
+          ErrorCode::InvalidEmergencyExitPolicy
+        );
+        require!(
+          *queue_jump_fee_bps <= TreasuryPool::MAX_QUEUE_JUMP_FEE_BPS,
+          ErrorCode::InvalidQueueJumpFee
+        );
+      }
+      CouncilAction::SetLstConfig {
+        rate, max_rate_age, ..
+      } => require!(
+        *rate > 0 && *max_rate_age > 0 && *max_rate_age <= LstConfig::MAX_RATE_AGE_CAP,
+        ErrorCode::InvalidLstConfig
+      ),
+      CouncilAction::CreditStakerBonus { amount, .. } => {
+        require!(*amount > 0, ErrorCode::InvalidAmount)
+      }
+      CouncilAction::SetMaxBufferRentLoan {
+        max_buffer_rent_loan,
+      } => require!(
+        *max_buffer_rent_loan <= TreasuryPool::MAX_BUFFER_RENT_LOAN_CAP,
+        ErrorCode::InvalidBufferRentLoanLimit
+      ),
+    }
+    Ok(())
+  }
+
+  /// Code hash seeding the coupon a SetCoupon action writes (zero otherwise)
+  pub fn coupon_code_hash(&self) -> [u8; 32] {
+    match self {
+      CouncilAction::SetCoupon { code_hash, .. } => *code_hash,
+      _ => [0u8; 32],
+    }
+  }
+
+  /// Token mint a SetLstConfig / SetSubscriptionStablecoin action configures,
+  /// seeding its vault (default otherwise)
+  pub fn token_mint(&self) -> Pubkey {
+    match self {
+      CouncilAction::SetLstConfig { mint, .. }
+      | CouncilAction::SetSubscriptionStablecoin { mint } => *mint,
+      _ => Pubkey::default(),
+    }
+  }
+
+  /// Leading steps of a SetPrepayDiscounts curve, up to the first unused one
+  pub fn active_prepay_steps(steps: &[PrepayDiscount]) -> &[PrepayDiscount] {
+    let len = steps
+      .iter()
+      .position(|step| step.min_months == 0)
+      .unwrap_or(steps.len());
+    &steps[..len]
+  }
+}
+
+/// Pending council proposal
+///
+/// Closed to the proposer when executed or cancelled.
+#[account]
+#[derive(InitSpace)]
+pub struct CouncilProposal {
+  pub proposal_id: u64,
+  pub proposer: Pubkey,
+  pub action: CouncilAction,
+  /// Council epoch the proposal was made in
+  pub council_epoch: u32,
+  /// Bitmap of approving member slots
+  pub approvals: u16,
+  pub approval_count: u8,
+  pub proposed_at: i64,
+  /// Proposal can no longer be approved or executed after this
+  pub expires_at: i64,
+  pub bump: u8,
+}
+
+impl CouncilProposal {
+  pub const PREFIX_SEED: &'static [u8] = b"council_proposal";
+  /// How long a proposal stays open for approvals and execution
+  pub const LIFETIME: i64 = 7 * 24 * 60 * 60;
+
+  /// Record `member_index`'s approval (each member approves once)
+  pub fn approve(&mut self, member_index: usize) -> Result<()> {
+    let bit = 1u16 << member_index;
+    require!(self.approvals & bit == 0, ErrorCode::AlreadyApproved);
+    self.approvals |= bit;
+    self.approval_count = self.approval_count.saturating_add(1);
+    Ok(())
+  }
+
+  /// The proposal belongs to the current council and has not expired
+  pub fn check_open(&self, council: &AdminCouncil, current_time: i64) -> Result<()> {
+    require!(
+      self.council_epoch == council.epoch,
+      ErrorCode::CouncilProposalStale
+    );
+    require!(
+      current_time <= self.expires_at,
+      ErrorCode::CouncilProposalExpired
+    );
+    Ok(())
+  }
+
+  pub fn is_approved(&self, council: &AdminCouncil) -> bool {
+    self.approval_count >= council.threshold
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn council(members: &[Pubkey], threshold: u8) -> AdminCouncil {
+    let mut council = AdminCouncil {
+      members: [Pubkey::default(); AdminCouncil::MAX_MEMBERS],
+      member_count: 0,
+      threshold: 0,
+      proposal_count: 0,
+      epoch: 0,
+      bump: 0,
+    };
+    council.set_members(members, threshold).unwrap();
+    council
+  }
+
+  #[test]
+  fn council_rejects_bad_member_sets() {
+    let a = Pubkey::new_unique();
+    let b = Pubkey::new_unique();
+    let mut c = council(&[a, b], 2);
+    assert_eq!(c.member_index(&b), Some(1));
+    assert!(c.set_members(&[a, b], 3).is_err());
+    assert!(c.set_members(&[a, a], 1).is_err());
+    assert!(c.set_members(&[a, Pubkey::default()], 1).is_err());
+    assert!(c.set_members(&[], 0).is_err());
+    assert!(c.set_members(&[a], 0).is_err());
+  }
+
+  #[test]
+  fn proposal_needs_threshold_distinct_approvals_in_current_epoch() {
+    let members = [
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    ];
+    let mut c = council(&members, 2);
+    let mut proposal = CouncilProposal {
+      proposal_id: 0,
+      proposer: members[0],
+      action: CouncilAction::SetDailyLimit { new_limit: 1 },
+      council_epoch: 0,
+      approvals: 0,
+      approval_count: 0,
+      proposed_at: 0,
+      expires_at: CouncilProposal::LIFETIME,
+      bump: 0,
+    };
+    proposal.approve(0).unwrap();
+    assert!(proposal.approve(0).is_err());
+    assert!(!proposal.is_approved(&c));
+    proposal.approve(2).unwrap();
+    assert!(proposal.is_approved(&c));
+
+    assert!(proposal.check_open(&c, CouncilProposal::LIFETIME).is_ok());
+    assert!(proposal
+      .check_open(&c, CouncilProposal::LIFETIME + 1)
+      .is_err());
+    c.epoch += 1;
+    assert!(proposal.check_open(&c, 0).is_err());
+  }
+
+  #[test]
+  fn withdrawal_and_timelock_actions_are_checked_when_proposed() {
+    let destination = Pubkey::new_unique();
+    let withdraw = |amount| CouncilAction::InitiateWithdrawal {
+      withdrawal_type: WithdrawalType::PlatformPool,
+      amount,
+      destination,
+    };
+    assert!(withdraw(1).validate().is_ok());
+    assert!(withdraw(0).validate().is_err());
+
+    let timelock = |new_duration| CouncilAction::SetTimelockDuration { new_duration };
+    assert!(timelock(TreasuryPool::MIN_TIMELOCK_DURATION)
+      .validate()
+      .is_ok());
+    assert!(timelock(TreasuryPool::MIN_TIMELOCK_DURATION - 1)
+      .validate()
+      .is_err());
+
+    let mint = Pubkey::new_unique();
+    let lst = CouncilAction::SetLstConfig {
+      mint,
+      rate: 0,
+      max_rate_age: LstConfig::MAX_RATE_AGE_CAP,
+      enabled: true,
+    };
+    assert!(lst.validate().is_err());
+    assert_eq!(lst.token_mint(), mint);
+    assert_eq!(withdraw(1).token_mint(), Pubkey::default());
+  }
+
+  #[test]
+  fn prepay_discount_action_ignores_unused_trailing_steps() {
+    let step = |min_months, discount_bps| PrepayDiscount {
+      min_months,
+      discount_bps,
+    };
+    let mut steps = [PrepayDiscount::default(); TreasuryPool::MAX_PREPAY_DISCOUNT_STEPS];
+    steps[0] = step(3, 500);
+    steps[1] = step(6, 1_000);
+    let action = CouncilAction::SetPrepayDiscounts { steps };
+    assert!(action.validate().is_ok());
+    assert_eq!(CouncilAction::active_prepay_steps(&steps).len(), 2);
+
+    steps[1] = step(2, 1_000);
+    assert!(CouncilAction::SetPrepayDiscounts { steps }
+      .validate()
+      .is_err());
+  }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+/// Address the admin allows managed programs to hand their upgrade authority
+/// to (typically a team's Squads multisig vault)
+///
+/// Disabling an entry blocks new proposals and pending transfers to it;
+/// authorities already handed over are unaffected.
+#[account]
+#[derive(InitSpace)]
+pub struct ApprovedAuthority {
+  pub authority: Pubkey,
+  pub enabled: bool,
+  pub approved_at: i64,
+  pub bump: u8,
+}
+
+impl ApprovedAuthority {
+  pub const PREFIX_SEED: &'static [u8] = b"approved_authority";
+}
+
+/// Pending hand-over of a managed program's upgrade authority
+///
+/// One per program; closed to the developer when executed or cancelled.
+#[account]
+#[derive(InitSpace)]
+pub struct AuthorityTransfer {
+  pub program_id: Pubkey,
+  pub deploy_request: Pubkey,
+  pub developer: Pubkey,
+  pub new_authority: Pubkey,
+  pub proposed_at: i64,
+  pub execute_after: i64,
+  pub bump: u8,
+}
+
+impl AuthorityTransfer {
+  pub const PREFIX_SEED: &'static [u8] = b"authority_transfer";
+  /// Delay between proposal and execution
+  pub const TIMELOCK: i64 = 48 * 60 * 60;
+
+  pub fn is_executable(&self, current_time: i64) -> bool {
+    current_time >= self.execute_after
+  }
+}
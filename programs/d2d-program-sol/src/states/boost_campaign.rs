@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+  errors::ErrorCode,
+  states::{BackerDeposit, TreasuryPool},
+};
+
+/// Which positions may enroll in a boost campaign
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum CampaignEligibility {
+  /// Any active position
+  AllStakers,
+  /// Positions whose first deposit was made at or after the campaign start
+  NewStakers,
+}
+
+/// Time-boxed reward boost funded from the platform pool
+///
+/// Enrolled positions earn `boost_bps` on top of the base rewards they accrue
+/// during the window. The budget is moved into this account at creation and
+/// boosts are paid from it first come, first served until it runs out.
+#[account]
+#[derive(InitSpace)]
+pub struct BoostCampaign {
+  pub campaign_id: u64,
+  pub start_at: i64,
+  pub end_at: i64,
+  /// Boost on base rewards accrued during the window (bps)
+  pub boost_bps: u64,
+  pub eligibility: CampaignEligibility,
+  /// Lamports funded from the platform pool
+  pub budget: u64,
+  pub paid_out: u64,
+  pub enrolled_count: u32,
+  /// Latest reward_per_share observed inside the window; boosts accrue up
+  /// to it once the window has closed
+  pub end_reward_per_share: u128,
+  pub created_at: i64,
+  pub bump: u8,
+}
+
+impl BoostCampaign {
+  pub const PREFIX_SEED: &'static [u8] = b"boost_campaign";
+
+  pub const MAX_BOOST_BPS: u64 = 10000; // Up to doubling base rewards
+  pub const MAX_CAMPAIGN_DURATION: i64 = 90 * 24 * 60 * 60; // 90 days
+  /// Time after end_at during which enrolled stakers can still claim
+  pub const CLAIM_GRACE_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+
+  pub fn is_active(&self, current_time: i64) -> bool {
+    current_time >= self.start_at && current_time < self.end_at
+  }
+
+  pub fn has_ended(&self, current_time: i64) -> bool {
+    current_time >= self.end_at
+  }
+
+  pub fn is_eligible(&self, position: &BackerDeposit) -> bool {
+    match self.eligibility {
+      CampaignEligibility::AllStakers => true,
+      CampaignEligibility::NewStakers => position.first_deposit_at >= self.start_at,
+    }
+  }
+
+  /// reward_per_share to accrue boosts up to: the live value during the
+  /// window (recorded on every touch), afterwards the last value recorded
+  /// before end_at, so rewards credited after the window are never boosted
+  pub fn get_accrual_reward_per_share(
+    &mut self,
+    reward_per_share: u128,
+    current_time: i64,
+  ) -> u128 {
+    if !self.has_ended(current_time) {
+      self.end_reward_per_share = reward_per_share;
+    }
+    self.end_reward_per_share
+  }
+
+  pub fn get_remaining_budget(&self) -> u64 {
+    self.budget.saturating_sub(self.paid_out)
+  }
+}
+
+/// A position's participation in one boost campaign
+#[account]
+#[derive(InitSpace)]
+pub struct CampaignEnrollment {
+  pub campaign: Pubkey,
+  pub backer: Pubkey,
+  /// Principal earning the boost; only ever decreases so stake added after
+  /// enrolling cannot be boosted. Checkpointed on every deposit decrease
+  /// while the campaign runs, so it never exceeds the stake held since the
+  /// last checkpoint
+  pub boosted_amount: u64,
+  pub reward_per_share_checkpoint: u128,
+  pub accrued_boost: u64,
+  pub claimed_boost: u64,
+  pub enrolled_at: i64,
+  pub bump: u8,
+}
+
+impl CampaignEnrollment {
+  pub const PREFIX_SEED: &'static [u8] = b"campaign_enrollment";
+
+  /// Accrue the boost on base rewards earned since the last checkpoint,
+  /// then lower the boosted principal to the current deposit for the next
+  /// interval
+  pub fn accrue(
+    &mut self,
+    reward_per_share: u128,
+    effective_deposit: u64,
+    boost_bps: u64,
+  ) -> Result<u64> {
+    let base_rewards = (self.boosted_amount as u128)
+      .checked_mul(reward_per_share.saturating_sub(self.reward_per_share_checkpoint))
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / TreasuryPool::PRECISION;
+    let boost = base_rewards
+      .checked_mul(boost_bps as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / 10000;
+
+    self.accrued_boost = self
+      .accrued_boost
+      .checked_add(u64::try_from(boost).map_err(|_| ErrorCode::CalculationOverflow)?)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.reward_per_share_checkpoint = self.reward_per_share_checkpoint.max(reward_per_share);
+    self.boosted_amount = self.boosted_amount.min(effective_deposit);
+
+    Ok(boost as u64)
+  }
+
+  pub fn get_unclaimed_boost(&self) -> u64 {
+    self.accrued_boost.saturating_sub(self.claimed_boost)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn boost_accrues_on_base_rewards_and_never_grows_principal() {
+    let mut enrollment = CampaignEnrollment {
+      campaign: Pubkey::default(),
+      backer: Pubkey::default(),
+      boosted_amount: 1_000_000_000,
+      reward_per_share_checkpoint: 0,
+      accrued_boost: 0,
+      claimed_boost: 0,
+      enrolled_at: 0,
+      bump: 0,
+    };
+
+    // 0.1 lamport base reward per lamport staked, 50% boost
+    let rps = TreasuryPool::PRECISION / 10;
+    assert_eq!(
+      enrollment.accrue(rps, 1_000_000_000, 5000).unwrap(),
+      50_000_000
+    );
+
+    // Adding stake does not raise the boosted principal
+    assert_eq!(
+      enrollment.accrue(rps * 2, 5_000_000_000, 5000).unwrap(),
+      50_000_000
+    );
+    assert_eq!(enrollment.boosted_amount, 1_000_000_000);
+
+    // A withdrawal checkpoint pays the elapsed interval on the old principal
+    // and lowers it from then on
+    assert_eq!(
+      enrollment.accrue(rps * 3, 500_000_000, 5000).unwrap(),
+      50_000_000
+    );
+    assert_eq!(enrollment.boosted_amount, 500_000_000);
+    assert_eq!(
+      enrollment.accrue(rps * 4, 5_000_000_000, 5000).unwrap(),
+      25_000_000
+    );
+    assert_eq!(enrollment.get_unclaimed_boost(), 175_000_000);
+  }
+
+  #[test]
+  fn accrual_freezes_at_campaign_end() {
+    let mut campaign = BoostCampaign {
+      campaign_id: 1,
+      start_at: 100,
+      end_at: 200,
+      boost_bps: 1000,
+      eligibility: CampaignEligibility::AllStakers,
+      budget: 0,
+      paid_out: 0,
+      enrolled_count: 0,
+      end_reward_per_share: 0,
+      created_at: 0,
+      bump: 0,
+    };
+    assert_eq!(campaign.get_accrual_reward_per_share(10, 150), 10);
+    assert_eq!(campaign.get_accrual_reward_per_share(20, 199), 20);
+    // Rewards credited after end_at are not boosted, however late the touch
+    assert_eq!(campaign.get_accrual_reward_per_share(50, 200), 20);
+    assert_eq!(campaign.get_accrual_reward_per_share(80, 300), 20);
+  }
+}
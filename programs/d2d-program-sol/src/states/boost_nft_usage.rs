@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+/// Position a boost NFT currently boosts
+///
+/// One per mint, so a single NFT boosts at most one position at a time.
+/// Freed by revoke_nft_boost once the NFT has left the staker's wallet (or
+/// the staker gives the boost up).
+#[account]
+#[derive(InitSpace)]
+pub struct BoostNftUsage {
+  pub mint: Pubkey,
+  /// Boosted position (default = free)
+  pub position: Pubkey,
+  pub applied_at: i64,
+  pub bump: u8,
+}
+
+impl BoostNftUsage {
+  pub const PREFIX_SEED: &'static [u8] = b"boost_nft_usage";
+
+  /// The NFT may boost `position`: it is free or already boosts it
+  pub fn is_available_to(&self, position: &Pubkey) -> bool {
+    self.position == Pubkey::default() || self.position == *position
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn nft_boosts_one_position_at_a_time() {
+    let position = Pubkey::new_unique();
+    let mut usage = BoostNftUsage {
+      mint: Pubkey::new_unique(),
+      position: Pubkey::default(),
+      applied_at: 0,
+      bump: 0,
+    };
+    assert!(usage.is_available_to(&position));
+
+    usage.position = position;
+    assert!(usage.is_available_to(&position));
+    assert!(!usage.is_available_to(&Pubkey::new_unique()));
+  }
+}
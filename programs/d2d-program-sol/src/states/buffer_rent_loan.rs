@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Short-term treasury loan covering the rent of an upgrade buffer
+///
+/// The loan PDA doubles as the spill account for `proxy_upgrade_program`:
+/// when the buffer is consumed (or closed) its rent lands here and is used
+/// to repay the treasury before anything is returned to the developer.
+#[account]
+#[derive(InitSpace)]
+pub struct BufferRentLoan {
+  /// Developer who borrowed
+  pub developer: Pubkey,
+  /// Managed program the buffer upgrades (one open loan per program)
+  pub program_id: Pubkey,
+  /// Lamports lent by the treasury
+  pub amount_borrowed: u64,
+  /// Lamports repaid so far
+  pub amount_repaid: u64,
+  /// When the loan was taken
+  pub borrowed_at: i64,
+  /// Expected repayment deadline (informational for keepers/UI)
+  pub due_at: i64,
+  /// PDA bump
+  pub bump: u8,
+}
+
+impl BufferRentLoan {
+  pub const PREFIX_SEED: &'static [u8] = b"buffer_rent_loan";
+  /// Buffers are expected to be consumed within a week
+  pub const LOAN_DURATION: i64 = 7 * 24 * 60 * 60;
+
+  /// Get outstanding amount still owed to the treasury
+  pub fn get_outstanding(&self) -> u64 {
+    self.amount_borrowed.saturating_sub(self.amount_repaid)
+  }
+
+  /// Check if the loan is fully repaid
+  pub fn is_repaid(&self) -> bool {
+    self.amount_repaid >= self.amount_borrowed
+  }
+}
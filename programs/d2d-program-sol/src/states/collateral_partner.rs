@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Partner lending protocol allowed to take stake positions as collateral
+///
+/// Registered by the admin. The partner program pledges and releases positions
+/// through CPI, signing with `authority`: its PDA at `[AUTHORITY_SEED]`.
+#[account]
+#[derive(InitSpace)]
+pub struct CollateralPartner {
+  pub program_id: Pubkey,
+  pub authority: Pubkey,
+  /// Disabled partners cannot take new pledges but can still release
+  pub enabled: bool,
+  pub active_pledges: u32,
+  pub registered_at: i64,
+  pub bump: u8,
+}
+
+impl CollateralPartner {
+  pub const PREFIX_SEED: &'static [u8] = b"collateral_partner";
+  /// Seed of the signer PDA partner programs use when calling in via CPI
+  pub const AUTHORITY_SEED: &'static [u8] = b"d2d_collateral";
+
+  pub fn derive_authority(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[Self::AUTHORITY_SEED], program_id).0
+  }
+}
@@ -0,0 +1,116 @@
+use anchor_lang::{prelude::*, solana_program::hash::hash};
+
+use crate::errors::ErrorCode;
+
+/// Admin-managed promo code discounting the one-time service fee
+///
+/// Only the hash of the code is stored; create_deploy_request and
+/// request_deployment_funds accept the coupon account together with the
+/// code itself and count each redemption against `max_uses`.
+#[account]
+#[derive(InitSpace, Default)]
+pub struct Coupon {
+  pub code_hash: [u8; 32],
+  /// Share of the service fee waived (bps, 10_000 = free)
+  pub discount_bps: u64,
+  /// Last time the coupon can be redeemed (0 = no expiry)
+  pub expires_at: i64,
+  /// Most redemptions allowed (0 = unlimited)
+  pub max_uses: u32,
+  pub uses: u32,
+  /// Service fee waived across all redemptions
+  pub total_discount: u64,
+  pub is_active: bool,
+  pub updated_at: i64,
+  pub bump: u8,
+}
+
+impl Coupon {
+  pub const PREFIX_SEED: &'static [u8] = b"coupon";
+  pub const MAX_DISCOUNT_BPS: u64 = 10_000;
+
+  /// SHA-256 of a promo code, which also seeds the coupon PDA
+  pub fn hash_code(code: &str) -> [u8; 32] {
+    hash(code.as_bytes()).to_bytes()
+  }
+
+  /// The redeemer knows the code, not just the coupon's address
+  pub fn check_code(&self, code: Option<&str>) -> Result<()> {
+    require!(
+      code.is_some_and(|code| Self::hash_code(code) == self.code_hash),
+      ErrorCode::CouponCodeMismatch
+    );
+    Ok(())
+  }
+
+  pub fn is_redeemable(&self, current_time: i64) -> bool {
+    self.is_active
+      && (self.expires_at == 0 || current_time <= self.expires_at)
+      && (self.max_uses == 0 || self.uses < self.max_uses)
+  }
+
+  /// Redeem against `service_fee`; returns the discount granted
+  pub fn redeem(&mut self, service_fee: u64, current_time: i64) -> Result<u64> {
+    require!(
+      self.is_redeemable(current_time),
+      ErrorCode::CouponNotRedeemable
+    );
+
+    let discount = (service_fee as u128)
+      .checked_mul(self.discount_bps as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / 10_000;
+    let discount = discount as u64;
+
+    self.uses = self.uses.saturating_add(1);
+    self.total_discount = self
+      .total_discount
+      .checked_add(discount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(discount)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn redeem_discounts_service_fee_until_used_up() {
+    let mut coupon = Coupon {
+      discount_bps: 2_500,
+      max_uses: 2,
+      is_active: true,
+      ..Default::default()
+    };
+    assert_eq!(coupon.redeem(1_000, 0).unwrap(), 250);
+    assert_eq!(coupon.redeem(2_000, 0).unwrap(), 500);
+    assert!(coupon.redeem(1_000, 0).is_err());
+    assert_eq!(coupon.total_discount, 750);
+  }
+
+  #[test]
+  fn expired_or_inactive_coupons_are_refused() {
+    let mut coupon = Coupon {
+      discount_bps: 1_000,
+      expires_at: 100,
+      is_active: true,
+      ..Default::default()
+    };
+    assert!(coupon.is_redeemable(100));
+    assert!(coupon.redeem(1_000, 101).is_err());
+    coupon.is_active = false;
+    assert!(!coupon.is_redeemable(0));
+  }
+
+  #[test]
+  fn redeeming_requires_the_code() {
+    let coupon = Coupon {
+      code_hash: Coupon::hash_code("LAUNCH50"),
+      ..Default::default()
+    };
+    assert!(coupon.check_code(Some("LAUNCH50")).is_ok());
+    assert!(coupon.check_code(Some("launch50")).is_err());
+    assert!(coupon.check_code(None).is_err());
+  }
+}
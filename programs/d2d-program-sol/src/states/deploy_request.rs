@@ -1,6 +1,6 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
 
-use crate::errors::ErrorCode;
+use crate::{errors::ErrorCode, states::PlanTier};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
 pub enum DeployRequestStatus {
@@ -12,6 +12,8 @@ pub enum DeployRequestStatus {
   Failed,              // Deployment failed
   Cancelled,           // Cancelled by developer
   Closed,              // Program closed, lamports recovered
+  Offboarded,          // Upgrade authority returned to developer
+  Hibernated,          // Program closed by the developer, request kept for redeployment
 }
 
 #[account]
@@ -50,6 +52,101 @@ pub struct DeployRequest {
   pub recovery_ratio_bps: u64,
   /// Timestamp when debt was fully repaid (0 if not yet repaid)
   pub debt_repaid_at: i64,
+
+  // === SERVICE FEE REFUND ===
+  /// Share of service_fee refunded on early voluntary closure (bps)
+  /// Snapshot of the treasury refund policy when the request was created
+  pub service_fee_refund_bps: u64,
+  /// Seconds after created_at during which the refund applies (0 = no refund)
+  pub service_fee_refund_window: i64,
+  /// Service fee already refunded (refund is paid at most once)
+  pub service_fee_refunded: u64,
+
+  // === TERMS ACCEPTANCE ===
+  /// Terms hash the developer accepted when the request was created
+  pub accepted_terms_hash: [u8; 32],
+  /// When the terms were accepted
+  pub terms_accepted_at: i64,
+
+  // === FUNDING RECONCILIATION ===
+  /// Cumulative lamports sent to ephemeral wallets by fund_temporary_wallet
+  pub total_funded: u64,
+  /// Lamports the backend reported as spent on the deployment
+  pub reported_spent: u64,
+  /// funded - (recovered + spent) at confirmation (positive = unexplained shortfall)
+  pub funding_variance: i64,
+
+  // === DEVELOPER PAYMENTS ===
+  /// SOL lamports paid by the developer for this request (all payment paths)
+  /// SPL renewals are not included - they are not refundable in SOL
+  pub total_paid_by_developer: u64,
+  /// Payment held in the reward pool until the deployment is confirmed
+  pub escrowed_payment: u64,
+
+  // === STABLECOIN SUBSCRIPTION ===
+  /// Fixed monthly fee in stablecoin base units for pay_subscription_spl
+  /// (0 = stablecoin payments not offered for this request)
+  pub stablecoin_monthly_fee: u64,
+
+  // === AUTO-RENEWAL LIMITS ===
+  /// Most months one automatic renewal may buy (0 = no per-program limit)
+  pub max_months_per_renewal: u32,
+
+  // === PENDING EXPIRY ===
+  /// Deadline for the backend to confirm a PendingDeployment request, reset
+  /// on every (re)request (0 = legacy request, created_at + timeout applies)
+  pub expiry_at: i64,
+
+  // === SUBSCRIPTION PLAN ===
+  /// PlanConfig tier the fees were taken from (0 = legacy free-form fees)
+  pub plan_id: u8,
+  /// Program size (bytes) declared against the tier's limit
+  pub program_size: u64,
+  /// Upgrades included by the tier (0 = unlimited)
+  pub included_upgrades: u32,
+
+  // === HIBERNATION ===
+  /// When pause_program closed the program (0 = never hibernated)
+  pub hibernated_at: i64,
+
+  // === UPGRADE FREQUENCY ===
+  /// Upgrades per 30-day period covered by the tier (0 = no monthly limit)
+  pub max_upgrades_per_month: u32,
+  /// Escrow charge per upgrade beyond the monthly limit (0 = refused)
+  pub upgrade_overage_fee: u64,
+
+  // === DEVELOPER COLLATERAL ===
+  /// SOL locked in the DeveloperCollateral PDA; offsets borrowed_amount for
+  /// the monthly borrow fee
+  pub collateral_amount: u64,
+
+  // === EXPIRY WARNINGS ===
+  /// Last SubscriptionExpiring event emitted for this request (0 = never)
+  pub last_expiry_warning_at: i64,
+
+  // === BORROW FEES ===
+  /// Monthly borrow fees collected so far (pay_subscription / collect_borrow_fee)
+  pub fees_paid: u64,
+  /// Borrow fees accrued up to the last checkpoint, at the rates in force
+  /// back then
+  pub accrued_borrow_fees: u64,
+  /// Months since created_at covered by accrued_borrow_fees
+  pub borrow_fee_months_checkpointed: u64,
+
+  // === PROGRAM EXTENSIONS ===
+  /// ProgramData rent the developer prepaid through proxy_extend_program;
+  /// not capital lent by the treasury, so it is returned to the developer out
+  /// of the rent recovered when the program is closed
+  pub developer_extension_rent: u64,
+
+  // === SUBSCRIPTION VALUE ===
+  /// Lamports actually paid (after prepay discounts) for the subscription
+  /// time between subscription_paid_from and subscription_paid_until;
+  /// unused time is refunded at this rate
+  pub subscription_paid_value: u64,
+  /// Start of the span subscription_paid_value pays for (0 on requests that
+  /// predate the ledger, which are refunded at the list monthly fee)
+  pub subscription_paid_from: i64,
 }
 
 impl DeployRequest {
@@ -57,6 +154,68 @@ impl DeployRequest {
   pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
   pub const SECONDS_PER_MONTH: i64 = 30 * Self::SECONDS_PER_DAY;
   pub const MAX_EXTENSION_MONTHS: u32 = 120; // Maximum 10 years extension at once
+  /// How long a request may sit in PendingDeployment before it can be
+  /// cancelled or expired with a full refund
+  pub const PENDING_DEPLOYMENT_TIMEOUT: i64 = 7 * Self::SECONDS_PER_DAY;
+  /// Share of the monthly fee (bps) charged while hibernated
+  pub const STORAGE_FEE_BPS: u64 = 1_000;
+  /// Account sizes a deployment pays rent for (upgradeable loader layouts)
+  pub const PROGRAM_ACCOUNT_SIZE: usize = 36;
+  pub const PROGRAMDATA_HEADER_SIZE: usize = 45;
+  pub const BUFFER_HEADER_SIZE: usize = 37;
+  /// Transaction fees a temporary wallet may spend on a deployment
+  pub const DEPLOYMENT_FEE_ALLOWANCE: u64 = 10_000_000;
+
+  /// Request id (and PDA seed) of a deployment of `program_hash`
+  ///
+  /// Nonce 0 is the original one-deployment-per-hash id (the hash itself);
+  /// any other nonce gives the developer an independent deployment of the
+  /// same bytecode, e.g. staging and production. Deploying again while the
+  /// request under a nonce is live takes the next nonce: an existing request
+  /// is only replaced once check_retryable passes.
+  pub fn derive_request_id(developer: &Pubkey, program_hash: &[u8; 32], nonce: u32) -> [u8; 32] {
+    if nonce == 0 {
+      return *program_hash;
+    }
+    hashv(&[developer.as_ref(), program_hash, &nonce.to_le_bytes()]).to_bytes()
+  }
+
+  /// Read-only load with migration support
+  ///
+  /// Old accounts may be smaller than the new struct; padding with zeros
+  /// gives correct defaults (0) for new debt tracking fields. Verifies the
+  /// account owner and PDA seeds.
+  pub fn load_padded(deploy_request_info: &AccountInfo) -> Result<Self> {
+    require!(
+      deploy_request_info.owner == &crate::ID,
+      ErrorCode::InvalidAccountOwner
+    );
+
+    let required_space = 8 + Self::INIT_SPACE;
+    let account_data = deploy_request_info.data.borrow();
+    let data_to_deserialize = if account_data.len() < required_space {
+      let mut padded = vec![0u8; required_space];
+      padded[..account_data.len()].copy_from_slice(&account_data);
+      padded
+    } else {
+      account_data[..required_space].to_vec()
+    };
+    drop(account_data);
+
+    let deploy_request = Self::try_deserialize(&mut &data_to_deserialize[..])
+      .map_err(|_| anchor_lang::error!(ErrorCode::InvalidAccountData))?;
+
+    let (expected_pda, _) = Pubkey::find_program_address(
+      &[Self::PREFIX_SEED, deploy_request.request_id.as_ref()],
+      &crate::ID,
+    );
+    require!(
+      expected_pda == deploy_request_info.key(),
+      ErrorCode::InvalidRequestId
+    );
+
+    Ok(deploy_request)
+  }
 
   pub fn is_subscription_valid(&self) -> Result<bool> {
     let current_time = Clock::get()?.unix_timestamp;
@@ -141,13 +300,18 @@ impl DeployRequest {
     self.auto_renewal_failed_count = self.auto_renewal_failed_count.saturating_add(1);
   }
 
-  /// Calculate the 1% monthly borrow fee on borrowed_amount
+  /// Borrowed amount not covered by developer collateral
+  pub fn uncollateralized_borrow(&self) -> u64 {
+    self.borrowed_amount.saturating_sub(self.collateral_amount)
+  }
+
+  /// Calculate the 1% monthly borrow fee on the uncollateralized borrow
   /// This fee is charged monthly for using treasury funds for deployment
   pub fn calculate_monthly_borrow_fee(&self) -> Result<u64> {
     // 1% = 100 basis points
     const MONTHLY_FEE_BPS: u64 = 100;
 
-    let fee = (self.borrowed_amount as u128)
+    let fee = (self.uncollateralized_borrow() as u128)
       .checked_mul(MONTHLY_FEE_BPS as u128)
       .ok_or(ErrorCode::CalculationOverflow)?
       .checked_div(10000)
@@ -158,22 +322,65 @@ impl DeployRequest {
 
   /// Calculate total borrow fees based on months elapsed since deployment
   pub fn calculate_total_borrow_fees(&self) -> Result<u64> {
-    let current_time = Clock::get()?.unix_timestamp;
-    let elapsed_seconds = current_time.checked_sub(self.created_at).unwrap_or(0);
+    self.total_borrow_fees_at(Clock::get()?.unix_timestamp)
+  }
+
+  /// Fees accrued at the last checkpoint plus the current monthly fee for
+  /// every month since
+  pub fn total_borrow_fees_at(&self, current_time: i64) -> Result<u64> {
+    let months_since_checkpoint = self
+      .borrow_fee_months_elapsed(current_time)?
+      .saturating_sub(self.borrow_fee_months_checkpointed);
 
-    // Calculate months elapsed (rounded up to next month)
-    let months_elapsed = (elapsed_seconds as u64)
+    let monthly_fee = self.calculate_monthly_borrow_fee()?;
+    monthly_fee
+      .checked_mul(months_since_checkpoint)
+      .and_then(|fee| fee.checked_add(self.accrued_borrow_fees))
+      .ok_or(ErrorCode::CalculationOverflow.into())
+  }
+
+  /// Fold the fees accrued so far into accrued_borrow_fees
+  ///
+  /// Must run before collateral_amount or borrowed_amount changes, so the new
+  /// monthly fee only applies from the next month on.
+  pub fn checkpoint_borrow_fees(&mut self, current_time: i64) -> Result<()> {
+    self.accrued_borrow_fees = self.total_borrow_fees_at(current_time)?;
+    self.borrow_fee_months_checkpointed = self.borrow_fee_months_elapsed(current_time)?;
+    Ok(())
+  }
+
+  /// Months elapsed since created_at, rounded up to the next month
+  fn borrow_fee_months_elapsed(&self, current_time: i64) -> Result<u64> {
+    let elapsed_seconds = current_time
+      .checked_sub(self.created_at)
+      .unwrap_or(0)
+      .max(0);
+    (elapsed_seconds as u64)
       .checked_add(Self::SECONDS_PER_MONTH as u64 - 1)
       .ok_or(ErrorCode::CalculationOverflow)?
       .checked_div(Self::SECONDS_PER_MONTH as u64)
-      .ok_or(ErrorCode::CalculationOverflow)?;
+      .ok_or(ErrorCode::CalculationOverflow.into())
+  }
 
-    let monthly_fee = self.calculate_monthly_borrow_fee()?;
-    let total_fee = monthly_fee
-      .checked_mul(months_elapsed)
-      .ok_or(ErrorCode::CalculationOverflow)?;
+  /// Accrued borrow fees not yet collected (none once the debt is repaid)
+  pub fn outstanding_borrow_fees(&self) -> Result<u64> {
+    if self.is_debt_repaid() {
+      return Ok(0);
+    }
+    Ok(
+      self
+        .calculate_total_borrow_fees()?
+        .saturating_sub(self.fees_paid),
+    )
+  }
 
-    Ok(total_fee)
+  /// Record collected borrow fees
+  pub fn record_borrow_fee_payment(&mut self, amount: u64) -> Result<()> {
+    self.fees_paid = self
+      .fees_paid
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.record_developer_payment(amount)
   }
 
   // === DEBT REPAYMENT METHODS ===
@@ -226,6 +433,44 @@ impl DeployRequest {
     Ok((debt_repayment, excess_to_rewards))
   }
 
+  /// Record debt settled directly by the developer (e.g. from escrow on off-boarding)
+  pub fn record_debt_settlement(&mut self, amount: u64) -> Result<()> {
+    self.repaid_amount = self
+      .repaid_amount
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    if self.is_debt_repaid() && self.debt_repaid_at == 0 {
+      self.debt_repaid_at = Clock::get()?.unix_timestamp;
+    }
+
+    Ok(())
+  }
+
+  /// Record ProgramData growth whose rent the developer prepaid
+  ///
+  /// The rent is tracked apart from borrowed_amount, so it adds neither to
+  /// the borrow fee nor to the authority exit fee.
+  pub fn record_program_extension(&mut self, additional_bytes: u64, rent: u64) -> Result<()> {
+    self.program_size = self
+      .program_size
+      .checked_add(additional_bytes)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.developer_extension_rent = self
+      .developer_extension_rent
+      .checked_add(rent)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// Developer's share of `recovered_lamports` from a closed program: the
+  /// extension rent they prepaid (clears it)
+  pub fn take_extension_rent_refund(&mut self, recovered_lamports: u64) -> u64 {
+    let refund = self.developer_extension_rent.min(recovered_lamports);
+    self.developer_extension_rent = 0;
+    refund
+  }
+
   /// Set expected rent recovery estimate (called during deployment funding)
   pub fn set_expected_rent_recovery(&mut self, deployment_cost: u64) {
     // Typically ~80% of deployment cost is recoverable as rent
@@ -235,6 +480,389 @@ impl DeployRequest {
       .unwrap_or(0);
   }
 
+  /// Most a decentralized pool lends for this request's deployment: rent for
+  /// the program account, a program data account of twice the program size
+  /// and the upload buffer, plus the fee allowance
+  pub fn max_deployment_funding(&self, rent: &Rent) -> Result<u64> {
+    let program_size =
+      usize::try_from(self.program_size).map_err(|_| ErrorCode::CalculationOverflow)?;
+    let programdata_size = program_size
+      .checked_mul(2)
+      .and_then(|size| size.checked_add(Self::PROGRAMDATA_HEADER_SIZE))
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    let buffer_size = program_size
+      .checked_add(Self::BUFFER_HEADER_SIZE)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    [
+      rent.minimum_balance(Self::PROGRAM_ACCOUNT_SIZE),
+      rent.minimum_balance(programdata_size),
+      rent.minimum_balance(buffer_size),
+    ]
+    .iter()
+    .try_fold(Self::DEPLOYMENT_FEE_ALLOWANCE, |total, rent| {
+      total.checked_add(*rent)
+    })
+    .ok_or(ErrorCode::CalculationOverflow.into())
+  }
+
+  // === SERVICE FEE REFUND METHODS ===
+
+  /// Store the refund schedule in force when the request is (re)created
+  pub fn set_service_fee_refund_schedule(&mut self, refund_bps: u64, refund_window: i64) {
+    self.service_fee_refund_bps = refund_bps;
+    self.service_fee_refund_window = refund_window;
+    self.service_fee_refunded = 0;
+  }
+
+  /// Record a paid service fee refund
+  pub fn record_service_fee_refund(&mut self, amount: u64) {
+    self.service_fee_refunded = amount;
+  }
+
+  /// Service fee refundable on a voluntary closure at `current_time`
+  /// Full schedule share within the window, nothing after it
+  pub fn calculate_service_fee_refund(&self, current_time: i64) -> Result<u64> {
+    let elapsed = current_time.saturating_sub(self.created_at);
+    if self.service_fee_refund_window == 0
+      || self.service_fee_refunded > 0
+      || elapsed > self.service_fee_refund_window
+    {
+      return Ok(0);
+    }
+
+    let refund = (self.service_fee as u128)
+      .checked_mul(self.service_fee_refund_bps as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      .checked_div(10000)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    Ok(refund as u64)
+  }
+
+  /// Value of the paid subscription time left at `current_time`, prorated
+  /// to the second at the rate the developer actually paid for it
+  pub fn calculate_unused_subscription(&self, current_time: i64) -> Result<u64> {
+    let remaining = self
+      .subscription_paid_until
+      .saturating_sub(current_time)
+      .max(0);
+    let (paid_value, paid_span) = if self.subscription_paid_from == 0 {
+      (self.monthly_fee, Self::SECONDS_PER_MONTH)
+    } else {
+      (
+        self.subscription_paid_value,
+        self
+          .subscription_paid_until
+          .saturating_sub(self.subscription_paid_from),
+      )
+    };
+    if paid_span <= 0 {
+      return Ok(0);
+    }
+    let value = (paid_value as u128)
+      .checked_mul(remaining.min(paid_span) as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / paid_span as u128;
+    u64::try_from(value).map_err(|_| ErrorCode::CalculationOverflow.into())
+  }
+
+  /// Record `amount` paid for subscription time, before the paid period is
+  /// extended or re-priced; the value still unused at `current_time` is
+  /// carried into the new span
+  pub fn record_subscription_payment(&mut self, amount: u64, current_time: i64) -> Result<()> {
+    let carried = self.calculate_unused_subscription(current_time)?;
+    self.subscription_paid_value = carried
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.subscription_paid_from = current_time.min(self.subscription_paid_until);
+    Ok(())
+  }
+
+  /// Start a fresh paid period of `months` bought for `amount`
+  pub fn start_subscription(&mut self, months: u32, amount: u64, current_time: i64) {
+    self.subscription_paid_until = current_time + (months as i64 * Self::SECONDS_PER_MONTH);
+    self.subscription_paid_from = current_time;
+    self.subscription_paid_value = amount;
+  }
+
+  /// End the paid period at `current_time` once its unused value is settled
+  pub fn end_subscription(&mut self, current_time: i64) {
+    self.subscription_paid_until = current_time;
+    self.subscription_paid_from = current_time;
+    self.subscription_paid_value = 0;
+  }
+
+  /// Add `value` already paid to the current period as time at `monthly_rate`
+  pub fn credit_subscription_value(&mut self, value: u64, monthly_rate: u64) -> Result<()> {
+    if value == 0 || monthly_rate == 0 {
+      return Ok(());
+    }
+    let seconds = (value as u128)
+      .checked_mul(Self::SECONDS_PER_MONTH as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / monthly_rate as u128;
+    let seconds = i64::try_from(seconds).map_err(|_| ErrorCode::SubscriptionExtensionOverflow)?;
+    self.subscription_paid_until = self
+      .subscription_paid_until
+      .checked_add(seconds)
+      .ok_or(ErrorCode::SubscriptionExtensionOverflow)?;
+    self.subscription_paid_value = self
+      .subscription_paid_value
+      .checked_add(value)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// Put the request in Hibernated, turning the unused paid time into
+  /// storage time at the storage-only fee
+  pub fn hibernate(&mut self, current_time: i64) -> Result<()> {
+    let unused = self.calculate_unused_subscription(current_time)?;
+    self.end_subscription(current_time);
+    self.credit_subscription_value(unused, self.storage_monthly_fee())?;
+    self.status = DeployRequestStatus::Hibernated;
+    self.hibernated_at = current_time;
+    Ok(())
+  }
+
+  /// Storage time still paid for at redeployment, as value to carry into
+  /// the new subscription; the storage fee has to be current
+  pub fn take_hibernation_credit(&mut self, current_time: i64) -> Result<u64> {
+    require!(
+      self.subscription_paid_until > current_time,
+      ErrorCode::StorageFeeOverdue
+    );
+    let credit = self.calculate_unused_subscription(current_time)?;
+    self.end_subscription(current_time);
+    Ok(credit)
+  }
+
+  /// Subscription prepaid when the request was created (initial months)
+  pub fn calculate_prepaid_subscription(&self) -> Result<u64> {
+    let subscription_duration = self.subscription_paid_until.saturating_sub(self.created_at);
+    let initial_months = (subscription_duration / Self::SECONDS_PER_MONTH).max(1) as u64;
+
+    self
+      .monthly_fee
+      .checked_mul(initial_months)
+      .ok_or(ErrorCode::CalculationOverflow.into())
+  }
+
+  // === FUNDING RECONCILIATION METHODS ===
+
+  /// Record lamports sent to the ephemeral wallet
+  pub fn record_funding(&mut self, amount: u64) -> Result<()> {
+    self.total_funded = self
+      .total_funded
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// Funded amount to reconcile against (borrowed_amount for requests funded
+  /// before cumulative tracking)
+  pub fn get_funded_amount(&self) -> u64 {
+    if self.total_funded > 0 {
+      self.total_funded
+    } else {
+      self.borrowed_amount
+    }
+  }
+
+  /// funded - (recovered + spent); positive means lamports are unaccounted for
+  pub fn calculate_funding_variance(&self, recovered: u64, spent: u64) -> i64 {
+    let accounted = recovered as i128 + spent as i128;
+    (self.get_funded_amount() as i128 - accounted).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+  }
+
+  // === DEVELOPER PAYMENT METHODS ===
+
+  /// Record a SOL payment made by the developer
+  pub fn record_developer_payment(&mut self, amount: u64) -> Result<()> {
+    self.total_paid_by_developer = self
+      .total_paid_by_developer
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// Stablecoin amount owed for `months` at the fixed stablecoin fee
+  pub fn calculate_stablecoin_payment(&self, months: u32) -> Result<u64> {
+    require!(
+      self.stablecoin_monthly_fee > 0,
+      ErrorCode::StablecoinFeeNotSet
+    );
+    require!(
+      months <= Self::MAX_EXTENSION_MONTHS,
+      ErrorCode::SubscriptionExtensionTooLarge
+    );
+    Ok(
+      self
+        .stablecoin_monthly_fee
+        .checked_mul(months as u64)
+        .ok_or(ErrorCode::MaxPaymentExceeded)?,
+    )
+  }
+
+  /// Months within this program's per-renewal limit
+  pub fn allows_renewal_months(&self, months: u32) -> bool {
+    self.max_months_per_renewal == 0 || months <= self.max_months_per_renewal
+  }
+
+  /// Copy the plan tier's terms onto the request
+  pub fn apply_plan(&mut self, tier: &PlanTier, program_size: u64) -> Result<()> {
+    require!(
+      program_size > 0 && program_size <= tier.max_program_size,
+      ErrorCode::ProgramSizeExceedsPlan
+    );
+    self.plan_id = tier.plan_id;
+    self.program_size = program_size;
+    self.service_fee = tier.service_fee;
+    self.monthly_fee = tier.monthly_fee;
+    self.included_upgrades = tier.included_upgrades;
+    self.max_upgrades_per_month = tier.max_upgrades_per_month;
+    self.upgrade_overage_fee = tier.upgrade_overage_fee;
+    Ok(())
+  }
+
+  /// Move an active subscription onto another plan tier
+  ///
+  /// The paid period left is prorated: a higher monthly fee returns the
+  /// difference owed for the remaining time (the end date is unchanged); a
+  /// lower fee stretches the remaining value over more time at the new rate.
+  /// The one-off service fee is not re-priced. Returns (charge, extension).
+  pub fn switch_plan(&mut self, tier: &PlanTier, current_time: i64) -> Result<(u64, i64)> {
+    require!(
+      self.program_size <= tier.max_program_size,
+      ErrorCode::ProgramSizeExceedsPlan
+    );
+
+    let remaining = self
+      .subscription_paid_until
+      .saturating_sub(current_time)
+      .max(0) as u128;
+    let old_fee = self.monthly_fee as u128;
+    let new_fee = tier.monthly_fee as u128;
+    let (charge, extension) = if new_fee >= old_fee {
+      let charge = (new_fee - old_fee)
+        .checked_mul(remaining)
+        .ok_or(ErrorCode::CalculationOverflow)?
+        / Self::SECONDS_PER_MONTH as u128;
+      (
+        u64::try_from(charge).map_err(|_| ErrorCode::CalculationOverflow)?,
+        0,
+      )
+    } else {
+      let stretched = old_fee
+        .checked_mul(remaining)
+        .ok_or(ErrorCode::CalculationOverflow)?
+        / new_fee;
+      let extension = i64::try_from(stretched - remaining)
+        .map_err(|_| ErrorCode::SubscriptionExtensionOverflow)?;
+      (0, extension)
+    };
+
+    // The paid value carries over; an upgrade charge adds to it
+    self.record_subscription_payment(charge, current_time)?;
+    self.subscription_paid_until = self
+      .subscription_paid_until
+      .checked_add(extension)
+      .ok_or(ErrorCode::SubscriptionExtensionOverflow)?;
+    self.plan_id = tier.plan_id;
+    self.monthly_fee = tier.monthly_fee;
+    self.included_upgrades = tier.included_upgrades;
+    self.max_upgrades_per_month = tier.max_upgrades_per_month;
+    self.upgrade_overage_fee = tier.upgrade_overage_fee;
+    Ok((charge, extension))
+  }
+
+  /// Storage-only monthly fee charged while the program is hibernated
+  pub fn storage_monthly_fee(&self) -> u64 {
+    ((self.monthly_fee as u128 * Self::STORAGE_FEE_BPS as u128) / 10_000) as u64
+  }
+
+  /// Whether the plan still covers another upgrade
+  pub fn allows_upgrade(&self, upgrades_done: u32) -> bool {
+    self.included_upgrades == 0 || upgrades_done < self.included_upgrades
+  }
+
+  /// Whether another upgrade this period exceeds the monthly limit
+  pub fn is_upgrade_overage(&self, upgrades_this_period: u32) -> bool {
+    self.max_upgrades_per_month > 0 && upgrades_this_period >= self.max_upgrades_per_month
+  }
+
+  /// Start the confirmation deadline for a new PendingDeployment request
+  pub fn set_pending_expiry(&mut self, current_time: i64) {
+    self.expiry_at = current_time.saturating_add(Self::PENDING_DEPLOYMENT_TIMEOUT);
+  }
+
+  /// Confirmation deadline, falling back to created_at for legacy requests
+  pub fn pending_expiry_at(&self) -> i64 {
+    if self.expiry_at > 0 {
+      self.expiry_at
+    } else {
+      self
+        .created_at
+        .saturating_add(Self::PENDING_DEPLOYMENT_TIMEOUT)
+    }
+  }
+
+  /// The backend has missed the deadline to fund and confirm
+  pub fn is_pending_deployment_stale(&self, current_time: i64) -> bool {
+    self.status == DeployRequestStatus::PendingDeployment
+      && current_time >= self.pending_expiry_at()
+  }
+
+  /// A new request may reuse this PDA only once the previous one has ended
+  /// without a deployment and without a loan
+  pub fn check_retryable(&self) -> Result<()> {
+    require!(
+      matches!(
+        self.status,
+        DeployRequestStatus::Failed | DeployRequestStatus::Cancelled
+      ),
+      ErrorCode::InvalidDeploymentStatus
+    );
+    require!(
+      self.total_funded == 0 && self.ephemeral_key.is_none(),
+      ErrorCode::InvalidDeploymentStatus
+    );
+    Ok(())
+  }
+
+  /// Take the escrowed payment (released or refunded by the caller)
+  pub fn take_escrowed_payment(&mut self) -> u64 {
+    std::mem::take(&mut self.escrowed_payment)
+  }
+
+  /// Refund owed when the deployment fails: exactly what the developer paid
+  /// Requests created before payment tracking fall back to the fee schedule
+  pub fn calculate_failure_refund(&self) -> Result<u64> {
+    if self.total_paid_by_developer > 0 {
+      return Ok(self.total_paid_by_developer);
+    }
+
+    self
+      .service_fee
+      .checked_add(self.calculate_prepaid_subscription()?)
+      .ok_or(ErrorCode::CalculationOverflow.into())
+  }
+
+  /// Refund owed when a pending request is cancelled at `current_time`:
+  /// what the developer paid, less the service fee share the schedule keeps
+  /// Returns (service_fee_refund, subscription_refund); requests created
+  /// before payment tracking fall back to the fee schedule
+  pub fn calculate_cancel_refund(&self, current_time: i64) -> Result<(u64, u64)> {
+    let service_fee_refund = self.calculate_service_fee_refund(current_time)?;
+    let subscription_refund = if self.total_paid_by_developer > 0 {
+      self
+        .total_paid_by_developer
+        .saturating_sub(self.service_fee)
+    } else {
+      self.calculate_prepaid_subscription()?
+    };
+    Ok((service_fee_refund, subscription_refund))
+  }
+
   /// Get debt repayment status as a percentage (0-100)
   pub fn get_repayment_percentage(&self) -> u8 {
     if self.borrowed_amount == 0 {
@@ -243,3 +871,183 @@ impl DeployRequest {
     ((self.repaid_amount as u128) * 100 / (self.borrowed_amount as u128)) as u8
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn request_id_is_unique_per_developer_and_nonce() {
+    let developer = Pubkey::new_unique();
+    let program_hash = [7u8; 32];
+    assert_eq!(
+      DeployRequest::derive_request_id(&developer, &program_hash, 0),
+      program_hash
+    );
+
+    let staging = DeployRequest::derive_request_id(&developer, &program_hash, 1);
+    let production = DeployRequest::derive_request_id(&developer, &program_hash, 2);
+    assert_ne!(staging, program_hash);
+    assert_ne!(staging, production);
+    assert_ne!(
+      staging,
+      DeployRequest::derive_request_id(&Pubkey::new_unique(), &program_hash, 1)
+    );
+  }
+
+  #[test]
+  fn unused_subscription_is_refunded_at_the_discounted_rate_paid() {
+    let mut request =
+      DeployRequest::deserialize(&mut &[0u8; DeployRequest::INIT_SPACE][..]).unwrap();
+    request.monthly_fee = 1_000;
+    let month = DeployRequest::SECONDS_PER_MONTH;
+    let start = 1_000 * month;
+
+    request.start_subscription(1, 1_000, start);
+    assert_eq!(
+      request
+        .calculate_unused_subscription(start + month / 2)
+        .unwrap(),
+      500
+    );
+
+    // Six more months prepaid for 5_000 instead of the 6_000 list price
+    let now = start + month / 2;
+    request.record_subscription_payment(5_000, now).unwrap();
+    request.extend_subscription(6).unwrap();
+    assert_eq!(request.calculate_unused_subscription(now).unwrap(), 5_500);
+    let halfway = now + (request.subscription_paid_until - now) / 2;
+    assert_eq!(
+      request.calculate_unused_subscription(halfway).unwrap(),
+      2_750
+    );
+
+    request.end_subscription(halfway);
+    assert_eq!(request.calculate_unused_subscription(halfway).unwrap(), 0);
+  }
+
+  #[test]
+  fn cancel_refunds_the_tracked_payment_less_the_retained_service_fee() {
+    let mut request =
+      DeployRequest::deserialize(&mut &[0u8; DeployRequest::INIT_SPACE][..]).unwrap();
+    let month = DeployRequest::SECONDS_PER_MONTH;
+    request.created_at = 1_000 * month;
+    request.monthly_fee = 1_000;
+    request.service_fee = 1_000;
+    request.set_service_fee_refund_schedule(5_000, month);
+    // Three months prepaid at a discount: 2_700 instead of 3_000
+    request.start_subscription(3, 2_700, request.created_at);
+    request.record_developer_payment(3_700).unwrap();
+
+    assert_eq!(
+      request.calculate_cancel_refund(request.created_at).unwrap(),
+      (500, 2_700)
+    );
+    assert_eq!(
+      request
+        .calculate_cancel_refund(request.created_at + month + 1)
+        .unwrap(),
+      (0, 2_700)
+    );
+  }
+
+  #[test]
+  fn hibernation_stretches_paid_time_and_credits_it_on_redeploy() {
+    let mut request =
+      DeployRequest::deserialize(&mut &[0u8; DeployRequest::INIT_SPACE][..]).unwrap();
+    request.monthly_fee = 1_000;
+    let month = DeployRequest::SECONDS_PER_MONTH;
+    let start = 1_000 * month;
+
+    // One month left at 1_000 lasts ten months at the 10% storage fee
+    request.start_subscription(1, 1_000, start);
+    request.hibernate(start).unwrap();
+    assert_eq!(request.status, DeployRequestStatus::Hibernated);
+    assert_eq!(request.subscription_paid_until, start + 10 * month);
+
+    // Half the storage time left is worth half a month of service again
+    let now = start + 5 * month;
+    assert_eq!(request.take_hibernation_credit(now).unwrap(), 500);
+    assert!(request.take_hibernation_credit(now).is_err());
+  }
+
+  #[test]
+  fn collateral_lowers_borrow_fees_only_from_the_checkpoint_on() {
+    let mut request =
+      DeployRequest::deserialize(&mut &[0u8; DeployRequest::INIT_SPACE][..]).unwrap();
+    request.borrowed_amount = 10_000_000;
+    let month = DeployRequest::SECONDS_PER_MONTH;
+
+    // Three months uncollateralized at 1% = 100_000 per month
+    assert_eq!(request.total_borrow_fees_at(3 * month).unwrap(), 300_000);
+
+    request.checkpoint_borrow_fees(3 * month).unwrap();
+    request.collateral_amount = request.borrowed_amount;
+    assert_eq!(request.total_borrow_fees_at(3 * month).unwrap(), 300_000);
+    assert_eq!(request.total_borrow_fees_at(6 * month).unwrap(), 300_000);
+
+    request.checkpoint_borrow_fees(6 * month).unwrap();
+    request.collateral_amount = 0;
+    assert_eq!(request.total_borrow_fees_at(7 * month).unwrap(), 400_000);
+  }
+
+  #[test]
+  fn deployment_funding_limit_grows_with_program_size() {
+    let rent = Rent::default();
+    let mut request =
+      DeployRequest::deserialize(&mut &[0u8; DeployRequest::INIT_SPACE][..]).unwrap();
+    request.program_size = 100_000;
+    let limit = request.max_deployment_funding(&rent).unwrap();
+    assert_eq!(
+      limit,
+      DeployRequest::DEPLOYMENT_FEE_ALLOWANCE
+        + rent.minimum_balance(36)
+        + rent.minimum_balance(200_045)
+        + rent.minimum_balance(100_037)
+    );
+
+    request.program_size = 200_000;
+    assert!(request.max_deployment_funding(&rent).unwrap() > limit);
+  }
+
+  #[test]
+  fn live_deployment_is_deployed_again_under_the_next_nonce() {
+    let developer = Pubkey::new_unique();
+    let program_hash = [7u8; 32];
+    let mut live = DeployRequest::deserialize(&mut &[0u8; DeployRequest::INIT_SPACE][..]).unwrap();
+    live.request_id = DeployRequest::derive_request_id(&developer, &program_hash, 0);
+    live.status = DeployRequestStatus::Active;
+    live.total_funded = 1_000;
+    assert!(live.check_retryable().is_err());
+
+    assert_ne!(
+      DeployRequest::derive_request_id(&developer, &program_hash, 1),
+      live.request_id
+    );
+  }
+
+  #[test]
+  fn only_unfunded_failed_or_cancelled_requests_are_retryable() {
+    let mut request =
+      DeployRequest::deserialize(&mut &[0u8; DeployRequest::INIT_SPACE][..]).unwrap();
+    for status in [
+      DeployRequestStatus::PendingDeployment,
+      DeployRequestStatus::Active,
+      DeployRequestStatus::Hibernated,
+    ] {
+      request.status = status;
+      assert!(request.check_retryable().is_err());
+    }
+
+    request.status = DeployRequestStatus::Failed;
+    assert!(request.check_retryable().is_ok());
+    request.status = DeployRequestStatus::Cancelled;
+    assert!(request.check_retryable().is_ok());
+
+    request.total_funded = 1;
+    assert!(request.check_retryable().is_err());
+    request.total_funded = 0;
+    request.ephemeral_key = Some(Pubkey::new_unique());
+    assert!(request.check_retryable().is_err());
+  }
+}
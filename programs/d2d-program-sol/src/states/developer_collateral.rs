@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// SOL a developer locks against one deployment's borrowed capital
+///
+/// The locked amount is excluded from the 1% monthly borrow fee (mirrored in
+/// `DeployRequest::collateral_amount`). The lamports stay in this PDA until
+/// release_developer_collateral settles any debt left after the program is
+/// closed and returns the rest.
+#[account]
+#[derive(InitSpace)]
+pub struct DeveloperCollateral {
+  pub deploy_request: Pubkey,
+  pub developer: Pubkey,
+  /// Collateral locked (lamports above the account's rent)
+  pub amount: u64,
+  pub locked_at: i64,
+  pub bump: u8,
+}
+
+impl DeveloperCollateral {
+  pub const PREFIX_SEED: &'static [u8] = b"developer_collateral";
+}
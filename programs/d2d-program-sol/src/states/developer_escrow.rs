@@ -14,10 +14,25 @@ impl Default for TokenType {
   }
 }
 
+/// Cap on what automatic renewals of one deploy request may take from a
+/// shared escrow
+#[derive(
+  AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct EscrowBudget {
+  /// Budgeted request (all zeros = unused slot)
+  pub request_id: [u8; 32],
+  /// Most the request's automatic renewals may deduct in total, in units
+  /// of the renewal token
+  pub limit: u64,
+  /// Deducted so far against `limit`
+  pub spent: u64,
+}
+
 /// Developer Escrow Account
 /// Stores pre-funded balance for automatic subscription renewals
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct DeveloperEscrow {
   /// Developer wallet public key (owner)
   pub developer: Pubkey,
@@ -63,10 +78,35 @@ pub struct DeveloperEscrow {
 
   /// PDA bump seed
   pub bump: u8,
+
+  // === SPENDING LIMITS ===
+  // Apply to automatic deductions (auto_renew_subscription and keeper
+  // renewals), in units of the renewal token
+  /// Highest per-month price an automatic renewal may pay (0 = no cap)
+  pub max_monthly_spend: u64,
+  /// Hard cap on automatic deductions per 30-day period (0 = no limit).
+  /// Bounds what a compromised or buggy renewal crank can take, e.g. by
+  /// renewing 120 months at once
+  pub max_auto_deduct_per_month: u64,
+  /// Start of the current 30-day deduction period
+  pub deduct_period_start: i64,
+  /// Automatically deducted so far in the current period
+  pub deducted_this_period: u64,
+
+  // === PER-PROGRAM BUDGETS ===
+  /// Optional caps per deploy request so one program's renewals cannot
+  /// starve the others; requests without an entry are only bound by the
+  /// limits above
+  pub budgets: [EscrowBudget; 8],
 }
 
 impl DeveloperEscrow {
   pub const PREFIX_SEED: &'static [u8] = b"developer_escrow";
+  /// Length of a max_auto_deduct_per_month period
+  pub const DEDUCT_PERIOD: i64 = 30 * 24 * 60 * 60;
+  /// Per-escrow token vault: [TOKEN_VAULT_SEED, escrow, mint], owned by the escrow PDA
+  pub const TOKEN_VAULT_SEED: &'static [u8] = b"escrow_token_vault";
+  pub const MAX_BUDGETS: usize = 8;
 
   /// Check if escrow can cover an auto-deduction for the given amount and token type
   pub fn can_auto_deduct(&self, amount: u64, token_type: TokenType) -> bool {
@@ -174,6 +214,136 @@ impl DeveloperEscrow {
     Ok(())
   }
 
+  /// Amount already deducted in the period containing `current_time`
+  pub fn period_deducted(&self, current_time: i64) -> u64 {
+    if current_time >= self.deduct_period_start.saturating_add(Self::DEDUCT_PERIOD) {
+      0
+    } else {
+      self.deducted_this_period
+    }
+  }
+
+  /// Budget entry for `request_id`, if the developer set one
+  pub fn budget(&self, request_id: &[u8; 32]) -> Option<&EscrowBudget> {
+    self
+      .budgets
+      .iter()
+      .find(|budget| budget.request_id == *request_id)
+  }
+
+  /// Set the budget of `request_id` (0 = remove it)
+  ///
+  /// Changing the limit keeps what was already spent against it.
+  pub fn set_budget(&mut self, request_id: [u8; 32], limit: u64) -> Result<()> {
+    require!(request_id != [0u8; 32], ErrorCode::InvalidRequestId);
+
+    if let Some(budget) = self
+      .budgets
+      .iter_mut()
+      .find(|budget| budget.request_id == request_id)
+    {
+      if limit == 0 {
+        *budget = EscrowBudget::default();
+      } else {
+        budget.limit = limit;
+      }
+      return Ok(());
+    }
+    if limit == 0 {
+      return Ok(());
+    }
+
+    let slot = self
+      .budgets
+      .iter_mut()
+      .find(|budget| budget.request_id == [0u8; 32])
+      .ok_or(ErrorCode::EscrowBudgetsFull)?;
+    *slot = EscrowBudget {
+      request_id,
+      limit,
+      spent: 0,
+    };
+    Ok(())
+  }
+
+  /// Check an automatic renewal of `months` for `request_id` against the
+  /// spending limits and the request's budget
+  pub fn check_spending_limits(
+    &self,
+    request_id: &[u8; 32],
+    amount: u64,
+    months: u32,
+    current_time: i64,
+  ) -> Result<()> {
+    if self.max_monthly_spend > 0 {
+      let per_month = amount.div_ceil(months.max(1) as u64);
+      require!(
+        per_month <= self.max_monthly_spend,
+        ErrorCode::EscrowSpendingCapExceeded
+      );
+    }
+    if self.max_auto_deduct_per_month > 0 {
+      let deducted = self
+        .period_deducted(current_time)
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationOverflow)?;
+      require!(
+        deducted <= self.max_auto_deduct_per_month,
+        ErrorCode::AutoDeductCapExceeded
+      );
+    }
+    if let Some(budget) = self.budget(request_id) {
+      let spent = budget
+        .spent
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationOverflow)?;
+      require!(spent <= budget.limit, ErrorCode::EscrowBudgetExceeded);
+    }
+    Ok(())
+  }
+
+  /// Check the spending limits and count an automatic deduction against the
+  /// current period and the request's budget (the balance itself is debited
+  /// by deduct_balance)
+  pub fn apply_auto_deduction(
+    &mut self,
+    request_id: &[u8; 32],
+    amount: u64,
+    months: u32,
+    current_time: i64,
+  ) -> Result<()> {
+    self.check_spending_limits(request_id, amount, months, current_time)?;
+    if let Some(budget) = self
+      .budgets
+      .iter_mut()
+      .find(|budget| budget.request_id == *request_id)
+    {
+      budget.spent = budget.spent.saturating_add(amount);
+    }
+    if current_time >= self.deduct_period_start.saturating_add(Self::DEDUCT_PERIOD) {
+      self.deduct_period_start = current_time;
+      self.deducted_this_period = 0;
+    }
+    self.deducted_this_period = self
+      .deducted_this_period
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// Withdraw from balance (unlike deduct_balance, not counted as auto-deducted)
+  pub fn withdraw_balance(&mut self, amount: u64, token_type: TokenType) -> Result<()> {
+    let balance = match token_type {
+      TokenType::SOL => &mut self.sol_balance,
+      TokenType::USDC => &mut self.usdc_balance,
+      TokenType::USDT => &mut self.usdt_balance,
+    };
+    require!(*balance >= amount, ErrorCode::InsufficientEscrowBalance);
+    *balance -= amount;
+
+    Ok(())
+  }
+
   /// Check if balance is below alert threshold
   pub fn is_below_alert_threshold(&self) -> bool {
     match self.preferred_token {
@@ -185,3 +355,123 @@ impl DeveloperEscrow {
 }
 
 use crate::errors::ErrorCode;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const REQUEST: [u8; 32] = [1u8; 32];
+
+  /// Account size before the spending limits and budgets were added
+  const LEGACY_SPACE: usize = 8 + 123;
+
+  #[test]
+  fn zero_padded_legacy_escrow_has_no_limits_or_budgets() {
+    let developer = Pubkey::new_unique();
+    let escrow = DeveloperEscrow {
+      developer,
+      sol_balance: 42,
+      bump: 255,
+      max_monthly_spend: 7,
+      budgets: [EscrowBudget {
+        request_id: REQUEST,
+        limit: 1,
+        spent: 0,
+      }; DeveloperEscrow::MAX_BUDGETS],
+      ..Default::default()
+    };
+    let mut data = Vec::new();
+    escrow.try_serialize(&mut data).unwrap();
+    assert_eq!(data.len(), 8 + DeveloperEscrow::INIT_SPACE);
+
+    // What migrate_developer_escrow leaves behind for a legacy account
+    data[LEGACY_SPACE..].fill(0);
+    let migrated = DeveloperEscrow::try_deserialize(&mut &data[..]).unwrap();
+    assert_eq!(migrated.developer, developer);
+    assert_eq!(migrated.sol_balance, 42);
+    assert_eq!(migrated.bump, 255);
+    assert_eq!(migrated.max_monthly_spend, 0);
+    assert!(migrated
+      .budgets
+      .iter()
+      .all(|b| *b == EscrowBudget::default()));
+  }
+
+  #[test]
+  fn spending_cap_limits_price_per_month() {
+    let escrow = DeveloperEscrow {
+      max_monthly_spend: 100,
+      ..Default::default()
+    };
+    assert!(escrow.check_spending_limits(&REQUEST, 300, 3, 0).is_ok());
+    assert!(escrow.check_spending_limits(&REQUEST, 301, 3, 0).is_err());
+  }
+
+  #[test]
+  fn deduct_limit_resets_each_period() {
+    let mut escrow = DeveloperEscrow {
+      max_auto_deduct_per_month: 500,
+      ..Default::default()
+    };
+    let start = 1_000;
+    escrow
+      .apply_auto_deduction(&REQUEST, 300, 1, start)
+      .unwrap();
+    escrow
+      .apply_auto_deduction(&REQUEST, 200, 1, start + 10)
+      .unwrap();
+    assert!(escrow
+      .apply_auto_deduction(&REQUEST, 1, 1, start + 20)
+      .is_err());
+    assert_eq!(escrow.deducted_this_period, 500);
+
+    // A new period starts DEDUCT_PERIOD after the first deduction
+    let next = start + DeveloperEscrow::DEDUCT_PERIOD;
+    assert_eq!(escrow.period_deducted(next), 0);
+    escrow.apply_auto_deduction(&REQUEST, 400, 1, next).unwrap();
+    assert_eq!(escrow.deduct_period_start, next);
+    assert_eq!(escrow.deducted_this_period, 400);
+  }
+
+  #[test]
+  fn auto_deduct_cap_blocks_bulk_renewal() {
+    let escrow = DeveloperEscrow {
+      sol_balance: 10_000,
+      max_auto_deduct_per_month: 100,
+      ..Default::default()
+    };
+    // One month at 100 fits, 120 months at once does not
+    assert!(escrow.check_spending_limits(&REQUEST, 100, 1, 0).is_ok());
+    assert!(escrow
+      .check_spending_limits(&REQUEST, 12_000, 120, 0)
+      .is_err());
+  }
+
+  #[test]
+  fn budget_caps_one_program_without_touching_others() {
+    let mut escrow = DeveloperEscrow::default();
+    let other = [2u8; 32];
+    escrow.set_budget(REQUEST, 250).unwrap();
+    escrow.apply_auto_deduction(&REQUEST, 200, 1, 0).unwrap();
+    assert!(escrow.apply_auto_deduction(&REQUEST, 100, 1, 0).is_err());
+    assert!(escrow.apply_auto_deduction(&other, 1_000, 1, 0).is_ok());
+    assert_eq!(escrow.budget(&REQUEST).unwrap().spent, 200);
+
+    // Raising the limit keeps the spend; removing it lifts the cap
+    escrow.set_budget(REQUEST, 300).unwrap();
+    assert!(escrow.check_spending_limits(&REQUEST, 100, 1, 0).is_ok());
+    escrow.set_budget(REQUEST, 0).unwrap();
+    assert!(escrow.budget(&REQUEST).is_none());
+  }
+
+  #[test]
+  fn budget_slots_are_limited() {
+    let mut escrow = DeveloperEscrow::default();
+    for i in 1..=DeveloperEscrow::MAX_BUDGETS as u8 {
+      escrow.set_budget([i; 32], 100).unwrap();
+    }
+    assert!(escrow.set_budget([100u8; 32], 100).is_err());
+    escrow.set_budget([1u8; 32], 0).unwrap();
+    assert!(escrow.set_budget([100u8; 32], 100).is_ok());
+  }
+}
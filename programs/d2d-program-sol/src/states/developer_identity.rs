@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// Link between a developer wallet and an off-chain profile (GitHub org, domain)
+///
+/// The developer stores a hash of the profile; the identity registrar attests
+/// it after verifying ownership off-chain. Attested identities are shown by the
+/// frontend and unlock the verified buffer rent loan cap.
+#[account]
+#[derive(InitSpace)]
+pub struct DeveloperIdentity {
+  pub developer: Pubkey,
+  /// Hash of the canonical off-chain profile descriptor
+  pub profile_hash: [u8; 32],
+  pub attested: bool,
+  /// Registrar that last attested or revoked the profile
+  pub attested_by: Pubkey,
+  pub attested_at: i64,
+  pub linked_at: i64,
+  pub bump: u8,
+}
+
+impl DeveloperIdentity {
+  pub const PREFIX_SEED: &'static [u8] = b"developer_identity";
+
+  /// Store a profile hash; a changed profile loses its attestation
+  pub fn link(&mut self, profile_hash: [u8; 32], current_time: i64) {
+    if self.profile_hash != profile_hash {
+      self.attested = false;
+      self.attested_by = Pubkey::default();
+      self.attested_at = 0;
+    }
+    self.profile_hash = profile_hash;
+    self.linked_at = current_time;
+  }
+
+  pub fn is_attested(&self) -> bool {
+    self.attested
+  }
+}
@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, states::TreasuryPool};
+
+/// Hypothetical stress scenario run by simulate_drawdown
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DrawdownScenario {
+  /// Share of reward-eligible stake that asks to exit (bps), on top of
+  /// withdrawals already queued
+  pub exit_bps: u64,
+  /// Outstanding debt of the deployments assumed to default
+  pub defaulted_debt: u64,
+  pub defaulted_deployments: u32,
+  /// Share of the defaulted debt still recovered from rent (bps)
+  pub default_recovery_bps: u64,
+}
+
+/// Latest guardian stress test of the treasury (one per pool, overwritten)
+///
+/// Every figure is computed on-chain from the treasury state at
+/// `simulated_slot`, so the report can be checked against the account
+/// history instead of an off-chain risk model.
+#[account]
+#[derive(InitSpace, Default)]
+pub struct DrawdownReport {
+  pub guardian: Pubkey,
+  // Scenario
+  pub exit_bps: u64,
+  pub defaulted_deployments: u32,
+  pub defaulted_debt: u64,
+  pub default_recovery_bps: u64,
+  // Liquidity
+  /// Queued withdrawals plus the hypothetical exits
+  pub exit_demand: u64,
+  pub liquid_balance: u64,
+  /// Exit demand the liquid balance cannot pay immediately
+  pub liquidity_shortfall: u64,
+  /// Share of exit demand payable immediately (bps)
+  pub exit_coverage_bps: u64,
+  // Losses
+  /// Defaulted debt not recovered from rent
+  pub default_loss: u64,
+  /// default_loss over total deposits (bps)
+  pub principal_loss_bps: u64,
+  /// Outstanding debt over the deposits left after exits and losses (bps)
+  pub utilization_after_bps: u64,
+  // Protected rewards
+  pub protected_rewards: u64,
+  pub reward_pool_balance: u64,
+  /// Credited-but-unclaimed rewards not backed by the reward pool balance
+  pub protected_rewards_shortfall: u64,
+  pub simulated_slot: u64,
+  pub simulated_at: i64,
+  pub bump: u8,
+}
+
+impl DrawdownReport {
+  pub const PREFIX_SEED: &'static [u8] = b"drawdown_report";
+  /// Max deployments assumed to default per run (compute bound)
+  pub const MAX_DEFAULTED_DEPLOYMENTS: usize = 20;
+
+  /// Compute the scenario's outcome from the current treasury state
+  pub fn simulate(
+    &mut self,
+    treasury_pool: &TreasuryPool,
+    scenario: &DrawdownScenario,
+  ) -> Result<()> {
+    require!(
+      scenario.exit_bps <= 10_000 && scenario.default_recovery_bps <= 10_000,
+      ErrorCode::InvalidDrawdownScenario
+    );
+
+    let bps_of = |amount: u64, bps: u64| -> Result<u64> {
+      Ok(
+        ((amount as u128)
+          .checked_mul(bps as u128)
+          .ok_or(ErrorCode::CalculationOverflow)?
+          / 10_000) as u64,
+      )
+    };
+    let ratio_bps = |part: u64, whole: u64| -> u64 {
+      if whole == 0 {
+        return 0;
+      }
+      ((part as u128 * 10_000) / whole as u128).min(u64::MAX as u128) as u64
+    };
+
+    // Liquidity: queued exits come first, the scenario's exits on top
+    let new_exits = bps_of(
+      treasury_pool.get_reward_eligible_deposits(),
+      scenario.exit_bps,
+    )?;
+    let exit_demand = treasury_pool
+      .queued_withdrawal_amount
+      .checked_add(new_exits)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    let paid_now = exit_demand.min(treasury_pool.liquid_balance);
+    let exit_coverage_bps = if exit_demand == 0 {
+      10_000
+    } else {
+      ratio_bps(paid_now, exit_demand)
+    };
+
+    // Defaults: the unrecovered part of the debt is lost principal
+    let defaulted_debt = scenario.defaulted_debt.min(treasury_pool.total_borrowed);
+    let default_loss = defaulted_debt - bps_of(defaulted_debt, scenario.default_recovery_bps)?;
+    let deposits_after = treasury_pool
+      .total_deposited
+      .saturating_sub(new_exits)
+      .saturating_sub(default_loss);
+    let debt_after = treasury_pool.total_borrowed - defaulted_debt;
+
+    let protected_rewards = treasury_pool.get_protected_rewards();
+
+    self.exit_bps = scenario.exit_bps;
+    self.defaulted_deployments = scenario.defaulted_deployments;
+    self.defaulted_debt = defaulted_debt;
+    self.default_recovery_bps = scenario.default_recovery_bps;
+    self.exit_demand = exit_demand;
+    self.liquid_balance = treasury_pool.liquid_balance;
+    self.liquidity_shortfall = exit_demand - paid_now;
+    self.exit_coverage_bps = exit_coverage_bps;
+    self.default_loss = default_loss;
+    self.principal_loss_bps = ratio_bps(default_loss, treasury_pool.total_deposited);
+    self.utilization_after_bps = ratio_bps(debt_after, deposits_after);
+    self.protected_rewards = protected_rewards;
+    self.reward_pool_balance = treasury_pool.reward_pool_balance;
+    self.protected_rewards_shortfall =
+      protected_rewards.saturating_sub(treasury_pool.reward_pool_balance);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn report(pool: &TreasuryPool, scenario: DrawdownScenario) -> DrawdownReport {
+    let mut report = DrawdownReport::default();
+    report.simulate(pool, &scenario).unwrap();
+    report
+  }
+
+  #[test]
+  fn exits_beyond_liquidity_leave_a_shortfall() {
+    let pool = TreasuryPool {
+      total_deposited: 10_000,
+      liquid_balance: 3_000,
+      queued_withdrawal_amount: 1_000,
+      total_borrowed: 7_000,
+      ..Default::default()
+    };
+    // 50% of the 9_000 eligible stake exits on top of 1_000 queued
+    let result = report(
+      &pool,
+      DrawdownScenario {
+        exit_bps: 5_000,
+        ..Default::default()
+      },
+    );
+    assert_eq!(result.exit_demand, 5_500);
+    assert_eq!(result.liquidity_shortfall, 2_500);
+    assert_eq!(result.exit_coverage_bps, 3_000 * 10_000 / 5_500);
+    assert_eq!(result.default_loss, 0);
+  }
+
+  #[test]
+  fn defaults_lose_unrecovered_debt() {
+    let pool = TreasuryPool {
+      total_deposited: 10_000,
+      liquid_balance: 6_000,
+      total_borrowed: 4_000,
+      reward_pool_balance: 100,
+      total_credited_rewards: 300,
+      total_claimed_rewards: 100,
+      ..Default::default()
+    };
+    let result = report(
+      &pool,
+      DrawdownScenario {
+        defaulted_debt: 2_000,
+        defaulted_deployments: 2,
+        default_recovery_bps: 2_500,
+        ..Default::default()
+      },
+    );
+    assert_eq!(result.default_loss, 1_500);
+    assert_eq!(result.principal_loss_bps, 1_500);
+    // 2_000 debt left over 8_500 deposits
+    assert_eq!(result.utilization_after_bps, 2_000 * 10_000 / 8_500);
+    assert_eq!(result.exit_coverage_bps, 10_000);
+    assert_eq!(result.protected_rewards_shortfall, 100);
+  }
+
+  #[test]
+  fn rejects_out_of_range_scenarios() {
+    let mut result = report(&TreasuryPool::default(), DrawdownScenario::default());
+    let scenario = DrawdownScenario {
+      exit_bps: 10_001,
+      ..Default::default()
+    };
+    assert!(result
+      .simulate(&TreasuryPool::default(), &scenario)
+      .is_err());
+  }
+}
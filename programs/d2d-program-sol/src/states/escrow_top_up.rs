@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+
+use crate::states::{DeveloperEscrow, TokenType, TreasuryPool};
+
+/// Standing allowance letting a crank refill a developer's escrow
+///
+/// The developer approves this PDA as SPL delegate on one of their token
+/// accounts (wSOL for SOL escrows). Whenever the escrow balance of
+/// `token_type` drops below the escrow's `min_balance_alert`, anyone may pull
+/// `top_up_amount` from that account into the escrow, until the approved
+/// allowance runs out.
+#[account]
+#[derive(InitSpace)]
+pub struct EscrowTopUp {
+  pub developer: Pubkey,
+  pub token_type: TokenType,
+  /// Developer token account the allowance was approved on
+  pub source_token_account: Pubkey,
+  /// Amount pulled per top-up
+  pub top_up_amount: u64,
+  pub total_topped_up: u64,
+  pub top_up_count: u32,
+  pub last_top_up_at: i64,
+  pub enabled_at: i64,
+  pub bump: u8,
+}
+
+impl EscrowTopUp {
+  pub const PREFIX_SEED: &'static [u8] = b"escrow_top_up";
+
+  /// Mint the source account must hold: wSOL for SOL, else the configured
+  /// escrow mint (None = token not accepted)
+  pub fn source_mint(treasury_pool: &TreasuryPool, token_type: TokenType) -> Option<Pubkey> {
+    match token_type {
+      TokenType::SOL => Some(native_mint::ID),
+      TokenType::USDC | TokenType::USDT => treasury_pool.escrow_token_mint(token_type),
+    }
+  }
+
+  /// Escrow balance is below the developer's alert threshold
+  pub fn is_due(&self, developer_escrow: &DeveloperEscrow) -> bool {
+    developer_escrow.get_balance(self.token_type) < developer_escrow.min_balance_alert
+  }
+
+  pub fn record_top_up(&mut self, amount: u64, current_time: i64) {
+    self.total_topped_up = self.total_topped_up.saturating_add(amount);
+    self.top_up_count = self.top_up_count.saturating_add(1);
+    self.last_top_up_at = current_time;
+  }
+}
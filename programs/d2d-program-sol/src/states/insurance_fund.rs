@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, states::BackerDeposit};
+
+/// Opt-in coverage pool for stakers against deployment losses
+///
+/// Stakers pay a one-time premium on the amount they cover. When a closed
+/// deployment recovers less rent than `loss_threshold_bps` of what it
+/// borrowed, the unrecovered debt (capped by the fund's available balance)
+/// is committed pro-rata to all covered stakers via an accumulator, exactly
+/// like reward_per_share, and claimed with `claim_insurance`.
+#[account]
+#[derive(InitSpace)]
+pub struct InsuranceFund {
+  /// Premium charged on coverage at opt-in (bps)
+  pub premium_bps: u64,
+  /// Recovery ratio below which a closed deployment counts as a loss event (bps)
+  pub loss_threshold_bps: u64,
+  /// Sum of coverage bought by all stakers
+  pub total_covered: u64,
+  /// Compensation per lamport of coverage (scaled by TreasuryPool::PRECISION)
+  pub compensation_per_coverage: u128,
+  /// Premiums not yet committed to a loss event
+  pub available_balance: u64,
+  /// Compensation committed to covered stakers but not yet claimed
+  pub reserved_compensation: u64,
+  /// Lifetime premiums collected
+  pub total_premiums: u64,
+  /// Lifetime compensation committed to loss events
+  pub total_losses_covered: u64,
+  /// Lifetime compensation paid out
+  pub total_compensation_paid: u64,
+  /// Number of loss events recorded
+  pub loss_event_count: u32,
+  /// PDA bump
+  pub bump: u8,
+}
+
+impl InsuranceFund {
+  pub const PREFIX_SEED: &'static [u8] = b"insurance_fund";
+
+  pub const DEFAULT_PREMIUM_BPS: u64 = 50; // 0.5% of coverage
+  pub const MAX_PREMIUM_BPS: u64 = 1000; // 10% max
+  pub const DEFAULT_LOSS_THRESHOLD_BPS: u64 = 8000; // Below 80% recovery is a loss
+
+  /// Calculate the premium for an amount of coverage (rounded up)
+  pub fn calculate_premium(&self, coverage_amount: u64) -> Result<u64> {
+    let premium = (coverage_amount as u128)
+      .checked_mul(self.premium_bps as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      .checked_add(9999)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / 10000;
+    Ok(premium as u64)
+  }
+
+  /// Commit compensation for a loss event to all covered stakers
+  /// Returns the amount committed (capped by the available balance)
+  pub fn commit_loss(&mut self, loss_amount: u64) -> Result<u64> {
+    require!(self.total_covered > 0, ErrorCode::NoInsuredStake);
+
+    let committed = loss_amount.min(self.available_balance);
+    let delta = (committed as u128)
+      .checked_mul(crate::states::TreasuryPool::PRECISION)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / self.total_covered as u128;
+
+    self.compensation_per_coverage = self
+      .compensation_per_coverage
+      .checked_add(delta)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.available_balance -= committed;
+    self.reserved_compensation = self
+      .reserved_compensation
+      .checked_add(committed)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.total_losses_covered = self
+      .total_losses_covered
+      .checked_add(committed)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.loss_event_count = self.loss_event_count.saturating_add(1);
+
+    Ok(committed)
+  }
+
+  /// Settle a position's compensation and cut its coverage down to what
+  /// is still staked
+  ///
+  /// Called whenever a deposit shrinks, so loss events are only shared among
+  /// coverage backed by stake when they are recorded. Returns the coverage
+  /// released.
+  pub fn shrink_coverage(&mut self, lender_stake: &mut BackerDeposit) -> Result<u64> {
+    lender_stake.settle_insurance_compensation(self.compensation_per_coverage)?;
+
+    let new_insured_amount = lender_stake
+      .insured_amount
+      .min(lender_stake.get_effective_deposit());
+    let released = lender_stake.insured_amount - new_insured_amount;
+
+    lender_stake.insured_amount = new_insured_amount;
+    lender_stake.compensation_debt = (new_insured_amount as u128)
+      .checked_mul(self.compensation_per_coverage)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.total_covered = self.total_covered.saturating_sub(released);
+
+    Ok(released)
+  }
+}
+
+/// Record of a deployment loss event (one per deploy request)
+/// Its existence prevents the same loss from being compensated twice
+#[account]
+#[derive(InitSpace)]
+pub struct InsuranceLossEvent {
+  pub request_id: [u8; 32],
+  /// Debt left unrecovered after rent reclamation
+  pub loss_amount: u64,
+  /// Compensation committed to covered stakers
+  pub compensation_committed: u64,
+  /// Recovery ratio of the deployment (bps)
+  pub recovery_ratio_bps: u64,
+  pub recorded_at: i64,
+  pub bump: u8,
+}
+
+impl InsuranceLossEvent {
+  pub const PREFIX_SEED: &'static [u8] = b"insurance_loss";
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn fund(total_covered: u64) -> InsuranceFund {
+    InsuranceFund {
+      premium_bps: 0,
+      loss_threshold_bps: InsuranceFund::DEFAULT_LOSS_THRESHOLD_BPS,
+      total_covered,
+      compensation_per_coverage: 0,
+      available_balance: 1_000,
+      reserved_compensation: 0,
+      total_premiums: 0,
+      total_losses_covered: 0,
+      total_compensation_paid: 0,
+      loss_event_count: 0,
+      bump: 0,
+    }
+  }
+
+  fn covered_stake(deposited_amount: u64, insured_amount: u64) -> BackerDeposit {
+    let mut stake = BackerDeposit::deserialize(&mut &[0u8; BackerDeposit::INIT_SPACE][..]).unwrap();
+    stake.deposited_amount = deposited_amount;
+    stake.insured_amount = insured_amount;
+    stake
+  }
+
+  #[test]
+  fn shrinking_a_deposit_keeps_past_compensation_and_drops_future_share() {
+    let mut insurance_fund = fund(200);
+    let mut leaver = covered_stake(100, 100);
+    let mut stayer = covered_stake(100, 100);
+
+    // Loss while both are fully staked: 50 each
+    insurance_fund.commit_loss(100).unwrap();
+
+    // Leaver unstakes most of the deposit
+    leaver.deposited_amount = 10;
+    assert!(leaver.is_over_insured());
+    assert_eq!(insurance_fund.shrink_coverage(&mut leaver).unwrap(), 90);
+    assert_eq!(leaver.pending_compensation, 50);
+    assert_eq!(leaver.insured_amount, 10);
+    assert_eq!(insurance_fund.total_covered, 110);
+
+    // Next loss is split over the coverage still staked
+    insurance_fund.commit_loss(110).unwrap();
+    leaver
+      .settle_insurance_compensation(insurance_fund.compensation_per_coverage)
+      .unwrap();
+    stayer
+      .settle_insurance_compensation(insurance_fund.compensation_per_coverage)
+      .unwrap();
+    assert_eq!(leaver.pending_compensation, 60);
+    assert_eq!(stayer.pending_compensation, 150);
+
+    // Re-staking later does not grow compensation for past losses
+    leaver.deposited_amount = 100;
+    leaver
+      .settle_insurance_compensation(insurance_fund.compensation_per_coverage)
+      .unwrap();
+    assert_eq!(leaver.pending_compensation, 60);
+  }
+}
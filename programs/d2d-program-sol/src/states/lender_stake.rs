@@ -1,6 +1,6 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hash};
 
-use crate::errors::ErrorCode;
+use crate::{constant::BACKER_DEPOSIT_SEED, errors::ErrorCode};
 
 #[account]
 #[derive(InitSpace)]
@@ -32,17 +32,151 @@ pub struct BackerDeposit {
   pub queue_position: u32,
   /// Timestamp when withdrawal was queued
   pub queued_at: i64,
+
+  // === CLAIM DELEGATION ===
+  /// Key allowed to call claim on the backer's behalf (default = none)
+  /// Rewards are always paid to the backer wallet
+  pub claim_delegate: Pubkey,
+
+  // === AUTO COMPOUND ===
+  /// Opt-in: settled rewards are restaked into deposited_amount by the
+  /// permissionless compound_rewards crank
+  pub auto_compound_enabled: bool,
+
+  // === STAKE RATE LIMIT ===
+  /// Day (unix timestamp at 00:00 UTC) staked_today refers to
+  pub last_stake_day: i64,
+  /// Lamports staked via stake_sol on last_stake_day
+  pub staked_today: u64,
+
+  // === BONUS VESTING ===
+  /// Duration bonus locked in the current linear vesting schedule
+  pub vesting_bonus_amount: u64,
+  /// Portion of vesting_bonus_amount already paid out
+  pub vesting_bonus_claimed: u64,
+  /// Start of the current vesting schedule (0 if none)
+  pub vesting_start_at: i64,
+  /// End of the current vesting schedule (fully vested from here on)
+  pub vesting_end_at: i64,
+
+  // === INSURANCE COVERAGE ===
+  /// Coverage bought in the insurance fund (0 = not covered)
+  pub insured_amount: u64,
+  /// insured_amount * compensation_per_coverage at last settlement
+  pub compensation_debt: u128,
+  /// Settled compensation waiting to be claimed
+  pub pending_compensation: u64,
+
+  // === TARGETED BONUS ===
+  /// Lifetime admin-credited bonus (promotions, make-goods)
+  pub total_bonus_credited: u64,
+
+  // === INDEXED POSITIONS ===
+  /// 0 for the wallet's primary position, otherwise the index of a position
+  /// carved out by split_stake_position: ["lender_stake", staker, index]
+  pub position_index: u32,
+  /// Indexed positions issued so far (primary position only)
+  pub positions_created: u32,
+
+  // === COLLATERAL PLEDGE ===
+  /// Partner program holding this position as collateral (default = none)
+  /// Blocks unstaking until the partner releases the pledge
+  pub pledged_to: Pubkey,
+  pub pledged_at: i64,
+
+  // === AUTO UNSTAKE ===
+  /// Once passed, trigger_auto_unstake may queue the whole position for
+  /// withdrawal (0 = never)
+  pub auto_unstake_at: i64,
+
+  // === DUST SWEEP ===
+  /// When sweep_dust_position queued this position out (0 = never)
+  pub dust_swept_at: i64,
+
+  // === PAYOUT ADDRESS ===
+  /// Wallet claim_rewards may pay to instead of the signer (default = none)
+  pub payout_address: Pubkey,
+
+  // === UNSTAKE COMMITMENT ===
+  /// Amount declared via commit_unstake (0 = none); unstake_sol must
+  /// withdraw exactly this amount once unstake_executable_at has passed
+  pub committed_unstake_amount: u64,
+  pub unstake_executable_at: i64,
+
+  // === BOOST NFT ===
+  /// Boost NFT presented at stake time (default = none)
+  pub boost_nft_mint: Pubkey,
+  /// Extra duration weight accrued by this position, in bps (0 = none)
+  pub nft_boost_bps: u64,
+
+  // === BOOST CAMPAIGN ===
+  /// Campaign this position is enrolled in (default = none); until it ends,
+  /// every decrease of the deposit checkpoints the enrollment
+  pub boost_campaign: Pubkey,
+  pub boost_campaign_end_at: i64,
+
+  // === POOL SHARE ===
+  /// Principal held in the staker's indexed positions (kept on the primary
+  /// position); counts toward max_pool_share_bps with deposited_amount
+  pub indexed_deposits: u64,
+
+  // === INSTANT UNSTAKE WINDOW ===
+  /// Uncommitted unstakes since instant_unstake_window_start; the commitment
+  /// threshold applies to this running total, not to each call
+  pub instant_unstaked: u64,
+  pub instant_unstake_window_start: i64,
+
+  // === QUEUE JUMP FEE ===
+  /// queued_withdrawal * queue_fee_per_share at last settlement
+  pub queue_fee_debt: u128,
 }
 
+/// Legacy name for `BackerDeposit` - same PDA seed and layout.
+/// Accounts created under this name carry the `LenderStake` discriminator
+/// and must be migrated via `migrate_lender_stake` before they deserialize.
+#[deprecated(note = "use BackerDeposit - LenderStake is kept for backwards compatibility only")]
 pub type LenderStake = BackerDeposit;
 
 impl BackerDeposit {
-  pub const PREFIX_SEED: &'static [u8] = b"lender_stake";
+  pub const PREFIX_SEED: &'static [u8] = BACKER_DEPOSIT_SEED;
+  /// Preimage of the discriminator used when the account was named `LenderStake`
+  pub const LEGACY_DISCRIMINATOR_PREIMAGE: &'static [u8] = b"account:LenderStake";
+  /// Max positions compounded per compound_rewards call (compute bound)
+  pub const MAX_COMPOUND_BATCH: usize = 10;
+  /// Max positions paid per claim_all_rewards call (compute bound)
+  pub const MAX_CLAIM_BATCH: usize = 10;
+  /// Positions below this are dust for sweep_dust_position
+  pub const DUST_THRESHOLD: u64 = 10_000_000; // 0.01 SOL
+  /// Dust positions untouched for this long may be swept
+  pub const DUST_INACTIVITY_PERIOD: i64 = 365 * 24 * 60 * 60;
+  /// Window over which uncommitted unstakes add up toward the commitment
+  /// threshold
+  pub const INSTANT_UNSTAKE_WINDOW: i64 = 24 * 60 * 60;
+
+  /// Discriminator written by the legacy `LenderStake` account type
+  pub fn legacy_discriminator() -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(Self::LEGACY_DISCRIMINATOR_PREIMAGE).to_bytes()[..8]);
+    discriminator
+  }
+
+  /// Check if raw account data still carries the legacy `LenderStake` discriminator
+  pub fn has_legacy_discriminator(data: &[u8]) -> bool {
+    data.len() >= 8 && data[..8] == Self::legacy_discriminator()
+  }
+
+  /// Amount that accrues reward-per-share
+  /// Queued withdrawals are exiting capital and stop earning once queued,
+  /// so callers must settle before and update reward_debt after any change
+  /// to either deposited_amount or queued_withdrawal
+  pub fn get_reward_eligible_amount(&self) -> u64 {
+    self.get_effective_deposit()
+  }
 
   pub fn calculate_claimable_rewards(&self, reward_per_share: u128) -> Result<u64> {
     use crate::states::TreasuryPool;
 
-    let accumulated = (self.deposited_amount as u128)
+    let accumulated = (self.get_reward_eligible_amount() as u128)
       .checked_mul(reward_per_share)
       .ok_or(ErrorCode::CalculationOverflow)?;
 
@@ -69,9 +203,29 @@ impl BackerDeposit {
   }
 
   pub fn settle_pending_rewards(&mut self, reward_per_share: u128) -> Result<()> {
+    let new_rewards = self.calculate_unsettled_rewards(reward_per_share)?;
+
+    self.pending_rewards = self
+      .pending_rewards
+      .checked_add(new_rewards)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    Ok(())
+  }
+
+  /// Drop rewards accrued since the last settlement (emergency exits)
+  /// Returns the forfeited amount
+  pub fn forfeit_unsettled_rewards(&mut self, reward_per_share: u128) -> Result<u64> {
+    let forfeited = self.calculate_unsettled_rewards(reward_per_share)?;
+    self.update_reward_debt(reward_per_share)?;
+    Ok(forfeited)
+  }
+
+  /// Rewards accrued since reward_debt was last updated
+  pub fn calculate_unsettled_rewards(&self, reward_per_share: u128) -> Result<u64> {
     use crate::states::TreasuryPool;
 
-    let accumulated = (self.deposited_amount as u128)
+    let accumulated = (self.get_reward_eligible_amount() as u128)
       .checked_mul(reward_per_share)
       .ok_or(ErrorCode::CalculationOverflow)?;
 
@@ -88,16 +242,11 @@ impl BackerDeposit {
       0
     };
 
-    self.pending_rewards = self
-      .pending_rewards
-      .checked_add(new_rewards as u64)
-      .ok_or(ErrorCode::CalculationOverflow)?;
-
-    Ok(())
+    Ok(new_rewards as u64)
   }
 
   pub fn update_reward_debt(&mut self, reward_per_share: u128) -> Result<()> {
-    self.reward_debt = (self.deposited_amount as u128)
+    self.reward_debt = (self.get_reward_eligible_amount() as u128)
       .checked_mul(reward_per_share)
       .ok_or(ErrorCode::CalculationOverflow)?;
     Ok(())
@@ -116,9 +265,15 @@ impl BackerDeposit {
 
     let duration = current_time.saturating_sub(self.last_action_at).max(0) as u128;
 
-    let weight_delta = (self.deposited_amount as u128)
+    let mut weight_delta = (self.deposited_amount as u128)
       .checked_mul(duration)
       .ok_or(ErrorCode::CalculationOverflow)?;
+    if self.nft_boost_bps > 0 {
+      weight_delta = weight_delta
+        .checked_mul(10_000 + self.nft_boost_bps as u128)
+        .ok_or(ErrorCode::CalculationOverflow)?
+        / 10_000;
+    }
 
     self.stake_duration_weight = self
       .stake_duration_weight
@@ -130,6 +285,23 @@ impl BackerDeposit {
     Ok(weight_delta)
   }
 
+  /// Accrue duration weight on this position and the pool total together
+  ///
+  /// Every instruction that changes deposited_amount (or pays the duration
+  /// bonus) must call this first, so the weight always reflects the balance
+  /// actually held over each interval regardless of the entry point used.
+  pub fn settle_duration_weight(
+    &mut self,
+    treasury_pool: &mut crate::states::TreasuryPool,
+    current_time: i64,
+  ) -> Result<u128> {
+    let weight_delta = self.update_duration_weight(current_time)?;
+    if weight_delta > 0 {
+      treasury_pool.update_stake_duration_weight(weight_delta)?;
+    }
+    Ok(weight_delta)
+  }
+
   /// Reset duration weight after claiming rewards
   pub fn reset_duration_weight(&mut self, current_time: i64) {
     self.stake_duration_weight = 0;
@@ -160,7 +332,13 @@ impl BackerDeposit {
   }
 
   /// Queue a withdrawal request
-  pub fn queue_withdrawal(&mut self, amount: u64, position: u32, current_time: i64) -> Result<()> {
+  pub fn queue_withdrawal(
+    &mut self,
+    amount: u64,
+    position: u32,
+    current_time: i64,
+    queue_fee_per_share: u128,
+  ) -> Result<()> {
     require!(
       self.queued_withdrawal == 0,
       ErrorCode::WithdrawalAlreadyQueued
@@ -173,13 +351,47 @@ impl BackerDeposit {
     self.queued_withdrawal = amount;
     self.queue_position = position;
     self.queued_at = current_time;
+    self.update_queue_fee_debt(queue_fee_per_share)
+  }
+
+  /// Queue jump fees accrued on queued_withdrawal since the last settlement
+  pub fn calculate_queue_fees(&self, queue_fee_per_share: u128) -> Result<u64> {
+    let accumulated = (self.queued_withdrawal as u128)
+      .checked_mul(queue_fee_per_share)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(
+      (accumulated.saturating_sub(self.queue_fee_debt) / crate::states::TreasuryPool::PRECISION)
+        as u64,
+    )
+  }
 
+  /// Move accrued queue jump fees into pending_rewards (claimed like rewards)
+  fn settle_queue_fees(&mut self, queue_fee_per_share: u128) -> Result<()> {
+    let fees = self.calculate_queue_fees(queue_fee_per_share)?;
+    self.pending_rewards = self
+      .pending_rewards
+      .checked_add(fees)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.update_queue_fee_debt(queue_fee_per_share)
+  }
+
+  fn update_queue_fee_debt(&mut self, queue_fee_per_share: u128) -> Result<()> {
+    self.queue_fee_debt = (self.queued_withdrawal as u128)
+      .checked_mul(queue_fee_per_share)
+      .ok_or(ErrorCode::CalculationOverflow)?;
     Ok(())
   }
 
-  /// Process (partial) withdrawal from queue
-  pub fn process_queued_withdrawal(&mut self, amount: u64) -> Result<()> {
+  /// Process (partial) withdrawal from queue, settling the queue jump fees
+  /// earned while queued
+  pub fn process_queued_withdrawal(
+    &mut self,
+    amount: u64,
+    queue_fee_per_share: u128,
+  ) -> Result<()> {
+    self.settle_queue_fees(queue_fee_per_share)?;
     self.queued_withdrawal = self.queued_withdrawal.saturating_sub(amount);
+    self.update_queue_fee_debt(queue_fee_per_share)?;
 
     // Clear queue fields if fully processed
     if self.queued_withdrawal == 0 {
@@ -190,17 +402,483 @@ impl BackerDeposit {
     Ok(())
   }
 
-  /// Cancel queued withdrawal
-  pub fn cancel_queued_withdrawal(&mut self) -> Result<u64> {
+  /// Cancel queued withdrawal, settling the queue jump fees earned while queued
+  pub fn cancel_queued_withdrawal(&mut self, queue_fee_per_share: u128) -> Result<u64> {
+    self.settle_queue_fees(queue_fee_per_share)?;
     let amount = self.queued_withdrawal;
     self.queued_withdrawal = 0;
+    self.queue_fee_debt = 0;
     self.queue_position = 0;
     self.queued_at = 0;
     Ok(amount)
   }
 
+  // === CLAIM DELEGATION METHODS ===
+
+  /// Check if a claim delegate is registered
+  pub fn has_claim_delegate(&self) -> bool {
+    self.claim_delegate != Pubkey::default()
+  }
+
+  /// Check if caller may claim rewards for this position
+  pub fn is_claim_delegate(&self, caller: &Pubkey) -> bool {
+    self.has_claim_delegate() && self.claim_delegate == *caller
+  }
+
+  // === PAYOUT ADDRESS METHODS ===
+
+  /// Check if `destination` is the registered payout address
+  pub fn is_payout_address(&self, destination: &Pubkey) -> bool {
+    self.payout_address != Pubkey::default() && self.payout_address == *destination
+  }
+
+  // === STAKE RATE LIMIT METHODS ===
+
+  /// Count a stake towards the wallet's daily limit (0 = unlimited)
+  pub fn check_and_update_daily_stake(
+    &mut self,
+    amount: u64,
+    daily_limit: u64,
+    current_time: i64,
+  ) -> Result<()> {
+    use crate::states::TreasuryPool;
+
+    let current_day = TreasuryPool::get_day_timestamp(current_time);
+    if current_day > self.last_stake_day {
+      self.last_stake_day = current_day;
+      self.staked_today = 0;
+    }
+
+    let new_total = self
+      .staked_today
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    require!(
+      daily_limit == 0 || new_total <= daily_limit,
+      ErrorCode::DailyStakeLimitExceeded
+    );
+
+    self.staked_today = new_total;
+
+    Ok(())
+  }
+
+  // === BONUS VESTING METHODS ===
+
+  /// Bonus vested so far in the current schedule but not yet paid out
+  pub fn get_vested_bonus(&self, current_time: i64) -> Result<u64> {
+    if self.vesting_bonus_amount == 0 {
+      return Ok(0);
+    }
+
+    let vested = if current_time >= self.vesting_end_at {
+      self.vesting_bonus_amount
+    } else {
+      let elapsed = current_time.saturating_sub(self.vesting_start_at).max(0) as u128;
+      let duration = self
+        .vesting_end_at
+        .saturating_sub(self.vesting_start_at)
+        .max(1) as u128;
+      ((self.vesting_bonus_amount as u128)
+        .checked_mul(elapsed)
+        .ok_or(ErrorCode::CalculationOverflow)?
+        / duration) as u64
+    };
+
+    Ok(vested.saturating_sub(self.vesting_bonus_claimed))
+  }
+
+  /// Bonus still locked in the current schedule
+  pub fn get_unvested_bonus(&self, current_time: i64) -> Result<u64> {
+    Ok(
+      self
+        .vesting_bonus_amount
+        .saturating_sub(self.vesting_bonus_claimed)
+        .saturating_sub(self.get_vested_bonus(current_time)?),
+    )
+  }
+
+  /// Mark the vested bonus as paid out; clears a fully paid schedule
+  /// Returns the amount released
+  pub fn release_vested_bonus(&mut self, current_time: i64) -> Result<u64> {
+    let vested = self.get_vested_bonus(current_time)?;
+    self.vesting_bonus_claimed = self
+      .vesting_bonus_claimed
+      .checked_add(vested)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    if self.vesting_bonus_claimed >= self.vesting_bonus_amount {
+      self.vesting_bonus_amount = 0;
+      self.vesting_bonus_claimed = 0;
+      self.vesting_start_at = 0;
+      self.vesting_end_at = 0;
+    }
+
+    Ok(vested)
+  }
+
+  /// Lock a new bonus; the unvested remainder of the current schedule is
+  /// rolled into a fresh schedule starting now
+  /// Callers must release the vested bonus first
+  pub fn add_vesting_bonus(
+    &mut self,
+    amount: u64,
+    current_time: i64,
+    vesting_period: i64,
+  ) -> Result<()> {
+    let unvested = self
+      .vesting_bonus_amount
+      .saturating_sub(self.vesting_bonus_claimed);
+
+    self.vesting_bonus_amount = unvested
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.vesting_bonus_claimed = 0;
+    self.vesting_start_at = current_time;
+    self.vesting_end_at = current_time
+      .checked_add(vesting_period)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    Ok(())
+  }
+
+  // === INSURANCE METHODS ===
+
+  /// Settle compensation accrued since the last settlement
+  ///
+  /// Coverage is shrunk with the position (see
+  /// InsuranceFund::shrink_coverage), so every loss event since the last
+  /// settlement hit coverage that was fully staked at the time.
+  pub fn settle_insurance_compensation(&mut self, compensation_per_coverage: u128) -> Result<()> {
+    use crate::states::TreasuryPool;
+
+    if self.insured_amount == 0 {
+      return Ok(());
+    }
+
+    let accrued = (self.insured_amount as u128)
+      .checked_mul(compensation_per_coverage)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      .saturating_sub(self.compensation_debt)
+      / TreasuryPool::PRECISION;
+
+    self.pending_compensation = self
+      .pending_compensation
+      .checked_add(accrued as u64)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.compensation_debt = (self.insured_amount as u128)
+      .checked_mul(compensation_per_coverage)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    Ok(())
+  }
+
+  /// Coverage exceeds what is still staked (the deposit shrank)
+  pub fn is_over_insured(&self) -> bool {
+    self.insured_amount > self.get_effective_deposit()
+  }
+
+  // === TARGETED BONUS METHODS ===
+
+  /// Add an admin-credited bonus to pending_rewards (claimable via claim_rewards)
+  pub fn credit_bonus(&mut self, amount: u64) -> Result<()> {
+    self.pending_rewards = self
+      .pending_rewards
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.total_bonus_credited = self
+      .total_bonus_credited
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  // === COLLATERAL PLEDGE METHODS ===
+
+  pub fn is_pledged(&self) -> bool {
+    self.pledged_to != Pubkey::default()
+  }
+
+  // === AUTO UNSTAKE METHODS ===
+
+  pub fn is_auto_unstake_due(&self, current_time: i64) -> bool {
+    self.auto_unstake_at != 0 && current_time >= self.auto_unstake_at
+  }
+
+  // === UNSTAKE COMMITMENT METHODS ===
+
+  pub fn has_unstake_commitment(&self) -> bool {
+    self.committed_unstake_amount > 0
+  }
+
+  pub fn is_unstake_commitment_ready(&self, current_time: i64) -> bool {
+    self.has_unstake_commitment() && current_time >= self.unstake_executable_at
+  }
+
+  /// Whether the current instant unstake window is still running
+  pub fn is_instant_unstake_window_open(&self, current_time: i64) -> bool {
+    current_time
+      < self
+        .instant_unstake_window_start
+        .saturating_add(Self::INSTANT_UNSTAKE_WINDOW)
+  }
+
+  /// Uncommitted unstakes in the current window, including `amount`
+  pub fn instant_unstake_total(&self, amount: u64, current_time: i64) -> u64 {
+    if self.is_instant_unstake_window_open(current_time) {
+      self.instant_unstaked.saturating_add(amount)
+    } else {
+      amount
+    }
+  }
+
+  /// Count an uncommitted unstake toward the current window, opening a new
+  /// one if it has passed
+  pub fn record_instant_unstake(&mut self, amount: u64, current_time: i64) {
+    self.instant_unstaked = self.instant_unstake_total(amount, current_time);
+    if !self.is_instant_unstake_window_open(current_time) {
+      self.instant_unstake_window_start = current_time;
+    }
+  }
+
+  // === DUST SWEEP METHODS ===
+
+  /// Nothing besides principal and base rewards is tied to the account:
+  /// no pledge, queue, unstake commitment, coverage, vesting bonus or
+  /// indexed positions
+  pub fn has_no_attachments(&self) -> bool {
+    !self.is_pledged()
+      && !self.has_queued_withdrawal()
+      && !self.has_unstake_commitment()
+      && self.insured_amount == 0
+      && self.pending_compensation == 0
+      && self.vesting_bonus_amount == 0
+      && self.positions_created == 0
+  }
+
+  /// Below the dust threshold and untouched for DUST_INACTIVITY_PERIOD
+  pub fn is_sweepable_dust(&self, current_time: i64) -> bool {
+    self.deposited_amount > 0
+      && self.deposited_amount < Self::DUST_THRESHOLD
+      && current_time.saturating_sub(self.last_action_at) > Self::DUST_INACTIVITY_PERIOD
+      && self.has_no_attachments()
+      && !self.is_in_live_campaign(current_time)
+  }
+
+  // === BOOST CAMPAIGN METHODS ===
+
+  /// Enrolled in a boost campaign that has not ended yet
+  pub fn is_in_live_campaign(&self, current_time: i64) -> bool {
+    self.boost_campaign != Pubkey::default() && current_time < self.boost_campaign_end_at
+  }
+
+  // === BOOST NFT METHODS ===
+
+  /// Record a verified boost NFT; callers must settle duration weight first
+  /// so the new rate only applies from now on
+  pub fn apply_nft_boost(&mut self, mint: Pubkey, boost_bps: u64) {
+    self.boost_nft_mint = mint;
+    self.nft_boost_bps = boost_bps;
+  }
+
+  // === INDEXED POSITION METHODS ===
+
+  /// Whether `key` is this position's PDA: ["lender_stake", backer] for the
+  /// primary position, with the index appended for split positions
+  pub fn is_position_address(&self, key: &Pubkey, program_id: &Pubkey) -> bool {
+    let index = self.position_index.to_le_bytes();
+    let bump = [self.bump];
+    let seeds: &[&[u8]] = if self.position_index == 0 {
+      &[Self::PREFIX_SEED, self.backer.as_ref(), &bump]
+    } else {
+      &[Self::PREFIX_SEED, self.backer.as_ref(), &index, &bump]
+    };
+    Pubkey::create_program_address(seeds, program_id).ok() == Some(*key)
+  }
+
+  /// Move `amount` of principal into a fresh indexed position
+  /// Callers must settle rewards and duration weight first; reward_debt is
+  /// updated on both positions here
+  pub fn split_into(
+    &mut self,
+    position: &mut BackerDeposit,
+    amount: u64,
+    reward_per_share: u128,
+    current_time: i64,
+  ) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    let remaining = self
+      .get_effective_deposit()
+      .checked_sub(amount)
+      .ok_or(ErrorCode::InsufficientStake)?;
+    // Coverage stays on this position and must remain fully backed
+    require!(
+      remaining >= self.insured_amount,
+      ErrorCode::CoverageExceedsStake
+    );
+
+    self.deposited_amount -= amount;
+    self.indexed_deposits = self
+      .indexed_deposits
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.update_reward_debt(reward_per_share)?;
+
+    position.backer = self.backer;
+    position.deposited_amount = amount;
+    position.is_active = true;
+    position.first_deposit_at = self.first_deposit_at;
+    position.last_action_at = current_time;
+    position.update_reward_debt(reward_per_share)?;
+
+    Ok(())
+  }
+
+  /// Fold another position of the same staker into this one
+  /// Callers must settle rewards and duration weight on both first
+  pub fn merge_from(&mut self, position: &BackerDeposit, reward_per_share: u128) -> Result<()> {
+    self.deposited_amount = self
+      .deposited_amount
+      .checked_add(position.deposited_amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.pending_rewards = self
+      .pending_rewards
+      .checked_add(position.pending_rewards)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.stake_duration_weight = self
+      .stake_duration_weight
+      .checked_add(position.stake_duration_weight)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.first_deposit_at = self.first_deposit_at.min(position.first_deposit_at);
+    self.is_active = true;
+    // Principal folded back into the primary position leaves the indexed total
+    if self.position_index == 0 {
+      self.indexed_deposits = self
+        .indexed_deposits
+        .saturating_sub(position.deposited_amount);
+    }
+    self.update_reward_debt(reward_per_share)
+  }
+
+  /// Principal across all of the staker's positions (on the primary position)
+  pub fn staker_total_deposit(&self) -> Result<u64> {
+    Ok(
+      self
+        .deposited_amount
+        .checked_add(self.indexed_deposits)
+        .ok_or(ErrorCode::CalculationOverflow)?,
+    )
+  }
+
+  // === AUTO COMPOUND METHODS ===
+
+  /// Restake settled rewards into principal
+  /// Callers must settle before and update reward_debt after
+  pub fn compound_pending_rewards(&mut self) -> Result<u64> {
+    let amount = self.pending_rewards;
+    self.deposited_amount = self
+      .deposited_amount
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.claimed_total = self
+      .claimed_total
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.pending_rewards = 0;
+    Ok(amount)
+  }
+
   /// Get effective deposited amount (excluding queued withdrawals)
   pub fn get_effective_deposit(&self) -> u64 {
     self.deposited_amount.saturating_sub(self.queued_withdrawal)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn split_and_merge_keep_the_staker_total_on_the_primary_position() {
+    let mut primary =
+      BackerDeposit::deserialize(&mut &[0u8; BackerDeposit::INIT_SPACE][..]).unwrap();
+    let mut indexed = primary.clone();
+    primary.deposited_amount = 1_000;
+
+    primary.split_into(&mut indexed, 400, 0, 0).unwrap();
+    assert_eq!(primary.deposited_amount, 600);
+    assert_eq!(primary.staker_total_deposit().unwrap(), 1_000);
+
+    primary.merge_from(&indexed, 0).unwrap();
+    assert_eq!(primary.indexed_deposits, 0);
+    assert_eq!(primary.staker_total_deposit().unwrap(), 1_000);
+  }
+
+  #[test]
+  fn instant_unstakes_add_up_within_the_window() {
+    let mut stake = BackerDeposit::deserialize(&mut &[0u8; BackerDeposit::INIT_SPACE][..]).unwrap();
+    let start = 1_000_000;
+    stake.record_instant_unstake(40, start);
+    stake.record_instant_unstake(40, start + 60);
+    assert_eq!(stake.instant_unstake_total(40, start + 120), 120);
+
+    let next_window = start + BackerDeposit::INSTANT_UNSTAKE_WINDOW;
+    assert_eq!(stake.instant_unstake_total(40, next_window), 40);
+    stake.record_instant_unstake(40, next_window);
+    assert_eq!(stake.instant_unstake_window_start, next_window);
+  }
+
+  #[test]
+  fn queue_jump_fee_goes_to_the_queued_stakers_pro_rata() {
+    use crate::states::TreasuryPool;
+
+    let mut pool = TreasuryPool {
+      total_deposited: 3_000,
+      ..Default::default()
+    };
+    let empty = BackerDeposit::deserialize(&mut &[0u8; BackerDeposit::INIT_SPACE][..]).unwrap();
+    let (mut first, mut second, mut late) = (empty.clone(), empty.clone(), empty);
+    first.deposited_amount = 1_000;
+    second.deposited_amount = 1_000;
+    late.deposited_amount = 1_000;
+
+    first
+      .queue_withdrawal(600, 0, 0, pool.queue_fee_per_share)
+      .unwrap();
+    pool.add_to_withdrawal_queue(600).unwrap();
+    second
+      .queue_withdrawal(400, 1, 0, pool.queue_fee_per_share)
+      .unwrap();
+    pool.add_to_withdrawal_queue(400).unwrap();
+
+    // An emergency exit jumps the queue; stakers outside it earn nothing
+    pool.credit_queue_jump_fee(100).unwrap();
+    assert_eq!(pool.reward_per_share, 0);
+    assert_eq!(pool.reward_pool_balance, 100);
+
+    // Joining the queue afterwards earns no share of the earlier fee
+    late
+      .queue_withdrawal(500, 2, 0, pool.queue_fee_per_share)
+      .unwrap();
+    pool.add_to_withdrawal_queue(500).unwrap();
+    assert_eq!(
+      late.calculate_queue_fees(pool.queue_fee_per_share).unwrap(),
+      0
+    );
+
+    // Paid out on processing, in part or in full, or on cancellation
+    first
+      .process_queued_withdrawal(300, pool.queue_fee_per_share)
+      .unwrap();
+    assert_eq!(first.pending_rewards, 60);
+    first
+      .process_queued_withdrawal(300, pool.queue_fee_per_share)
+      .unwrap();
+    assert_eq!(first.pending_rewards, 60);
+    second
+      .cancel_queued_withdrawal(pool.queue_fee_per_share)
+      .unwrap();
+    assert_eq!(second.pending_rewards, 40);
+  }
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// Accepted liquid staking token (mSOL, jitoSOL, ...) for stake_from_lst
+///
+/// Tokens are held in a per-mint vault owned by this PDA and credited to the
+/// staker's deposit at `rate`, an oracle rate pushed by the admin. Vault
+/// tokens are later redeemed for SOL through redeem_lst_vault.
+#[account]
+#[derive(InitSpace)]
+pub struct LstConfig {
+  pub mint: Pubkey,
+  pub vault: Pubkey,
+  /// Lamports per token base unit, scaled by RATE_PRECISION
+  pub rate: u64,
+  pub rate_updated_at: i64,
+  /// Max age of `rate` accepted by stake_from_lst (seconds)
+  pub max_rate_age: i64,
+  pub enabled: bool,
+  /// Tokens currently held in the vault
+  pub vault_balance: u64,
+  /// Lamport-equivalent of vault_balance at the current rate
+  pub normalized_value: u64,
+  /// Lifetime tokens received / SOL value credited to deposits
+  pub total_tokens_received: u64,
+  pub total_sol_credited: u64,
+  pub bump: u8,
+}
+
+impl LstConfig {
+  pub const PREFIX_SEED: &'static [u8] = b"lst_config";
+  pub const VAULT_SEED: &'static [u8] = b"lst_vault";
+
+  pub const RATE_PRECISION: u64 = 1_000_000_000;
+  pub const MAX_RATE_AGE_CAP: i64 = 24 * 60 * 60;
+  /// Max shortfall vs. the oracle value accepted when redeeming vault tokens
+  pub const MAX_REDEMPTION_SLIPPAGE_BPS: u64 = 100; // 1%
+
+  /// SOL value (lamports) of `token_amount` at the configured rate
+  pub fn calculate_sol_value(&self, token_amount: u64) -> Result<u64> {
+    let value = (token_amount as u128)
+      .checked_mul(self.rate as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      .checked_div(Self::RATE_PRECISION as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    u64::try_from(value).map_err(|_| ErrorCode::CalculationOverflow.into())
+  }
+
+  /// Least SOL redeem_lst_vault must deposit for `token_amount` tokens:
+  /// their value at the current rate less MAX_REDEMPTION_SLIPPAGE_BPS
+  pub fn calculate_min_redemption(&self, token_amount: u64) -> Result<u64> {
+    let value = self.calculate_sol_value(token_amount)?;
+    let slippage = (value as u128)
+      .checked_mul(Self::MAX_REDEMPTION_SLIPPAGE_BPS as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / 10_000;
+    Ok(value.saturating_sub(slippage as u64))
+  }
+
+  /// Re-value the vault at the current rate; returns (previous, current)
+  /// for TreasuryPool::update_normalized_token_value
+  pub fn revalue_vault(&mut self) -> Result<(u64, u64)> {
+    let previous = self.normalized_value;
+    self.normalized_value = self.calculate_sol_value(self.vault_balance)?;
+    Ok((previous, self.normalized_value))
+  }
+
+  pub fn is_rate_fresh(&self, current_time: i64) -> bool {
+    current_time.saturating_sub(self.rate_updated_at) <= self.max_rate_age
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn redemption_must_cover_value_less_slippage() {
+    let config = LstConfig {
+      mint: Pubkey::default(),
+      vault: Pubkey::default(),
+      rate: 1_100_000_000, // 1.1 SOL per token
+      rate_updated_at: 0,
+      max_rate_age: 0,
+      enabled: true,
+      vault_balance: 0,
+      normalized_value: 0,
+      total_tokens_received: 0,
+      total_sol_credited: 0,
+      bump: 0,
+    };
+    assert_eq!(config.calculate_sol_value(1_000).unwrap(), 1_100);
+    assert_eq!(config.calculate_min_redemption(1_000).unwrap(), 1_089);
+  }
+}
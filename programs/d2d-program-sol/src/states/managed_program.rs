@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ErrorCode;
+
 /// State to track programs managed by D2D Protocol
 /// This enables PDA-based authority proxy for trustless upgrades
 #[account]
@@ -32,14 +34,141 @@ pub struct ManagedProgram {
 
   /// PDA bump seed
   pub bump: u8,
+
+  // === SCHEDULED UPGRADE ===
+  /// Buffer queued by schedule_upgrade (default = nothing scheduled)
+  /// Its authority is held by the PDA until executed or cancelled
+  pub scheduled_buffer: Pubkey,
+  /// Earliest time execute_scheduled_upgrade may run
+  pub scheduled_upgrade_after: i64,
+  pub upgrade_scheduled_at: i64,
+
+  // === CO-DEVELOPERS ===
+  /// Extra wallets allowed to call proxy_upgrade_program (managed by the owner)
+  #[max_len(5)]
+  pub upgrade_signers: Vec<Pubkey>,
+
+  // === UPGRADE FREQUENCY ===
+  /// Start of the current 30-day upgrade period
+  pub upgrade_period_start: i64,
+  /// Upgrades performed in the current period
+  pub upgrades_this_period: u32,
 }
 
 impl ManagedProgram {
   pub const PREFIX_SEED: &'static [u8] = b"managed_program";
   pub const AUTHORITY_SEED: &'static [u8] = b"program_authority";
+  /// Minimum notice between schedule_upgrade and execution
+  pub const MIN_UPGRADE_NOTICE: i64 = 24 * 60 * 60;
+  pub const MAX_UPGRADE_SIGNERS: usize = 5;
+  /// Length of a max_upgrades_per_month period
+  pub const UPGRADE_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+  /// Check if program can be upgraded by `signer` (owner or co-developer)
+  pub fn can_upgrade(&self, signer: &Pubkey) -> bool {
+    self.is_active && (self.developer == *signer || self.upgrade_signers.contains(signer))
+  }
+
+  pub fn add_upgrade_signer(&mut self, signer: Pubkey) -> Result<()> {
+    require!(
+      signer != Pubkey::default()
+        && signer != self.developer
+        && !self.upgrade_signers.contains(&signer),
+      ErrorCode::InvalidUpgradeSigner
+    );
+    require!(
+      self.upgrade_signers.len() < Self::MAX_UPGRADE_SIGNERS,
+      ErrorCode::UpgradeSignerLimitReached
+    );
+    self.upgrade_signers.push(signer);
+    Ok(())
+  }
+
+  pub fn remove_upgrade_signer(&mut self, signer: &Pubkey) -> Result<()> {
+    let index = self
+      .upgrade_signers
+      .iter()
+      .position(|s| s == signer)
+      .ok_or(ErrorCode::InvalidUpgradeSigner)?;
+    self.upgrade_signers.swap_remove(index);
+    Ok(())
+  }
+
+  pub fn has_scheduled_upgrade(&self) -> bool {
+    self.scheduled_buffer != Pubkey::default()
+  }
+
+  pub fn clear_scheduled_upgrade(&mut self) {
+    self.scheduled_buffer = Pubkey::default();
+    self.scheduled_upgrade_after = 0;
+    self.upgrade_scheduled_at = 0;
+  }
+
+  /// Upgrades already performed in the period containing `current_time`
+  pub fn period_upgrades(&self, current_time: i64) -> u32 {
+    if current_time
+      >= self
+        .upgrade_period_start
+        .saturating_add(Self::UPGRADE_PERIOD)
+    {
+      0
+    } else {
+      self.upgrades_this_period
+    }
+  }
+
+  /// Count an upgrade against the current period, starting a new one if
+  /// the last has ended
+  pub fn record_period_upgrade(&mut self, current_time: i64) {
+    if current_time
+      >= self
+        .upgrade_period_start
+        .saturating_add(Self::UPGRADE_PERIOD)
+    {
+      self.upgrade_period_start = current_time;
+      self.upgrades_this_period = 0;
+    }
+    self.upgrades_this_period = self.upgrades_this_period.saturating_add(1);
+  }
+
+  /// Address of the upgradeable loader ProgramData account for this program
+  pub fn program_data_address(&self) -> Pubkey {
+    Pubkey::find_program_address(&[self.program_id.as_ref()], &ProgramData::owner()).0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn upgrade_period_resets_after_thirty_days() {
+    let mut managed_program = ManagedProgram {
+      program_id: Pubkey::default(),
+      developer: Pubkey::default(),
+      deploy_request: Pubkey::default(),
+      authority_pda: Pubkey::default(),
+      created_at: 0,
+      last_upgraded_at: 0,
+      upgrade_count: 0,
+      is_active: true,
+      bump: 0,
+      scheduled_buffer: Pubkey::default(),
+      scheduled_upgrade_after: 0,
+      upgrade_scheduled_at: 0,
+      upgrade_signers: Vec::new(),
+      upgrade_period_start: 0,
+      upgrades_this_period: 0,
+    };
+    let start = 1_000;
+    managed_program.record_period_upgrade(start);
+    managed_program.record_period_upgrade(start + 10);
+    assert_eq!(managed_program.period_upgrades(start + 20), 2);
 
-  /// Check if program can be upgraded (developer owns it and it's active)
-  pub fn can_upgrade(&self, developer: &Pubkey) -> bool {
-    self.is_active && self.developer == *developer
+    let next = start + ManagedProgram::UPGRADE_PERIOD;
+    assert_eq!(managed_program.period_upgrades(next), 0);
+    managed_program.record_period_upgrade(next);
+    assert_eq!(managed_program.upgrade_period_start, next);
+    assert_eq!(managed_program.upgrades_this_period, 1);
   }
 }
@@ -1,17 +1,53 @@
+pub mod admin_council;
+pub mod authority_transfer;
+pub mod boost_campaign;
+pub mod boost_nft_usage;
+pub mod buffer_rent_loan;
+pub mod collateral_partner;
+pub mod coupon;
 pub mod deploy_request;
+pub mod developer_collateral;
 pub mod developer_escrow;
+pub mod developer_identity;
+pub mod drawdown_report;
+pub mod escrow_top_up;
+pub mod insurance_fund;
 pub mod lender_stake;
+pub mod lst_config;
 pub mod managed_program;
 pub mod pending_withdrawal;
+pub mod plan_config;
+pub mod reward_history;
+pub mod stake_snapshot;
 pub mod treasury_pool;
+pub mod upgrade_record;
 pub mod user_deploy_stats;
+pub mod watchtower;
 pub mod withdrawal_queue;
 
+pub use admin_council::*;
+pub use authority_transfer::*;
+pub use boost_campaign::*;
+pub use boost_nft_usage::*;
+pub use buffer_rent_loan::*;
+pub use collateral_partner::*;
+pub use coupon::*;
 pub use deploy_request::*;
+pub use developer_collateral::*;
 pub use developer_escrow::*;
+pub use developer_identity::*;
+pub use drawdown_report::*;
+pub use escrow_top_up::*;
+pub use insurance_fund::*;
 pub use lender_stake::*;
+pub use lst_config::*;
 pub use managed_program::*;
 pub use pending_withdrawal::*;
+pub use plan_config::*;
+pub use reward_history::*;
+pub use stake_snapshot::*;
 pub use treasury_pool::*;
+pub use upgrade_record::*;
 pub use user_deploy_stats::*;
+pub use watchtower::*;
 pub use withdrawal_queue::*;
@@ -1,11 +1,21 @@
 use anchor_lang::prelude::*;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
 pub enum WithdrawalType {
   PlatformPool,
   RewardPool,
 }
 
+impl WithdrawalType {
+  /// Name used in withdrawal events
+  pub fn label(&self) -> &'static str {
+    match self {
+      WithdrawalType::PlatformPool => "PlatformPool",
+      WithdrawalType::RewardPool => "RewardPool",
+    }
+  }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct PendingWithdrawal {
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// One subscription tier of the plan catalogue
+#[derive(
+  AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct PlanTier {
+  /// Referenced by deploy requests (never 0)
+  pub plan_id: u8,
+  /// Largest program (bytes) a request on this plan may deploy
+  pub max_program_size: u64,
+  pub service_fee: u64,
+  pub monthly_fee: u64,
+  /// Upgrades through proxy_upgrade_program included (0 = unlimited)
+  pub included_upgrades: u32,
+  /// Upgrades allowed per 30-day period before the overage fee applies
+  /// (0 = no monthly limit)
+  pub max_upgrades_per_month: u32,
+  /// Lamports charged from the developer escrow for each upgrade beyond the
+  /// monthly limit (0 = such upgrades are refused)
+  pub upgrade_overage_fee: u64,
+  /// Disabled tiers stay listed for existing requests but accept no new ones
+  pub enabled: bool,
+}
+
+/// Admin-managed catalogue of subscription plans (one per pool)
+///
+/// create_deploy_request and request_deployment_funds price requests from
+/// the referenced tier instead of caller-supplied fees, so every request's
+/// pricing can be traced to an on-chain plan.
+#[account]
+#[derive(InitSpace, Default)]
+pub struct PlanConfig {
+  #[max_len(8)]
+  pub tiers: Vec<PlanTier>,
+  pub updated_at: i64,
+  pub bump: u8,
+}
+
+impl PlanConfig {
+  pub const PREFIX_SEED: &'static [u8] = b"plan_config";
+  /// Must match the `max_len` of `tiers`
+  pub const MAX_TIERS: usize = 8;
+
+  /// Enabled tier for a new request
+  pub fn get_tier(&self, plan_id: u8) -> Result<&PlanTier> {
+    self
+      .tiers
+      .iter()
+      .find(|tier| tier.plan_id == plan_id && tier.enabled)
+      .ok_or_else(|| error!(ErrorCode::PlanNotFound))
+  }
+
+  /// Add a tier or replace the one with the same plan_id
+  pub fn upsert_tier(&mut self, tier: PlanTier) -> Result<()> {
+    require!(
+      tier.plan_id != 0
+        && tier.max_program_size > 0
+        && tier.service_fee > 0
+        && tier.monthly_fee > 0,
+      ErrorCode::InvalidPlanTier
+    );
+
+    if let Some(existing) = self.tiers.iter_mut().find(|t| t.plan_id == tier.plan_id) {
+      *existing = tier;
+    } else {
+      require!(
+        self.tiers.len() < Self::MAX_TIERS,
+        ErrorCode::InvalidPlanTier
+      );
+      self.tiers.push(tier);
+    }
+    Ok(())
+  }
+
+  /// Set the monthly upgrade limit and overage fee of an existing tier
+  pub fn set_upgrade_pricing(
+    &mut self,
+    plan_id: u8,
+    max_upgrades_per_month: u32,
+    upgrade_overage_fee: u64,
+  ) -> Result<()> {
+    let tier = self
+      .tiers
+      .iter_mut()
+      .find(|tier| tier.plan_id == plan_id)
+      .ok_or(ErrorCode::PlanNotFound)?;
+    tier.max_upgrades_per_month = max_upgrades_per_month;
+    tier.upgrade_overage_fee = upgrade_overage_fee;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn tier(plan_id: u8, monthly_fee: u64) -> PlanTier {
+    PlanTier {
+      plan_id,
+      max_program_size: 200_000,
+      service_fee: 1_000,
+      monthly_fee,
+      included_upgrades: 5,
+      enabled: true,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn upsert_replaces_tier_with_same_id() {
+    let mut config = PlanConfig::default();
+    config.upsert_tier(tier(1, 100)).unwrap();
+    config.upsert_tier(tier(2, 200)).unwrap();
+    config.upsert_tier(tier(1, 150)).unwrap();
+    assert_eq!(config.tiers.len(), 2);
+    assert_eq!(config.get_tier(1).unwrap().monthly_fee, 150);
+  }
+
+  #[test]
+  fn disabled_or_unknown_tiers_are_not_offered() {
+    let mut config = PlanConfig::default();
+    config
+      .upsert_tier(PlanTier {
+        enabled: false,
+        ..tier(1, 100)
+      })
+      .unwrap();
+    assert!(config.get_tier(1).is_err());
+    assert!(config.get_tier(2).is_err());
+  }
+
+  #[test]
+  fn rejects_invalid_tiers_and_overflow() {
+    let mut config = PlanConfig::default();
+    assert!(config.upsert_tier(tier(0, 100)).is_err());
+    assert!(config.upsert_tier(tier(1, 0)).is_err());
+    for plan_id in 1..=PlanConfig::MAX_TIERS as u8 {
+      config.upsert_tier(tier(plan_id, 100)).unwrap();
+    }
+    assert!(config.upsert_tier(tier(9, 100)).is_err());
+  }
+
+  #[test]
+  fn upgrade_pricing_targets_existing_tier() {
+    let mut config = PlanConfig::default();
+    config.upsert_tier(tier(1, 100)).unwrap();
+    config.set_upgrade_pricing(1, 3, 50_000).unwrap();
+    let updated = config.get_tier(1).unwrap();
+    assert_eq!(updated.max_upgrades_per_month, 3);
+    assert_eq!(updated.upgrade_overage_fee, 50_000);
+    assert!(config.set_upgrade_pricing(2, 3, 50_000).is_err());
+  }
+}
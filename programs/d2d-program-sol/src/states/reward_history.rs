@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+/// A single claim recorded in a staker's reward history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct RewardCheckpoint {
+  pub amount: u64,
+  pub claimed_at: i64,
+  /// Pool reward_per_share at the time of the claim
+  pub reward_per_share: u128,
+}
+
+/// Ring buffer of a staker's most recent claims, so payouts can be verified
+/// on-chain without an indexer
+#[account]
+#[derive(InitSpace)]
+pub struct RewardHistory {
+  pub backer: Pubkey,
+  pub checkpoints: [RewardCheckpoint; 16],
+  /// Index the next checkpoint is written to
+  pub head: u8,
+  /// Number of valid checkpoints (saturates at MAX_CHECKPOINTS)
+  pub count: u8,
+  /// Lifetime number of recorded claims
+  pub total_recorded: u64,
+  pub bump: u8,
+}
+
+impl RewardHistory {
+  pub const PREFIX_SEED: &'static [u8] = b"reward_history";
+  pub const MAX_CHECKPOINTS: usize = 16;
+
+  /// Record a claim, overwriting the oldest checkpoint once full
+  pub fn record(&mut self, amount: u64, claimed_at: i64, reward_per_share: u128) {
+    self.checkpoints[self.head as usize] = RewardCheckpoint {
+      amount,
+      claimed_at,
+      reward_per_share,
+    };
+    self.head = ((self.head as usize + 1) % Self::MAX_CHECKPOINTS) as u8;
+    if (self.count as usize) < Self::MAX_CHECKPOINTS {
+      self.count += 1;
+    }
+    self.total_recorded = self.total_recorded.saturating_add(1);
+  }
+}
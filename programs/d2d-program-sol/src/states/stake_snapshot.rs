@@ -0,0 +1,104 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+/// Point-in-time record of pool stake for off-chain governance
+///
+/// One snapshot per Solana epoch. Per-staker weights are committed through
+/// `merkle_root`, built off-chain over `(backer, effective_deposit)` leaves
+/// read at `slot` (see `voting_power_leaf`). Partner governance systems
+/// read a staker's weight through get_voting_power.
+#[account]
+#[derive(InitSpace)]
+pub struct StakeSnapshot {
+  pub epoch: u64,
+  pub slot: u64,
+  pub total_deposited: u64,
+  pub reward_per_share: u128,
+  pub total_stake_duration_weight: u128,
+  /// Merkle root of per-staker weights (zero if not provided)
+  pub merkle_root: [u8; 32],
+  /// Number of leaves in the merkle tree
+  pub staker_count: u32,
+  pub recorded_by: Pubkey,
+  pub recorded_at: i64,
+  pub bump: u8,
+}
+
+impl StakeSnapshot {
+  pub const PREFIX_SEED: &'static [u8] = b"stake_snapshot";
+  /// Max proof length accepted by get_voting_power (2^24 stakers)
+  pub const MAX_PROOF_DEPTH: usize = 24;
+
+  /// Merkle leaf for a staker's weight; prefixed to separate leaves from nodes
+  pub fn voting_power_leaf(staker: &Pubkey, weight: u64) -> [u8; 32] {
+    hashv(&[&[0u8], staker.as_ref(), &weight.to_le_bytes()]).to_bytes()
+  }
+
+  /// Verify a proof of `leaf` against merkle_root (sorted-pair hashing)
+  pub fn verify_leaf(&self, leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    if self.merkle_root == [0u8; 32] || proof.len() > Self::MAX_PROOF_DEPTH {
+      return false;
+    }
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+      let (left, right) = if node <= *sibling {
+        (node, *sibling)
+      } else {
+        (*sibling, node)
+      };
+      hashv(&[&[1u8], &left, &right]).to_bytes()
+    });
+    computed == self.merkle_root
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&[1u8], &left, &right]).to_bytes()
+  }
+
+  fn snapshot(merkle_root: [u8; 32]) -> StakeSnapshot {
+    let mut snapshot =
+      StakeSnapshot::deserialize(&mut &[0u8; StakeSnapshot::INIT_SPACE][..]).unwrap();
+    snapshot.merkle_root = merkle_root;
+    snapshot
+  }
+
+  #[test]
+  fn proof_round_trips_for_every_leaf() {
+    let stakers = [
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    ];
+    let leaves = [
+      StakeSnapshot::voting_power_leaf(&stakers[0], 100),
+      StakeSnapshot::voting_power_leaf(&stakers[1], 250),
+      StakeSnapshot::voting_power_leaf(&stakers[2], 7),
+    ];
+    let left = node(leaves[0], leaves[1]);
+    let s = snapshot(node(left, leaves[2]));
+
+    assert!(s.verify_leaf(leaves[0], &[leaves[1], leaves[2]]));
+    assert!(s.verify_leaf(leaves[1], &[leaves[0], leaves[2]]));
+    assert!(s.verify_leaf(leaves[2], &[left]));
+    assert!(!s.verify_leaf(leaves[2], &[]));
+    assert!(!snapshot([0u8; 32]).verify_leaf(leaves[2], &[left]));
+  }
+
+  #[test]
+  fn proof_rejects_a_wrong_weight_or_staker() {
+    let staker = Pubkey::new_unique();
+    let other = StakeSnapshot::voting_power_leaf(&Pubkey::new_unique(), 50);
+    let s = snapshot(node(StakeSnapshot::voting_power_leaf(&staker, 100), other));
+
+    assert!(s.verify_leaf(StakeSnapshot::voting_power_leaf(&staker, 100), &[other]));
+    assert!(!s.verify_leaf(StakeSnapshot::voting_power_leaf(&staker, 101), &[other]));
+    assert!(!s.verify_leaf(
+      StakeSnapshot::voting_power_leaf(&Pubkey::new_unique(), 100),
+      &[other]
+    ));
+  }
+}
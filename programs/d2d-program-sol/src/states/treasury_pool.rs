@@ -1,9 +1,50 @@
 use anchor_lang::prelude::*;
 
-use crate::errors::ErrorCode;
+use crate::{
+  errors::ErrorCode,
+  events::{FeeCollected, PoolSnapshot},
+  states::{DeployRequest, TokenType},
+};
+
+/// Payment path a fee entered through (tagged on FeeCollected)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeSource {
+  DeploymentRequest, // request_deployment_funds: service fee + initial subscription
+  DeployRequest,     // create_deploy_request: admin-created deployment fees
+  Subscription,      // pay_subscription
+  AutoRenewal,       // auto_renew_subscription / renew_from_escrow (escrow)
+  AdminCredit,       // credit_fee_to_pool
+  Donation,          // absorb_donations
+  RentRecovery,      // reclaim_program_rent: recovery beyond debt
+  EmergencyHaircut,  // emergency_unstake_sol: early exit haircut
+  Deposit,           // stake_sol: deposit fee (only when deposit_fees_enabled)
+  AuthorityExit,     // reclaim_authority: buy-out exit fee
+  UpgradeOverage,    // proxy upgrades beyond the plan's monthly limit (escrow)
+  BorrowFee,         // pay_subscription / collect_borrow_fee: 1% monthly borrow fee
+  QueueJumpFee,      // emergency_unstake_sol: exit drawing on queue-reserved liquidity
+}
+
+/// Operational role that may run a subset of admin-only instructions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum Role {
+  Pauser,           // emergency_pause (pause only)
+  Treasurer,        // fee crediting, reward distribution and pool reconciliation
+  DeployerOperator, // deploy request, funding, confirmation and lifecycle cranks
+  QueueProcessor,   // out-of-order withdrawal queue processing
+}
+
+/// One step of the multi-month prepayment discount curve
+#[derive(
+  AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct PrepayDiscount {
+  /// Months paid at once that unlock the discount (0 = unused step)
+  pub min_months: u32,
+  pub discount_bps: u64,
+}
 
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct TreasuryPool {
   // === EXISTING FIELDS ===
   pub reward_per_share: u128,
@@ -61,12 +102,235 @@ pub struct TreasuryPool {
   pub max_apy_multiplier_bps: u64,
   /// Target utilization for optimal APY (e.g., 6000 = 60%)
   pub target_utilization_bps: u64,
+
+  // === KEEPER INCENTIVES ===
+  /// Tip paid to permissionless queue crankers, taken from the processed amount (bps)
+  pub keeper_tip_bps: u64,
+
+  // === DONATION ACCOUNTING ===
+  /// Where surplus lamports sent directly to the treasury PDA are routed
+  pub donation_policy: u8,
+
+  // === EMERGENCY EXIT POLICY ===
+  /// How emergency_unstake treats liquidity reserved for queued withdrawals:
+  /// EMERGENCY_EXIT_RESPECT_QUEUE - queued stakers are paid first, the exit is rejected
+  /// EMERGENCY_EXIT_QUEUE_JUMP_FEE - the exit may use reserved liquidity but pays a fee
+  pub emergency_exit_policy: u8,
+  /// Fee on the portion of an emergency exit drawn from queue-reserved liquidity (bps)
+  /// Paid to the queued stakers through queue_fee_per_share
+  pub queue_jump_fee_bps: u64,
+
+  // === PAUSE POLICY ===
+  /// Whether emergency_unstake stays available while emergency_pause is set
+  pub allow_emergency_exit_during_pause: bool,
+
+  // === QUEUE EXPIRY ===
+  /// Max age (seconds) of an unfulfilled queue entry before anyone may expire it (0 = never)
+  pub max_queue_age: i64,
+
+  // === OFF-BOARDING POLICY ===
+  /// What happens to a managed program at subscription end in good standing:
+  /// OFFBOARDING_POLICY_CLOSE - the program is closed and its rent reclaimed
+  /// OFFBOARDING_POLICY_RETURN_AUTHORITY - upgrade authority may be returned to the developer
+  pub offboarding_policy: u8,
+
+  // === BUFFER RENT LOANS ===
+  /// Max lamports a developer may borrow for an upgrade buffer (0 = disabled)
+  pub max_buffer_rent_loan: u64,
+  /// Lamports currently lent out for upgrade buffers (not yet repaid)
+  pub outstanding_buffer_rent_loans: u64,
+
+  // === SUBSCRIPTION REVENUE SPLIT ===
+  /// Share of subscription and auto-renewal payments routed to the platform pool (bps)
+  /// The rest goes to the reward pool for stakers
+  pub subscription_platform_bps: u64,
+  /// Proposed split waiting for the timelock
+  pub pending_subscription_platform_bps: u64,
+  /// When the proposed split may be applied (0 = no pending change)
+  pub subscription_split_effective_at: i64,
+
+  // === STAKE LIMITS ===
+  /// Max lamports per stake_sol call (0 = unlimited)
+  pub max_stake_per_tx: u64,
+  /// Max lamports a single wallet may stake per day (0 = unlimited)
+  pub daily_stake_limit: u64,
+
+  // === SERVICE FEE REFUND POLICY ===
+  /// Share of service_fee refunded on early voluntary closure (bps)
+  pub service_fee_refund_bps: u64,
+  /// Seconds after the request is created during which the refund applies
+  /// Snapshotted into each DeployRequest at creation
+  pub service_fee_refund_window: i64,
+
+  // === BONUS VESTING ===
+  /// Linear vesting period for duration bonuses (0 = paid in full on claim)
+  pub bonus_vesting_period: i64,
+  /// Duration bonus locked in staker vesting schedules (held in the reward pool)
+  pub total_vesting_bonus: u64,
+
+  // === TERMS OF SERVICE ===
+  /// Hash of the current terms developers accept when a deploy request is created
+  pub terms_hash: [u8; 32],
+  /// When terms_hash was last changed
+  pub terms_updated_at: i64,
+
+  // === REQUEST PARAMETER BOUNDS ===
+  /// Bounds validated when a deploy request is created (max 0 = unbounded)
+  pub min_service_fee: u64,
+  pub max_service_fee: u64,
+  pub min_monthly_fee: u64,
+  pub max_monthly_fee: u64,
+  pub min_initial_months: u32,
+  pub max_initial_months: u32,
+
+  // === EMERGENCY HAIRCUT ===
+  /// Haircut on emergency_unstake amounts, credited to the reward pool (bps)
+  pub emergency_haircut_bps: u64,
+
+  // === FUNDING RECONCILIATION ===
+  /// Max lamports by which recovered + spent may differ from the funded amount
+  pub funding_variance_tolerance: u64,
+
+  // === DEVELOPER PAYMENT ESCROW ===
+  /// Developer payments held in the reward pool until deployment confirmation
+  /// Not part of reward_pool_balance and not yet credited to reward_per_share
+  pub total_escrowed_payments: u64,
+
+  // === LST DEPOSITS ===
+  /// Deposits credited from LST vault tokens not yet redeemed for SOL
+  /// Part of total_deposited but never of liquid_balance
+  pub lst_backed_deposits: u64,
+
+  // === MULTI-ASSET REPORTING ===
+  /// Lamport-equivalent of all non-SOL vault balances at their latest oracle
+  /// rates, kept in sync by the LST instructions
+  pub normalized_token_value: u64,
+
+  // === WATCHTOWER BOUNTIES ===
+  /// Lamports paid from the platform pool per validated alert (0 = no bounty)
+  pub watchtower_bounty: u64,
+  /// Min seconds between paid alerts of the same kind
+  pub watchtower_alert_cooldown: i64,
+  /// Last paid alert per WatchtowerAlert kind
+  pub last_alert_paid_at: [i64; 3],
+
+  // === POOL SHARE CAP ===
+  /// Max share of total_deposited a single staker may hold (0 = no cap)
+  pub max_pool_share_bps: u64,
+
+  // === LARGE UNSTAKE COMMITMENTS ===
+  /// Unstakes above this share of total_deposited must be committed first
+  /// (0 = every unstake is instant)
+  pub large_unstake_threshold_bps: u64,
+  /// Delay between commit_unstake and the committed unstake_sol
+  pub unstake_commit_delay: i64,
+  /// Liquidity declared by pending commitments, withheld from new borrows
+  pub committed_unstake_amount: u64,
+
+  // === TREASURY VAULT ===
+  /// Bump of the data-less vault PDA that custodies liquid principal
+  /// (0 = not migrated yet)
+  pub vault_bump: u8,
+
+  // === DEPOSIT FEES ===
+  /// When set, stake_sol charges REWARD_FEE_BPS + PLATFORM_FEE_BPS on deposits
+  pub deposit_fees_enabled: bool,
+
+  // === RECOVERY RATE ===
+  /// Rolling average of debt repaid per day (lamports), for queue wait estimates
+  pub recovery_rate_per_day: u64,
+  /// Day boundary the recovery rate was last decayed to (0 = no recovery yet)
+  pub last_recovery_at: i64,
+
+  // === DEVELOPER IDENTITY ===
+  /// Role that attests developer identity links (default = none)
+  pub identity_registrar: Pubkey,
+  /// Buffer rent loan cap for developers with an attested identity
+  /// (0 = same as max_buffer_rent_loan)
+  pub verified_max_buffer_rent_loan: u64,
+
+  // === BOOST NFT ===
+  /// Verified Metaplex collection whose holders earn a duration weight boost
+  /// when they present an NFT at stake time (default = disabled)
+  pub boost_nft_collection: Pubkey,
+  /// Extra duration weight for boosted positions, in bps
+  pub nft_boost_bps: u64,
+
+  // === STABLECOIN SUBSCRIPTIONS ===
+  /// Stablecoin (USDC) mint accepted by pay_subscription_spl (default = disabled)
+  pub subscription_stablecoin_mint: Pubkey,
+  /// Protocol token vault receiving stablecoin subscription payments
+  pub subscription_stablecoin_vault: Pubkey,
+  /// Lifetime stablecoin subscription revenue (token base units)
+  pub total_stablecoin_subscription_revenue: u64,
+
+  // === DECENTRALIZATION ===
+  /// When the admin permanently retired the admin-only paths (0 = not yet)
+  pub decentralized_at: i64,
+
+  // === ESCROW TOKENS ===
+  /// USDC mint accepted by deposit_escrow_token (default = disabled)
+  pub escrow_usdc_mint: Pubkey,
+  /// USDT mint accepted by deposit_escrow_token (default = disabled)
+  pub escrow_usdt_mint: Pubkey,
+
+  // === ESCROW RENEWALS ===
+  /// How close to expiry anyone may trigger renew_from_escrow for an
+  /// auto-renewing subscription, in seconds (0 = developer only)
+  pub permissionless_renewal_window: i64,
+
+  // === AUTHORITY BUY-OUT ===
+  /// Exit fee (bps of the borrowed amount) a developer pays on top of the
+  /// outstanding debt to take back the upgrade authority
+  pub authority_exit_fee_bps: u64,
+
+  // === PREPAYMENT DISCOUNTS ===
+  /// Discount curve for paying several months at once, by ascending
+  /// min_months (applied by pay_subscription and escrow renewals)
+  pub prepay_discounts: [PrepayDiscount; 4],
+
+  // === DEVELOPER QUOTA ===
+  /// Most pending plus live deployments one developer may hold (0 = unlimited)
+  pub max_active_deployments_per_developer: u32,
+
+  // === EXPIRY WARNINGS ===
+  /// How close to expiry emit_expiry_warning may fire, in seconds (0 = disabled)
+  pub expiry_warning_window: i64,
+  /// Minimum seconds between two warnings for the same subscription
+  pub expiry_warning_interval: i64,
+
+  // === OPERATIONAL ROLES ===
+  /// Keys that may run one group of admin-only instructions each, so the
+  /// backend hot key needs no full treasury authority (default = admin only)
+  pub pauser: Pubkey,
+  pub treasurer: Pubkey,
+  pub deployer_operator: Pubkey,
+  pub queue_processor: Pubkey,
+
+  // === ADMIN COUNCIL ===
+  /// Set once the admin council exists; high-risk admin operations then
+  /// only run through approved council proposals
+  pub admin_council_enabled: bool,
+
+  // === SCHEDULED UNPAUSE ===
+  /// When the pending unpause was scheduled (0 = none pending)
+  pub unpause_scheduled_at: i64,
+  /// When execute_unpause may lift the pause; the admin or guardian may veto
+  /// until then
+  pub unpause_executable_at: i64,
+
+  // === QUEUE JUMP FEE ===
+  /// Queue jump fees per queued lamport (scaled by PRECISION), owed to the
+  /// stakers whose queued withdrawals an emergency exit jumped
+  pub queue_fee_per_share: u128,
 }
 
 impl TreasuryPool {
   pub const PREFIX_SEED: &'static [u8] = b"treasury_pool";
   pub const REWARD_POOL_SEED: &'static [u8] = b"reward_pool";
   pub const PLATFORM_POOL_SEED: &'static [u8] = b"platform_pool";
+  pub const VAULT_SEED: &'static [u8] = b"treasury_vault";
+  pub const STABLECOIN_VAULT_SEED: &'static [u8] = b"stablecoin_vault";
 
   pub const REWARD_FEE_BPS: u64 = 100;
   pub const PLATFORM_FEE_BPS: u64 = 10;
@@ -88,6 +352,102 @@ impl TreasuryPool {
   pub const DEFAULT_MAX_APY_MULTIPLIER_BPS: u64 = 30000; // 3x max multiplier
   pub const DEFAULT_TARGET_UTILIZATION_BPS: u64 = 6000; // 60% target utilization
 
+  // Keeper tip for permissionless queue processing
+  pub const DEFAULT_KEEPER_TIP_BPS: u64 = 0; // No tip unless configured
+  pub const MAX_KEEPER_TIP_BPS: u64 = 100; // 1% max tip
+
+  // Donation routing policies for absorb_donations
+  pub const DONATION_POLICY_REWARDS: u8 = 0; // Default - stakers benefit
+  pub const DONATION_POLICY_PLATFORM: u8 = 1;
+
+  // Emergency exit policies for liquidity reserved by the withdrawal queue
+  pub const EMERGENCY_EXIT_RESPECT_QUEUE: u8 = 0; // Default - no queue jumping
+  pub const EMERGENCY_EXIT_QUEUE_JUMP_FEE: u8 = 1;
+  pub const DEFAULT_QUEUE_JUMP_FEE_BPS: u64 = 500; // 5% on the reserved portion
+  pub const MAX_QUEUE_JUMP_FEE_BPS: u64 = 2000; // 20% max
+
+  // Emergency exits stay open during a pause unless the admin closes them
+  pub const DEFAULT_ALLOW_EMERGENCY_EXIT_DURING_PAUSE: bool = true;
+
+  // Queue entry expiry (0 disables expiry)
+  pub const DEFAULT_MAX_QUEUE_AGE: i64 = 0;
+  pub const MIN_MAX_QUEUE_AGE: i64 = 24 * 60 * 60; // 1 day
+  pub const MAX_MAX_QUEUE_AGE: i64 = 365 * 24 * 60 * 60; // 1 year
+
+  // Managed program off-boarding policies
+  pub const OFFBOARDING_POLICY_CLOSE: u8 = 0; // Default - close and reclaim rent
+  pub const OFFBOARDING_POLICY_RETURN_AUTHORITY: u8 = 1;
+
+  // Buffer rent loans for upgrades (disabled unless configured)
+  pub const DEFAULT_MAX_BUFFER_RENT_LOAN: u64 = 0;
+  pub const MAX_BUFFER_RENT_LOAN_CAP: u64 = 100 * 1_000_000_000; // 100 SOL
+
+  // Subscription revenue split (timelocked changes)
+  pub const DEFAULT_SUBSCRIPTION_PLATFORM_BPS: u64 = 0; // Default - 100% to stakers
+  pub const MAX_SUBSCRIPTION_PLATFORM_BPS: u64 = 5000; // 50% max
+
+  // Stake limits dampen reward-per-share manipulation (disabled unless configured)
+  pub const DEFAULT_MAX_STAKE_PER_TX: u64 = 0;
+  pub const DEFAULT_DAILY_STAKE_LIMIT: u64 = 0;
+
+  // Service fee refund schedule (50% within 7 days, nothing after)
+  pub const DEFAULT_SERVICE_FEE_REFUND_BPS: u64 = 5000;
+  pub const DEFAULT_SERVICE_FEE_REFUND_WINDOW: i64 = 7 * 24 * 60 * 60;
+  pub const MAX_SERVICE_FEE_REFUND_WINDOW: i64 = 30 * 24 * 60 * 60;
+
+  // Duration bonus vesting (disabled unless configured)
+  pub const DEFAULT_BONUS_VESTING_PERIOD: i64 = 0;
+  pub const MAX_BONUS_VESTING_PERIOD: i64 = 365 * 24 * 60 * 60; // 1 year
+
+  // Request parameter bounds
+  pub const DEFAULT_MIN_INITIAL_MONTHS: u32 = 1;
+  pub const DEFAULT_MAX_INITIAL_MONTHS: u32 = 24;
+
+  // Emergency haircut
+  pub const DEFAULT_EMERGENCY_HAIRCUT_BPS: u64 = 200; // 2%
+  pub const MAX_EMERGENCY_HAIRCUT_BPS: u64 = 2000; // 20% max
+
+  // Funding reconciliation
+  pub const DEFAULT_FUNDING_VARIANCE_TOLERANCE: u64 = 10_000_000; // 0.01 SOL (tx fees)
+  pub const MAX_FUNDING_VARIANCE_TOLERANCE: u64 = 1_000_000_000; // 1 SOL
+
+  // Watchtower bounties (no bounty unless configured)
+  pub const DEFAULT_WATCHTOWER_BOUNTY: u64 = 0;
+  pub const MAX_WATCHTOWER_BOUNTY: u64 = 100_000_000; // 0.1 SOL
+  pub const DEFAULT_WATCHTOWER_ALERT_COOLDOWN: i64 = 24 * 60 * 60;
+  pub const MIN_WATCHTOWER_ALERT_COOLDOWN: i64 = 60 * 60;
+  pub const MAX_WATCHTOWER_ALERT_COOLDOWN: i64 = 30 * 24 * 60 * 60;
+
+  // Per-staker pool share cap (disabled unless configured)
+  pub const DEFAULT_MAX_POOL_SHARE_BPS: u64 = 0;
+  /// The cap is not enforced below this TVL so the pool can bootstrap
+  pub const POOL_SHARE_CAP_MIN_DEPOSITS: u64 = 100_000_000_000; // 100 SOL
+
+  // Two-phase unstake for large amounts (disabled unless configured)
+  pub const DEFAULT_LARGE_UNSTAKE_THRESHOLD_BPS: u64 = 0;
+  pub const DEFAULT_UNSTAKE_COMMIT_DELAY: i64 = 60 * 60; // 1 hour
+  pub const MAX_UNSTAKE_COMMIT_DELAY: i64 = 3 * 24 * 60 * 60; // 3 days
+
+  // Rolling recovery rate: exponential average over ~a week of daily repayments
+  pub const RECOVERY_RATE_SMOOTHING_DAYS: u64 = 7;
+  /// Beyond this many idle days the decayed rate is treated as zero
+  pub const MAX_RECOVERY_DECAY_DAYS: i64 = 64;
+
+  // Boost NFTs: a small edge on the duration bonus, not a second reward stream
+  pub const MAX_NFT_BOOST_BPS: u64 = 2_000; // 20%
+
+  // Permissionless escrow renewals near expiry (disabled unless configured)
+  pub const MAX_PERMISSIONLESS_RENEWAL_WINDOW: i64 = 30 * 24 * 60 * 60; // 30 days
+
+  // Authority buy-out exit fee
+  pub const MAX_AUTHORITY_EXIT_FEE_BPS: u64 = 2_000; // 20%
+  pub const MAX_PREPAY_DISCOUNT_STEPS: usize = 4;
+  pub const MAX_PREPAY_DISCOUNT_BPS: u64 = 5_000; // 50%
+
+  // Expiry warning crank (disabled unless configured)
+  pub const MAX_EXPIRY_WARNING_WINDOW: i64 = 30 * 24 * 60 * 60; // 30 days
+  pub const MIN_EXPIRY_WARNING_INTERVAL: i64 = 60 * 60; // 1 hour
+
   // SECURITY FIX M-06: Add rounding to minimize precision loss in fee calculations
   // Using round-half-up: (numerator + denominator/2) / denominator
 
@@ -119,7 +479,28 @@ impl TreasuryPool {
     Ok(fee as u64)
   }
 
-  pub fn credit_fee_to_pool(&mut self, fee_reward: u64, fee_platform: u64) -> Result<()> {
+  /// Reward and platform fee on a deposit - (0, 0) unless deposit fees are enabled
+  pub fn calculate_deposit_fees(&self, deposit_amount: u64) -> Result<(u64, u64)> {
+    if !self.deposit_fees_enabled {
+      return Ok((0, 0));
+    }
+    Ok((
+      Self::calculate_reward_fee(deposit_amount)?,
+      Self::calculate_platform_fee(deposit_amount)?,
+    ))
+  }
+
+  /// Canonical entry point for every fee entering the pools
+  ///
+  /// Credits the platform pool, and for the reward portion credits the
+  /// reward pool, protected-rewards tracking (total_credited_rewards) and
+  /// reward_per_share together so the three never drift apart.
+  pub fn collect_fee(
+    &mut self,
+    source: FeeSource,
+    fee_reward: u64,
+    fee_platform: u64,
+  ) -> Result<()> {
     require!(
       fee_reward <= Self::MAX_AMOUNT as u64,
       ErrorCode::FeeAmountTooLarge
@@ -144,19 +525,70 @@ impl TreasuryPool {
       .checked_add(fee_reward)
       .ok_or_else(|| ErrorCode::CalculationOverflow)?;
 
-    if self.total_deposited > 0 {
+    let reward_eligible_deposits = self.get_reward_eligible_deposits();
+    if reward_eligible_deposits > 0 {
       let delta = (fee_reward as u128)
         .checked_mul(Self::PRECISION)
         .ok_or(ErrorCode::CalculationOverflow)?
-        .checked_div(self.total_deposited as u128)
+        .checked_div(reward_eligible_deposits as u128)
         .ok_or(ErrorCode::CalculationOverflow)?;
 
       self.reward_per_share = self
         .reward_per_share
         .checked_add(delta)
         .ok_or_else(|| ErrorCode::CalculationOverflow)?;
+    } else if self.total_deposited > 0 {
+      // Every deposit is queued for withdrawal - hold the rewards for
+      // gradual distribution once active stake returns
+      self.move_to_pending_rewards(fee_reward)?;
+    }
+
+    emit!(FeeCollected {
+      source,
+      fee_reward,
+      fee_platform,
+      reward_per_share: self.reward_per_share,
+    });
+
+    Ok(())
+  }
+
+  /// Credit a queue jump fee (already in the reward pool) to the stakers
+  /// waiting in the withdrawal queue, pro rata to their queued amounts
+  ///
+  /// Queued amounts are excluded from reward_per_share, so the fee is
+  /// tracked in queue_fee_per_share and settled on each queued position.
+  pub fn credit_queue_jump_fee(&mut self, amount: u64) -> Result<()> {
+    if self.queued_withdrawal_amount == 0 {
+      return self.collect_fee(FeeSource::QueueJumpFee, amount, 0);
     }
 
+    self.reward_pool_balance = self
+      .reward_pool_balance
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.total_credited_rewards = self
+      .total_credited_rewards
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    let delta = (amount as u128)
+      .checked_mul(Self::PRECISION)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      .checked_div(self.queued_withdrawal_amount as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.queue_fee_per_share = self
+      .queue_fee_per_share
+      .checked_add(delta)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+
+    emit!(FeeCollected {
+      source: FeeSource::QueueJumpFee,
+      fee_reward: amount,
+      fee_platform: 0,
+      reward_per_share: self.reward_per_share,
+    });
+
     Ok(())
   }
 
@@ -178,15 +610,6 @@ impl TreasuryPool {
     Ok(claimable as u64)
   }
 
-  pub fn credit_reward_pool(&mut self, amount: u128) -> Result<()> {
-    require!(amount <= Self::MAX_AMOUNT, ErrorCode::FeeAmountTooLarge);
-    self.reward_pool_balance = self
-      .reward_pool_balance
-      .checked_add(amount as u64)
-      .ok_or_else(|| ErrorCode::CalculationOverflow)?;
-    Ok(())
-  }
-
   pub fn debit_reward_pool(&mut self, amount: u64) -> Result<()> {
     require!(
       amount <= Self::MAX_AMOUNT as u64,
@@ -196,16 +619,7 @@ impl TreasuryPool {
       .reward_pool_balance
       .checked_sub(amount)
       .ok_or_else(|| ErrorCode::CalculationOverflow)?;
-    Ok(())
-  }
-
-  pub fn credit_platform_pool(&mut self, amount: u128) -> Result<()> {
-    require!(amount <= Self::MAX_AMOUNT, ErrorCode::FeeAmountTooLarge);
-    self.platform_pool_balance = self
-      .platform_pool_balance
-      .checked_add(amount as u64)
-      .ok_or_else(|| ErrorCode::CalculationOverflow)?;
-    Ok(())
+    self.check_protected_rewards_backed()
   }
 
   pub fn has_guardian(&self) -> bool {
@@ -220,10 +634,185 @@ impl TreasuryPool {
     self.has_guardian() && self.guardian == *caller
   }
 
+  pub fn is_identity_registrar(&self, caller: &Pubkey) -> bool {
+    self.identity_registrar != Pubkey::default() && self.identity_registrar == *caller
+  }
+
+  /// Once set, reinitialize/close/force paths and instant admin withdrawals
+  /// are rejected for good; fund movements go through the timelock
+  pub fn is_decentralized(&self) -> bool {
+    self.decentralized_at != 0
+  }
+
+  /// Read decentralized_at from raw pool data of any layout version
+  ///
+  /// Fields are only ever appended, so zero-padding older (shorter) layouts
+  /// yields 0 for the missing field. Unparseable data reads as 0.
+  pub fn read_decentralized_at(data: &[u8]) -> i64 {
+    Self::read_padded(data).map_or(0, |pool| pool.decentralized_at)
+  }
+
+  /// Read admin_council_enabled from raw pool data of any layout version
+  /// (same padding rules as read_decentralized_at)
+  pub fn read_admin_council_enabled(data: &[u8]) -> bool {
+    Self::read_padded(data).is_some_and(|pool| pool.admin_council_enabled)
+  }
+
+  /// Read (emergency_pause, unpause_scheduled_at, unpause_executable_at)
+  /// from raw pool data of any layout version (same padding rules as
+  /// read_decentralized_at)
+  pub fn read_pause_state(data: &[u8]) -> (bool, i64, i64) {
+    Self::read_padded(data).map_or((false, 0, 0), |pool| {
+      (
+        pool.emergency_pause,
+        pool.unpause_scheduled_at,
+        pool.unpause_executable_at,
+      )
+    })
+  }
+
+  /// Deserialize pool data of any layout version, zero-filling fields the
+  /// layout predates (None = unparseable)
+  pub fn read_padded(data: &[u8]) -> Option<Self> {
+    let required_space = 8 + Self::INIT_SPACE;
+    let mut padded = vec![0u8; required_space.max(data.len())];
+    padded[..data.len()].copy_from_slice(data);
+    Self::try_deserialize(&mut &padded[..]).ok()
+  }
+
+  /// Require `key` to be the `seed` PDA for the stored `bump`
+  ///
+  /// Used where the pool is an UncheckedAccount, so an account's seeds
+  /// constraint cannot reference the stored bump
+  pub fn check_pda(key: &Pubkey, seed: &[u8], bump: u8, program_id: &Pubkey) -> Result<()> {
+    require!(
+      Pubkey::create_program_address(&[seed, &[bump]], program_id).ok() == Some(*key),
+      ErrorCode::InvalidAccountData
+    );
+    Ok(())
+  }
+
+  /// Escrow balance a token mint is credited to (None = not accepted)
+  pub fn escrow_token_type(&self, mint: &Pubkey) -> Option<TokenType> {
+    if *mint == Pubkey::default() {
+      None
+    } else if *mint == self.escrow_usdc_mint {
+      Some(TokenType::USDC)
+    } else if *mint == self.escrow_usdt_mint {
+      Some(TokenType::USDT)
+    } else {
+      None
+    }
+  }
+
+  /// Configured mint backing an escrow token balance (None = SOL or unset)
+  pub fn escrow_token_mint(&self, token_type: TokenType) -> Option<Pubkey> {
+    let mint = match token_type {
+      TokenType::SOL => return None,
+      TokenType::USDC => self.escrow_usdc_mint,
+      TokenType::USDT => self.escrow_usdt_mint,
+    };
+    (mint != Pubkey::default()).then_some(mint)
+  }
+
+  pub fn is_stablecoin_subscription_enabled(&self) -> bool {
+    self.subscription_stablecoin_mint != Pubkey::default()
+  }
+
+  pub fn is_boost_nft_enabled(&self) -> bool {
+    self.boost_nft_collection != Pubkey::default() && self.nft_boost_bps > 0
+  }
+
+  /// Buffer rent loan cap for a developer; attested identities may get a
+  /// higher cap, never a lower one
+  pub fn get_buffer_rent_loan_cap(&self, identity_attested: bool) -> u64 {
+    if identity_attested && self.max_buffer_rent_loan > 0 {
+      self
+        .max_buffer_rent_loan
+        .max(self.verified_max_buffer_rent_loan)
+    } else {
+      self.max_buffer_rent_loan
+    }
+  }
+
   pub fn is_admin_or_guardian(&self, caller: &Pubkey) -> bool {
     self.is_admin(caller) || self.is_guardian(caller)
   }
 
+  pub fn has_scheduled_unpause(&self) -> bool {
+    self.unpause_executable_at != 0
+  }
+
+  /// Schedule lifting the pause after the withdrawal timelock duration
+  /// (never shorter than MIN_TIMELOCK_DURATION); returns when it may execute
+  pub fn schedule_unpause(&mut self, current_time: i64) -> Result<i64> {
+    require!(self.emergency_pause, ErrorCode::ProgramNotPaused);
+    require!(
+      !self.has_scheduled_unpause(),
+      ErrorCode::UnpauseAlreadyScheduled
+    );
+    let delay = self.timelock_duration.max(Self::MIN_TIMELOCK_DURATION);
+    self.unpause_scheduled_at = current_time;
+    self.unpause_executable_at = current_time
+      .checked_add(delay)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(self.unpause_executable_at)
+  }
+
+  /// Lift the pause once the scheduled delay has passed
+  pub fn execute_unpause(&mut self, current_time: i64) -> Result<()> {
+    require!(self.has_scheduled_unpause(), ErrorCode::UnpauseNotScheduled);
+    require!(
+      current_time >= self.unpause_executable_at,
+      ErrorCode::TimelockNotExpired
+    );
+    self.cancel_scheduled_unpause();
+    self.emergency_pause = false;
+    Ok(())
+  }
+
+  /// Drop a pending unpause; returns whether one was pending
+  pub fn cancel_scheduled_unpause(&mut self) -> bool {
+    let was_scheduled = self.has_scheduled_unpause();
+    self.unpause_scheduled_at = 0;
+    self.unpause_executable_at = 0;
+    was_scheduled
+  }
+
+  /// Reject single-key calls of an operation the admin council has taken over
+  pub fn check_not_council_governed(&self) -> Result<()> {
+    require!(
+      !self.admin_council_enabled,
+      ErrorCode::CouncilApprovalRequired
+    );
+    Ok(())
+  }
+
+  /// Key currently holding `role` (default = unassigned)
+  pub fn role_holder(&self, role: Role) -> Pubkey {
+    match role {
+      Role::Pauser => self.pauser,
+      Role::Treasurer => self.treasurer,
+      Role::DeployerOperator => self.deployer_operator,
+      Role::QueueProcessor => self.queue_processor,
+    }
+  }
+
+  pub fn set_role_holder(&mut self, role: Role, holder: Pubkey) {
+    match role {
+      Role::Pauser => self.pauser = holder,
+      Role::Treasurer => self.treasurer = holder,
+      Role::DeployerOperator => self.deployer_operator = holder,
+      Role::QueueProcessor => self.queue_processor = holder,
+    }
+  }
+
+  /// The admin holds every role; an assigned key holds only its own
+  pub fn has_role(&self, role: Role, caller: &Pubkey) -> bool {
+    let holder = self.role_holder(role);
+    self.is_admin(caller) || (holder != Pubkey::default() && holder == *caller)
+  }
+
   pub fn get_day_timestamp(unix_timestamp: i64) -> i64 {
     (unix_timestamp / Self::SECONDS_PER_DAY) * Self::SECONDS_PER_DAY
   }
@@ -255,6 +844,114 @@ impl TreasuryPool {
     Ok(())
   }
 
+  /// Enforce the per-transaction stake cap
+  pub fn check_stake_amount(&self, amount: u64) -> Result<()> {
+    require!(
+      self.max_stake_per_tx == 0 || amount <= self.max_stake_per_tx,
+      ErrorCode::StakeExceedsPerTxLimit
+    );
+    Ok(())
+  }
+
+  /// Check that a staker holding `staker_deposit` across all positions after
+  /// staking `amount` stays within max_pool_share_bps of the post-stake pool
+  pub fn check_pool_share(&self, staker_deposit: u64, amount: u64) -> Result<()> {
+    let total_after = self
+      .total_deposited
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    if self.max_pool_share_bps == 0 || total_after < Self::POOL_SHARE_CAP_MIN_DEPOSITS {
+      return Ok(());
+    }
+
+    let max_share = (total_after as u128)
+      .checked_mul(self.max_pool_share_bps as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / 10000;
+    require!(
+      staker_deposit as u128 <= max_share,
+      ErrorCode::StakeExceedsPoolShare
+    );
+    Ok(())
+  }
+
+  /// Whether unstaking `amount` must be declared via commit_unstake first
+  pub fn requires_unstake_commitment(&self, amount: u64) -> bool {
+    self.large_unstake_threshold_bps != 0
+      && (amount as u128) * 10000
+        > (self.total_deposited as u128) * (self.large_unstake_threshold_bps as u128)
+  }
+
+  pub fn record_unstake_commitment(&mut self, amount: u64) -> Result<()> {
+    self.committed_unstake_amount = self
+      .committed_unstake_amount
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  pub fn release_unstake_commitment(&mut self, amount: u64) {
+    self.committed_unstake_amount = self.committed_unstake_amount.saturating_sub(amount);
+  }
+
+  /// Liquidity new borrows may draw on (excludes committed unstakes)
+  pub fn get_uncommitted_liquidity(&self, available_balance: u64) -> u64 {
+    available_balance.saturating_sub(self.committed_unstake_amount)
+  }
+
+  /// Validate deploy request parameters against the configured bounds
+  pub fn check_request_params(
+    &self,
+    service_fee: u64,
+    monthly_fee: u64,
+    initial_months: u32,
+  ) -> Result<()> {
+    require!(
+      service_fee >= self.min_service_fee
+        && (self.max_service_fee == 0 || service_fee <= self.max_service_fee),
+      ErrorCode::ServiceFeeOutOfBounds
+    );
+    require!(
+      monthly_fee >= self.min_monthly_fee
+        && (self.max_monthly_fee == 0 || monthly_fee <= self.max_monthly_fee),
+      ErrorCode::MonthlyFeeOutOfBounds
+    );
+    require!(
+      initial_months >= self.min_initial_months
+        && (self.max_initial_months == 0 || initial_months <= self.max_initial_months),
+      ErrorCode::InitialMonthsOutOfBounds
+    );
+    Ok(())
+  }
+
+  /// Size a subscription payment (monthly_fee * months) with checked math
+  ///
+  /// Months are capped at DeployRequest::MAX_EXTENSION_MONTHS; the total is
+  /// capped at max_monthly_fee for that many months when a fee ceiling is
+  /// configured, and at MAX_AMOUNT otherwise.
+  pub fn calculate_subscription_payment(&self, monthly_fee: u64, months: u32) -> Result<u64> {
+    require!(
+      months <= DeployRequest::MAX_EXTENSION_MONTHS,
+      ErrorCode::SubscriptionExtensionTooLarge
+    );
+
+    let payment = monthly_fee
+      .checked_mul(months as u64)
+      .ok_or(ErrorCode::MaxPaymentExceeded)?;
+
+    let max_payment = if self.max_monthly_fee > 0 {
+      self
+        .max_monthly_fee
+        .saturating_mul(DeployRequest::MAX_EXTENSION_MONTHS as u64)
+        .min(Self::MAX_AMOUNT as u64)
+    } else {
+      Self::MAX_AMOUNT as u64
+    };
+    require!(payment <= max_payment, ErrorCode::MaxPaymentExceeded);
+
+    Ok(payment)
+  }
+
   pub fn get_remaining_daily_allowance(&self, current_time: i64) -> u64 {
     if self.daily_withdrawal_limit == 0 {
       return u64::MAX;
@@ -286,20 +983,71 @@ impl TreasuryPool {
     amount <= self.get_excess_rewards()
   }
 
-  pub fn credit_rewards_with_tracking(&mut self, amount: u64) -> Result<()> {
-    self.reward_pool_balance = self
-      .reward_pool_balance
-      .checked_add(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
+  /// Invariant: credited-but-unclaimed rewards must stay fully backed by the reward pool
+  pub fn check_protected_rewards_backed(&self) -> Result<()> {
+    require!(
+      self.reward_pool_balance >= self.get_protected_rewards(),
+      ErrorCode::ProtectedRewardsUnbacked
+    );
+    Ok(())
+  }
 
-    self.total_credited_rewards = self
-      .total_credited_rewards
-      .checked_add(amount)
-      .ok_or(ErrorCode::CalculationOverflow)?;
+  /// Lamports the reward pool must hold above rent: reward_pool_balance
+  /// (which already carries pending_undistributed_rewards and vesting
+  /// bonuses) plus developer payments held in escrow
+  pub fn get_reward_pool_obligations(&self) -> Result<u64> {
+    Ok(
+      self
+        .reward_pool_balance
+        .checked_add(self.total_escrowed_payments)
+        .ok_or(ErrorCode::CalculationOverflow)?,
+    )
+  }
 
+  /// Invariant: the reward pool's payable lamports cover everything the
+  /// accounting says it owes
+  pub fn check_reward_pool_solvency(&self, payable_lamports: u64) -> Result<()> {
+    require!(
+      payable_lamports >= self.get_reward_pool_obligations()?,
+      ErrorCode::RewardPoolInsolvent
+    );
     Ok(())
   }
 
+  /// Write reward_pool_balance down to what the reward pool actually holds.
+  /// The missing lamports already left the pool as unrecorded claims, so
+  /// they are booked as claimed and pending rewards are capped at the new
+  /// balance. Returns the amount written off.
+  pub fn write_down_reward_pool(&mut self, payable_lamports: u64) -> Result<u64> {
+    let shortfall = self
+      .get_reward_pool_obligations()?
+      .saturating_sub(payable_lamports)
+      .min(self.reward_pool_balance);
+    if shortfall == 0 {
+      return Ok(0);
+    }
+
+    self.record_claimed_rewards(shortfall)?;
+    self.reward_pool_balance -= shortfall;
+    self.pending_undistributed_rewards = self
+      .pending_undistributed_rewards
+      .min(self.reward_pool_balance);
+    Ok(shortfall)
+  }
+
+  /// Split a fee clawback between the reward pool's unprotected excess and the
+  /// platform pool, which covers whatever would dip into protected rewards.
+  /// Returns (from_rewards, from_platform).
+  pub fn split_reward_clawback(&self, amount: u64) -> Result<(u64, u64)> {
+    let from_rewards = amount.min(self.get_excess_rewards());
+    let from_platform = amount - from_rewards;
+    require!(
+      from_platform <= self.platform_pool_balance,
+      ErrorCode::ProtectedRewardsUnbacked
+    );
+    Ok((from_rewards, from_platform))
+  }
+
   pub fn record_claimed_rewards(&mut self, amount: u64) -> Result<()> {
     self.total_claimed_rewards = self
       .total_claimed_rewards
@@ -380,6 +1128,7 @@ impl TreasuryPool {
     &mut self,
     recovered_amount: u64,
     remaining_debt: u64,
+    current_time: i64,
   ) -> Result<(u64, u64)> {
     // Calculate how much goes to debt repayment vs rewards
     let debt_repayment = recovered_amount.min(remaining_debt);
@@ -403,15 +1152,125 @@ impl TreasuryPool {
       .checked_add(debt_repayment)
       .ok_or(ErrorCode::CalculationOverflow)?;
 
+    self.record_recovery(debt_repayment, current_time);
+
     Ok((debt_repayment, excess_to_rewards))
   }
 
+  /// Record debt left after a deployment closed and settled from developer
+  /// collateral (the deployment itself was already closed out)
+  pub fn record_collateral_settlement(&mut self, amount: u64, current_time: i64) -> Result<()> {
+    self.total_recovered = self
+      .total_recovered
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.total_debt_repaid = self
+      .total_debt_repaid
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.total_borrowed = self.total_borrowed.saturating_sub(amount);
+    self.liquid_balance = self
+      .liquid_balance
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.record_recovery(amount, current_time);
+    Ok(())
+  }
+
+  /// Recovery rate decayed for the whole days elapsed since the last repayment
+  pub fn get_recovery_rate_per_day(&self, current_time: i64) -> u64 {
+    if self.last_recovery_at == 0 {
+      return self.recovery_rate_per_day;
+    }
+    let elapsed_days = current_time.saturating_sub(self.last_recovery_at) / Self::SECONDS_PER_DAY;
+    if elapsed_days >= Self::MAX_RECOVERY_DECAY_DAYS {
+      return 0;
+    }
+
+    let n = Self::RECOVERY_RATE_SMOOTHING_DAYS as u128;
+    let mut rate = self.recovery_rate_per_day as u128;
+    for _ in 0..elapsed_days.max(0) {
+      rate = rate * (n - 1) / n;
+    }
+    rate as u64
+  }
+
+  /// Fold a debt repayment into the rolling recovery rate
+  ///
+  /// Each repayment adds amount / RECOVERY_RATE_SMOOTHING_DAYS after the
+  /// existing rate has been decayed to the current day, so a steady daily
+  /// repayment converges to that amount per day.
+  pub fn record_recovery(&mut self, amount: u64, current_time: i64) {
+    let decayed = self.get_recovery_rate_per_day(current_time);
+    self.recovery_rate_per_day =
+      decayed.saturating_add(amount / Self::RECOVERY_RATE_SMOOTHING_DAYS);
+
+    // Advance by whole days only so partial days keep counting toward decay
+    self.last_recovery_at = if self.last_recovery_at == 0 {
+      current_time
+    } else {
+      let elapsed_days = current_time.saturating_sub(self.last_recovery_at) / Self::SECONDS_PER_DAY;
+      self
+        .last_recovery_at
+        .saturating_add(elapsed_days.max(0) * Self::SECONDS_PER_DAY)
+    };
+  }
+
+  /// Replace one token vault's contribution to normalized_token_value
+  pub fn update_normalized_token_value(&mut self, previous: u64, current: u64) -> Result<()> {
+    self.normalized_token_value = self
+      .normalized_token_value
+      .saturating_sub(previous)
+      .checked_add(current)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// Deposits valued in lamports at current rates: LST-backed deposits count
+  /// at what their vault tokens are worth now, not at the rate they were staked
+  pub fn get_normalized_deposits(&self) -> u64 {
+    self
+      .total_deposited
+      .saturating_sub(self.lst_backed_deposits)
+      .saturating_add(self.normalized_token_value)
+  }
+
   /// Get current utilization rate in basis points
   pub fn get_utilization_bps(&self) -> u64 {
-    if self.total_deposited == 0 {
+    let normalized_deposits = self.get_normalized_deposits();
+    if normalized_deposits == 0 {
       return 0;
     }
-    ((self.total_borrowed as u128) * 10000 / (self.total_deposited as u128)) as u64
+    ((self.total_borrowed as u128) * 10000 / (normalized_deposits as u128)) as u64
+  }
+
+  /// Capture pool state for event payloads
+  pub fn snapshot(&self) -> PoolSnapshot {
+    PoolSnapshot {
+      reward_per_share: self.reward_per_share,
+      total_deposited: self.total_deposited,
+      liquid_balance: self.liquid_balance,
+      utilization_bps: self.get_utilization_bps(),
+      queued_withdrawal_amount: self.queued_withdrawal_amount,
+    }
+  }
+
+  /// Utilization above the deployment limit (watchtower alert condition)
+  pub fn is_utilization_breached(&self) -> bool {
+    self.get_utilization_bps() > Self::MAX_UTILIZATION_BPS
+  }
+
+  /// Record a paid alert of `kind`; errors while its cooldown is running
+  /// Returns the bounty, capped at the platform pool balance
+  pub fn claim_alert_bounty(&mut self, kind: usize, current_time: i64) -> Result<u64> {
+    let last_paid_at = self.last_alert_paid_at[kind];
+    require!(
+      last_paid_at == 0
+        || current_time.saturating_sub(last_paid_at) >= self.watchtower_alert_cooldown,
+      ErrorCode::AlertCooldownActive
+    );
+    self.last_alert_paid_at[kind] = current_time;
+    Ok(self.watchtower_bounty.min(self.platform_pool_balance))
   }
 
   /// Get global recovery ratio in basis points
@@ -504,6 +1363,206 @@ impl TreasuryPool {
     Ok(())
   }
 
+  /// Calculate keeper tip for a processed queue amount
+  pub fn calculate_keeper_tip(&self, processed_amount: u64) -> Result<u64> {
+    let tip = (processed_amount as u128)
+      .checked_mul(self.keeper_tip_bps as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      .checked_div(10000)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(tip as u64)
+  }
+
+  /// Check if a donation routing policy is known
+  pub fn is_valid_donation_policy(policy: u8) -> bool {
+    policy == Self::DONATION_POLICY_REWARDS || policy == Self::DONATION_POLICY_PLATFORM
+  }
+
+  /// Check if an off-boarding policy is known
+  pub fn is_valid_offboarding_policy(policy: u8) -> bool {
+    policy == Self::OFFBOARDING_POLICY_CLOSE || policy == Self::OFFBOARDING_POLICY_RETURN_AUTHORITY
+  }
+
+  /// Replace the prepayment discount curve
+  ///
+  /// Steps must unlock at strictly increasing month counts with
+  /// non-decreasing discounts; unused slots are cleared.
+  pub fn set_prepay_discounts(&mut self, steps: &[PrepayDiscount]) -> Result<()> {
+    Self::validate_prepay_discounts(steps)?;
+    self.prepay_discounts = Default::default();
+    self.prepay_discounts[..steps.len()].copy_from_slice(steps);
+    Ok(())
+  }
+
+  /// Curve checks of set_prepay_discounts, also run on council proposals
+  pub fn validate_prepay_discounts(steps: &[PrepayDiscount]) -> Result<()> {
+    require!(
+      steps.len() <= Self::MAX_PREPAY_DISCOUNT_STEPS,
+      ErrorCode::InvalidPrepayDiscount
+    );
+    let mut previous = PrepayDiscount::default();
+    for step in steps {
+      require!(
+        step.min_months > previous.min_months
+          && step.min_months <= DeployRequest::MAX_EXTENSION_MONTHS
+          && step.discount_bps >= previous.discount_bps
+          && step.discount_bps <= Self::MAX_PREPAY_DISCOUNT_BPS,
+        ErrorCode::InvalidPrepayDiscount
+      );
+      previous = *step;
+    }
+    Ok(())
+  }
+
+  /// Discount (bps) for paying `months` at once
+  pub fn prepay_discount_bps(&self, months: u32) -> u64 {
+    self
+      .prepay_discounts
+      .iter()
+      .filter(|step| step.min_months > 0 && months >= step.min_months)
+      .map(|step| step.discount_bps)
+      .max()
+      .unwrap_or(0)
+  }
+
+  /// Apply the prepayment discount to a `months` payment
+  /// Returns (amount due, discount)
+  pub fn apply_prepay_discount(&self, payment: u64, months: u32) -> Result<(u64, u64)> {
+    let discount = (payment as u128)
+      .checked_mul(self.prepay_discount_bps(months) as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / 10_000;
+    let discount = discount as u64;
+    Ok((payment - discount, discount))
+  }
+
+  /// Exit fee owed by reclaim_authority on a deployment of `borrowed_amount`
+  pub fn calculate_authority_exit_fee(&self, borrowed_amount: u64) -> Result<u64> {
+    let fee = (borrowed_amount as u128)
+      .checked_mul(self.authority_exit_fee_bps as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      / 10_000;
+    u64::try_from(fee).map_err(|_| ErrorCode::CalculationOverflow.into())
+  }
+
+  /// Check if a subscription expiring at `paid_until` is due an expiry
+  /// warning: inside the warning window, not yet expired, and not warned
+  /// within the last `expiry_warning_interval`
+  pub fn is_expiry_warning_due(
+    &self,
+    paid_until: i64,
+    last_warned_at: i64,
+    current_time: i64,
+  ) -> bool {
+    self.expiry_warning_window > 0
+      && current_time <= paid_until
+      && current_time >= paid_until.saturating_sub(self.expiry_warning_window)
+      && (last_warned_at == 0
+        || current_time.saturating_sub(last_warned_at) >= self.expiry_warning_interval)
+  }
+
+  /// Check if managed programs may be handed back to their developers
+  pub fn allows_authority_return(&self) -> bool {
+    self.offboarding_policy == Self::OFFBOARDING_POLICY_RETURN_AUTHORITY
+  }
+
+  /// Record lamports lent out for an upgrade buffer
+  pub fn record_buffer_rent_loan(&mut self, amount: u64) -> Result<()> {
+    self.outstanding_buffer_rent_loans = self
+      .outstanding_buffer_rent_loans
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// Record a buffer rent loan repayment - restores liquid_balance
+  pub fn record_buffer_rent_repayment(&mut self, amount: u64) -> Result<()> {
+    self.outstanding_buffer_rent_loans = self.outstanding_buffer_rent_loans.saturating_sub(amount);
+    self.liquid_balance = self
+      .liquid_balance
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// Platform fee (0.1%) on a deployment's cost, paid by the developer
+  /// with the deploy request
+  pub fn calculate_deployment_platform_fee(deployment_cost: u64) -> u64 {
+    deployment_cost / 1000
+  }
+
+  /// Split a subscription payment into (reward, platform) portions
+  /// Platform share rounds down so stakers keep the remainder
+  pub fn split_subscription_payment(&self, payment_amount: u64) -> Result<(u64, u64)> {
+    let platform_share = (payment_amount as u128)
+      .checked_mul(self.subscription_platform_bps as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      .checked_div(10000)
+      .ok_or(ErrorCode::CalculationOverflow)? as u64;
+    let reward_share = payment_amount
+      .checked_sub(platform_share)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok((reward_share, platform_share))
+  }
+
+  /// Check if duration bonuses vest instead of being paid in full
+  pub fn is_bonus_vesting_enabled(&self) -> bool {
+    self.bonus_vesting_period > 0
+  }
+
+  /// Check if a subscription split change is waiting for its timelock
+  pub fn has_pending_subscription_split(&self) -> bool {
+    self.subscription_split_effective_at != 0
+  }
+
+  /// Check if an emergency exit policy is known
+  pub fn is_valid_emergency_exit_policy(policy: u8) -> bool {
+    policy == Self::EMERGENCY_EXIT_RESPECT_QUEUE || policy == Self::EMERGENCY_EXIT_QUEUE_JUMP_FEE
+  }
+
+  /// Check if emergency_unstake may run under the current pause state
+  pub fn is_emergency_exit_allowed(&self) -> bool {
+    !self.emergency_pause || self.allow_emergency_exit_during_pause
+  }
+
+  /// Deposits that accrue reward-per-share (excludes queued withdrawals,
+  /// so exiting capital does not dilute active stakers)
+  pub fn get_reward_eligible_deposits(&self) -> u64 {
+    self
+      .total_deposited
+      .saturating_sub(self.queued_withdrawal_amount)
+  }
+
+  /// Liquidity not reserved for queued withdrawals
+  pub fn get_unreserved_liquidity(&self, available_balance: u64) -> u64 {
+    available_balance.saturating_sub(self.queued_withdrawal_amount)
+  }
+
+  /// Calculate fee for drawing on queue-reserved liquidity
+  pub fn calculate_queue_jump_fee(&self, reserved_portion: u64) -> Result<u64> {
+    let fee = (reserved_portion as u128)
+      .checked_mul(self.queue_jump_fee_bps as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      .checked_div(10000)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(fee as u64)
+  }
+
+  /// Calculate the haircut on an emergency exit
+  pub fn calculate_emergency_haircut(&self, amount: u64) -> Result<u64> {
+    let haircut = (amount as u128)
+      .checked_mul(self.emergency_haircut_bps as u128)
+      .ok_or(ErrorCode::CalculationOverflow)?
+      .checked_div(10000)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(haircut as u64)
+  }
+
+  /// Check if a queue entry has waited longer than max_queue_age
+  pub fn is_queue_entry_expired(&self, queued_at: i64, current_time: i64) -> bool {
+    self.max_queue_age > 0 && current_time.saturating_sub(queued_at) >= self.max_queue_age
+  }
+
   /// Check if withdrawal queue has pending entries
   pub fn has_pending_withdrawals(&self) -> bool {
     self.withdrawal_queue_tail > self.withdrawal_queue_head
@@ -527,9 +1586,65 @@ impl TreasuryPool {
     Ok(())
   }
 
+  /// Record rewards credited to a single staker (does not touch reward_per_share)
+  pub fn credit_targeted_rewards(&mut self, amount: u64) -> Result<()> {
+    self.reward_pool_balance = self
+      .reward_pool_balance
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.total_credited_rewards = self
+      .total_credited_rewards
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// Hold a developer payment (already in the reward pool) until confirmation
+  pub fn hold_developer_payment(&mut self, amount: u64) -> Result<()> {
+    self.total_escrowed_payments = self
+      .total_escrowed_payments
+      .checked_add(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// Release a held developer payment to stakers through the canonical fee path
+  pub fn release_escrowed_payment(&mut self, source: FeeSource, amount: u64) -> Result<()> {
+    if amount == 0 {
+      return Ok(());
+    }
+    self.total_escrowed_payments = self
+      .total_escrowed_payments
+      .checked_sub(amount)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.collect_fee(source, amount, 0)
+  }
+
+  /// Split a refund between a request's escrowed payment and credited rewards
+  ///
+  /// The escrow is consumed: the part covering the refund leaves the escrow
+  /// total (caller pays it out), any surplus is released to stakers. Returns
+  /// `(from_escrow, from_rewards)`; `from_rewards` must be debited from
+  /// reward_pool_balance (requests created before escrowing).
+  pub fn split_escrowed_refund(
+    &mut self,
+    source: FeeSource,
+    escrowed: u64,
+    refund_amount: u64,
+  ) -> Result<(u64, u64)> {
+    let from_escrow = escrowed.min(refund_amount);
+    self.total_escrowed_payments = self
+      .total_escrowed_payments
+      .checked_sub(from_escrow)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    self.release_escrowed_payment(source, escrowed - from_escrow)?;
+    Ok((from_escrow, refund_amount - from_escrow))
+  }
+
   /// Distribute a portion of pending rewards to reward_per_share
   pub fn distribute_pending_rewards(&mut self, percentage_bps: u64) -> Result<u64> {
-    if self.pending_undistributed_rewards == 0 || self.total_deposited == 0 {
+    let reward_eligible_deposits = self.get_reward_eligible_deposits();
+    if self.pending_undistributed_rewards == 0 || reward_eligible_deposits == 0 {
       return Ok(0);
     }
 
@@ -547,7 +1662,7 @@ impl TreasuryPool {
     let delta = (amount_to_distribute as u128)
       .checked_mul(Self::PRECISION)
       .ok_or(ErrorCode::CalculationOverflow)?
-      .checked_div(self.total_deposited as u128)
+      .checked_div(reward_eligible_deposits as u128)
       .ok_or(ErrorCode::CalculationOverflow)?;
 
     self.reward_per_share = self
@@ -586,3 +1701,547 @@ impl TreasuryPool {
     Ok(bonus)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn emergency_exit_allowed_when_not_paused() {
+    for allow_during_pause in [false, true] {
+      let pool = TreasuryPool {
+        emergency_pause: false,
+        allow_emergency_exit_during_pause: allow_during_pause,
+        ..Default::default()
+      };
+      assert!(pool.is_emergency_exit_allowed());
+    }
+  }
+
+  #[test]
+  fn emergency_exit_during_pause_follows_flag() {
+    let open_exit = TreasuryPool {
+      emergency_pause: true,
+      allow_emergency_exit_during_pause: true,
+      ..Default::default()
+    };
+    assert!(open_exit.is_emergency_exit_allowed());
+
+    let closed_exit = TreasuryPool {
+      emergency_pause: true,
+      allow_emergency_exit_during_pause: false,
+      ..Default::default()
+    };
+    assert!(!closed_exit.is_emergency_exit_allowed());
+  }
+
+  #[test]
+  fn queue_entry_expiry_respects_max_age() {
+    let day = TreasuryPool::SECONDS_PER_DAY;
+    let disabled = TreasuryPool::default();
+    assert!(!disabled.is_queue_entry_expired(0, 1000 * day));
+
+    let pool = TreasuryPool {
+      max_queue_age: 30 * day,
+      ..Default::default()
+    };
+    assert!(!pool.is_queue_entry_expired(day, 30 * day));
+    assert!(pool.is_queue_entry_expired(day, 31 * day));
+  }
+
+  #[test]
+  fn queued_withdrawals_do_not_accrue_rewards() {
+    let mut pool = TreasuryPool {
+      total_deposited: 1_000,
+      queued_withdrawal_amount: 600,
+      ..Default::default()
+    };
+    pool.collect_fee(FeeSource::AdminCredit, 400, 0).unwrap();
+    assert_eq!(pool.reward_per_share, 400 * TreasuryPool::PRECISION / 400);
+    assert_eq!(pool.pending_undistributed_rewards, 0);
+
+    // Fully queued pool holds rewards for later distribution
+    pool.queued_withdrawal_amount = 1_000;
+    let reward_per_share_before = pool.reward_per_share;
+    pool.collect_fee(FeeSource::AdminCredit, 100, 0).unwrap();
+    assert_eq!(pool.reward_per_share, reward_per_share_before);
+    assert_eq!(pool.pending_undistributed_rewards, 100);
+  }
+
+  #[test]
+  fn subscription_split_keeps_remainder_for_stakers() {
+    let all_rewards = TreasuryPool::default();
+    assert_eq!(
+      all_rewards.split_subscription_payment(999).unwrap(),
+      (999, 0)
+    );
+
+    let pool = TreasuryPool {
+      subscription_platform_bps: 2500,
+      ..Default::default()
+    };
+    assert_eq!(pool.split_subscription_payment(1_000).unwrap(), (750, 250));
+    assert_eq!(pool.split_subscription_payment(999).unwrap(), (750, 249));
+  }
+
+  #[test]
+  fn stake_cap_applies_only_when_configured() {
+    let unlimited = TreasuryPool::default();
+    assert!(unlimited.check_stake_amount(u64::MAX).is_ok());
+
+    let capped = TreasuryPool {
+      max_stake_per_tx: 1_000,
+      ..Default::default()
+    };
+    assert!(capped.check_stake_amount(1_000).is_ok());
+    assert!(capped.check_stake_amount(1_001).is_err());
+  }
+
+  #[test]
+  fn request_params_respect_bounds() {
+    let unbounded = TreasuryPool::default();
+    assert!(unbounded
+      .check_request_params(u64::MAX, u64::MAX, u32::MAX)
+      .is_ok());
+
+    let bounded = TreasuryPool {
+      min_service_fee: 100,
+      max_service_fee: 1_000,
+      min_monthly_fee: 10,
+      max_monthly_fee: 500,
+      min_initial_months: 1,
+      max_initial_months: 12,
+      ..Default::default()
+    };
+    assert!(bounded.check_request_params(100, 10, 1).is_ok());
+    assert!(bounded.check_request_params(1_000, 500, 12).is_ok());
+    assert!(bounded.check_request_params(99, 10, 1).is_err());
+    assert!(bounded.check_request_params(1_001, 10, 1).is_err());
+    assert!(bounded.check_request_params(100, 501, 1).is_err());
+    assert!(bounded.check_request_params(100, 10, 0).is_err());
+    assert!(bounded.check_request_params(100, 10, 13).is_err());
+  }
+
+  #[test]
+  fn emergency_haircut_scales_with_bps() {
+    let no_haircut = TreasuryPool::default();
+    assert_eq!(
+      no_haircut.calculate_emergency_haircut(1_000_000).unwrap(),
+      0
+    );
+
+    let pool = TreasuryPool {
+      emergency_haircut_bps: TreasuryPool::DEFAULT_EMERGENCY_HAIRCUT_BPS,
+      ..Default::default()
+    };
+    assert_eq!(pool.calculate_emergency_haircut(1_000_000).unwrap(), 20_000);
+    assert_eq!(pool.calculate_emergency_haircut(49).unwrap(), 0);
+  }
+
+  #[test]
+  fn escrowed_refund_uses_escrow_first() {
+    let mut pool = TreasuryPool {
+      total_deposited: 1_000,
+      ..Default::default()
+    };
+    pool.hold_developer_payment(500).unwrap();
+
+    // Refund below escrow: surplus goes to stakers
+    let (from_escrow, from_rewards) = pool
+      .split_escrowed_refund(FeeSource::DeploymentRequest, 300, 200)
+      .unwrap();
+    assert_eq!((from_escrow, from_rewards), (200, 0));
+    assert_eq!(pool.total_escrowed_payments, 200);
+    assert_eq!(pool.reward_pool_balance, 100);
+
+    // Refund above escrow: the rest comes from credited rewards
+    let (from_escrow, from_rewards) = pool
+      .split_escrowed_refund(FeeSource::DeploymentRequest, 200, 250)
+      .unwrap();
+    assert_eq!((from_escrow, from_rewards), (200, 50));
+    assert_eq!(pool.total_escrowed_payments, 0);
+  }
+
+  #[test]
+  fn reward_clawback_keeps_protected_rewards_backed() {
+    let mut pool = TreasuryPool {
+      reward_pool_balance: 1_000,
+      total_credited_rewards: 800,
+      platform_pool_balance: 500,
+      ..Default::default()
+    };
+
+    // Fits entirely within the unprotected excess
+    assert_eq!(pool.split_reward_clawback(150).unwrap(), (150, 0));
+    // Shortfall is covered by the platform pool
+    assert_eq!(pool.split_reward_clawback(450).unwrap(), (200, 250));
+    // Neither source can cover it
+    assert!(pool.split_reward_clawback(800).is_err());
+
+    assert!(pool.debit_reward_pool(200).is_ok());
+    assert!(pool.debit_reward_pool(1).is_err());
+  }
+
+  #[test]
+  fn utilization_uses_normalized_token_value() {
+    let mut pool = TreasuryPool {
+      total_deposited: 1_000,
+      total_borrowed: 500,
+      ..Default::default()
+    };
+    assert_eq!(pool.get_utilization_bps(), 5000);
+
+    // 600 of the deposits are LST-backed; the vault is now worth 1_100
+    pool.lst_backed_deposits = 600;
+    pool.update_normalized_token_value(0, 600).unwrap();
+    assert_eq!(pool.get_normalized_deposits(), 1_000);
+    pool.update_normalized_token_value(600, 1_100).unwrap();
+    assert_eq!(pool.get_normalized_deposits(), 1_500);
+    assert_eq!(pool.get_utilization_bps(), 3333);
+  }
+
+  #[test]
+  fn alert_bounty_respects_cooldown_and_platform_pool() {
+    let mut pool = TreasuryPool {
+      watchtower_bounty: 1_000,
+      watchtower_alert_cooldown: 100,
+      platform_pool_balance: 600,
+      ..Default::default()
+    };
+    assert_eq!(pool.claim_alert_bounty(0, 1_000).unwrap(), 600);
+    assert!(pool.claim_alert_bounty(0, 1_050).is_err());
+    // Other kinds have their own cooldown
+    assert!(pool.claim_alert_bounty(1, 1_050).is_ok());
+    assert!(pool.claim_alert_bounty(0, 1_100).is_ok());
+  }
+
+  #[test]
+  fn pool_share_cap_applies_after_bootstrap() {
+    let min = TreasuryPool::POOL_SHARE_CAP_MIN_DEPOSITS;
+    let uncapped = TreasuryPool {
+      total_deposited: min,
+      ..Default::default()
+    };
+    assert!(uncapped.check_pool_share(min, min).is_ok());
+
+    let capped = TreasuryPool {
+      total_deposited: 3 * min,
+      max_pool_share_bps: 2500,
+      ..Default::default()
+    };
+    // Bootstrapping pool is exempt
+    let bootstrapping = TreasuryPool {
+      total_deposited: 0,
+      ..capped
+    };
+    assert!(bootstrapping.check_pool_share(min / 2, min / 2).is_ok());
+    // 1 of 4 after the stake is exactly 25%
+    assert!(capped.check_pool_share(min, min).is_ok());
+    assert!(capped.check_pool_share(min + 1, min).is_err());
+  }
+
+  #[test]
+  fn large_unstakes_require_commitment() {
+    let instant = TreasuryPool {
+      total_deposited: 1_000,
+      ..Default::default()
+    };
+    assert!(!instant.requires_unstake_commitment(1_000));
+
+    let mut pool = TreasuryPool {
+      total_deposited: 1_000,
+      large_unstake_threshold_bps: 1000,
+      ..Default::default()
+    };
+    assert!(!pool.requires_unstake_commitment(100));
+    assert!(pool.requires_unstake_commitment(101));
+
+    pool.record_unstake_commitment(300).unwrap();
+    assert_eq!(pool.get_uncommitted_liquidity(500), 200);
+    assert_eq!(pool.get_uncommitted_liquidity(200), 0);
+    pool.release_unstake_commitment(300);
+    assert_eq!(pool.get_uncommitted_liquidity(500), 500);
+  }
+
+  #[test]
+  fn deposit_fees_only_charged_when_enabled() {
+    let disabled = TreasuryPool::default();
+    assert_eq!(
+      disabled.calculate_deposit_fees(1_000_000_000).unwrap(),
+      (0, 0)
+    );
+
+    let enabled = TreasuryPool {
+      deposit_fees_enabled: true,
+      ..Default::default()
+    };
+    // 1% reward fee, 0.1% platform fee
+    assert_eq!(
+      enabled.calculate_deposit_fees(1_000_000_000).unwrap(),
+      (10_000_000, 1_000_000)
+    );
+  }
+
+  #[test]
+  fn subscription_payment_uses_checked_bounds() {
+    let pool = TreasuryPool::default();
+    assert_eq!(
+      pool.calculate_subscription_payment(1_000_000, 12).unwrap(),
+      12_000_000
+    );
+    // u64 overflow and months beyond the extension cap are rejected
+    assert!(pool.calculate_subscription_payment(u64::MAX, 2).is_err());
+    assert!(pool
+      .calculate_subscription_payment(1, DeployRequest::MAX_EXTENSION_MONTHS + 1)
+      .is_err());
+
+    // With a fee ceiling the total is bounded by the plan configuration
+    let capped = TreasuryPool {
+      max_monthly_fee: 1_000,
+      ..Default::default()
+    };
+    assert!(capped.calculate_subscription_payment(1_000, 120).is_ok());
+    assert!(capped.calculate_subscription_payment(2_000, 120).is_err());
+  }
+
+  #[test]
+  fn recovery_rate_converges_and_decays() {
+    let day = TreasuryPool::SECONDS_PER_DAY;
+    let mut pool = TreasuryPool::default();
+    let start = 1_700_000_000;
+
+    // A steady 7 SOL/day of repayments converges towards 7 SOL/day
+    for i in 0..100 {
+      pool.record_recovery(7_000_000_000, start + i * day);
+    }
+    let rate = pool.get_recovery_rate_per_day(start + 99 * day);
+    assert!(rate > 6_900_000_000 && rate <= 7_000_000_000);
+
+    // Idle days decay it, and a long idle period zeroes it
+    assert!(pool.get_recovery_rate_per_day(start + 101 * day) < rate);
+    assert_eq!(pool.get_recovery_rate_per_day(start + 200 * day), 0);
+  }
+
+  #[test]
+  fn attested_identity_only_raises_buffer_rent_loan_cap() {
+    let pool = TreasuryPool {
+      max_buffer_rent_loan: 1_000,
+      verified_max_buffer_rent_loan: 5_000,
+      ..Default::default()
+    };
+    assert_eq!(pool.get_buffer_rent_loan_cap(false), 1_000);
+    assert_eq!(pool.get_buffer_rent_loan_cap(true), 5_000);
+
+    let lower = TreasuryPool {
+      verified_max_buffer_rent_loan: 500,
+      ..pool.clone()
+    };
+    assert_eq!(lower.get_buffer_rent_loan_cap(true), 1_000);
+
+    // Loans disabled stays disabled for everyone
+    let disabled = TreasuryPool {
+      max_buffer_rent_loan: 0,
+      ..pool
+    };
+    assert_eq!(disabled.get_buffer_rent_loan_cap(true), 0);
+  }
+
+  #[test]
+  fn reward_pool_write_down_covers_shortfall() {
+    let mut pool = TreasuryPool {
+      reward_pool_balance: 1_000,
+      pending_undistributed_rewards: 900,
+      total_escrowed_payments: 200,
+      total_credited_rewards: 1_000,
+      ..Default::default()
+    };
+    assert_eq!(pool.get_reward_pool_obligations().unwrap(), 1_200);
+    assert!(pool.check_reward_pool_solvency(1_200).is_ok());
+    assert!(pool.check_reward_pool_solvency(1_000).is_err());
+
+    // 200 lamports left as unrecorded bonus payouts
+    assert_eq!(pool.write_down_reward_pool(1_000).unwrap(), 200);
+    assert_eq!(pool.reward_pool_balance, 800);
+    assert_eq!(pool.pending_undistributed_rewards, 800);
+    assert_eq!(pool.total_claimed_rewards, 200);
+    assert!(pool.check_reward_pool_solvency(1_000).is_ok());
+    assert!(pool.check_protected_rewards_backed().is_ok());
+    assert_eq!(pool.write_down_reward_pool(1_000).unwrap(), 0);
+  }
+
+  #[test]
+  fn decentralized_at_survives_any_layout() {
+    let pool = TreasuryPool {
+      decentralized_at: 1_700_000_000,
+      ..Default::default()
+    };
+    let mut data = Vec::new();
+    pool.try_serialize(&mut data).unwrap();
+    assert!(pool.is_decentralized());
+    assert_eq!(TreasuryPool::read_decentralized_at(&data), 1_700_000_000);
+
+    // Older layouts end before the field and read as not decentralized
+    let mut undecentralized = Vec::new();
+    TreasuryPool::default()
+      .try_serialize(&mut undecentralized)
+      .unwrap();
+    let field_offset = data
+      .iter()
+      .zip(&undecentralized)
+      .position(|(a, b)| a != b)
+      .unwrap();
+    assert_eq!(
+      TreasuryPool::read_decentralized_at(&data[..field_offset]),
+      0
+    );
+    assert_eq!(TreasuryPool::read_decentralized_at(&[0u8; 16]), 0);
+  }
+
+  #[test]
+  fn pause_state_survives_migration_reads() {
+    let pool = TreasuryPool {
+      emergency_pause: true,
+      unpause_scheduled_at: 100,
+      unpause_executable_at: 200,
+      ..Default::default()
+    };
+    let mut data = Vec::new();
+    pool.try_serialize(&mut data).unwrap();
+    assert_eq!(TreasuryPool::read_pause_state(&data), (true, 100, 200));
+    assert_eq!(TreasuryPool::read_pause_state(&[0u8; 16]), (false, 0, 0));
+  }
+
+  #[test]
+  fn authority_exit_fee_is_bps_of_borrowed_amount() {
+    let mut pool = TreasuryPool::default();
+    assert_eq!(pool.calculate_authority_exit_fee(5_000_000).unwrap(), 0);
+    pool.authority_exit_fee_bps = 500;
+    assert_eq!(
+      pool.calculate_authority_exit_fee(5_000_000).unwrap(),
+      250_000
+    );
+    assert_eq!(
+      pool.calculate_authority_exit_fee(u64::MAX).unwrap(),
+      u64::MAX / 20
+    );
+  }
+
+  #[test]
+  fn collateral_settlement_repays_without_closing_a_deployment() {
+    let mut pool = TreasuryPool {
+      total_borrowed: 1_000,
+      active_deployment_count: 2,
+      ..Default::default()
+    };
+    pool.record_collateral_settlement(400, 1_000).unwrap();
+    assert_eq!(pool.total_borrowed, 600);
+    assert_eq!(pool.total_debt_repaid, 400);
+    assert_eq!(pool.liquid_balance, 400);
+    assert_eq!(pool.active_deployment_count, 2);
+  }
+
+  #[test]
+  fn prepay_discount_follows_curve() {
+    let mut pool = TreasuryPool::default();
+    pool
+      .set_prepay_discounts(&[
+        PrepayDiscount {
+          min_months: 6,
+          discount_bps: 500,
+        },
+        PrepayDiscount {
+          min_months: 12,
+          discount_bps: 1_000,
+        },
+      ])
+      .unwrap();
+    assert_eq!(pool.apply_prepay_discount(1_000, 1).unwrap(), (1_000, 0));
+    assert_eq!(pool.apply_prepay_discount(6_000, 6).unwrap(), (5_700, 300));
+    assert_eq!(
+      pool.apply_prepay_discount(24_000, 24).unwrap(),
+      (21_600, 2_400)
+    );
+  }
+
+  #[test]
+  fn prepay_discount_rejects_unordered_steps() {
+    let mut pool = TreasuryPool::default();
+    let step = |min_months, discount_bps| PrepayDiscount {
+      min_months,
+      discount_bps,
+    };
+    assert!(pool
+      .set_prepay_discounts(&[step(12, 1_000), step(6, 500)])
+      .is_err());
+    assert!(pool
+      .set_prepay_discounts(&[step(6, 1_000), step(12, 500)])
+      .is_err());
+    assert!(pool.set_prepay_discounts(&[step(12, 6_000)]).is_err());
+  }
+
+  #[test]
+  fn expiry_warning_fires_inside_window_once_per_interval() {
+    let day = 24 * 60 * 60;
+    let disabled = TreasuryPool::default();
+    assert!(!disabled.is_expiry_warning_due(10 * day, 0, 9 * day));
+
+    let pool = TreasuryPool {
+      expiry_warning_window: 3 * day,
+      expiry_warning_interval: day,
+      ..Default::default()
+    };
+    assert!(!pool.is_expiry_warning_due(10 * day, 0, 6 * day));
+    assert!(pool.is_expiry_warning_due(10 * day, 0, 7 * day));
+    assert!(!pool.is_expiry_warning_due(10 * day, 7 * day, 7 * day + 60));
+    assert!(pool.is_expiry_warning_due(10 * day, 7 * day, 8 * day));
+    assert!(!pool.is_expiry_warning_due(10 * day, 0, 10 * day + 1));
+  }
+
+  #[test]
+  fn roles_grant_only_their_own_group_and_admin_holds_all() {
+    let admin = Pubkey::new_unique();
+    let operator = Pubkey::new_unique();
+    let mut pool = TreasuryPool {
+      admin,
+      ..Default::default()
+    };
+    assert!(!pool.has_role(Role::DeployerOperator, &operator));
+    assert!(!pool.has_role(Role::Pauser, &Pubkey::default()));
+
+    pool.set_role_holder(Role::DeployerOperator, operator);
+    assert_eq!(pool.role_holder(Role::DeployerOperator), operator);
+    assert!(pool.has_role(Role::DeployerOperator, &operator));
+    assert!(!pool.has_role(Role::Treasurer, &operator));
+    assert!(pool.has_role(Role::Treasurer, &admin));
+
+    pool.set_role_holder(Role::DeployerOperator, Pubkey::default());
+    assert!(!pool.has_role(Role::DeployerOperator, &operator));
+  }
+
+  #[test]
+  fn unpause_waits_for_the_timelock_and_can_be_vetoed() {
+    let hour = 60 * 60;
+    let mut pool = TreasuryPool {
+      timelock_duration: 0,
+      ..Default::default()
+    };
+    assert!(pool.schedule_unpause(0).is_err());
+
+    pool.emergency_pause = true;
+    assert_eq!(
+      pool.schedule_unpause(100).unwrap(),
+      100 + TreasuryPool::MIN_TIMELOCK_DURATION
+    );
+    assert!(pool.schedule_unpause(100).is_err());
+    assert!(pool.cancel_scheduled_unpause());
+    assert!(!pool.cancel_scheduled_unpause());
+    assert!(pool.execute_unpause(100 + hour).is_err());
+
+    pool.timelock_duration = 2 * hour;
+    pool.schedule_unpause(100).unwrap();
+    assert!(pool.execute_unpause(100 + hour).is_err());
+    pool.execute_unpause(100 + 2 * hour).unwrap();
+    assert!(!pool.emergency_pause);
+    assert!(!pool.has_scheduled_unpause());
+  }
+}
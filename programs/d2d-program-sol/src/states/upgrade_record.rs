@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// One entry of a managed program's upgrade history
+///
+/// Written by every proxy upgrade and rollback at `[PREFIX_SEED, program_id, index]`, so
+/// the lineage can be walked from index 0 to `ManagedProgram::upgrade_count`.
+#[account]
+#[derive(InitSpace)]
+pub struct UpgradeRecord {
+  pub program_id: Pubkey,
+  /// Position in the history (the program's upgrade_count before it)
+  pub index: u32,
+  pub buffer_address: Pubkey,
+  /// Bytecode hash verified before upgrading (zeros when none was supplied)
+  pub buffer_hash: [u8; 32],
+  /// Owner or co-developer who signed the upgrade
+  pub signer: Pubkey,
+  pub slot: u64,
+  pub upgraded_at: i64,
+  pub bump: u8,
+  /// Written by proxy_rollback_program (buffer_hash is the restored release)
+  pub is_rollback: bool,
+}
+
+impl UpgradeRecord {
+  pub const PREFIX_SEED: &'static [u8] = b"upgrade_record";
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
+use crate::{errors::ErrorCode, states::DeployRequestStatus};
+
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct UserDeployStats {
   pub user: Pubkey,         // User public key
   pub active_sessions: u32, // Current active sessions
@@ -9,8 +11,107 @@ pub struct UserDeployStats {
   pub total_deploys: u64,   // Total deployments
   pub last_reset: i64,      // Last daily reset timestamp
   pub bump: u8,             // PDA bump
+
+  // === DEPLOYMENT TRACKING ===
+  /// Confirmed deployments not yet closed or off-boarded
+  pub active_deployments: u32,
+  pub successful_deploys: u64,
+  pub failed_deploys: u64,
+  /// Request fees paid, net of failure refunds (lamports)
+  pub total_spent: u64,
+  /// Deployment cost borrowed for confirmed deployments (lamports)
+  pub total_deployment_cost: u64,
 }
 
 impl UserDeployStats {
   pub const PREFIX_SEED: &'static [u8] = b"user_stats";
+
+  /// Pending requests plus live deployments, counted against the quota
+  pub fn concurrent_deployments(&self) -> u32 {
+    self.active_sessions.saturating_add(self.active_deployments)
+  }
+
+  /// Check room for one more deployment under `max_active` (0 = unlimited)
+  pub fn check_quota(&self, max_active: u32) -> Result<()> {
+    require!(
+      max_active == 0 || self.concurrent_deployments() < max_active,
+      ErrorCode::DeploymentQuotaExceeded
+    );
+    Ok(())
+  }
+
+  /// Record a new deploy request and the fees paid for it
+  pub fn record_request(&mut self, payment: u64) -> Result<()> {
+    self.active_sessions = self.active_sessions.saturating_add(1);
+    self.daily_deploys = self.daily_deploys.saturating_add(1);
+    self.total_deploys = self.total_deploys.saturating_add(1);
+    self.total_spent = self
+      .total_spent
+      .checked_add(payment)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// A pending request went live
+  pub fn record_deployment_success(&mut self, deployment_cost: u64) -> Result<()> {
+    self.active_sessions = self.active_sessions.saturating_sub(1);
+    self.active_deployments = self.active_deployments.saturating_add(1);
+    self.successful_deploys = self.successful_deploys.saturating_add(1);
+    self.total_deployment_cost = self
+      .total_deployment_cost
+      .checked_add(deployment_cost)
+      .ok_or(ErrorCode::CalculationOverflow)?;
+    Ok(())
+  }
+
+  /// A pending request failed and `refund` of its fees was returned
+  pub fn record_deployment_failure(&mut self, refund: u64) {
+    self.active_sessions = self.active_sessions.saturating_sub(1);
+    self.failed_deploys = self.failed_deploys.saturating_add(1);
+    self.total_spent = self.total_spent.saturating_sub(refund);
+  }
+
+  /// Free the quota slot held by a request leaving `status` (cancelled,
+  /// expired, closed or off-boarded)
+  pub fn release_slot(&mut self, status: &DeployRequestStatus) {
+    match status {
+      DeployRequestStatus::PendingDeployment => {
+        self.active_sessions = self.active_sessions.saturating_sub(1);
+      }
+      DeployRequestStatus::Active
+      | DeployRequestStatus::SubscriptionExpired
+      | DeployRequestStatus::InGracePeriod
+      | DeployRequestStatus::Suspended
+      | DeployRequestStatus::Hibernated => {
+        self.active_deployments = self.active_deployments.saturating_sub(1);
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn quota_counts_pending_and_live_deployments() {
+    let mut stats = UserDeployStats::default();
+    stats.record_request(100).unwrap();
+    stats.record_request(100).unwrap();
+    assert!(stats.check_quota(2).is_err());
+    assert!(stats.check_quota(0).is_ok());
+
+    stats.record_deployment_success(5_000).unwrap();
+    stats.record_deployment_failure(100);
+    assert_eq!(stats.concurrent_deployments(), 1);
+    assert!(stats.check_quota(2).is_ok());
+    assert_eq!(stats.total_spent, 100);
+    assert_eq!(stats.total_deployment_cost, 5_000);
+
+    stats.release_slot(&DeployRequestStatus::Active);
+    stats.release_slot(&DeployRequestStatus::Closed);
+    stats.release_slot(&DeployRequestStatus::Active);
+    assert_eq!(stats.concurrent_deployments(), 0);
+  }
 }
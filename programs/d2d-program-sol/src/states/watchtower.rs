@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Condition a watchtower can report; each is re-checked on-chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchtowerAlert {
+  /// Utilization above TreasuryPool::MAX_UTILIZATION_BPS
+  /// Remaining accounts: none
+  UtilizationBreach,
+  /// An enabled LST's oracle rate is older than its max_rate_age
+  /// Remaining accounts: [lst_config]
+  StaleCrank,
+  /// A managed program's upgrade authority is no longer its authority PDA
+  /// Remaining accounts: [managed_program, program_data]
+  AuthorityDrift,
+}
+
+impl WatchtowerAlert {
+  /// Index into TreasuryPool::last_alert_paid_at
+  pub fn index(self) -> usize {
+    self as usize
+  }
+}
+
+/// Third-party monitor registered to submit alerts for bounties
+///
+/// Registration is permissionless and nothing is staked: invalid alerts are
+/// rejected on-chain rather than slashed.
+#[account]
+#[derive(InitSpace)]
+pub struct Watchtower {
+  pub operator: Pubkey,
+  pub alerts_validated: u32,
+  pub bounties_earned: u64,
+  pub last_alert_at: i64,
+  pub registered_at: i64,
+  pub bump: u8,
+}
+
+impl Watchtower {
+  pub const PREFIX_SEED: &'static [u8] = b"watchtower";
+}
@@ -26,6 +26,21 @@ pub struct WithdrawalQueueEntry {
 impl WithdrawalQueueEntry {
   pub const PREFIX_SEED: &'static [u8] = b"withdrawal_queue";
 
+  /// Max entries fulfilled automatically per liquidity event (compute bound)
+  pub const MAX_AUTO_FULFILL_ENTRIES: usize = 3;
+
+  /// Whether `info` is the already-closed entry PDA for `position`
+  ///
+  /// Finished entries are closed (rent back to the staker), so an empty
+  /// system-owned account at a position below the tail counts as processed
+  /// when advancing the queue head.
+  pub fn is_closed_slot(info: &AccountInfo, position: u32, program_id: &Pubkey) -> bool {
+    info.owner == &anchor_lang::system_program::ID
+      && info.data_is_empty()
+      && Pubkey::find_program_address(&[Self::PREFIX_SEED, &position.to_le_bytes()], program_id).0
+        == info.key()
+  }
+
   /// Check if this entry is pending (not yet fully processed)
   pub fn is_pending(&self) -> bool {
     !self.processed && self.amount > self.amount_withdrawn
@@ -0,0 +1,269 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::TokenAccount;
+
+use crate::{constant::TOKEN_METADATA_PROGRAM_ID, errors::ErrorCode, states::BoostNftUsage};
+
+/// Fields of a Metaplex metadata account needed for boost NFT checks
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NftMetadata {
+  pub mint: Pubkey,
+  /// Collection key, only when the collection authority verified it
+  pub verified_collection: Option<Pubkey>,
+}
+
+/// Metaplex `Key::MetadataV1`
+const METADATA_V1_KEY: u8 = 4;
+/// Creator entries are (address, verified, share)
+const CREATOR_LEN: usize = 32 + 1 + 1;
+
+/// Minimal borsh reader over the Metaplex metadata layout
+struct MetadataReader<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> MetadataReader<'a> {
+  fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+    if self.data.len() < len {
+      return None;
+    }
+    let (head, rest) = self.data.split_at(len);
+    self.data = rest;
+    Some(head)
+  }
+
+  fn read_u8(&mut self) -> Option<u8> {
+    self.take(1).map(|b| b[0])
+  }
+
+  fn read_u32(&mut self) -> Option<u32> {
+    self
+      .take(4)
+      .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+  }
+
+  fn read_pubkey(&mut self) -> Option<Pubkey> {
+    self.take(32).and_then(|b| Pubkey::try_from(b).ok())
+  }
+
+  fn skip_string(&mut self) -> Option<()> {
+    let len = self.read_u32()? as usize;
+    self.take(len).map(|_| ())
+  }
+
+  /// Borsh `Option<T>` tag: true when a value follows
+  fn read_option_tag(&mut self) -> Option<bool> {
+    match self.read_u8()? {
+      0 => Some(false),
+      1 => Some(true),
+      _ => None,
+    }
+  }
+}
+
+/// Parse the mint and verified collection out of a Metaplex metadata account
+///
+/// Layout: key, update_authority, mint, name, symbol, uri,
+/// seller_fee_basis_points, creators, primary_sale_happened, is_mutable,
+/// edition_nonce, token_standard, collection. Returns None on anything that
+/// does not parse as MetadataV1.
+pub fn parse_nft_metadata(data: &[u8]) -> Option<NftMetadata> {
+  let mut reader = MetadataReader { data };
+  if reader.read_u8()? != METADATA_V1_KEY {
+    return None;
+  }
+  reader.take(32)?; // update_authority
+  let mint = reader.read_pubkey()?;
+  reader.skip_string()?; // name
+  reader.skip_string()?; // symbol
+  reader.skip_string()?; // uri
+  reader.take(2)?; // seller_fee_basis_points
+  if reader.read_option_tag()? {
+    let creators = reader.read_u32()? as usize;
+    reader.take(creators.checked_mul(CREATOR_LEN)?)?;
+  }
+  reader.take(2)?; // primary_sale_happened, is_mutable
+  if reader.read_option_tag()? {
+    reader.take(1)?; // edition_nonce
+  }
+  if reader.read_option_tag()? {
+    reader.take(1)?; // token_standard
+  }
+
+  let verified_collection = if reader.read_option_tag()? {
+    let verified = reader.read_u8()? == 1;
+    let key = reader.read_pubkey()?;
+    verified.then_some(key)
+  } else {
+    None
+  };
+
+  Some(NftMetadata {
+    mint,
+    verified_collection,
+  })
+}
+
+/// Check that `owner` holds an NFT of the verified `collection`
+///
+/// `metadata_info` must be the canonical Metaplex metadata PDA of the token
+/// account's mint, and the token account must hold exactly one token.
+/// Returns the NFT mint.
+pub fn verify_boost_nft(
+  metadata_info: &AccountInfo,
+  nft_token: &TokenAccount,
+  owner: &Pubkey,
+  collection: &Pubkey,
+) -> Result<Pubkey> {
+  require!(
+    metadata_info.owner == &TOKEN_METADATA_PROGRAM_ID,
+    ErrorCode::InvalidBoostNft
+  );
+  let (expected_metadata, _bump) = Pubkey::find_program_address(
+    &[
+      b"metadata",
+      TOKEN_METADATA_PROGRAM_ID.as_ref(),
+      nft_token.mint.as_ref(),
+    ],
+    &TOKEN_METADATA_PROGRAM_ID,
+  );
+  require!(
+    metadata_info.key() == expected_metadata,
+    ErrorCode::InvalidBoostNft
+  );
+
+  let metadata =
+    parse_nft_metadata(&metadata_info.data.borrow()).ok_or(ErrorCode::InvalidBoostNft)?;
+  require!(
+    metadata.mint == nft_token.mint
+      && metadata.verified_collection == Some(*collection)
+      && nft_token.owner == *owner
+      && nft_token.amount == 1,
+    ErrorCode::InvalidBoostNft
+  );
+
+  Ok(metadata.mint)
+}
+
+/// Bind `mint` to `position` through its BoostNftUsage PDA
+///
+/// Creates the PDA on first use (payer: `payer`); an NFT already boosting
+/// another position must be freed with revoke_nft_boost first.
+pub fn claim_boost_nft_usage<'info>(
+  usage_info: &AccountInfo<'info>,
+  mint: Pubkey,
+  position: Pubkey,
+  payer: &AccountInfo<'info>,
+  system_program_info: &AccountInfo<'info>,
+  program_id: &Pubkey,
+  current_time: i64,
+) -> Result<()> {
+  let (expected_usage, bump) =
+    Pubkey::find_program_address(&[BoostNftUsage::PREFIX_SEED, mint.as_ref()], program_id);
+  require!(
+    usage_info.key() == expected_usage,
+    ErrorCode::InvalidBoostNft
+  );
+
+  if usage_info.data_is_empty() {
+    let space = 8 + BoostNftUsage::INIT_SPACE;
+    let usage_seeds: &[&[u8]] = &[BoostNftUsage::PREFIX_SEED, mint.as_ref(), &[bump]];
+    system_program::create_account(
+      CpiContext::new_with_signer(
+        system_program_info.clone(),
+        system_program::CreateAccount {
+          from: payer.clone(),
+          to: usage_info.clone(),
+        },
+        &[usage_seeds],
+      ),
+      Rent::get()?.minimum_balance(space),
+      space as u64,
+      program_id,
+    )?;
+  } else {
+    require!(
+      usage_info.owner == program_id,
+      ErrorCode::InvalidAccountOwner
+    );
+    let usage = BoostNftUsage::try_deserialize(&mut &usage_info.data.borrow()[..])?;
+    require!(usage.is_available_to(&position), ErrorCode::BoostNftInUse);
+  }
+
+  let usage = BoostNftUsage {
+    mint,
+    position,
+    applied_at: current_time,
+    bump,
+  };
+  usage.try_serialize(&mut &mut usage_info.try_borrow_mut_data()?[..])?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn borsh_string(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    buf.extend_from_slice(value.as_bytes());
+  }
+
+  fn metadata_bytes(mint: &Pubkey, collection: Option<(bool, Pubkey)>) -> Vec<u8> {
+    let mut buf = vec![METADATA_V1_KEY];
+    buf.extend_from_slice(Pubkey::new_unique().as_ref());
+    buf.extend_from_slice(mint.as_ref());
+    borsh_string(&mut buf, "Boost #1");
+    borsh_string(&mut buf, "BOOST");
+    borsh_string(&mut buf, "https://example.com/1.json");
+    buf.extend_from_slice(&500u16.to_le_bytes());
+    // One creator
+    buf.push(1);
+    buf.extend_from_slice(&1u32.to_le_bytes());
+    buf.extend_from_slice(Pubkey::new_unique().as_ref());
+    buf.extend_from_slice(&[1, 100]);
+    buf.extend_from_slice(&[1, 1]);
+    // edition_nonce, token_standard
+    buf.extend_from_slice(&[1, 255, 1, 0]);
+    match collection {
+      Some((verified, key)) => {
+        buf.extend_from_slice(&[1, verified as u8]);
+        buf.extend_from_slice(key.as_ref());
+      }
+      None => buf.push(0),
+    }
+    // Trailing fields (uses, collection_details, ...) are ignored
+    buf.extend_from_slice(&[0; 8]);
+    buf
+  }
+
+  #[test]
+  fn parses_verified_collection() {
+    let (mint, collection) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let parsed = parse_nft_metadata(&metadata_bytes(&mint, Some((true, collection)))).unwrap();
+    assert_eq!(parsed.mint, mint);
+    assert_eq!(parsed.verified_collection, Some(collection));
+  }
+
+  #[test]
+  fn ignores_unverified_or_missing_collection() {
+    let mint = Pubkey::new_unique();
+    let unverified = metadata_bytes(&mint, Some((false, Pubkey::new_unique())));
+    assert_eq!(
+      parse_nft_metadata(&unverified).unwrap().verified_collection,
+      None
+    );
+    let missing = metadata_bytes(&mint, None);
+    assert_eq!(
+      parse_nft_metadata(&missing).unwrap().verified_collection,
+      None
+    );
+  }
+
+  #[test]
+  fn rejects_malformed_metadata() {
+    let mut data = metadata_bytes(&Pubkey::new_unique(), None);
+    assert!(parse_nft_metadata(&data[..40]).is_none());
+    data[0] = 0;
+    assert!(parse_nft_metadata(&data).is_none());
+  }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::solana_program::hash::hash;
+
+/// `UpgradeableLoaderState::Buffer` tag (bincode u32)
+const BUFFER_STATE_TAG: u32 = 1;
+/// Tag + Option<Pubkey> authority
+const BUFFER_METADATA_LEN: usize = 37;
+
+/// SHA-256 of the bytecode region of an upgradeable loader buffer
+///
+/// Covers every byte after the 37-byte buffer header, trailing zero padding
+/// included, so clients must hash the account data the same way. Returns
+/// None when the data is not a buffer.
+pub fn buffer_bytecode_hash(data: &[u8]) -> Option<[u8; 32]> {
+  let tag = data.get(..4)?;
+  if u32::from_le_bytes([tag[0], tag[1], tag[2], tag[3]]) != BUFFER_STATE_TAG {
+    return None;
+  }
+  let bytecode = data.get(BUFFER_METADATA_LEN..)?;
+  Some(hash(bytecode).to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn buffer_data(bytecode: &[u8]) -> Vec<u8> {
+    let mut data = BUFFER_STATE_TAG.to_le_bytes().to_vec();
+    data.push(1);
+    data.extend_from_slice(&[7; 32]);
+    data.extend_from_slice(bytecode);
+    data
+  }
+
+  #[test]
+  fn hashes_only_the_bytecode() {
+    let data = buffer_data(b"\x7fELF program");
+    assert_eq!(
+      buffer_bytecode_hash(&data),
+      Some(hash(b"\x7fELF program").to_bytes())
+    );
+    // The authority is part of the header, not the hash
+    let mut other_authority = data.clone();
+    other_authority[5] = 9;
+    assert_eq!(
+      buffer_bytecode_hash(&other_authority),
+      buffer_bytecode_hash(&data)
+    );
+  }
+
+  #[test]
+  fn rejects_non_buffer_accounts() {
+    let mut data = buffer_data(b"code");
+    data[0] = 3; // ProgramData
+    assert!(buffer_bytecode_hash(&data).is_none());
+    assert!(buffer_bytecode_hash(&data[..2]).is_none());
+  }
+}
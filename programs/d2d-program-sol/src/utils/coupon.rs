@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::CouponRedeemed, states::Coupon};
+
+/// Redeem an optional coupon against a new request's service fee
+///
+/// Shared by create_deploy_request and request_deployment_funds; the caller
+/// must present the coupon's code, not just its account. Returns the service
+/// fee left to pay.
+pub fn apply_coupon(
+  coupon: Option<&mut Account<Coupon>>,
+  coupon_code: Option<&str>,
+  request_id: [u8; 32],
+  developer: Pubkey,
+  service_fee: u64,
+  current_time: i64,
+) -> Result<u64> {
+  let Some(coupon) = coupon else {
+    return Ok(service_fee);
+  };
+
+  coupon.check_code(coupon_code)?;
+  let discount = coupon.redeem(service_fee, current_time)?;
+  emit!(CouponRedeemed {
+    code_hash: coupon.code_hash,
+    request_id,
+    developer,
+    discount,
+    uses: coupon.uses,
+    redeemed_at: current_time,
+  });
+
+  Ok(service_fee - discount)
+}
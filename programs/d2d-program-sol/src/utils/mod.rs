@@ -0,0 +1,9 @@
+pub mod boost_nft;
+pub mod buffer;
+pub mod coupon;
+pub mod transfer;
+
+pub use boost_nft::*;
+pub use buffer::*;
+pub use coupon::*;
+pub use transfer::*;
@@ -0,0 +1,236 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{errors::ErrorCode, states::TreasuryPool};
+
+// === PDA LAMPORT TRANSFERS ===
+//
+// Program-owned PDAs cannot use the System Program transfer CPI, so lamports are
+// moved by direct mutation. All such moves go through these helpers so the
+// arithmetic, rent and tracked-balance invariants are checked in one place.
+
+/// Compute post-transfer balances, failing on underflow/overflow
+///
+/// Lamports are conserved: `from + to` is identical before and after.
+pub fn apply_lamport_transfer(
+  from_lamports: u64,
+  to_lamports: u64,
+  amount: u64,
+) -> Result<(u64, u64)> {
+  let new_from = from_lamports
+    .checked_sub(amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  let new_to = to_lamports
+    .checked_add(amount)
+    .ok_or(ErrorCode::CalculationOverflow)?;
+  Ok((new_from, new_to))
+}
+
+/// Lamports that can leave an account while keeping it rent-exempt
+pub fn payable_lamports(lamports: u64, rent_exemption: u64) -> u64 {
+  lamports.saturating_sub(rent_exemption)
+}
+
+/// Debit a tracked pool balance, failing if the pool doesn't hold enough
+pub fn debit_tracked_balance(tracked_balance: u64, amount: u64) -> Result<u64> {
+  require!(
+    amount <= tracked_balance,
+    ErrorCode::InsufficientTreasuryFunds
+  );
+  Ok(tracked_balance - amount)
+}
+
+/// Move lamports out of a program-owned account via direct mutation
+///
+/// No rent check - callers draining an account on close use this directly.
+pub fn pda_transfer(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+  if amount == 0 {
+    return Ok(());
+  }
+
+  let mut from_lamports = from.try_borrow_mut_lamports()?;
+  let mut to_lamports = to.try_borrow_mut_lamports()?;
+
+  let (new_from, new_to) = apply_lamport_transfer(**from_lamports, **to_lamports, amount)?;
+  **from_lamports = new_from;
+  **to_lamports = new_to;
+
+  Ok(())
+}
+
+/// Pay out lamports from a program-owned PDA via direct lamport mutation
+///
+/// The source PDA must keep its rent-exempt minimum after the payout,
+/// otherwise the runtime could garbage-collect pool state or the transaction
+/// would fail with an opaque rent error. Fails with `PayoutBelowRentExemption`.
+pub fn payout_from_pda(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+  if amount == 0 {
+    return Ok(());
+  }
+
+  let rent_exemption = Rent::get()?.minimum_balance(from.data_len());
+  require!(
+    amount <= payable_lamports(from.lamports(), rent_exemption),
+    ErrorCode::PayoutBelowRentExemption
+  );
+
+  pda_transfer(from, to, amount)
+}
+
+/// Lamports a program-owned PDA can pay out while staying rent-exempt
+pub fn pda_payable_lamports(account: &AccountInfo) -> Result<u64> {
+  Ok(payable_lamports(
+    account.lamports(),
+    Rent::get()?.minimum_balance(account.data_len()),
+  ))
+}
+
+/// Close a program-owned PDA: drain all lamports to `destination` and hand
+/// the account back to the system program (same as Anchor's `close =`)
+pub fn close_pda(account: &AccountInfo, destination: &AccountInfo) -> Result<()> {
+  pda_transfer(account, destination, account.lamports())?;
+  account.assign(&anchor_lang::system_program::ID);
+  account.resize(0)?;
+  Ok(())
+}
+
+/// Pay out from a pool PDA and debit its tracked balance together
+///
+/// Keeps the on-chain lamports and the accounting field (liquid_balance,
+/// reward_pool_balance, platform_pool_balance, escrow sol_balance, ...) in
+/// lockstep. The tracked balance is only updated once the transfer succeeds.
+pub fn pool_debit_credit(
+  from: &AccountInfo,
+  to: &AccountInfo,
+  amount: u64,
+  tracked_balance: &mut u64,
+) -> Result<()> {
+  let new_tracked_balance = debit_tracked_balance(*tracked_balance, amount)?;
+  payout_from_pda(from, to, amount)?;
+  *tracked_balance = new_tracked_balance;
+  Ok(())
+}
+
+// === TREASURY VAULT TRANSFERS ===
+//
+// Liquid principal sits in a data-less, system-owned vault PDA. Anything may
+// credit it; lamports leave it only through a System Program transfer signed
+// with the vault seeds.
+
+/// Lamports the vault can pay out while staying rent-exempt
+pub fn vault_payable_lamports(vault: &AccountInfo) -> Result<u64> {
+  Ok(payable_lamports(
+    vault.lamports(),
+    Rent::get()?.minimum_balance(0),
+  ))
+}
+
+/// Pay out lamports from the treasury vault via a signed System Program transfer
+///
+/// Fails with `PayoutBelowRentExemption` if the vault would drop below its
+/// rent-exempt minimum.
+pub fn vault_transfer<'info>(
+  system_program: &AccountInfo<'info>,
+  vault: &AccountInfo<'info>,
+  to: &AccountInfo<'info>,
+  amount: u64,
+  vault_bump: u8,
+) -> Result<()> {
+  if amount == 0 {
+    return Ok(());
+  }
+
+  require!(
+    amount <= vault_payable_lamports(vault)?,
+    ErrorCode::PayoutBelowRentExemption
+  );
+
+  let vault_seeds: &[&[u8]] = &[TreasuryPool::VAULT_SEED, &[vault_bump]];
+  system_program::transfer(
+    CpiContext::new_with_signer(
+      system_program.clone(),
+      system_program::Transfer {
+        from: vault.clone(),
+        to: to.clone(),
+      },
+      &[vault_seeds],
+    ),
+    amount,
+  )
+}
+
+/// Pay out from the treasury vault and debit liquid_balance together
+///
+/// Same lockstep guarantee as `pool_debit_credit`.
+pub fn vault_debit_credit<'info>(
+  system_program: &AccountInfo<'info>,
+  vault: &AccountInfo<'info>,
+  to: &AccountInfo<'info>,
+  amount: u64,
+  vault_bump: u8,
+  tracked_balance: &mut u64,
+) -> Result<()> {
+  let new_tracked_balance = debit_tracked_balance(*tracked_balance, amount)?;
+  vault_transfer(system_program, vault, to, amount, vault_bump)?;
+  *tracked_balance = new_tracked_balance;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn account_info<'a>(
+    key: &'a Pubkey,
+    owner: &'a Pubkey,
+    lamports: &'a mut u64,
+    data: &'a mut [u8],
+  ) -> AccountInfo<'a> {
+    AccountInfo::new(key, false, true, lamports, data, owner, false, 0)
+  }
+
+  #[test]
+  fn apply_lamport_transfer_conserves_lamports() {
+    let (from, to) = apply_lamport_transfer(1_000, 250, 400).unwrap();
+    assert_eq!(from, 600);
+    assert_eq!(to, 650);
+    assert_eq!(from + to, 1_250);
+  }
+
+  #[test]
+  fn apply_lamport_transfer_rejects_underflow_and_overflow() {
+    assert!(apply_lamport_transfer(100, 0, 101).is_err());
+    assert!(apply_lamport_transfer(100, u64::MAX, 1).is_err());
+  }
+
+  #[test]
+  fn payable_lamports_preserves_rent_exemption() {
+    assert_eq!(payable_lamports(2_000, 890), 1_110);
+    assert_eq!(payable_lamports(500, 890), 0);
+  }
+
+  #[test]
+  fn debit_tracked_balance_requires_sufficient_balance() {
+    assert_eq!(debit_tracked_balance(1_000, 1_000).unwrap(), 0);
+    assert!(debit_tracked_balance(999, 1_000).is_err());
+  }
+
+  #[test]
+  fn pda_transfer_moves_lamports() {
+    let owner = Pubkey::new_unique();
+    let (from_key, to_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (mut from_lamports, mut to_lamports) = (5_000u64, 0u64);
+    let (mut from_data, mut to_data) = ([0u8; 8], [0u8; 0]);
+
+    let from = account_info(&from_key, &owner, &mut from_lamports, &mut from_data);
+    let to = account_info(&to_key, &owner, &mut to_lamports, &mut to_data);
+
+    pda_transfer(&from, &to, 2_000).unwrap();
+    assert_eq!(from.lamports(), 3_000);
+    assert_eq!(to.lamports(), 2_000);
+
+    // Underflow leaves both balances untouched
+    assert!(pda_transfer(&from, &to, 3_001).is_err());
+    assert_eq!(from.lamports(), 3_000);
+    assert_eq!(to.lamports(), 2_000);
+  }
+}
@@ -19,6 +19,7 @@ describe("Reward-Per-Share Model", () => {
   
   // PDAs
   let treasuryPoolPda: PublicKey;
+  let treasuryVaultPda: PublicKey;
   let rewardPoolPda: PublicKey;
   let platformPoolPda: PublicKey;
   let backer1DepositPda: PublicKey;
@@ -47,6 +48,11 @@ describe("Reward-Per-Share Model", () => {
       program.programId
     );
     
+    [treasuryVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_vault")],
+      program.programId
+    );
+    
     [rewardPoolPda, rewardPoolBump] = PublicKey.findProgramAddressSync(
       [Buffer.from("reward_pool")],
       program.programId
@@ -97,7 +103,7 @@ describe("Reward-Per-Share Model", () => {
           treasuryPool: treasuryPoolPda,
           rewardPool: rewardPoolPda,
           platformPool: platformPoolPda,
-          treasuryPda: treasuryPoolPda,
+          treasuryVault: treasuryVaultPda,
           lenderStake: backer1DepositPda,
           lender: backer1.publicKey,
           systemProgram: SystemProgram.programId,
@@ -113,7 +119,7 @@ describe("Reward-Per-Share Model", () => {
           treasuryPool: treasuryPoolPda,
           rewardPool: rewardPoolPda,
           platformPool: platformPoolPda,
-          treasuryPda: treasuryPoolPda,
+          treasuryVault: treasuryVaultPda,
           lenderStake: backer2DepositPda,
           lender: backer2.publicKey,
           systemProgram: SystemProgram.programId,
@@ -264,7 +270,7 @@ describe("Reward-Per-Share Model", () => {
           .unstakeSol(unstakeAmount)
           .accounts({
             treasuryPool: treasuryPoolPda,
-            treasuryPda: treasuryPoolPda,
+            treasuryVault: treasuryVaultPda,
             lenderStake: backer1DepositPda,
             lender: backer1.publicKey,
             systemProgram: SystemProgram.programId,
@@ -306,7 +312,7 @@ describe("Reward-Per-Share Model", () => {
             .unstakeSol(unstakeAmount)
             .accounts({
               treasuryPool: treasuryPoolPda,
-              treasuryPda: treasuryPoolPda,
+              treasuryVault: treasuryVaultPda,
               lenderStake: backer1DepositPda,
               lender: backer1.publicKey,
               systemProgram: SystemProgram.programId,